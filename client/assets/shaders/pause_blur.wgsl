@@ -0,0 +1,40 @@
+#import bevy_ui::ui_vertex_output::UiVertexOutput
+
+// Rust의 PauseBlurUniform 구조체와 1:1로 대응되는 부분
+struct PauseBlurUniform {
+    // x: 블러 반경 (텍셀), y: 밝기 (0.0 ~ 1.0), z: 좌우 반전 (1.0이면 반전)
+    params: vec4<f32>,
+};
+
+@group(1) @binding(0) var<uniform> pause_blur: PauseBlurUniform;
+@group(1) @binding(1) var frame_texture: texture_2d<f32>;
+@group(1) @binding(2) var frame_sampler: sampler;
+
+const TAU: f32 = 6.28318530718;
+const NUM_TAPS: i32 = 8;
+
+@fragment
+fn fragment(in: UiVertexOutput) -> @location(0) vec4<f32> {
+    let radius = pause_blur.params.x;
+    let brightness = pause_blur.params.y;
+    let texel = 1.0 / vec2<f32>(textureDimensions(frame_texture));
+
+    // 미러 모드에서는 화면에 보이던 대로 좌우를 뒤집어 샘플링합니다.
+    let uv = select(in.uv, vec2<f32>(1.0 - in.uv.x, in.uv.y), pause_blur.params.z > 0.5);
+
+    // 중심과 두 개의 원 위에서 샘플링하여 가우시안에 가까운 블러를 만듭니다.
+    var color = textureSample(frame_texture, frame_sampler, uv).rgb;
+    var weight = 1.0;
+    for (var i = 0; i < NUM_TAPS; i++) {
+        let angle = f32(i) / f32(NUM_TAPS) * TAU;
+        let dir = vec2<f32>(cos(angle), sin(angle)) * texel * radius;
+
+        // 안쪽 원은 반 칸 비틀어서 바깥쪽 원의 빈틈을 채웁니다.
+        let inner = vec2<f32>(cos(angle + TAU / 16.0), sin(angle + TAU / 16.0)) * texel * radius * 0.5;
+        color += textureSample(frame_texture, frame_sampler, uv + inner).rgb * 0.8;
+        color += textureSample(frame_texture, frame_sampler, uv + dir).rgb * 0.4;
+        weight += 0.8 + 0.4;
+    }
+
+    return vec4<f32>(color / weight * brightness, 1.0);
+}
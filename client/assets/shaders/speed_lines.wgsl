@@ -0,0 +1,39 @@
+#import bevy_ui::ui_vertex_output::UiVertexOutput
+
+// Rust의 SpeedLineUniform 구조체와 1:1로 대응되는 부분
+struct SpeedLineUniform {
+    // x: 강도 (0.0 ~ 1.0), y: 경과 시간 (초)
+    params: vec4<f32>,
+};
+
+@group(1) @binding(0) var<uniform> speed_line: SpeedLineUniform;
+
+const TAU: f32 = 6.28318530718;
+const NUM_SLICES: f32 = 96.0;
+
+fn hash(n: f32) -> f32 {
+    return fract(sin(n * 12.9898) * 43758.5453);
+}
+
+@fragment
+fn fragment(in: UiVertexOutput) -> @location(0) vec4<f32> {
+    let intensity = speed_line.params.x;
+    let time = speed_line.params.y;
+
+    // 화면 중심을 원점으로 하는 좌표로 변환합니다.
+    let p = in.uv * 2.0 - 1.0;
+    let radius = length(p);
+    let angle = atan2(p.y, p.x);
+
+    // 각도를 일정한 조각으로 나누고, 조각마다 무작위로 선을 그립니다.
+    let slice = floor((angle / TAU + 0.5) * NUM_SLICES);
+    let seed = hash(slice);
+    let flicker = fract(seed + time * (1.5 + seed));
+    let streak = step(0.6, seed) * smoothstep(0.3, 1.0, flicker);
+
+    // 화면 가장자리에서만 선이 보이도록 합니다.
+    let mask = smoothstep(0.55, 1.1, radius);
+
+    let alpha = streak * mask * intensity * 0.6;
+    return vec4<f32>(1.0, 1.0, 1.0, alpha);
+}
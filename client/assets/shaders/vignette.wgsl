@@ -0,0 +1,22 @@
+#import bevy_ui::ui_vertex_output::UiVertexOutput
+
+// Rust의 VignetteUniform 구조체와 1:1로 대응되는 부분
+struct VignetteUniform {
+    // rgb: 화면 가장자리의 색상, a: 강도 (0.0 ~ 1.0)
+    color: vec4<f32>,
+};
+
+@group(1) @binding(0) var<uniform> vignette: VignetteUniform;
+
+@fragment
+fn fragment(in: UiVertexOutput) -> @location(0) vec4<f32> {
+    // 화면 중심을 원점으로 하는 좌표로 변환합니다.
+    let p = in.uv * 2.0 - 1.0;
+    let radius = length(p);
+
+    // 화면 가장자리로 갈수록 진해지도록 합니다.
+    let mask = smoothstep(0.6, 1.4, radius);
+
+    let alpha = mask * vignette.color.a * 0.7;
+    return vec4<f32>(vignette.color.rgb, alpha);
+}
@@ -20,8 +20,29 @@ const MASK: &[u8] = include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/assets/
 const_assert_eq!(OBFUSCATED_KEY.len(), 32);
 const_assert_eq!(MASK.len(), 32);
 
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
 // --- STRUCTURES ---
 
+/// The size and content hash of an asset file, recorded in the generated asset manifest.
+struct AssetEntry {
+    path: String,
+    size: u64,
+    hash: u64,
+}
+
+impl AssetEntry {
+    fn new(rel: &Path, filename: &str, from: &Path) -> Self {
+        let bytes = fs::read(from).unwrap();
+        Self {
+            path: asset_path(rel, filename),
+            size: bytes.len() as u64,
+            hash: fnv1a(&bytes),
+        }
+    }
+}
+
 #[derive(Deserialize)]
 struct Hierarchy {
     #[serde(default)]
@@ -57,25 +78,38 @@ fn main() {
     fs_extra::dir::create(&dst_path, true).unwrap();
 
     let mut handles = Vec::new();
+    let mut entries = Vec::new();
     copy_asset(
         src_path,
-        dst_path,
+        dst_path.clone(),
+        Path::new(""),
         &hierarchy,
         &CopyOptions::default(),
         &mut handles,
+        &mut entries,
     );
 
     for handle in handles {
         handle.join().unwrap();
     }
+
+    entries.sort_by(|a, b| a.path.cmp(&b.path));
+    let version = entries.iter().fold(FNV_OFFSET_BASIS, |version, entry| {
+        fnv1a_with(version, &entry.hash.to_le_bytes())
+    });
+
+    write_asset_manifest(&entries, version);
+    write_precache_list(&dst_path, &entries, version);
 }
 
 fn copy_asset(
     src: PathBuf,
     dst: PathBuf,
+    rel: &Path,
     hierarchy: &Hierarchy,
     options: &CopyOptions,
     handles: &mut Vec<JoinHandle<()>>,
+    entries: &mut Vec<AssetEntry>,
 ) {
     for filename in hierarchy.files.iter() {
         let mut from = src.clone();
@@ -89,6 +123,7 @@ fn copy_asset(
             "Could not find asset file! (PATH:{})",
             from.display()
         );
+        entries.push(AssetEntry::new(rel, filename, &from));
 
         fs_extra::file::copy(from, to, options).unwrap();
     }
@@ -105,13 +140,17 @@ fn copy_asset(
             "Could not find asset file! (PATH:{})",
             from.display()
         );
+        entries.push(AssetEntry::new(rel, filename, &from));
+        handles.push(encrypt_asset(from, to));
 
-        handles.push(thread::spawn(|| {
-            let key = reconstruct_key();
-            let plaintext = fs::read(from).unwrap();
-            let ciphertext = encrypted_bytes(&plaintext, &key);
-            fs::write(to, ciphertext).unwrap();
-        }));
+        // The web build loads the block-compressed variant of a texture in place of the PNG file,
+        // so it is shipped with the web build whenever the `compress-textures` command has created one.
+        let variant = format!("{}.ktx2", filename);
+        let from = src.join(&variant);
+        if is_web_target() && is_texture(filename) && from.is_file() {
+            entries.push(AssetEntry::new(rel, &variant, &from));
+            handles.push(encrypt_asset(from, dst.join(&variant)));
+        }
     }
 
     // 하위 디렉토리로 이동합니다.
@@ -130,8 +169,96 @@ fn copy_asset(
         fs_extra::dir::create(&dst, true).unwrap();
 
         // 에셋을 복사합니다.
-        copy_asset(src, dst, node, options, handles);
+        copy_asset(src, dst, &rel.join(dir), node, options, handles, entries);
+    }
+}
+
+fn encrypt_asset(from: PathBuf, to: PathBuf) -> JoinHandle<()> {
+    thread::spawn(|| {
+        let key = reconstruct_key();
+        let plaintext = fs::read(from).unwrap();
+        let ciphertext = encrypted_bytes(&plaintext, &key);
+        fs::write(to, ciphertext).unwrap();
+    })
+}
+
+/// Whether the assets are built for the web, the only build that loads the compressed variants.
+fn is_web_target() -> bool {
+    env::var("CARGO_CFG_TARGET_ARCH").is_ok_and(|arch| arch == "wasm32")
+}
+
+fn is_texture(filename: &str) -> bool {
+    filename.ends_with(".texture") || filename.ends_with(".sprite")
+}
+
+/// Returns the path of an asset as it is requested from the asset server.
+fn asset_path(rel: &Path, filename: &str) -> String {
+    let path = rel.join(filename);
+    let components: Vec<_> = path
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect();
+    components.join("/")
+}
+
+/// Writes the size and content hash of every asset file,
+/// so that loading progress can be weighted by size and cached assets can be invalidated.
+fn write_asset_manifest(entries: &[AssetEntry], version: u64) {
+    // Only the web build caches assets, so the version is unused elsewhere.
+    let mut source = String::from("#[cfg_attr(not(target_arch = \"wasm32\"), allow(dead_code))]\n");
+    source.push_str(&format!(
+        "pub const ASSET_MANIFEST_VERSION: u64 = {:#018x};\n\n",
+        version
+    ));
+    source.push_str("pub const ASSET_MANIFEST: &[ManifestEntry] = &[\n");
+    for entry in entries {
+        source.push_str(&format!(
+            "    ManifestEntry {{ path: {:?}, size: {}, hash: {:#018x} }},\n",
+            entry.path, entry.size, entry.hash
+        ));
     }
+    source.push_str("];\n");
+
+    let mut out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
+    out_path.push("asset_manifest.rs");
+    fs::write(out_path, source).unwrap();
+}
+
+/// Writes the list of files the service worker of the web build caches for offline play.
+/// The list holds the page, the script and module the bindings of the package are generated as,
+/// and every asset. The version changes whenever an asset or the package version changes,
+/// so that the service worker replaces its cache.
+fn write_precache_list(dst: &Path, entries: &[AssetEntry], version: u64) {
+    let name = env::var("CARGO_PKG_NAME").unwrap();
+    let package_version = env::var("CARGO_PKG_VERSION").unwrap();
+    let version = fnv1a_with(version, package_version.as_bytes());
+
+    let mut files = vec![
+        "./".to_string(),
+        "index.html".to_string(),
+        "manifest.webmanifest".to_string(),
+        format!("{}.js", name),
+        format!("{}_bg.wasm", name),
+    ];
+    files.extend(entries.iter().map(|entry| format!("assets/{}", entry.path)));
+    let list = serde_json::json!({
+        "version": format!("{:016x}", version),
+        "files": files,
+    });
+
+    let mut out_path = dst.to_path_buf();
+    out_path.push("precache.json");
+    fs::write(out_path, serde_json::to_string_pretty(&list).unwrap()).unwrap();
+}
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    fnv1a_with(FNV_OFFSET_BASIS, bytes)
+}
+
+fn fnv1a_with(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+    })
 }
 
 #[inline(never)]
@@ -0,0 +1,93 @@
+use bevy::{
+    asset::{AssetLoader, LoadContext, io::Reader},
+    prelude::*,
+    tasks::ConditionalSendFuture,
+};
+use serde::Deserialize;
+
+/// The beats of a looping background track, with the sections in which bells spawn on the beat.
+#[derive(Deserialize, Asset, TypePath)]
+pub struct BeatMap {
+    pub bpm: f32,
+    /// The time, in seconds, of the first beat of the track.
+    pub offset: f32,
+    /// The length of the track, in seconds, after which it loops.
+    pub length: f32,
+    #[serde(default)]
+    pub sections: Vec<RhythmSection>,
+}
+
+/// A run of beats on which a bell is placed.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RhythmSection {
+    /// The index of the first beat of the section.
+    pub start: u32,
+    /// The number of beats the section lasts.
+    pub beats: u32,
+    /// Places a bell on only one of this many beats.
+    #[serde(default = "RhythmSection::default_every")]
+    pub every: u32,
+    /// The lanes the bells are placed in, repeated through the section.
+    pub lanes: Vec<usize>,
+}
+
+impl RhythmSection {
+    const fn default_every() -> u32 {
+        1
+    }
+}
+
+impl BeatMap {
+    pub fn beat_duration(&self) -> f32 {
+        60.0 / self.bpm
+    }
+
+    /// Returns the time within the track and the lane of every bell, section by section.
+    pub fn bells(&self) -> impl Iterator<Item = (f32, usize)> + '_ {
+        self.sections.iter().flat_map(|section| {
+            (0..section.beats)
+                .step_by(section.every.max(1) as usize)
+                .enumerate()
+                .filter_map(|(i, beat)| {
+                    let lane = *section.lanes.get(i % section.lanes.len().max(1))?;
+                    let time = self.offset + (section.start + beat) as f32 * self.beat_duration();
+                    Some((time, lane))
+                })
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum BeatMapLoaderError {
+    #[error("Failed to load asset for the following reason:{0}")]
+    IO(#[from] std::io::Error),
+    #[error("Failed to decode asset for the following reason:{0}")]
+    Json(#[from] serde_json::Error),
+}
+
+#[derive(Default)]
+pub struct BeatMapLoader;
+
+impl AssetLoader for BeatMapLoader {
+    type Asset = BeatMap;
+    type Settings = ();
+    type Error = BeatMapLoaderError;
+
+    fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext,
+    ) -> impl ConditionalSendFuture<Output = Result<Self::Asset, Self::Error>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            let beat_map: BeatMap = serde_json::from_slice(&bytes)?;
+            Ok(beat_map)
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["beats"]
+    }
+}
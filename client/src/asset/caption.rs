@@ -0,0 +1,66 @@
+use bevy::{
+    asset::{AssetLoader, LoadContext, io::Reader},
+    platform::collections::HashMap,
+    prelude::*,
+    tasks::ConditionalSendFuture,
+};
+use serde::Deserialize;
+
+use super::locale::Locale;
+
+/// The subtitle text of each voice line, keyed by the file name of the line without its extension.
+#[derive(Deserialize, Asset, TypePath)]
+pub struct CaptionTable(pub HashMap<String, HashMap<Locale, String>>);
+
+impl CaptionTable {
+    /// Looks up the caption of a voice line by its asset path, falling back to English.
+    pub fn get(&self, path: &str, locale: Locale) -> Option<&str> {
+        let captions = self.0.get(Self::key(path))?;
+        captions
+            .get(&locale)
+            .or_else(|| captions.get(&Locale::En))
+            .map(String::as_str)
+    }
+
+    /// Strips the directory, extension and version query from an asset path.
+    fn key(path: &str) -> &str {
+        let path = path.split('?').next().unwrap_or(path);
+        let name = path.rsplit('/').next().unwrap_or(path);
+        name.split('.').next().unwrap_or(name)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CaptionTableLoaderError {
+    #[error("Failed to load asset for the following reason:{0}")]
+    IO(#[from] std::io::Error),
+    #[error("Failed to decode asset for the following reason:{0}")]
+    Json(#[from] serde_json::Error),
+}
+
+#[derive(Default)]
+pub struct CaptionTableLoader;
+
+impl AssetLoader for CaptionTableLoader {
+    type Asset = CaptionTable;
+    type Settings = ();
+    type Error = CaptionTableLoaderError;
+
+    fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext,
+    ) -> impl ConditionalSendFuture<Output = Result<Self::Asset, Self::Error>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            let table: CaptionTable = serde_json::from_slice(&bytes)?;
+            Ok(table)
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["captions"]
+    }
+}
@@ -0,0 +1,492 @@
+//! Converts the PNG textures and sprites into block-compressed KTX2 files,
+//! which the web build loads in place of the PNG files when the GPU supports them.
+//!
+//! The engine can only read KTX2 files, and no encoder is among the dependencies,
+//! so the BC1 and BC3 blocks are encoded here from the bounding box of their colors.
+//! ETC2 and ASTC, the formats of mobile GPUs, are not written.
+#![cfg(not(target_arch = "wasm32"))]
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use bevy::color::Srgba;
+use image::RgbaImage;
+use ruzstd::encoding::{CompressionLevel, compress_to_vec};
+
+use super::texture::KTX2_IDENTIFIER;
+
+const VK_FORMAT_BC1_RGBA_SRGB_BLOCK: u32 = 134;
+const VK_FORMAT_BC3_SRGB_BLOCK: u32 = 138;
+const SUPERCOMPRESSION_ZSTANDARD: u32 = 2;
+
+const KHR_DF_MODEL_BC1A: u8 = 128;
+const KHR_DF_MODEL_BC3: u8 = 130;
+const KHR_DF_PRIMARIES_BT709: u8 = 1;
+const KHR_DF_TRANSFER_SRGB: u8 = 2;
+const KHR_DF_CHANNEL_COLOR: u8 = 0x00;
+/// The alpha channel of a BC3 block, which is stored linearly.
+const KHR_DF_CHANNEL_ALPHA_LINEAR: u8 = 0x1F;
+
+const HEADER_LENGTH: usize = 80;
+const LEVEL_INDEX_LENGTH: usize = 24;
+const BLOCK_SIZE: u32 = 4;
+
+/// The block compression a texture is converted to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockFormat {
+    /// For opaque textures.
+    Bc1,
+    /// For textures with an alpha channel.
+    Bc3,
+}
+
+impl BlockFormat {
+    fn block_bytes(self) -> usize {
+        match self {
+            Self::Bc1 => 8,
+            Self::Bc3 => 16,
+        }
+    }
+
+    fn vk_format(self) -> u32 {
+        match self {
+            Self::Bc1 => VK_FORMAT_BC1_RGBA_SRGB_BLOCK,
+            Self::Bc3 => VK_FORMAT_BC3_SRGB_BLOCK,
+        }
+    }
+
+    fn color_model(self) -> u8 {
+        match self {
+            Self::Bc1 => KHR_DF_MODEL_BC1A,
+            Self::Bc3 => KHR_DF_MODEL_BC3,
+        }
+    }
+
+    /// The bit offset and channel of every sample in a block.
+    fn samples(self) -> &'static [(u16, u8)] {
+        match self {
+            Self::Bc1 => &[(0, KHR_DF_CHANNEL_COLOR)],
+            Self::Bc3 => &[(0, KHR_DF_CHANNEL_ALPHA_LINEAR), (64, KHR_DF_CHANNEL_COLOR)],
+        }
+    }
+}
+
+/// An error that can occur when converting a texture.
+#[derive(Debug, thiserror::Error)]
+pub enum CompressError {
+    #[error("{0}")]
+    IO(#[from] io::Error),
+    #[error("{0}")]
+    Decode(#[from] image::ImageError),
+    /// Block-compressed textures must be a multiple of the block size.
+    #[error("{0}x{1} is not a multiple of 4")]
+    Unaligned(u32, u32),
+}
+
+/// Returns the directory the build script reads the assets from.
+pub fn default_asset_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("assets")
+}
+
+/// Converts every texture and sprite in the directory, prints a report and returns the exit code of the process.
+/// Each KTX2 file is written next to its PNG file, with `.ktx2` appended to the file name.
+pub fn run(dir: &Path) -> i32 {
+    let mut files = Vec::new();
+    if let Err(e) = collect_textures(dir, &mut files) {
+        eprintln!("Failed to read {}: {}", dir.display(), e);
+        return 1;
+    }
+    files.sort();
+
+    println!("Compressing {} textures in {}", files.len(), dir.display());
+    let mut errors = 0;
+    for file in files.iter() {
+        let name = file.strip_prefix(dir).unwrap_or(file).display();
+        match compress_file(file) {
+            Ok((format, levels)) => println!("  {}: {:?}, {} level(s)", name, format, levels),
+            Err(CompressError::Unaligned(width, height)) => {
+                println!(
+                    "  {}: skipped ({}x{} is not a multiple of 4)",
+                    name, width, height
+                )
+            }
+            Err(e) => {
+                println!("  {}: {}", name, e);
+                errors += 1;
+            }
+        }
+    }
+
+    match errors {
+        0 => {
+            println!("All textures are compressed.");
+            0
+        }
+        _ => {
+            eprintln!("Failed to compress {} texture(s).", errors);
+            1
+        }
+    }
+}
+
+fn collect_textures(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_textures(&path, files)?;
+        } else if matches!(
+            path.extension().and_then(|ext| ext.to_str()),
+            Some("texture" | "sprite")
+        ) {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Converts one PNG file. Only world textures get mipmaps, since sprites are drawn at their own size.
+fn compress_file(path: &Path) -> Result<(BlockFormat, usize), CompressError> {
+    let image = image::load_from_memory_with_format(&fs::read(path)?, image::ImageFormat::Png)?;
+    let mipmaps = path.extension().is_some_and(|ext| ext == "texture");
+    let (format, levels) = encode_levels(&image.to_rgba8(), mipmaps)?;
+
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(".ktx2");
+    let bytes = write_ktx2(format, image.width(), image.height(), &levels);
+    fs::write(path.with_file_name(file_name), bytes)?;
+    Ok((format, levels.len()))
+}
+
+/// Returns the block data of every mip level, from the largest to the smallest.
+pub fn encode_levels(
+    image: &RgbaImage,
+    mipmaps: bool,
+) -> Result<(BlockFormat, Vec<Vec<u8>>), CompressError> {
+    let (width, height) = image.dimensions();
+    if width == 0 || height == 0 || width % BLOCK_SIZE != 0 || height % BLOCK_SIZE != 0 {
+        return Err(CompressError::Unaligned(width, height));
+    }
+
+    let format = match image.pixels().all(|texel| texel[3] == u8::MAX) {
+        true => BlockFormat::Bc1,
+        false => BlockFormat::Bc3,
+    };
+
+    let mut levels = vec![encode_blocks(image, format)];
+    if mipmaps {
+        let mut level = image.clone();
+        while level.width() > 1 || level.height() > 1 {
+            level = downsample(&level);
+            levels.push(encode_blocks(&level, format));
+        }
+    }
+    Ok((format, levels))
+}
+
+/// Halves the size of an image, averaging the colors in linear space.
+fn downsample(image: &RgbaImage) -> RgbaImage {
+    let (width, height) = image.dimensions();
+    RgbaImage::from_fn((width / 2).max(1), (height / 2).max(1), |x, y| {
+        let mut sum = [0.0; 4];
+        for (dx, dy) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+            let texel = image.get_pixel((x * 2 + dx).min(width - 1), (y * 2 + dy).min(height - 1));
+            for channel in 0..3 {
+                sum[channel] += Srgba::gamma_function(texel[channel] as f32 / 255.0);
+            }
+            sum[3] += texel[3] as f32 / 255.0;
+        }
+
+        let to_byte = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+        image::Rgba([
+            to_byte(Srgba::gamma_function_inverse(sum[0] / 4.0)),
+            to_byte(Srgba::gamma_function_inverse(sum[1] / 4.0)),
+            to_byte(Srgba::gamma_function_inverse(sum[2] / 4.0)),
+            to_byte(sum[3] / 4.0),
+        ])
+    })
+}
+
+fn encode_blocks(image: &RgbaImage, format: BlockFormat) -> Vec<u8> {
+    let (width, height) = image.dimensions();
+    let blocks_x = width.div_ceil(BLOCK_SIZE);
+    let blocks_y = height.div_ceil(BLOCK_SIZE);
+
+    let mut data = Vec::with_capacity((blocks_x * blocks_y) as usize * format.block_bytes());
+    for by in 0..blocks_y {
+        for bx in 0..blocks_x {
+            // Levels smaller than a block repeat their edge texels.
+            let block: [[u8; 4]; 16] = std::array::from_fn(|i| {
+                let x = (bx * BLOCK_SIZE + i as u32 % BLOCK_SIZE).min(width - 1);
+                let y = (by * BLOCK_SIZE + i as u32 / BLOCK_SIZE).min(height - 1);
+                image.get_pixel(x, y).0
+            });
+
+            if format == BlockFormat::Bc3 {
+                data.extend(encode_alpha_block(&block));
+            }
+            data.extend(encode_color_block(&block));
+        }
+    }
+    data
+}
+
+/// Encodes the colors of a block between two endpoints on the diagonal of their bounding box.
+fn encode_color_block(block: &[[u8; 4]; 16]) -> [u8; 8] {
+    let mut min = [u8::MAX; 3];
+    let mut max = [u8::MIN; 3];
+    let mut mean = [0.0f32; 3];
+    for texel in block.iter() {
+        for channel in 0..3 {
+            min[channel] = min[channel].min(texel[channel]);
+            max[channel] = max[channel].max(texel[channel]);
+            mean[channel] += texel[channel] as f32 / 16.0;
+        }
+    }
+
+    // Picks the diagonal of the bounding box the colors run along.
+    let (mut covariance_rg, mut covariance_bg) = (0.0, 0.0);
+    for texel in block.iter() {
+        let g = texel[1] as f32 - mean[1];
+        covariance_rg += (texel[0] as f32 - mean[0]) * g;
+        covariance_bg += (texel[2] as f32 - mean[2]) * g;
+    }
+    if covariance_rg < 0.0 {
+        std::mem::swap(&mut min[0], &mut max[0]);
+    }
+    if covariance_bg < 0.0 {
+        std::mem::swap(&mut min[2], &mut max[2]);
+    }
+
+    // Moves the endpoints in a little, which lowers the error of the colors in between.
+    for channel in 0..3 {
+        let inset = (max[channel] as i32 - min[channel] as i32) / 16;
+        max[channel] = (max[channel] as i32 - inset) as u8;
+        min[channel] = (min[channel] as i32 + inset) as u8;
+    }
+
+    let mut color0 = to_rgb565(max);
+    let mut color1 = to_rgb565(min);
+    if color0 < color1 {
+        std::mem::swap(&mut color0, &mut color1);
+    }
+
+    let mut indices = 0u32;
+    if color0 != color1 {
+        let (c0, c1) = (from_rgb565(color0), from_rgb565(color1));
+        let palette = [
+            c0,
+            c1,
+            std::array::from_fn(|i| (2 * c0[i] + c1[i]) / 3),
+            std::array::from_fn(|i| (c0[i] + 2 * c1[i]) / 3),
+        ];
+
+        for (i, texel) in block.iter().enumerate() {
+            let distance = |color: &[u32; 3]| -> u32 {
+                (0..3)
+                    .map(|channel| color[channel].abs_diff(texel[channel] as u32).pow(2))
+                    .sum()
+            };
+            let index = (0..4).min_by_key(|&k| distance(&palette[k])).unwrap_or(0);
+            indices |= (index as u32) << (i * 2);
+        }
+    }
+
+    let mut bytes = [0; 8];
+    bytes[0..2].copy_from_slice(&color0.to_le_bytes());
+    bytes[2..4].copy_from_slice(&color1.to_le_bytes());
+    bytes[4..8].copy_from_slice(&indices.to_le_bytes());
+    bytes
+}
+
+/// Encodes the alpha of a block between its lowest and highest alpha, with six values in between.
+fn encode_alpha_block(block: &[[u8; 4]; 16]) -> [u8; 8] {
+    let alpha0 = block.iter().map(|texel| texel[3]).max().unwrap_or(u8::MAX);
+    let alpha1 = block.iter().map(|texel| texel[3]).min().unwrap_or(u8::MAX);
+
+    let mut indices = 0u64;
+    if alpha0 != alpha1 {
+        let (a0, a1) = (alpha0 as u32, alpha1 as u32);
+        let palette: [u32; 8] = std::array::from_fn(|k| match k {
+            0 => a0,
+            1 => a1,
+            _ => ((8 - k as u32) * a0 + (k as u32 - 1) * a1) / 7,
+        });
+
+        for (i, texel) in block.iter().enumerate() {
+            let alpha = texel[3] as u32;
+            let index = (0..8)
+                .min_by_key(|&k| palette[k].abs_diff(alpha))
+                .unwrap_or(0);
+            indices |= (index as u64) << (i * 3);
+        }
+    }
+
+    let mut bytes = [0; 8];
+    bytes[0] = alpha0;
+    bytes[1] = alpha1;
+    bytes[2..8].copy_from_slice(&indices.to_le_bytes()[0..6]);
+    bytes
+}
+
+fn to_rgb565(color: [u8; 3]) -> u16 {
+    let r = (color[0] as u16 * 31 + 127) / 255;
+    let g = (color[1] as u16 * 63 + 127) / 255;
+    let b = (color[2] as u16 * 31 + 127) / 255;
+    (r << 11) | (g << 5) | b
+}
+
+fn from_rgb565(color: u16) -> [u32; 3] {
+    let r = (color >> 11) as u32 & 0x1F;
+    let g = (color >> 5) as u32 & 0x3F;
+    let b = color as u32 & 0x1F;
+    [
+        (r << 3) | (r >> 2),
+        (g << 2) | (g >> 4),
+        (b << 3) | (b >> 2),
+    ]
+}
+
+/// Writes the mip levels into a KTX2 file, each level supercompressed with Zstandard.
+pub fn write_ktx2(format: BlockFormat, width: u32, height: u32, levels: &[Vec<u8>]) -> Vec<u8> {
+    let descriptor = data_format_descriptor(format);
+    let descriptor_offset = HEADER_LENGTH + levels.len() * LEVEL_INDEX_LENGTH;
+    let compressed: Vec<Vec<u8>> = levels
+        .iter()
+        .map(|level| compress_to_vec(level.as_slice(), CompressionLevel::Fastest))
+        .collect();
+
+    // The levels are stored from the smallest to the largest.
+    let mut offsets = vec![0; levels.len()];
+    let mut offset = descriptor_offset + descriptor.len();
+    for (level, data) in compressed.iter().enumerate().rev() {
+        offsets[level] = offset;
+        offset += data.len();
+    }
+
+    let mut bytes = Vec::with_capacity(offset);
+    bytes.extend(KTX2_IDENTIFIER);
+    for value in [
+        format.vk_format(),
+        1, // type size
+        width,
+        height,
+        0, // depth
+        0, // layer count
+        1, // face count
+        levels.len() as u32,
+        SUPERCOMPRESSION_ZSTANDARD,
+        descriptor_offset as u32,
+        descriptor.len() as u32,
+        0, // key/value data offset
+        0, // key/value data length
+    ] {
+        bytes.extend(value.to_le_bytes());
+    }
+    bytes.extend(0u64.to_le_bytes()); // supercompression global data offset
+    bytes.extend(0u64.to_le_bytes()); // supercompression global data length
+
+    for ((offset, data), level) in offsets.iter().zip(compressed.iter()).zip(levels.iter()) {
+        bytes.extend((*offset as u64).to_le_bytes());
+        bytes.extend((data.len() as u64).to_le_bytes());
+        bytes.extend((level.len() as u64).to_le_bytes());
+    }
+
+    bytes.extend(descriptor);
+    for data in compressed.iter().rev() {
+        bytes.extend(data);
+    }
+    bytes
+}
+
+/// Returns the basic data format descriptor of a block format, in sRGB with straight alpha.
+fn data_format_descriptor(format: BlockFormat) -> Vec<u8> {
+    let samples = format.samples();
+    let block_size = 24 + 16 * samples.len();
+
+    let mut bytes = Vec::with_capacity(4 + block_size);
+    bytes.extend(((4 + block_size) as u32).to_le_bytes());
+    bytes.extend(0u32.to_le_bytes()); // Khronos vendor, basic descriptor type
+    bytes.extend(2u16.to_le_bytes()); // version
+    bytes.extend((block_size as u16).to_le_bytes());
+    bytes.extend([
+        format.color_model(),
+        KHR_DF_PRIMARIES_BT709,
+        KHR_DF_TRANSFER_SRGB,
+        0, // straight alpha
+    ]);
+    bytes.extend([3, 3, 0, 0]); // 4x4 texels per block
+    bytes.extend([format.block_bytes() as u8, 0, 0, 0, 0, 0, 0, 0]);
+
+    for &(bit_offset, channel) in samples {
+        bytes.extend(bit_offset.to_le_bytes());
+        bytes.push(63); // bit length - 1
+        bytes.push(channel);
+        bytes.extend([0; 4]); // sample position
+        bytes.extend(0u32.to_le_bytes()); // lower
+        bytes.extend(u32::MAX.to_le_bytes()); // upper
+    }
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{
+        asset::RenderAssetUsages,
+        image::{CompressedImageFormats, Image, ImageSampler, ImageType},
+        render::render_resource::TextureFormat,
+    };
+
+    use super::*;
+
+    fn load(image: &RgbaImage, mipmaps: bool) -> Image {
+        let (format, levels) = encode_levels(image, mipmaps).unwrap();
+        let bytes = write_ktx2(format, image.width(), image.height(), &levels);
+        Image::from_buffer(
+            &bytes,
+            ImageType::Extension("ktx2"),
+            CompressedImageFormats::BC,
+            true,
+            ImageSampler::Default,
+            RenderAssetUsages::RENDER_WORLD,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn writes_ktx2_files_the_engine_can_load() {
+        let opaque = RgbaImage::from_fn(16, 8, |x, y| {
+            image::Rgba([x as u8 * 16, y as u8 * 32, 0, 255])
+        });
+        let image = load(&opaque, true);
+        assert_eq!(
+            image.texture_descriptor.format,
+            TextureFormat::Bc1RgbaUnormSrgb
+        );
+        assert_eq!(image.texture_descriptor.mip_level_count, 5);
+
+        let translucent = RgbaImage::from_pixel(8, 8, image::Rgba([255, 0, 0, 128]));
+        let image = load(&translucent, false);
+        assert_eq!(
+            image.texture_descriptor.format,
+            TextureFormat::Bc3RgbaUnormSrgb
+        );
+        assert_eq!(image.texture_descriptor.mip_level_count, 1);
+    }
+
+    #[test]
+    fn keeps_solid_colors_exact() {
+        let block = [[255, 0, 0, 128]; 16];
+        let color = encode_color_block(&block);
+        assert_eq!(u16::from_le_bytes([color[0], color[1]]), 0xF800);
+        let alpha = encode_alpha_block(&block);
+        assert_eq!(alpha, [128, 128, 0, 0, 0, 0, 0, 0]);
+
+        let image = RgbaImage::new(6, 4);
+        assert!(matches!(
+            encode_levels(&image, false),
+            Err(CompressError::Unaligned(6, 4))
+        ));
+    }
+}
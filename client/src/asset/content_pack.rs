@@ -0,0 +1,137 @@
+use bevy::{
+    asset::{AssetLoader, LoadContext, io::Reader},
+    platform::collections::HashMap,
+    prelude::*,
+    tasks::ConditionalSendFuture,
+};
+use serde::Deserialize;
+use shupogaki_core::{Object, collider::Collider};
+
+use super::locale::Locale;
+
+/// The asset source that content packs are read from.
+/// Native builds read the `mods` directory, and the web build requests `mods/` next to the page.
+pub const CONTENT_PACK_SOURCE: &str = "mods";
+
+/// A content pack that adds obstacles, spawn patterns, skins and strings to the game.
+/// Model paths are relative to the pack file.
+#[derive(Deserialize, Asset, TypePath)]
+pub struct ContentPack {
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+    /// Packs are registered in ascending order, and a later pack wins a conflict.
+    #[serde(default)]
+    pub load_order: i32,
+    #[serde(default)]
+    pub obstacles: Vec<PackObstacle>,
+    #[serde(default)]
+    pub patterns: Vec<PackPattern>,
+    #[serde(default)]
+    pub skins: Vec<PackSkin>,
+    #[serde(default)]
+    pub strings: HashMap<Locale, HashMap<String, String>>,
+}
+
+/// A new obstacle, which hits the player like the built-in obstacle it is based on.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PackObstacle {
+    pub id: String,
+    pub base: Object,
+    pub model: String,
+    pub collider: Collider,
+    /// How often the obstacle spawns, on the same scale as the built-in objects.
+    pub weight: u32,
+}
+
+/// The lanes an obstacle is placed in together.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PackPattern {
+    pub id: String,
+    pub obstacle: String,
+    pub lanes: Vec<usize>,
+    #[serde(default = "PackPattern::default_weight")]
+    pub weight: u32,
+}
+
+impl PackPattern {
+    const fn default_weight() -> u32 {
+        1
+    }
+}
+
+/// Replaces the model of a built-in object.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PackSkin {
+    pub object: Object,
+    pub model: String,
+}
+
+/// The list of pack files the web build loads, since it cannot read a directory.
+#[derive(Debug, Deserialize, Asset, TypePath)]
+pub struct ContentPackIndex(pub Vec<String>);
+
+#[derive(Debug, thiserror::Error)]
+pub enum ContentPackLoaderError {
+    /// An I/O error occurred while reading the asset file.
+    #[error("Failed to load asset for the following reason:{0}")]
+    IO(#[from] std::io::Error),
+    /// A JSON deserialization error occurred.
+    #[error("Failed to decode asset for the following reason:{0}")]
+    Json(#[from] serde_json::Error),
+}
+
+#[derive(Default)]
+pub struct ContentPackLoader;
+
+impl AssetLoader for ContentPackLoader {
+    type Asset = ContentPack;
+    type Settings = ();
+    type Error = ContentPackLoaderError;
+
+    fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        load_context: &mut LoadContext,
+    ) -> impl ConditionalSendFuture<Output = Result<Self::Asset, Self::Error>> {
+        info!("asset load: {}", &load_context.asset_path());
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            let pack: ContentPack = serde_json::from_slice(&bytes)?;
+            Ok(pack)
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["pack"]
+    }
+}
+
+#[derive(Default)]
+pub struct ContentPackIndexLoader;
+
+impl AssetLoader for ContentPackIndexLoader {
+    type Asset = ContentPackIndex;
+    type Settings = ();
+    type Error = ContentPackLoaderError;
+
+    fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext,
+    ) -> impl ConditionalSendFuture<Output = Result<Self::Asset, Self::Error>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            let index: ContentPackIndex = serde_json::from_slice(&bytes)?;
+            Ok(index)
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["packs"]
+    }
+}
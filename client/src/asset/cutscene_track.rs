@@ -0,0 +1,127 @@
+use bevy::{
+    asset::{AssetLoader, LoadContext, io::Reader},
+    prelude::*,
+    tasks::ConditionalSendFuture,
+};
+use serde::Deserialize;
+
+use crate::scene::{CameraKeyframe, CutsceneCue};
+
+/// A cutscene authored as a RON file: a keyframed camera track and the cues fired along it.
+/// Keyframes and cues must be sorted by time.
+#[derive(Deserialize, Asset, TypePath)]
+pub struct CutsceneTrack {
+    #[serde(default)]
+    pub camera: Vec<CameraKeyframe>,
+    #[serde(default)]
+    pub cues: Vec<CueKey>,
+}
+
+/// A cue fired once when the playback reaches its time.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CueKey {
+    pub time: f32,
+    pub cue: CutsceneCue,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CutsceneTrackLoaderError {
+    #[error("Failed to load asset for the following reason:{0}")]
+    IO(#[from] std::io::Error),
+    #[error("Failed to decode asset for the following reason:{0}")]
+    Ron(#[from] ron::error::SpannedError),
+}
+
+#[derive(Default)]
+pub struct CutsceneTrackLoader;
+
+impl AssetLoader for CutsceneTrackLoader {
+    type Asset = CutsceneTrack;
+    type Settings = ();
+    type Error = CutsceneTrackLoaderError;
+
+    fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext,
+    ) -> impl ConditionalSendFuture<Output = Result<Self::Asset, Self::Error>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            let track: CutsceneTrack = ron::de::from_bytes(&bytes)?;
+            Ok(track)
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["cutscene"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scene::{Cutscene, CutsceneActor, Easing};
+
+    use super::*;
+
+    fn parse(source: &str) -> CutsceneTrack {
+        ron::de::from_str(source).unwrap()
+    }
+
+    #[test]
+    fn parses_the_cutscenes_of_the_game() {
+        for source in [
+            include_str!("../../assets/cutscenes/Departure.cutscene"),
+            include_str!("../../assets/cutscenes/Result.cutscene"),
+        ] {
+            let track = parse(source);
+            assert!(!track.camera.is_empty());
+            assert!(track.camera.is_sorted_by(|a, b| a.time <= b.time));
+            assert!(track.cues.is_sorted_by(|a, b| a.time <= b.time));
+        }
+    }
+
+    #[test]
+    fn eases_each_key_with_its_own_curve() {
+        let track = parse(
+            "(camera: [
+                (time: 0.0, position: (0.0, 0.0, 0.0), look_at: (0.0, 0.0, 1.0), zoom: 1.0),
+                (time: 1.0, position: (1.0, 0.0, 0.0), look_at: (0.0, 0.0, 1.0), zoom: 1.0, ease: Linear),
+                (time: 2.0, position: (2.0, 0.0, 0.0), look_at: (0.0, 0.0, 1.0), zoom: 1.0, ease: Cut),
+            ])",
+        );
+        assert_eq!(track.camera[0].ease, Easing::Smooth);
+
+        let at = |time| CameraKeyframe::sample(&track.camera, time).unwrap();
+        assert!((at(0.25).position.x - 0.25).abs() < 1e-6);
+        assert!((at(1.5).position.x - 1.0).abs() < 1e-6);
+        assert!((at(2.0).position.x - 2.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn fires_cues_once_and_again_after_seeking_back() {
+        let track = parse(
+            r#"(cues: [
+                (time: 0.5, cue: Sound("sounds/SFX_TrainSteamWhistle.sound")),
+                (time: 1.0, cue: Mouth(2)),
+                (time: 1.0, cue: Hide(Nozomi)),
+            ])"#,
+        );
+        let mut cutscene = Cutscene::from_track(&track);
+
+        assert!(cutscene.tick(0.4).is_empty());
+        assert_eq!(cutscene.tick(0.2).len(), 1);
+        assert_eq!(
+            cutscene.tick(0.5),
+            vec![
+                CutsceneCue::Mouth(2),
+                CutsceneCue::Hide(CutsceneActor::Nozomi)
+            ]
+        );
+        assert!(cutscene.tick(1.0).is_empty());
+
+        assert_eq!(cutscene.seek(0.75).len(), 1);
+        assert_eq!(cutscene.tick(0.25).len(), 2);
+    }
+}
@@ -8,7 +8,7 @@ use bevy::{
 };
 use serde::Deserialize;
 
-#[derive(Default, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Default, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Locale {
     #[default]
     En,
@@ -16,6 +16,26 @@ pub enum Locale {
     Ko,
 }
 
+impl Locale {
+    /// Returns the locale of a language code, as the locale files are named.
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code {
+            "en" => Some(Self::En),
+            "ja" => Some(Self::Ja),
+            "ko" => Some(Self::Ko),
+            _ => None,
+        }
+    }
+
+    fn index(self) -> usize {
+        match self {
+            Self::En => 0,
+            Self::Ja => 1,
+            Self::Ko => 2,
+        }
+    }
+}
+
 impl fmt::Display for Locale {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -37,6 +57,77 @@ pub struct LocalizationAssets {
 #[derive(Deserialize, Asset, TypePath)]
 pub struct LocalizationData(pub HashMap<String, String>);
 
+impl LocalizationData {
+    /// Adds the built-in strings of the locale that the locale file does not have.
+    fn with_builtin_strings(mut self, locale: Locale) -> Self {
+        for (key, translations) in BUILTIN_STRINGS {
+            self.0
+                .entry(key.to_string())
+                .or_insert_with(|| translations[locale.index()].to_string());
+        }
+        self
+    }
+}
+
+/// The strings of the interface that are not in the locale files, in English, Japanese and Korean.
+/// A string of the same key in a locale file takes precedence.
+#[rustfmt::skip]
+const BUILTIN_STRINGS: &[(&str, [&str; 3])] = &[
+    ("export", ["Export", "エクスポート", "내보내기"]),
+    ("import", ["Import", "インポート", "가져오기"]),
+    ("import_waiting", ["...", "...", "..."]),
+    ("import_confirm", ["Confirm", "確認", "확인"]),
+    ("import_invalid", ["Invalid", "無効", "잘못된 코드"]),
+    ("imported", ["Imported", "完了", "완료"]),
+    ("master", ["Master", "マスター", "마스터"]),
+    ("mute", ["Mute", "ミュート", "음소거"]),
+    ("output", ["Output", "出力", "출력"]),
+    ("system_default", ["System Default", "システム既定", "시스템 기본값"]),
+    ("speed_lines", ["Speed Lines", "集中線", "속도선"]),
+    ("reduce_motion", ["Reduce Motion", "視差効果を減らす", "동작 줄이기"]),
+    ("captions", ["Captions", "字幕", "자막"]),
+    ("jump_guide", ["Jump Guide", "ジャンプガイド", "점프 가이드"]),
+    ("pace_clock", ["Pace Clock", "ペース表示", "페이스 표시"]),
+    ("scattering", ["Scattering", "大気散乱", "대기 산란"]),
+    ("camera_classic", ["Classic", "クラシック", "클래식"]),
+    ("camera_low_chase", ["Low Chase", "ローチェイス", "낮은 추격"]),
+    ("camera_high", ["High", "ハイ", "높이"]),
+    ("render_scale_native", ["Native", "ネイティブ", "기본 해상도"]),
+    ("render_scale_75", ["Auto 75%", "自動 75%", "자동 75%"]),
+    ("render_scale_50", ["Auto 50%", "自動 50%", "자동 50%"]),
+    ("tier_mobile", ["Mobile", "モバイル", "모바일"]),
+    ("tier_desktop", ["Desktop", "デスクトップ", "데스크톱"]),
+    ("fog_off", ["No Fog", "霧なし", "안개 없음"]),
+    ("fog_linear", ["Linear Fog", "線形の霧", "선형 안개"]),
+    ("fog_exponential", ["Exp Fog", "指数の霧", "지수 안개"]),
+    ("assist_on", ["Assist: On", "アシスト: オン", "어시스트: 켜짐"]),
+    ("assist_off", ["Assist: Off", "アシスト: オフ", "어시스트: 꺼짐"]),
+    ("install", ["Install", "インストール", "설치"]),
+    ("mutators", ["Mutators", "ミューテーター", "뮤테이터"]),
+    ("double_speed", ["Double Speed", "倍速", "2배속"]),
+    ("double_speed_on", ["Double Speed: On", "倍速: オン", "2배속: 켜짐"]),
+    ("double_speed_off", ["Double Speed: Off", "倍速: オフ", "2배속: 꺼짐"]),
+    ("one_hit_fuel", ["One-Hit Fuel", "一撃で燃料切れ", "한 방 연료"]),
+    ("one_hit_fuel_on", ["One-Hit Fuel: On", "一撃で燃料切れ: オン", "한 방 연료: 켜짐"]),
+    ("one_hit_fuel_off", ["One-Hit Fuel: Off", "一撃で燃料切れ: オフ", "한 방 연료: 꺼짐"]),
+    ("mirror_lanes", ["Mirror Mode", "ミラーモード", "미러 모드"]),
+    ("mirror_lanes_on", ["Mirror Mode: On", "ミラーモード: オン", "미러 모드: 켜짐"]),
+    ("mirror_lanes_off", ["Mirror Mode: Off", "ミラーモード: オフ", "미러 모드: 꺼짐"]),
+    ("no_fuel_pickups", ["No Fuel Pickups", "燃料アイテムなし", "연료 아이템 없음"]),
+    ("no_fuel_pickups_on", ["No Fuel Pickups: On", "燃料アイテムなし: オン", "연료 아이템 없음: 켜짐"]),
+    ("no_fuel_pickups_off", ["No Fuel Pickups: Off", "燃料アイテムなし: オフ", "연료 아이템 없음: 꺼짐"]),
+    ("hardcore", ["Hardcore", "ハードコア", "하드코어"]),
+    ("hardcore_on", ["Hardcore: On", "ハードコア: オン", "하드코어: 켜짐"]),
+    ("hardcore_off", ["Hardcore: Off", "ハードコア: オフ", "하드코어: 꺼짐"]),
+    ("chip_seed", ["Seed", "シード", "시드"]),
+    ("chip_endless", ["ENDLESS", "エンドレス", "무한"]),
+    ("chip_daily", ["DAILY", "デイリー", "데일리"]),
+    ("chip_practice", ["PRACTICE", "練習", "연습"]),
+    ("chip_assist", ["ASSIST", "アシスト", "어시스트"]),
+    ("copy_seed", ["Copy Seed", "シードをコピー", "시드 복사"]),
+    ("miss", ["Miss", "ミス", "놓침"]),
+];
+
 #[derive(Debug, thiserror::Error)]
 pub enum LocalizationDataLoaderError {
     /// An I/O error occurred while reading the asset file.
@@ -59,13 +150,22 @@ impl AssetLoader for LocalizationDataLoader {
         &self,
         reader: &mut dyn Reader,
         _settings: &Self::Settings,
-        _load_context: &mut LoadContext,
+        load_context: &mut LoadContext,
     ) -> impl ConditionalSendFuture<Output = Result<Self::Asset, Self::Error>> {
+        // The locale files are named after their language code.
+        let locale = load_context
+            .path()
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .and_then(Locale::from_code);
         Box::pin(async move {
             let mut bytes = Vec::new();
             reader.read_to_end(&mut bytes).await?;
             let data: LocalizationData = serde_json::from_slice(&bytes)?;
-            Ok(data)
+            Ok(match locale {
+                Some(locale) => data.with_builtin_strings(locale),
+                None => data,
+            })
         })
     }
 
@@ -73,3 +173,51 @@ impl AssetLoader for LocalizationDataLoader {
         &["json"]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locale_files_take_precedence_over_builtin_strings() {
+        let mut strings = HashMap::default();
+        strings.insert("import".to_string(), "Load".to_string());
+        let data = LocalizationData(strings).with_builtin_strings(Locale::Ko);
+
+        assert_eq!(data.0["import"], "Load");
+        assert_eq!(data.0["export"], "내보내기");
+        assert_eq!(Locale::from_code(&Locale::Ja.to_string()), Some(Locale::Ja));
+    }
+
+    #[test]
+    fn option_and_mutator_labels_have_builtin_strings() {
+        use crate::scene::Mutator;
+        use crate::scene::{CameraPreset, DeviceTier, FogMode, RenderScaleLimit};
+
+        let mut keys: Vec<String> = Vec::new();
+        keys.extend(
+            CameraPreset::ALL
+                .iter()
+                .map(|preset| preset.label().to_string()),
+        );
+        keys.extend(
+            RenderScaleLimit::ALL
+                .iter()
+                .map(|limit| limit.label().to_string()),
+        );
+        keys.extend(DeviceTier::ALL.iter().map(|tier| tier.label().to_string()));
+        keys.extend(FogMode::ALL.iter().map(|mode| mode.label().to_string()));
+        for mutator in Mutator::ALL {
+            keys.push(mutator.label().to_string());
+            keys.push(format!("{}_on", mutator.label()));
+            keys.push(format!("{}_off", mutator.label()));
+        }
+
+        for key in keys {
+            assert!(
+                BUILTIN_STRINGS.iter().any(|(other, _)| *other == key),
+                "missing builtin string: {key}"
+            );
+        }
+    }
+}
@@ -0,0 +1,19 @@
+/// The size and content hash of an asset file, recorded by the build script.
+#[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+pub struct ManifestEntry {
+    pub path: &'static str,
+    pub size: u64,
+    pub hash: u64,
+}
+
+include!(concat!(env!("OUT_DIR"), "/asset_manifest.rs"));
+
+/// Returns the manifest entry of the asset at the given path.
+/// The query string of the path is ignored.
+pub fn find(path: &str) -> Option<&'static ManifestEntry> {
+    let path = path.split('?').next().unwrap_or(path);
+    ASSET_MANIFEST
+        .binary_search_by(|entry| entry.path.cmp(path))
+        .ok()
+        .map(|index| &ASSET_MANIFEST[index])
+}
@@ -1,8 +1,16 @@
 pub mod animation;
+pub mod beat_map;
+pub mod caption;
+pub mod compress;
+pub mod content_pack;
+pub mod cutscene_track;
 pub mod locale;
+pub mod manifest;
 pub mod material;
 pub mod mesh;
 pub mod model;
+pub mod preflight;
+pub mod size;
 pub mod sound;
 pub mod spawner;
 pub mod sprite;
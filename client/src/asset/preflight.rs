@@ -0,0 +1,136 @@
+//! Checks the shipped asset directory against the manifest recorded by the build script,
+//! so that content authors can catch a broken asset before it reaches players.
+#![cfg(not(target_arch = "wasm32"))]
+
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use super::{
+    decrypt_bytes,
+    manifest::{ASSET_MANIFEST, ManifestEntry},
+    reconstruct_key,
+};
+
+/// The largest asset file the game is expected to load in one piece.
+const MAX_ASSET_SIZE: u64 = 16 * 1024 * 1024;
+
+// The same hash the build script records in the manifest.
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x100_0000_01b3;
+
+/// A problem found with one asset file.
+#[derive(Debug, PartialEq, Eq)]
+pub enum AssetProblem {
+    /// The file does not exist or could not be read.
+    Missing,
+    /// The file is larger than the game is expected to load.
+    Oversized { size: u64 },
+    /// The file could not be decrypted, or its content differs from the manifest.
+    Malformed { reason: String },
+}
+
+impl fmt::Display for AssetProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing => write!(f, "missing"),
+            Self::Oversized { size } => {
+                write!(f, "oversized ({} bytes, limit {})", size, MAX_ASSET_SIZE)
+            }
+            Self::Malformed { reason } => write!(f, "malformed ({})", reason),
+        }
+    }
+}
+
+/// Returns the directory the build script copies the assets into.
+pub fn default_asset_dir() -> PathBuf {
+    Path::new(env!("CARGO_WORKSPACE_DIR"))
+        .join("target")
+        .join("assets")
+}
+
+/// Checks every asset in the manifest, prints a report and returns the exit code of the process.
+pub fn run(dir: &Path) -> i32 {
+    println!(
+        "Validating {} assets in {}",
+        ASSET_MANIFEST.len(),
+        dir.display()
+    );
+
+    let key = reconstruct_key();
+    let mut errors = 0;
+    for entry in ASSET_MANIFEST {
+        for problem in validate(dir, entry, &key) {
+            println!("  {}: {}", entry.path, problem);
+            errors += 1;
+        }
+    }
+
+    match errors {
+        0 => {
+            println!("All assets are valid.");
+            0
+        }
+        _ => {
+            eprintln!("Found {} problem(s).", errors);
+            1
+        }
+    }
+}
+
+/// Returns every problem with the file of the given manifest entry.
+fn validate(dir: &Path, entry: &ManifestEntry, key: &[u8]) -> Vec<AssetProblem> {
+    let bytes = match fs::read(dir.join(entry.path)) {
+        Ok(bytes) => bytes,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return vec![AssetProblem::Missing],
+        Err(e) => {
+            return vec![AssetProblem::Malformed {
+                reason: e.to_string(),
+            }];
+        }
+    };
+
+    let mut problems = Vec::new();
+    if bytes.len() as u64 > MAX_ASSET_SIZE {
+        problems.push(AssetProblem::Oversized {
+            size: bytes.len() as u64,
+        });
+    }
+    if let Some(reason) = check_content(&bytes, entry, key) {
+        problems.push(AssetProblem::Malformed { reason });
+    }
+    problems
+}
+
+/// Compares the content of a file with the manifest, decrypting it first if it was encrypted by the build script.
+/// Returns why the content does not match, if it doesn't.
+fn check_content(bytes: &[u8], entry: &ManifestEntry, key: &[u8]) -> Option<String> {
+    if matches(bytes, entry) {
+        return None;
+    }
+
+    match decrypt_bytes(bytes, key) {
+        Ok(plaintext) if matches(&plaintext, entry) => None,
+        Ok(plaintext) if plaintext.len() as u64 != entry.size => Some(format!(
+            "expected {} bytes, found {}",
+            entry.size,
+            plaintext.len()
+        )),
+        Ok(_) => Some("content hash does not match the manifest".to_string()),
+        Err(_) if bytes.len() as u64 == entry.size => {
+            Some("content hash does not match the manifest".to_string())
+        }
+        Err(e) => Some(e.to_string()),
+    }
+}
+
+fn matches(bytes: &[u8], entry: &ManifestEntry) -> bool {
+    bytes.len() as u64 == entry.size && fnv1a(bytes) == entry.hash
+}
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(FNV_OFFSET_BASIS, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+    })
+}
@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+
+use super::manifest;
+
+/// The weight of an asset whose size was not recorded by the build script.
+const DEFAULT_ASSET_SIZE: u64 = 4 * 1024;
+
+/// Returns the approximate size, in bytes, of the asset at the given path.
+/// The query string of the path is ignored.
+pub fn asset_size(path: &str) -> u64 {
+    manifest::find(path)
+        .map(|entry| entry.size)
+        .filter(|&size| size > 0)
+        .unwrap_or(DEFAULT_ASSET_SIZE)
+}
+
+/// Returns how much of the given assets has been loaded, from 0.0 to 1.0, weighted by their size.
+pub fn loaded_fraction(asset_server: &AssetServer, handles: &[UntypedHandle]) -> f32 {
+    let (loaded, total) = handles.iter().fold((0, 0), |(loaded, total), handle| {
+        let size = handle
+            .path()
+            .map(|path| asset_size(&path.path().to_string_lossy().replace('\\', "/")))
+            .unwrap_or(DEFAULT_ASSET_SIZE);
+
+        match asset_server.is_loaded_with_dependencies(handle.id()) {
+            true => (loaded + size, total + size),
+            false => (loaded, total + size),
+        }
+    });
+
+    match total {
+        0 => 1.0,
+        _ => loaded as f32 / total as f32,
+    }
+}
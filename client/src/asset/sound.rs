@@ -7,33 +7,122 @@ use serde::Serialize;
 
 use super::*;
 
-#[derive(Debug, Resource, Deserialize, Serialize)]
+#[derive(Debug, Clone, Resource, Deserialize, Serialize)]
 pub struct SystemVolume {
+    #[serde(default = "SystemVolume::default_master")]
+    pub master: u8,
     pub background: u8,
     pub effect: u8,
     pub voice: u8,
+    #[serde(default)]
+    pub background_muted: bool,
+    #[serde(default)]
+    pub effect_muted: bool,
+    #[serde(default)]
+    pub voice_muted: bool,
+    #[serde(default)]
+    pub rolloff: AudioRolloff,
 }
 
 impl SystemVolume {
-    pub fn background_percentage(&self) -> f32 {
+    const fn default_master() -> u8 {
+        255
+    }
+
+    /// The master volume set on its slider, in the range of 0.0 to 1.0.
+    pub fn master_level(&self) -> f32 {
+        self.master as f32 / 255.0
+    }
+
+    /// The background volume set on its slider, ignoring the master volume and mute.
+    pub fn background_level(&self) -> f32 {
         self.background as f32 / 255.0
     }
 
-    pub fn effect_percentage(&self) -> f32 {
+    /// The effect volume set on its slider, ignoring the master volume and mute.
+    pub fn effect_level(&self) -> f32 {
         self.effect as f32 / 255.0
     }
 
-    pub fn voice_percentage(&self) -> f32 {
+    /// The voice volume set on its slider, ignoring the master volume and mute.
+    pub fn voice_level(&self) -> f32 {
         self.voice as f32 / 255.0
     }
+
+    /// The volume to play background sounds at (master × background × mute).
+    pub fn background_percentage(&self) -> f32 {
+        Self::mix(
+            self.master_level(),
+            self.background_level(),
+            self.background_muted,
+        )
+    }
+
+    /// The volume to play effect sounds at (master × effect × mute).
+    pub fn effect_percentage(&self) -> f32 {
+        Self::mix(self.master_level(), self.effect_level(), self.effect_muted)
+    }
+
+    /// The volume to play voice sounds at (master × voice × mute).
+    pub fn voice_percentage(&self) -> f32 {
+        Self::mix(self.master_level(), self.voice_level(), self.voice_muted)
+    }
+
+    fn mix(master: f32, category: f32, muted: bool) -> f32 {
+        if muted { 0.0 } else { master * category }
+    }
 }
 
 impl Default for SystemVolume {
     fn default() -> Self {
         Self {
+            master: Self::default_master(),
             background: 204,
             effect: 204,
             voice: 204,
+            background_muted: false,
+            effect_muted: false,
+            voice_muted: false,
+            rolloff: AudioRolloff::default(),
+        }
+    }
+}
+
+/// How the sounds placed in the world fade with their distance to the player.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(default)]
+pub struct AudioRolloff {
+    /// The distance, in world units, within which a sound plays at full volume.
+    pub reference_distance: f32,
+    /// The distance at which a sound becomes silent.
+    pub max_distance: f32,
+    /// The shape of the fade between the two distances. 1.0 fades linearly, larger values fade faster up close.
+    pub exponent: f32,
+    /// The distance behind the player over which a sound that has passed fades out.
+    pub behind_fade: f32,
+}
+
+impl AudioRolloff {
+    /// Returns the gain, in the range of 0.0 to 1.0, of a sound at `offset` from the player.
+    /// The player faces +z, so a negative z offset means the sound is behind.
+    pub fn gain(&self, offset: Vec3) -> f32 {
+        let range = (self.max_distance - self.reference_distance).max(f32::EPSILON);
+        let t = ((self.max_distance - offset.length()) / range).clamp(0.0, 1.0);
+        let behind = match offset.z < 0.0 {
+            true => (1.0 + offset.z / self.behind_fade.max(f32::EPSILON)).clamp(0.0, 1.0),
+            false => 1.0,
+        };
+        t.powf(self.exponent) * behind
+    }
+}
+
+impl Default for AudioRolloff {
+    fn default() -> Self {
+        Self {
+            reference_distance: 5.0,
+            max_distance: 60.0,
+            exponent: 2.0,
+            behind_fade: 8.0,
         }
     }
 }
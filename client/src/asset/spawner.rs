@@ -1,20 +1,27 @@
 use bevy::{
     animation::{AnimationTarget, AnimationTargetId},
+    image::CompressedImageFormats,
     platform::collections::HashMap,
     prelude::*,
-    render::mesh::skinning::{SkinnedMesh, SkinnedMeshInverseBindposes},
+    render::{
+        mesh::skinning::{SkinnedMesh, SkinnedMeshInverseBindposes},
+        renderer::RenderDevice,
+    },
 };
 
 use crate::{
     asset::{
         animation::AnimationAssetLoader,
+        beat_map::{BeatMap, BeatMapLoader},
+        caption::{CaptionTable, CaptionTableLoader},
+        cutscene_track::{CutsceneTrack, CutsceneTrackLoader},
         locale::{CurrentLocale, LocalizationAssets, LocalizationData, LocalizationDataLoader},
         material::{FaceMouthMaterialAssetLoader, MaterialAssetLoader},
         mesh::{MeshAsset, MeshAssetLoader},
         model::{MaterialHandle, ModelAsset, ModelAssetLoader, SerializableModelNode},
         sound::SoundAssetLoader,
         sprite::SpriteAssetLoader,
-        texture::TexelAssetLoader,
+        texture::{TexelAssetLoader, set_supported_formats},
         texture_atlas::TextureAtlasAssetLoader,
     },
     scene::BaseColor,
@@ -29,6 +36,9 @@ impl Plugin for CustomAssetPlugin {
         app.init_asset::<ModelAsset>()
             .init_asset::<MeshAsset>()
             .init_asset::<LocalizationData>()
+            .init_asset::<CutsceneTrack>()
+            .init_asset::<CaptionTable>()
+            .init_asset::<BeatMap>()
             .init_resource::<CurrentLocale>()
             .register_asset_loader(ModelAssetLoader)
             .register_asset_loader(MeshAssetLoader)
@@ -40,6 +50,9 @@ impl Plugin for CustomAssetPlugin {
             .register_asset_loader(AnimationAssetLoader)
             .register_asset_loader(LocalizationDataLoader)
             .register_asset_loader(SoundAssetLoader)
+            .register_asset_loader(CutsceneTrackLoader)
+            .register_asset_loader(CaptionTableLoader)
+            .register_asset_loader(BeatMapLoader)
             .add_systems(
                 Update,
                 (
@@ -49,6 +62,14 @@ impl Plugin for CustomAssetPlugin {
                 ),
             );
     }
+
+    fn finish(&self, app: &mut App) {
+        let formats = match app.world().get_resource::<RenderDevice>() {
+            Some(render_device) => CompressedImageFormats::from_features(render_device.features()),
+            None => CompressedImageFormats::NONE,
+        };
+        set_supported_formats(formats);
+    }
 }
 
 #[derive(Component)]
@@ -77,11 +98,12 @@ fn changed_translation_system(
     }
 }
 
+/// Translates the texts that were added, or whose key was changed to that of another string.
 fn added_translation_system(
     locale: Res<CurrentLocale>,
     localization_assets: Res<LocalizationAssets>,
     localization_data: Res<Assets<LocalizationData>>,
-    mut query: Query<(&mut Text, &TranslatableText), Added<TranslatableText>>,
+    mut query: Query<(&mut Text, &TranslatableText), Changed<TranslatableText>>,
 ) {
     if let Some(locale_data) = localization_assets.locale.get(&locale.0)
         && let Some(translations) = localization_data.get(locale_data.id())
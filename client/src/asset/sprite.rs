@@ -1,14 +1,11 @@
-use std::io::Cursor;
-
 use bevy::{
-    asset::{AssetLoader, LoadContext, RenderAssetUsages, io::Reader},
-    image::ImageSampler,
+    asset::{AssetLoader, LoadContext, io::Reader},
+    image::{ImageSampler, TextureError},
     prelude::*,
-    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
     tasks::ConditionalSendFuture,
 };
 
-use super::*;
+use super::{texture::decode_image, *};
 
 #[derive(Debug, thiserror::Error)]
 pub enum SpriteLoaderError {
@@ -20,6 +17,8 @@ pub enum SpriteLoaderError {
     Json(#[from] serde_json::Error),
     #[error("Failed to decode asset for the following reason:{0}")]
     Decode(#[from] image::ImageError),
+    #[error("Failed to decode asset for the following reason:{0}")]
+    Texture(#[from] TextureError),
     #[error("Failed to decrypt asset for the following reason:{0}")]
     Crypt(#[from] anyhow::Error),
 }
@@ -48,27 +47,9 @@ impl AssetLoader for SpriteAssetLoader {
             let key = reconstruct_key();
             let decrypted_data = decrypt_bytes(&bytes, &key)?;
 
-            // Decode the image data using the `image` crate and create a Bevy `Image` asset.
-            let mut reader = image::ImageReader::new(Cursor::new(decrypted_data));
-            reader.set_format(image::ImageFormat::Png);
-
-            let image = reader.decode()?;
-            let size = Extent3d {
-                width: image.width(),
-                height: image.height(),
-                depth_or_array_layers: 1,
-            };
-            let dimension = TextureDimension::D2;
-            let data = image.to_rgba8().to_vec();
-            let format = TextureFormat::Rgba8UnormSrgb;
-            let asset_usage = RenderAssetUsages::RENDER_WORLD;
-
-            let mut image_asset = Image::new(size, dimension, data, format, asset_usage);
             // Set the sampler to `Nearest` for a pixelated look, which is common for sprites.
             // This prevents blurring when scaling the image.
-            image_asset.sampler = ImageSampler::nearest();
-
-            Ok(image_asset)
+            decode_image(&decrypted_data, ImageSampler::nearest())
         })
     }
 
@@ -1,7 +1,8 @@
-use std::io::Cursor;
+use std::{io::Cursor, sync::OnceLock};
 
 use bevy::{
     asset::{AssetLoader, LoadContext, RenderAssetUsages, io::Reader},
+    image::{CompressedImageFormats, ImageSampler, ImageType, TextureError},
     prelude::*,
     render::render_resource::{Extent3d, TextureDimension, TextureFormat},
     tasks::ConditionalSendFuture,
@@ -9,6 +10,71 @@ use bevy::{
 
 use super::*;
 
+/// The identifier every KTX2 file starts with.
+pub const KTX2_IDENTIFIER: [u8; 12] = [
+    0xAB, 0x4B, 0x54, 0x58, 0x20, 0x32, 0x30, 0xBB, 0x0D, 0x0A, 0x1A, 0x0A,
+];
+
+/// The compressed texture formats the GPU can sample, recorded once the renderer is ready.
+static SUPPORTED_FORMATS: OnceLock<CompressedImageFormats> = OnceLock::new();
+
+pub fn set_supported_formats(formats: CompressedImageFormats) {
+    SUPPORTED_FORMATS.set(formats).ok();
+}
+
+pub fn supported_formats() -> CompressedImageFormats {
+    SUPPORTED_FORMATS
+        .get()
+        .copied()
+        .unwrap_or(CompressedImageFormats::NONE)
+}
+
+/// Whether the block-compressed variants of the textures can be loaded in place of the PNG files.
+/// The variants are only written in the BC formats, so GPUs without them, as most mobile GPUs are,
+/// keep loading the PNG files.
+#[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+pub fn supports_compressed_variants() -> bool {
+    supported_formats().contains(CompressedImageFormats::BC)
+}
+
+/// Decodes the decrypted data of a texture, which is either a PNG file or its KTX2 variant.
+pub fn decode_image<E>(data: &[u8], sampler: ImageSampler) -> Result<Image, E>
+where
+    E: From<image::ImageError> + From<TextureError>,
+{
+    let asset_usage = RenderAssetUsages::RENDER_WORLD;
+    if data.starts_with(&KTX2_IDENTIFIER) {
+        let image_type = ImageType::Extension("ktx2");
+        let image = Image::from_buffer(
+            data,
+            image_type,
+            supported_formats(),
+            true,
+            sampler,
+            asset_usage,
+        )?;
+        return Ok(image);
+    }
+
+    // Decode the image data using the `image` crate and create a Bevy `Image` asset.
+    let mut reader = image::ImageReader::new(Cursor::new(data));
+    reader.set_format(image::ImageFormat::Png);
+
+    let image = reader.decode()?;
+    let size = Extent3d {
+        width: image.width(),
+        height: image.height(),
+        depth_or_array_layers: 1,
+    };
+    let dimension = TextureDimension::D2;
+    let data = image.to_rgba8().to_vec();
+    let format = TextureFormat::Rgba8UnormSrgb;
+
+    let mut image_asset = Image::new(size, dimension, data, format, asset_usage);
+    image_asset.sampler = sampler;
+    Ok(image_asset)
+}
+
 /// An error that can occur when loading a texel data.
 #[derive(Debug, thiserror::Error)]
 pub enum TexelLoaderError {
@@ -20,6 +86,8 @@ pub enum TexelLoaderError {
     Json(#[from] serde_json::Error),
     #[error("Failed to decode asset for the following reason:{0}")]
     Decode(#[from] image::ImageError),
+    #[error("Failed to decode asset for the following reason:{0}")]
+    Texture(#[from] TextureError),
     #[error("Failed to decrypt asset for the following reason:{0}")]
     Crypt(#[from] anyhow::Error),
 }
@@ -48,22 +116,7 @@ impl AssetLoader for TexelAssetLoader {
             let key = reconstruct_key();
             let decrypted_data = decrypt_bytes(&bytes, &key)?;
 
-            // Decode the image data using the `image` crate and create a Bevy `Image` asset.
-            let mut reader = image::ImageReader::new(Cursor::new(decrypted_data));
-            reader.set_format(image::ImageFormat::Png);
-
-            let image = reader.decode()?;
-            let size = Extent3d {
-                width: image.width(),
-                height: image.height(),
-                depth_or_array_layers: 1,
-            };
-            let dimension = TextureDimension::D2;
-            let data = image.to_rgba8().to_vec();
-            let format = TextureFormat::Rgba8UnormSrgb;
-            let asset_usage = RenderAssetUsages::RENDER_WORLD;
-
-            Ok(Image::new(size, dimension, data, format, asset_usage))
+            decode_image(&decrypted_data, ImageSampler::Default)
         })
     }
 
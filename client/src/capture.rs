@@ -0,0 +1,259 @@
+#![cfg(feature = "capture")]
+
+use std::collections::VecDeque;
+
+use bevy::{
+    prelude::*,
+    render::{
+        camera::RenderTarget,
+        render_resource::{Extent3d, TextureDimension, TextureFormat, TextureUsages},
+        view::screenshot::{Screenshot, ScreenshotCaptured, save_to_disk},
+    },
+    tasks::{AsyncComputeTaskPool, Task, block_on, futures_lite::future},
+};
+use image::{
+    Delay, Frame, RgbaImage,
+    codecs::gif::{GifEncoder, Repeat},
+};
+
+/// The directory screenshots and clips are saved to, relative to the working directory.
+#[cfg(not(target_arch = "wasm32"))]
+const CAPTURE_DIR: &str = "screenshots";
+/// The size, in pixels, of the clip frames.
+const CLIP_WIDTH: u32 = 320;
+const CLIP_HEIGHT: u32 = 180;
+/// The time, in seconds, between two clip frames.
+const CLIP_FRAME_INTERVAL: f32 = 0.1;
+/// The length, in seconds, of a saved clip.
+const CLIP_LENGTH: f32 = 10.0;
+const CLIP_FRAME_COUNT: usize = (CLIP_LENGTH / CLIP_FRAME_INTERVAL) as usize;
+/// The speed of the GIF color quantization, from 1 (best quality) to 30 (fastest).
+const CLIP_ENCODE_SPEED: i32 = 10;
+
+// --- PLUGIN ---
+
+/// Saves a screenshot when F12 is pressed, and the last seconds of play as a GIF when F11 is pressed.
+/// The clip is recorded from a low resolution copy of the scene camera, so the UI isn't included.
+pub struct CapturePlugin;
+
+impl Plugin for CapturePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ClipRecorder>()
+            .add_systems(Startup, create_clip_target)
+            .add_systems(
+                Update,
+                (
+                    attach_capture_camera,
+                    sync_capture_projection,
+                    record_clip_frame,
+                    handle_capture_hotkeys,
+                    finish_clip_encoding,
+                ),
+            );
+    }
+}
+
+// --- COMPONENTS ---
+
+/// The camera rendering the clip frames. It follows the scene camera it is attached to.
+#[derive(Component)]
+struct CaptureCamera;
+
+// --- RESOURCES ---
+
+#[derive(Resource)]
+struct ClipTarget(Handle<Image>);
+
+/// Keeps the frames of the last seconds of play.
+#[derive(Resource, Default)]
+struct ClipRecorder {
+    frames: VecDeque<RgbaImage>,
+    elapsed: f32,
+    encoding: Option<Task<Result<Vec<u8>, image::ImageError>>>,
+}
+
+impl ClipRecorder {
+    fn push(&mut self, frame: RgbaImage) {
+        if self.frames.len() >= CLIP_FRAME_COUNT {
+            self.frames.pop_front();
+        }
+        self.frames.push_back(frame);
+    }
+}
+
+// --- STARTUP SYSTEMS ---
+
+fn create_clip_target(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+    let size = Extent3d {
+        width: CLIP_WIDTH,
+        height: CLIP_HEIGHT,
+        depth_or_array_layers: 1,
+    };
+    let mut image = Image::new_fill(
+        size,
+        TextureDimension::D2,
+        &[0, 0, 0, 255],
+        TextureFormat::Rgba8UnormSrgb,
+        default(),
+    );
+    image.texture_descriptor.usage |= TextureUsages::RENDER_ATTACHMENT | TextureUsages::COPY_SRC;
+
+    commands.insert_resource(ClipTarget(images.add(image)));
+}
+
+// --- UPDATE SYSTEMS ---
+
+#[allow(clippy::type_complexity)]
+fn attach_capture_camera(
+    mut commands: Commands,
+    target: Res<ClipTarget>,
+    query: Query<(Entity, &Projection), (Added<Camera3d>, Without<CaptureCamera>)>,
+) {
+    for (entity, projection) in query.iter() {
+        commands.entity(entity).with_child((
+            Camera3d::default(),
+            Camera {
+                target: RenderTarget::Image(target.0.clone().into()),
+                order: -1,
+                ..Default::default()
+            },
+            projection.clone(),
+            Transform::IDENTITY,
+            CaptureCamera,
+        ));
+    }
+}
+
+fn sync_capture_projection(
+    mut capture_query: Query<(&ChildOf, &mut Projection), With<CaptureCamera>>,
+    scene_query: Query<&Projection, Without<CaptureCamera>>,
+) {
+    for (child_of, mut projection) in capture_query.iter_mut() {
+        if let Ok(scene_projection) = scene_query.get(child_of.parent()) {
+            *projection = scene_projection.clone();
+        }
+    }
+}
+
+fn record_clip_frame(
+    mut commands: Commands,
+    mut recorder: ResMut<ClipRecorder>,
+    target: Res<ClipTarget>,
+    query: Query<(), With<CaptureCamera>>,
+    time: Res<Time<Real>>,
+) {
+    recorder.elapsed += time.delta_secs();
+    if recorder.elapsed < CLIP_FRAME_INTERVAL || query.is_empty() {
+        return;
+    }
+    recorder.elapsed = 0.0;
+
+    commands
+        .spawn(Screenshot::image(target.0.clone()))
+        .observe(store_clip_frame);
+}
+
+fn store_clip_frame(trigger: Trigger<ScreenshotCaptured>, mut recorder: ResMut<ClipRecorder>) {
+    match trigger.event().0.clone().try_into_dynamic() {
+        Ok(image) => recorder.push(image.to_rgba8()),
+        Err(e) => warn!("Failed to convert the clip frame: {:?}", e),
+    }
+}
+
+fn handle_capture_hotkeys(
+    mut commands: Commands,
+    mut recorder: ResMut<ClipRecorder>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::F12) {
+        let path = capture_path(&format!("screenshot-{}.png", timestamp()));
+        commands
+            .spawn(Screenshot::primary_window())
+            .observe(save_to_disk(path));
+    }
+
+    if keyboard_input.just_pressed(KeyCode::F11)
+        && recorder.encoding.is_none()
+        && !recorder.frames.is_empty()
+    {
+        let frames: Vec<_> = recorder.frames.iter().cloned().collect();
+        let task = AsyncComputeTaskPool::get().spawn(async move { encode_clip(frames) });
+        recorder.encoding = Some(task);
+    }
+}
+
+fn finish_clip_encoding(mut recorder: ResMut<ClipRecorder>) {
+    let Some(task) = recorder.encoding.as_mut() else {
+        return;
+    };
+    let Some(result) = block_on(future::poll_once(task)) else {
+        return;
+    };
+    recorder.encoding = None;
+
+    let filename = format!("clip-{}.gif", timestamp());
+    match result {
+        Ok(bytes) => save_clip(&filename, &bytes),
+        Err(e) => error!("Failed to encode the clip: {:?}", e),
+    }
+}
+
+// --- UTILITIES ---
+
+fn encode_clip(frames: Vec<RgbaImage>) -> Result<Vec<u8>, image::ImageError> {
+    let mut bytes = Vec::new();
+    {
+        let mut encoder = GifEncoder::new_with_speed(&mut bytes, CLIP_ENCODE_SPEED);
+        encoder.set_repeat(Repeat::Infinite)?;
+        let delay = Delay::from_numer_denom_ms((CLIP_FRAME_INTERVAL * 1000.0) as u32, 1);
+        encoder.encode_frames(
+            frames
+                .into_iter()
+                .map(|frame| Frame::from_parts(frame, 0, 0, delay)),
+        )?;
+    }
+    Ok(bytes)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn save_clip(filename: &str, bytes: &[u8]) {
+    let path = capture_path(filename);
+    match std::fs::write(&path, bytes) {
+        Ok(_) => info!("Clip saved to {}", path.display()),
+        Err(e) => error!("Failed to save the clip: {:?}", e),
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn save_clip(filename: &str, bytes: &[u8]) {
+    crate::web::download_file(filename, bytes, "image/gif");
+}
+
+/// Returns the path a capture is saved to, creating the capture directory if needed.
+#[cfg(not(target_arch = "wasm32"))]
+fn capture_path(filename: &str) -> std::path::PathBuf {
+    if let Err(e) = std::fs::create_dir_all(CAPTURE_DIR) {
+        warn!("Failed to create the capture directory: {:?}", e);
+    }
+    std::path::Path::new(CAPTURE_DIR).join(filename)
+}
+
+/// The browser downloads the capture, so only the file name is used.
+#[cfg(target_arch = "wasm32")]
+fn capture_path(filename: &str) -> std::path::PathBuf {
+    std::path::PathBuf::from(filename)
+}
+
+/// Returns the current time in milliseconds, used to name the captures.
+#[cfg(not(target_arch = "wasm32"))]
+fn timestamp() -> u128 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis())
+        .unwrap_or_default()
+}
+
+#[cfg(target_arch = "wasm32")]
+fn timestamp() -> u128 {
+    js_sys::Date::now() as u128
+}
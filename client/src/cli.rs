@@ -0,0 +1,252 @@
+//! Command-line options of the native build.
+#![cfg(not(target_arch = "wasm32"))]
+
+use std::{
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use bevy::prelude::*;
+
+pub const USAGE: &str = "\
+Usage: shupogaki [OPTIONS]
+       shupogaki validate-assets [--asset-dir <PATH>]
+       shupogaki compress-textures [--asset-dir <PATH>]
+
+Options:
+  --fullscreen                       Start in borderless fullscreen
+  --resolution <WIDTH>x<HEIGHT>      Set the window size (default: 1280x720)
+  --seed <HEX>                       Play every run with the given seed
+  --mode <daily|endless|practice>    Select the game mode (default: endless)
+  --mute                             Mute all sounds
+  --skip-title                       Start a run as soon as the game has loaded
+  --asset-dir <PATH>                 Run the command on the assets in the given directory
+                                     (default: target/assets for validate-assets,
+                                     client/assets for compress-textures)
+  -h, --help                         Print this help
+
+Commands:
+  validate-assets                    Check every asset against the build manifest and exit
+  compress-textures                  Write a block-compressed KTX2 file next to every texture
+                                     and sprite for the web build, and exit";
+
+/// Mixed into the day number, so the daily seed doesn't look like a plain count.
+const DAILY_SEED_SALT: u64 = 0x5348_5550_4F47_414B;
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum CliError {
+    #[error("Help requested")]
+    Help,
+    #[error("Unknown option: {0}")]
+    UnknownOption(String),
+    #[error("Missing value for option: {0}")]
+    MissingValue(String),
+    #[error("Invalid value for option {0}: {1}")]
+    InvalidValue(String, String),
+}
+
+/// How the runs of the session are played.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum GameMode {
+    /// Every run uses a new random seed.
+    #[default]
+    Endless,
+    /// Every run of the day uses the same seed, shared by all players.
+    Daily,
+    /// Runs are played in assist mode.
+    Practice,
+}
+
+/// The options given on the command line, read by the setup systems.
+#[derive(Debug, Default, Resource, PartialEq, Eq)]
+pub struct LaunchOptions {
+    pub fullscreen: bool,
+    pub resolution: Option<(u32, u32)>,
+    pub seed: Option<u64>,
+    pub mode: GameMode,
+    pub mute: bool,
+    pub skip_title: bool,
+    /// Validates the assets instead of starting the game.
+    pub validate_assets: bool,
+    /// Writes the compressed variants of the textures instead of starting the game.
+    pub compress_textures: bool,
+    pub asset_dir: Option<PathBuf>,
+}
+
+impl LaunchOptions {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, CliError> {
+        let mut options = Self::default();
+        let mut args = args.into_iter().peekable();
+
+        if args.next_if(|arg| arg == "validate-assets").is_some() {
+            options.validate_assets = true;
+        } else if args.next_if(|arg| arg == "compress-textures").is_some() {
+            options.compress_textures = true;
+        }
+
+        while let Some(arg) = args.next() {
+            let (name, inline_value) = match arg.split_once('=') {
+                Some((name, value)) => (name.to_string(), Some(value.to_string())),
+                None => (arg, None),
+            };
+
+            match name.as_str() {
+                "-h" | "--help" => return Err(CliError::Help),
+                "--fullscreen" => options.fullscreen = true,
+                "--mute" => options.mute = true,
+                "--skip-title" => options.skip_title = true,
+                "--resolution" | "--seed" | "--mode" | "--asset-dir" => {
+                    let value = inline_value
+                        .or_else(|| args.next())
+                        .ok_or_else(|| CliError::MissingValue(name.clone()))?;
+                    let invalid = || CliError::InvalidValue(name.clone(), value.clone());
+
+                    match name.as_str() {
+                        "--resolution" => {
+                            options.resolution = Some(parse_resolution(&value).ok_or_else(invalid)?)
+                        }
+                        "--seed" => {
+                            options.seed =
+                                Some(u64::from_str_radix(value.trim(), 16).map_err(|_| invalid())?)
+                        }
+                        "--mode" => options.mode = parse_mode(&value).ok_or_else(invalid)?,
+                        _ => options.asset_dir = Some(PathBuf::from(value)),
+                    }
+                }
+                _ => return Err(CliError::UnknownOption(name)),
+            }
+        }
+
+        Ok(options)
+    }
+
+    /// Returns the seed every run of the session uses, if any.
+    /// A seed given on the command line takes precedence over the daily seed.
+    pub fn fixed_seed(&self) -> Option<u64> {
+        match (self.seed, self.mode) {
+            (Some(seed), _) => Some(seed),
+            (None, GameMode::Daily) => Some(daily_seed()),
+            _ => None,
+        }
+    }
+}
+
+fn parse_resolution(text: &str) -> Option<(u32, u32)> {
+    let (width, height) = text.split_once(['x', 'X'])?;
+    let width = width.trim().parse().ok().filter(|&width| width > 0)?;
+    let height = height.trim().parse().ok().filter(|&height| height > 0)?;
+    Some((width, height))
+}
+
+fn parse_mode(text: &str) -> Option<GameMode> {
+    match text {
+        "endless" => Some(GameMode::Endless),
+        "daily" => Some(GameMode::Daily),
+        "practice" => Some(GameMode::Practice),
+        _ => None,
+    }
+}
+
+/// Returns the seed of the current day in UTC.
+fn daily_seed() -> u64 {
+    let days = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs() / SECONDS_PER_DAY)
+        .unwrap_or_default();
+    days ^ DAILY_SEED_SALT
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<LaunchOptions, CliError> {
+        LaunchOptions::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parses_flags_and_values() {
+        let options = parse(&[
+            "--fullscreen",
+            "--resolution",
+            "1920x1080",
+            "--seed=00000000DEADBEEF",
+            "--mode",
+            "practice",
+            "--mute",
+            "--skip-title",
+        ])
+        .unwrap();
+
+        assert_eq!(
+            options,
+            LaunchOptions {
+                fullscreen: true,
+                resolution: Some((1920, 1080)),
+                seed: Some(0xDEAD_BEEF),
+                mode: GameMode::Practice,
+                mute: true,
+                skip_title: true,
+                validate_assets: false,
+                compress_textures: false,
+                asset_dir: None,
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_bad_input() {
+        assert_eq!(
+            parse(&["--resolution", "0x720"]),
+            Err(CliError::InvalidValue(
+                "--resolution".to_string(),
+                "0x720".to_string()
+            ))
+        );
+        assert_eq!(
+            parse(&["--mode"]),
+            Err(CliError::MissingValue("--mode".to_string()))
+        );
+        assert_eq!(
+            parse(&["--speed"]),
+            Err(CliError::UnknownOption("--speed".to_string()))
+        );
+        assert_eq!(parse(&["--help"]), Err(CliError::Help));
+    }
+
+    #[test]
+    fn parses_validate_assets_command() {
+        let options = parse(&["validate-assets", "--asset-dir", "content/assets"]).unwrap();
+        assert!(options.validate_assets);
+        assert_eq!(options.asset_dir, Some(PathBuf::from("content/assets")));
+
+        assert!(!parse(&[]).unwrap().validate_assets);
+        assert_eq!(
+            parse(&["--mute", "validate-assets"]),
+            Err(CliError::UnknownOption("validate-assets".to_string()))
+        );
+    }
+
+    #[test]
+    fn parses_compress_textures_command() {
+        let options = parse(&["compress-textures", "--asset-dir=client/assets"]).unwrap();
+        assert!(options.compress_textures);
+        assert!(!options.validate_assets);
+        assert_eq!(options.asset_dir, Some(PathBuf::from("client/assets")));
+
+        assert_eq!(
+            parse(&["validate-assets", "compress-textures"]),
+            Err(CliError::UnknownOption("compress-textures".to_string()))
+        );
+    }
+
+    #[test]
+    fn seed_takes_precedence_over_daily_seed() {
+        let options = parse(&["--mode", "daily", "--seed", "2A"]).unwrap();
+        assert_eq!(options.fixed_seed(), Some(0x2A));
+
+        let options = parse(&["--mode", "daily"]).unwrap();
+        assert!(options.fixed_seed().is_some());
+    }
+}
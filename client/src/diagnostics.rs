@@ -0,0 +1,197 @@
+use bevy::prelude::*;
+#[cfg(not(feature = "no-debuging-budget"))]
+use bevy::{
+    diagnostic::{
+        Diagnostic, DiagnosticPath, Diagnostics, DiagnosticsStore, FrameTimeDiagnosticsPlugin,
+        RegisterDiagnostic,
+    },
+    platform::{collections::HashMap, time::Instant},
+};
+
+#[cfg(not(feature = "no-debuging-budget"))]
+use crate::scene::{Persistent, RenderScale, UiLayer};
+
+/// The number of frames the shown timings are averaged over.
+#[cfg(not(feature = "no-debuging-budget"))]
+const HISTORY_LENGTH: usize = 60;
+
+// --- PLUGIN ---
+
+/// Measures the time spent in each [`FrameBudgetGroup`] every frame, and shows it with the frame rate
+/// in an overlay toggled with the F3 key.
+/// Only the system sets are kept in product builds, as systems are ordered by them.
+pub struct FrameBudgetPlugin;
+
+impl Plugin for FrameBudgetPlugin {
+    #[cfg_attr(feature = "no-debuging-budget", allow(unused_variables))]
+    fn build(&self, app: &mut App) {
+        #[cfg(not(feature = "no-debuging-budget"))]
+        if !app.is_plugin_added::<FrameTimeDiagnosticsPlugin>() {
+            app.add_plugins(FrameTimeDiagnosticsPlugin::default());
+        }
+
+        #[cfg(not(feature = "no-debuging-budget"))]
+        app.init_resource::<FrameBudget>()
+            .add_systems(Startup, spawn_budget_overlay)
+            .add_systems(Update, (toggle_budget_overlay, update_budget_overlay));
+
+        #[cfg(not(feature = "no-debuging-budget"))]
+        for group in FrameBudgetGroup::ALL {
+            app.register_diagnostic(
+                Diagnostic::new(group.path())
+                    .with_suffix("ms")
+                    .with_max_history_length(HISTORY_LENGTH),
+            )
+            .add_systems(
+                PostUpdate,
+                (
+                    (move |mut budget: ResMut<FrameBudget>| budget.begin(group)).before(group),
+                    (move |mut budget: ResMut<FrameBudget>, mut diagnostics: Diagnostics| {
+                        diagnostics.add_measurement(&group.path(), || budget.end(group))
+                    })
+                    .after(group),
+                ),
+            );
+        }
+    }
+}
+
+// --- SYSTEM SETS ---
+
+/// The heavy groups of systems whose time is measured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemSet)]
+pub enum FrameBudgetGroup {
+    Collision,
+    Spawner,
+    TrainChain,
+    Ui,
+}
+
+#[cfg(not(feature = "no-debuging-budget"))]
+impl FrameBudgetGroup {
+    const ALL: [Self; 4] = [Self::Collision, Self::Spawner, Self::TrainChain, Self::Ui];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Collision => "collision",
+            Self::Spawner => "spawner",
+            Self::TrainChain => "train_chain",
+            Self::Ui => "ui",
+        }
+    }
+
+    fn path(&self) -> DiagnosticPath {
+        DiagnosticPath::from_components(["frame_budget", self.name()])
+    }
+}
+
+// --- COMPONENTS ---
+
+#[cfg(not(feature = "no-debuging-budget"))]
+#[derive(Component)]
+struct FrameBudgetOverlay;
+
+// --- RESOURCES ---
+
+#[cfg(not(feature = "no-debuging-budget"))]
+#[derive(Default, Resource)]
+struct FrameBudget {
+    started: HashMap<FrameBudgetGroup, Instant>,
+}
+
+#[cfg(not(feature = "no-debuging-budget"))]
+impl FrameBudget {
+    fn begin(&mut self, group: FrameBudgetGroup) {
+        self.started.insert(group, Instant::now());
+    }
+
+    /// Returns the time, in milliseconds, since the group began.
+    fn end(&mut self, group: FrameBudgetGroup) -> f64 {
+        self.started
+            .remove(&group)
+            .map(|started| started.elapsed().as_secs_f64() * 1000.0)
+            .unwrap_or_default()
+    }
+}
+
+// --- STARTUP SYSTEMS ---
+
+#[cfg(not(feature = "no-debuging-budget"))]
+fn spawn_budget_overlay(mut commands: Commands) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(4.0),
+            left: Val::Px(4.0),
+            padding: UiRect::all(Val::Px(4.0)),
+            ..Default::default()
+        },
+        Text::default(),
+        TextFont::from_font_size(14.0),
+        TextColor(Color::WHITE),
+        BackgroundColor(Color::BLACK.with_alpha(0.6)),
+        UiLayer::Debug,
+        Visibility::Hidden,
+        FrameBudgetOverlay,
+        Persistent,
+    ));
+}
+
+// --- UPDATE SYSTEMS ---
+
+#[cfg(not(feature = "no-debuging-budget"))]
+fn toggle_budget_overlay(
+    mut query: Query<&mut Visibility, With<FrameBudgetOverlay>>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::F3) {
+        for mut visibility in query.iter_mut() {
+            *visibility = match *visibility {
+                Visibility::Hidden => Visibility::Visible,
+                _ => Visibility::Hidden,
+            };
+        }
+    }
+}
+
+#[cfg(not(feature = "no-debuging-budget"))]
+fn update_budget_overlay(
+    mut query: Query<(&mut Text, &Visibility), With<FrameBudgetOverlay>>,
+    diagnostics: Res<DiagnosticsStore>,
+    render_scale: Option<Res<RenderScale>>,
+) {
+    let Ok((mut text, visibility)) = query.single_mut() else {
+        return;
+    };
+    if *visibility == Visibility::Hidden {
+        return;
+    }
+
+    let smoothed = |path: &DiagnosticPath| {
+        diagnostics
+            .get(path)
+            .and_then(Diagnostic::smoothed)
+            .unwrap_or_default()
+    };
+
+    let mut content = format!(
+        "FPS {:.0} ({:.2} ms)",
+        smoothed(&FrameTimeDiagnosticsPlugin::FPS),
+        smoothed(&FrameTimeDiagnosticsPlugin::FRAME_TIME)
+    );
+    for group in FrameBudgetGroup::ALL {
+        content.push_str(&format!(
+            "\n{:<12}{:>6.2} ms",
+            group.name(),
+            smoothed(&group.path())
+        ));
+    }
+    if let Some(render_scale) = render_scale {
+        content.push_str(&format!(
+            "\n{:<12}{:>6.0} %",
+            "render_scale",
+            render_scale.get() * 100.0
+        ));
+    }
+    text.0 = content;
+}
@@ -1,8 +1,20 @@
+#[cfg(not(feature = "no-debuging-gizmo"))]
+use std::f32::consts::FRAC_PI_2;
+
 use bevy::prelude::*;
 
-// Conditionally import the Collider for debug gizmos.
+// Conditionally import the components drawn as debug gizmos.
 #[cfg(not(feature = "no-debuging-gizmo"))]
-use crate::collider::Collider;
+use bevy::platform::collections::HashMap;
+
+#[cfg(not(feature = "no-debuging-gizmo"))]
+use shupogaki_core::collider::Collider;
+
+#[cfg(not(feature = "no-debuging-gizmo"))]
+use crate::scene::{
+    LANE_POSITIONS, LateralDrift, MirroredView, OBJECT_COLLIDER, Object, ObjectSpawner, Persistent,
+    TrackHorizon, UiLayer,
+};
 
 // --- PLUGIN ---
 
@@ -12,27 +24,93 @@ impl Plugin for GizmoPlugin {
     #[allow(unused_variables)]
     fn build(&self, app: &mut App) {
         #[cfg(not(feature = "no-debuging-gizmo"))]
-        app.add_systems(PostUpdate, (update_gizmo_config, draw_collider_gizmos));
+        app.insert_gizmo_config(LaneGizmos, disabled_gizmo_config())
+            .insert_gizmo_config(SpawnPlaneGizmos, disabled_gizmo_config())
+            .insert_gizmo_config(SpawnPreviewGizmos, disabled_gizmo_config())
+            .insert_gizmo_config(ColliderLabelGizmos, disabled_gizmo_config())
+            .init_resource::<ColliderLabels>()
+            .add_systems(
+                PostUpdate,
+                (
+                    update_gizmo_config,
+                    draw_collider_gizmos,
+                    draw_lateral_drift_gizmos,
+                    draw_lane_gizmos,
+                    draw_spawn_plane_gizmos,
+                    draw_spawn_preview_gizmos,
+                    update_collider_labels.after(TransformSystem::TransformPropagate),
+                ),
+            );
+    }
+}
+
+// --- GIZMO GROUPS ---
+
+/// The center line of every lane. Toggled with F1.
+#[cfg(not(feature = "no-debuging-gizmo"))]
+#[derive(Default, Reflect, GizmoConfigGroup)]
+struct LaneGizmos;
+
+/// The planes where objects spawn and despawn. Toggled with F2.
+#[cfg(not(feature = "no-debuging-gizmo"))]
+#[derive(Default, Reflect, GizmoConfigGroup)]
+struct SpawnPlaneGizmos;
+
+/// The object the spawner places next. Toggled with F8.
+#[cfg(not(feature = "no-debuging-gizmo"))]
+#[derive(Default, Reflect, GizmoConfigGroup)]
+struct SpawnPreviewGizmos;
+
+/// The name of every entity with a collider. Toggled with F9.
+/// Nothing is drawn through this group; it only holds whether the labels are shown.
+#[cfg(not(feature = "no-debuging-gizmo"))]
+#[derive(Default, Reflect, GizmoConfigGroup)]
+struct ColliderLabelGizmos;
+
+#[cfg(not(feature = "no-debuging-gizmo"))]
+fn disabled_gizmo_config() -> GizmoConfig {
+    GizmoConfig {
+        enabled: false,
+        ..Default::default()
     }
 }
 
+// --- RESOURCES ---
+
+/// The label node shown for each entity with a collider.
+#[cfg(not(feature = "no-debuging-gizmo"))]
+#[derive(Default, Resource)]
+struct ColliderLabels(HashMap<Entity, Entity>);
+
 // --- DEBUG GIZMO SYSTEMS ---
 // These systems are only compiled if the "no-debuging-gizmo" feature is NOT enabled.
 
-/// Toggles the visibility of debug gizmos when the F4 key is pressed.
+/// Toggles the collider gizmos when the F4 key is pressed,
+/// and each of the other debug drawings with its own function key.
 // This system is only compiled if the "no-debuging-gizmo" feature is NOT enabled.
 #[cfg(not(feature = "no-debuging-gizmo"))]
 pub fn update_gizmo_config(
     mut config_store: ResMut<GizmoConfigStore>,
     keyboard_input: Res<ButtonInput<KeyCode>>,
 ) {
-    // Check if F4 was just pressed.
+    // `^=` is the XOR assignment operator, a concise way to toggle a boolean.
     if keyboard_input.just_pressed(KeyCode::F4) {
-        // Iterate through all gizmo configurations and toggle their `enabled` flag.
-        for (_, config, _) in config_store.iter_mut() {
-            // `^=` is the XOR assignment operator, a concise way to toggle a boolean.
-            config.enabled ^= true;
-        }
+        config_store
+            .config_mut::<DefaultGizmoConfigGroup>()
+            .0
+            .enabled ^= true;
+    }
+    if keyboard_input.just_pressed(KeyCode::F1) {
+        config_store.config_mut::<LaneGizmos>().0.enabled ^= true;
+    }
+    if keyboard_input.just_pressed(KeyCode::F2) {
+        config_store.config_mut::<SpawnPlaneGizmos>().0.enabled ^= true;
+    }
+    if keyboard_input.just_pressed(KeyCode::F8) {
+        config_store.config_mut::<SpawnPreviewGizmos>().0.enabled ^= true;
+    }
+    if keyboard_input.just_pressed(KeyCode::F9) {
+        config_store.config_mut::<ColliderLabelGizmos>().0.enabled ^= true;
     }
 }
 
@@ -46,19 +124,199 @@ pub fn draw_collider_gizmos(mut gizmos: Gizmos, query: Query<(&Collider, &Transf
     for (collider, transform) in query.iter() {
         // Draw axes to show the orientation of the entity.
         gizmos.axes(*transform, 2.0);
+        draw_collider(&mut gizmos, collider, transform.translation, GIZMO_COLOR);
+    }
+}
+
+/// Draws the range that each wandering obstacle sways over.
+// This system is only compiled if the "no-debuging-gizmo" feature is NOT enabled.
+#[cfg(not(feature = "no-debuging-gizmo"))]
+pub fn draw_lateral_drift_gizmos(mut gizmos: Gizmos, query: Query<(&LateralDrift, &Transform)>) {
+    const GIZMO_COLOR: Color = Color::srgb(1.0, 0.5, 0.0);
+
+    for (drift, transform) in query.iter() {
+        let (min_x, max_x) = drift.range();
+        let y = transform.translation.y + 0.1;
+        let z = transform.translation.z;
+        gizmos.line(Vec3::new(min_x, y, z), Vec3::new(max_x, y, z), GIZMO_COLOR);
+    }
+}
+
+/// Draws the center line of every lane, from the despawn plane to the spawn plane.
+// This system is only compiled if the "no-debuging-gizmo" feature is NOT enabled.
+#[cfg(not(feature = "no-debuging-gizmo"))]
+fn draw_lane_gizmos(mut gizmos: Gizmos<LaneGizmos>, horizon: Option<Res<TrackHorizon>>) {
+    const GIZMO_COLOR: Color = Color::srgb(0.0, 1.0, 1.0);
+
+    let horizon = horizon.map(|horizon| *horizon).unwrap_or_default();
+    for x in LANE_POSITIONS {
+        gizmos.line(
+            Vec3::new(x, 0.05, horizon.despawn),
+            Vec3::new(x, 0.05, horizon.spawn),
+            GIZMO_COLOR,
+        );
+    }
+}
+
+/// Draws the planes across the track where objects spawn and despawn.
+// This system is only compiled if the "no-debuging-gizmo" feature is NOT enabled.
+#[cfg(not(feature = "no-debuging-gizmo"))]
+fn draw_spawn_plane_gizmos(
+    mut gizmos: Gizmos<SpawnPlaneGizmos>,
+    horizon: Option<Res<TrackHorizon>>,
+) {
+    const SPAWN_COLOR: Color = Color::srgb(0.0, 1.0, 0.0);
+    const DESPAWN_COLOR: Color = Color::srgb(1.0, 0.0, 0.0);
+
+    let horizon = horizon.map(|horizon| *horizon).unwrap_or_default();
+    let min_x = LANE_POSITIONS[0] - 2.0;
+    let max_x = LANE_POSITIONS[LANE_POSITIONS.len() - 1] + 2.0;
+    let size = Vec2::new(max_x - min_x, 6.0);
+    for (z, color) in [
+        (horizon.spawn, SPAWN_COLOR),
+        (horizon.despawn, DESPAWN_COLOR),
+    ] {
+        let center = Vec3::new((min_x + max_x) * 0.5, size.y * 0.5, z);
+        gizmos.rect(Isometry3d::from_translation(center), size, color);
+    }
+}
+
+/// Draws the collider of the object the spawner places next, in every lane it may take.
+// This system is only compiled if the "no-debuging-gizmo" feature is NOT enabled.
+#[cfg(not(feature = "no-debuging-gizmo"))]
+fn draw_spawn_preview_gizmos(
+    mut gizmos: Gizmos<SpawnPreviewGizmos>,
+    spawner: Option<Res<ObjectSpawner>>,
+) {
+    const GIZMO_COLOR: Color = Color::srgb(1.0, 0.0, 1.0);
+
+    let Some(spawner) = spawner else {
+        return;
+    };
+
+    let (obj, z) = spawner.next_spawn();
+    let Some(collider) = OBJECT_COLLIDER.get(&obj) else {
+        return;
+    };
+    for x in LANE_POSITIONS {
+        draw_collider(&mut gizmos, collider, Vec3::new(x, 0.0, z), GIZMO_COLOR);
+    }
+}
+
+/// Keeps a text label over every visible entity with a collider while the labels are toggled on.
+// This system is only compiled if the "no-debuging-gizmo" feature is NOT enabled.
+#[cfg(not(feature = "no-debuging-gizmo"))]
+#[allow(clippy::type_complexity)]
+fn update_collider_labels(
+    mut commands: Commands,
+    mut labels: ResMut<ColliderLabels>,
+    mut nodes: Query<(&mut Node, &mut Visibility), Without<Collider>>,
+    colliders: Query<
+        (
+            Entity,
+            &GlobalTransform,
+            &InheritedVisibility,
+            Option<&Name>,
+            Option<&Object>,
+        ),
+        With<Collider>,
+    >,
+    cameras: Query<(&Camera, &GlobalTransform, Has<MirroredView>), With<Camera3d>>,
+    config_store: Res<GizmoConfigStore>,
+) {
+    let enabled = config_store.config::<ColliderLabelGizmos>().0.enabled;
+    let camera = cameras.iter().find(|(camera, ..)| camera.order == 0);
+
+    // Remove the labels of despawned entities, or all of them once the labels are toggled off.
+    labels.0.retain(|target, label| {
+        let keep = enabled && colliders.contains(*target);
+        if !keep {
+            commands.entity(*label).despawn();
+        }
+        keep
+    });
+    let (true, Some((camera, camera_transform, mirrored))) = (enabled, camera) else {
+        return;
+    };
+
+    for (entity, transform, inherited, name, obj) in colliders.iter() {
+        let position = camera
+            .world_to_viewport(camera_transform, transform.translation() + Vec3::Y * 1.5)
+            .ok()
+            .filter(|_| inherited.get())
+            // The mirrored world is flipped after it is rendered, so the label is flipped too.
+            .map(
+                |position| match (mirrored, camera.logical_viewport_size()) {
+                    (true, Some(size)) => position.with_x(size.x - position.x),
+                    _ => position,
+                },
+            );
 
-        match collider {
-            Collider::Aabb { offset, size } => {
-                let center = transform.translation + *offset;
-                gizmos.cuboid(
-                    Transform::from_translation(center).with_scale(*size),
-                    GIZMO_COLOR,
-                );
+        let Some(&label) = labels.0.get(&entity) else {
+            let text = match (name, obj) {
+                (Some(name), _) => format!("{name} {entity}"),
+                (None, Some(obj)) => format!("{obj:?} {entity}"),
+                (None, None) => format!("{entity}"),
+            };
+            let label = commands
+                .spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        ..Default::default()
+                    },
+                    Text::new(text),
+                    TextFont::from_font_size(12.0),
+                    TextColor(Color::WHITE),
+                    BackgroundColor(Color::BLACK.with_alpha(0.6)),
+                    UiLayer::Debug,
+                    Visibility::Hidden,
+                    Persistent,
+                ))
+                .id();
+            labels.0.insert(entity, label);
+            continue;
+        };
+
+        let Ok((mut node, mut visibility)) = nodes.get_mut(label) else {
+            continue;
+        };
+        match position {
+            Some(position) => {
+                node.left = Val::Px(position.x);
+                node.top = Val::Px(position.y);
+                visibility.set_if_neq(Visibility::Inherited);
             }
-            Collider::Sphere { offset, radius } => {
-                let center = transform.translation + *offset;
-                gizmos.sphere(Isometry3d::from_translation(center), *radius, GIZMO_COLOR);
+            None => {
+                visibility.set_if_neq(Visibility::Hidden);
             }
         }
     }
 }
+
+#[cfg(not(feature = "no-debuging-gizmo"))]
+fn draw_collider<Config: GizmoConfigGroup>(
+    gizmos: &mut Gizmos<Config>,
+    collider: &Collider,
+    translation: Vec3,
+    color: Color,
+) {
+    match collider {
+        Collider::Aabb { offset, size } => {
+            let center = translation + *offset;
+            gizmos.cuboid(Transform::from_translation(center).with_scale(*size), color);
+        }
+        Collider::Sphere { offset, radius } => {
+            let center = translation + *offset;
+            gizmos.sphere(Isometry3d::from_translation(center), *radius, color);
+        }
+        Collider::Capsule {
+            offset,
+            half_length,
+            radius,
+        } => {
+            // The capsule primitive stands along the y axis, so it is laid down along the track.
+            let isometry = Isometry3d::new(translation + *offset, Quat::from_rotation_x(FRAC_PI_2));
+            gizmos.primitive_3d(&Capsule3d::new(*radius, 2.0 * half_length), isometry, color);
+        }
+    }
+}
@@ -0,0 +1,199 @@
+//! Records where the player is hit and how runs end, so that unfair obstacle layouts can be found and tuned.
+#![cfg(not(feature = "no-debuging-heatmap"))]
+
+use std::{collections::VecDeque, f32::consts::FRAC_PI_2};
+
+use bevy::{platform::collections::HashMap, prelude::*};
+use serde::{Deserialize, Serialize};
+
+use crate::scene::{GameState, Lane, Object, ObstacleHit, Player, SpawnPattern};
+
+/// The file the hit log is kept in, relative to the working directory.
+#[cfg(not(target_arch = "wasm32"))]
+const HIT_LOG_PATH: &str = "hit_heatmap.json";
+/// The number of records kept. The oldest record is dropped once the log is full.
+const MAX_HIT_RECORDS: usize = 2000;
+
+const COLD_COLOR: Color = Color::srgb(0.2, 0.8, 0.3);
+const HOT_COLOR: Color = Color::srgb(1.0, 0.1, 0.1);
+const MIN_MARKER_RADIUS: f32 = 0.3;
+const MAX_MARKER_RADIUS: f32 = 0.9;
+
+// --- PLUGIN ---
+
+/// Logs every hit and the end of every run to a ring file, and colors each obstacle on the track
+/// by how often its pattern has hit the player in the same lane when F10 is pressed.
+/// The web build keeps the log only until the page is closed.
+pub struct HitHeatmapPlugin;
+
+impl Plugin for HitHeatmapPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_gizmo_config(
+            HeatmapGizmos,
+            GizmoConfig {
+                enabled: false,
+                ..Default::default()
+            },
+        )
+        .add_systems(Startup, load_hit_log)
+        .add_systems(
+            OnEnter(GameState::WrapUpInGame),
+            (record_run_end, save_hit_log).chain(),
+        )
+        .add_systems(Update, record_hits)
+        .add_systems(PostUpdate, (toggle_heatmap, draw_heatmap_gizmos));
+    }
+}
+
+// --- GIZMO GROUPS ---
+
+/// The hit frequency of the obstacles on the track. Toggled with F10.
+#[derive(Default, Reflect, GizmoConfigGroup)]
+struct HeatmapGizmos;
+
+// --- RESOURCES ---
+
+/// What hit the player, or ended the run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+enum HitCause {
+    Object(Object),
+    Tok9Train,
+    /// The fuel ran out without a hit.
+    OutOfFuel,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+struct HitRecord {
+    lane: usize,
+    pattern: Option<usize>,
+    cause: HitCause,
+    /// Whether the run ended here.
+    fatal: bool,
+}
+
+/// The latest hits, with the number of hits of each obstacle pattern in each lane.
+#[derive(Default, Resource)]
+struct HitLog {
+    records: VecDeque<HitRecord>,
+    counts: HashMap<(Object, usize, usize), u32>,
+    /// Whether the current run was ended by a hit, rather than by running out of fuel.
+    run_ended: bool,
+}
+
+impl HitLog {
+    fn push(&mut self, record: HitRecord) {
+        if self.records.len() >= MAX_HIT_RECORDS
+            && let Some(oldest) = self.records.pop_front()
+            && let Some(key) = Self::key(&oldest)
+            && let Some(count) = self.counts.get_mut(&key)
+        {
+            *count -= 1;
+        }
+        if let Some(key) = Self::key(&record) {
+            *self.counts.entry(key).or_default() += 1;
+        }
+        self.records.push_back(record);
+    }
+
+    /// Only the hits of obstacles placed by a spawn pattern are drawn on the track.
+    fn key(record: &HitRecord) -> Option<(Object, usize, usize)> {
+        match (record.cause, record.pattern) {
+            (HitCause::Object(obj), Some(pattern)) => Some((obj, pattern, record.lane)),
+            _ => None,
+        }
+    }
+}
+
+// --- SETUP SYSTEMS ---
+
+#[cfg(not(target_arch = "wasm32"))]
+fn load_hit_log(mut commands: Commands) {
+    let mut log = HitLog::default();
+    if let Ok(bytes) = std::fs::read(HIT_LOG_PATH) {
+        match serde_json::from_slice::<Vec<HitRecord>>(&bytes) {
+            Ok(records) => records.into_iter().for_each(|record| log.push(record)),
+            Err(e) => warn!("Failed to read the hit log: {}", e),
+        }
+    }
+    info!("Loaded {} hit records", log.records.len());
+    commands.insert_resource(log);
+}
+
+#[cfg(target_arch = "wasm32")]
+fn load_hit_log(mut commands: Commands) {
+    commands.insert_resource(HitLog::default());
+}
+
+/// Records how the run ended when the fuel ran out without a hit.
+fn record_run_end(mut log: ResMut<HitLog>, query: Query<&Lane, With<Player>>) {
+    if !std::mem::take(&mut log.run_ended)
+        && let Ok(lane) = query.single()
+    {
+        log.push(HitRecord {
+            lane: lane.get(),
+            pattern: None,
+            cause: HitCause::OutOfFuel,
+            fatal: true,
+        });
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn save_hit_log(log: Res<HitLog>) {
+    let result = serde_json::to_vec(&log.records)
+        .map_err(std::io::Error::from)
+        .and_then(|bytes| std::fs::write(HIT_LOG_PATH, bytes));
+    if let Err(e) = result {
+        warn!("Failed to save the hit log: {}", e);
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn save_hit_log() {}
+
+// --- UPDATE SYSTEMS ---
+
+fn record_hits(mut log: ResMut<HitLog>, mut events: EventReader<ObstacleHit>) {
+    for hit in events.read() {
+        log.run_ended |= hit.fatal;
+        log.push(HitRecord {
+            lane: hit.lane,
+            pattern: hit.pattern,
+            cause: hit.object.map_or(HitCause::Tok9Train, HitCause::Object),
+            fatal: hit.fatal,
+        });
+    }
+}
+
+fn toggle_heatmap(
+    mut config_store: ResMut<GizmoConfigStore>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::F10) {
+        config_store.config_mut::<HeatmapGizmos>().0.enabled ^= true;
+    }
+}
+
+/// Draws a ring under every obstacle on the track, growing and turning red
+/// the more often its pattern has hit the player in the same lane.
+fn draw_heatmap_gizmos(
+    mut gizmos: Gizmos<HeatmapGizmos>,
+    log: Res<HitLog>,
+    query: Query<(&Object, &Lane, &SpawnPattern, &Transform)>,
+) {
+    let max_count = log.counts.values().copied().max().unwrap_or(0).max(1);
+    for (&obj, lane, pattern, transform) in query.iter() {
+        let count = log
+            .counts
+            .get(&(obj, pattern.0, lane.get()))
+            .copied()
+            .unwrap_or(0);
+        let heat = count as f32 / max_count as f32;
+        let position = transform.translation.with_y(0.05);
+        gizmos.circle(
+            Isometry3d::new(position, Quat::from_rotation_x(FRAC_PI_2)),
+            MIN_MARKER_RADIUS.lerp(MAX_MARKER_RADIUS, heat),
+            COLD_COLOR.mix(&HOT_COLOR, heat),
+        );
+    }
+}
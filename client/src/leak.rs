@@ -0,0 +1,102 @@
+use bevy::prelude::*;
+
+// Conditionally import the resources and markers used by the entity census.
+#[cfg(not(feature = "no-debuging-leak"))]
+use bevy::platform::collections::HashMap;
+
+#[cfg(not(feature = "no-debuging-leak"))]
+use crate::scene::*;
+
+/// The time, in seconds, between two entity censuses.
+#[cfg(not(feature = "no-debuging-leak"))]
+const CENSUS_INTERVAL: f32 = 10.0;
+/// The number of censuses in a row a count must grow in before it is reported as a leak.
+#[cfg(not(feature = "no-debuging-leak"))]
+const LEAK_GROWTH_LIMIT: u32 = 6;
+
+#[cfg(not(feature = "no-debuging-leak"))]
+type Counter = fn(&mut World) -> usize;
+
+/// The groups of entities counted by the census, and how to count them.
+#[cfg(not(feature = "no-debuging-leak"))]
+const CENSUS: [(&str, Counter); 14] = [
+    ("Entity", |world| world.entities().len() as usize),
+    ("Persistent", count::<Persistent>),
+    ("LoadingStateRoot", count::<LoadingStateRoot>),
+    ("OptionStateRoot", count::<OptionStateRoot>),
+    ("TitleStateRoot", count::<TitleStateRoot>),
+    ("InGameStateRoot", count::<InGameStateRoot>),
+    ("InGameStateEntity", count::<InGameStateEntity>),
+    ("ResultStateRoot", count::<ResultStateRoot>),
+    ("ResultStateEntity", count::<ResultStateEntity>),
+    ("BackgroundSound", count::<BackgroundSound>),
+    ("EffectSound", count::<EffectSound>),
+    ("VoiceSound", count::<VoiceSound>),
+    ("Node", count::<Node>),
+    ("Mesh3d", count::<Mesh3d>),
+];
+
+// --- PLUGIN ---
+
+pub struct LeakDetectorPlugin;
+
+impl Plugin for LeakDetectorPlugin {
+    #[allow(unused_variables)]
+    fn build(&self, app: &mut App) {
+        #[cfg(not(feature = "no-debuging-leak"))]
+        app.init_resource::<EntityCensus>()
+            .add_systems(Last, take_entity_census);
+    }
+}
+
+// --- RESOURCES ---
+
+/// The entity counts of the last census, and how many censuses in a row each of them grew.
+#[cfg(not(feature = "no-debuging-leak"))]
+#[derive(Default, Resource)]
+struct EntityCensus {
+    elapsed: f32,
+    tallies: HashMap<&'static str, (usize, u32)>,
+}
+
+// --- DEBUG LEAK SYSTEMS ---
+// These systems are only compiled if the "no-debuging-leak" feature is NOT enabled.
+
+/// Periodically counts the entities of every census group, and warns about the groups
+/// that keep growing, since the entities of a game state should be removed with it.
+#[cfg(not(feature = "no-debuging-leak"))]
+fn take_entity_census(world: &mut World) {
+    let delta = world.resource::<Time<Real>>().delta_secs();
+    let mut census = world.resource_mut::<EntityCensus>();
+    census.elapsed += delta;
+    if census.elapsed < CENSUS_INTERVAL {
+        return;
+    }
+    census.elapsed = 0.0;
+
+    let counts: Vec<_> = CENSUS
+        .iter()
+        .map(|(name, count)| (*name, count(world)))
+        .collect();
+    debug!("Entity census: {:?}", counts);
+
+    let mut census = world.resource_mut::<EntityCensus>();
+    for (name, count) in counts {
+        let (last, growth) = census.tallies.entry(name).or_insert((count, 0));
+        *growth = if count > *last { *growth + 1 } else { 0 };
+        *last = count;
+
+        if *growth >= LEAK_GROWTH_LIMIT {
+            warn!(
+                "Possible entity leak: {} has grown for {} censuses in a row, now {}.",
+                name, growth, count
+            );
+            *growth = 0;
+        }
+    }
+}
+
+#[cfg(not(feature = "no-debuging-leak"))]
+fn count<T: Component>(world: &mut World) -> usize {
+    world.query_filtered::<(), With<T>>().iter(world).count()
+}
@@ -4,8 +4,13 @@
 //! defining game states, and scheduling all the systems that make up the game's logic.
 
 mod asset;
-mod collider;
+mod capture;
+mod cli;
+mod diagnostics;
 mod gizmo;
+mod heatmap;
+mod leak;
+mod overlay;
 mod scene;
 mod shader;
 mod web;
@@ -14,56 +19,130 @@ use std::num::NonZeroU32;
 
 // Import necessary Bevy modules.
 use bevy::{
-    asset::AssetMetaCheck,
+    asset::{
+        AssetMetaCheck,
+        io::{AssetSource, AssetSourceBuilder},
+    },
     log::{Level, LogPlugin},
     prelude::*,
+    window::WindowMode,
 };
 use bevy_tweening::TweeningPlugin;
 
 // Import local modules for asset handling and game scenes.
 use crate::{
-    asset::{material::EyeMouthMaterial, spawner::CustomAssetPlugin},
+    asset::{
+        content_pack::CONTENT_PACK_SOURCE, material::EyeMouthMaterial, spawner::CustomAssetPlugin,
+    },
     scene::GameState,
+    shader::{
+        pause_blur::PauseBlurMaterial, speed_lines::SpeedLineMaterial, vignette::VignetteMaterial,
+    },
 };
 
 // --- MAIN FUNCTION ---
 // This is the entry point of the application.
 fn main() {
-    App::new()
-        .add_plugins((
-            DefaultPlugins
-                .set(WindowPlugin {
-                    primary_window: Some(Window {
-                        title: "Shupogaki 💢".into(),
-                        resolution: (1280.0, 720.0).into(),
-                        resizable: false,
-                        fit_canvas_to_parent: true,
-                        prevent_default_event_handling: true,
-                        desired_maximum_frame_latency: Some(NonZeroU32::new(3).unwrap()),
-                        ..default()
-                    }),
+    #[cfg(not(target_arch = "wasm32"))]
+    let options = match cli::LaunchOptions::parse(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(cli::CliError::Help) => {
+            println!("{}", cli::USAGE);
+            return;
+        }
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+    #[cfg(not(target_arch = "wasm32"))]
+    if options.validate_assets {
+        let dir = options
+            .asset_dir
+            .clone()
+            .unwrap_or_else(asset::preflight::default_asset_dir);
+        std::process::exit(asset::preflight::run(&dir));
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    if options.compress_textures {
+        let dir = options
+            .asset_dir
+            .clone()
+            .unwrap_or_else(asset::compress::default_asset_dir);
+        std::process::exit(asset::compress::run(&dir));
+    }
+    #[cfg(target_arch = "wasm32")]
+    let (resolution, mode) = ((1280, 720), WindowMode::Windowed);
+    #[cfg(not(target_arch = "wasm32"))]
+    let (resolution, mode) = (
+        options.resolution.unwrap_or((1280, 720)),
+        match options.fullscreen {
+            true => WindowMode::BorderlessFullscreen(MonitorSelection::Current),
+            false => WindowMode::Windowed,
+        },
+    );
+
+    let mut app = App::new();
+
+    // The asset sources must be registered before the asset plugin is added.
+    #[cfg(target_arch = "wasm32")]
+    app.add_plugins(web::WebAssetCachePlugin);
+    app.register_asset_source(
+        CONTENT_PACK_SOURCE,
+        AssetSourceBuilder::default()
+            .with_reader(AssetSource::get_default_reader(CONTENT_PACK_SOURCE.into())),
+    );
+
+    app.add_plugins((
+        DefaultPlugins
+            .set(WindowPlugin {
+                primary_window: Some(Window {
+                    title: "Shupogaki 💢".into(),
+                    resolution: (resolution.0 as f32, resolution.1 as f32).into(),
+                    mode,
+                    resizable: false,
+                    fit_canvas_to_parent: true,
+                    prevent_default_event_handling: true,
+                    desired_maximum_frame_latency: Some(NonZeroU32::new(3).unwrap()),
                     ..default()
-                })
-                .set(AssetPlugin {
-                    meta_check: AssetMetaCheck::Never,
-                    ..Default::default()
-                })
-                .set(LogPlugin {
-                    level: if cfg!(feature = "no-debuging-log") {
-                        Level::WARN
-                    } else {
-                        Level::INFO
-                    },
-                    ..Default::default()
                 }),
-            TweeningPlugin,
-            #[cfg(target_arch = "wasm32")]
-            web::WebAudioPlugin,
-        ))
-        .add_plugins(MaterialPlugin::<EyeMouthMaterial>::default())
-        .add_plugins(CustomAssetPlugin)
-        .add_plugins(gizmo::GizmoPlugin)
-        .add_plugins(scene::StatePlugin)
-        .init_state::<GameState>()
-        .run();
+                ..default()
+            })
+            .set(AssetPlugin {
+                meta_check: AssetMetaCheck::Never,
+                ..Default::default()
+            })
+            .set(LogPlugin {
+                level: if cfg!(feature = "no-debuging-log") {
+                    Level::WARN
+                } else {
+                    Level::INFO
+                },
+                ..Default::default()
+            }),
+        TweeningPlugin,
+        #[cfg(target_arch = "wasm32")]
+        web::WebAudioPlugin,
+        #[cfg(all(feature = "stream-overlay", not(target_arch = "wasm32")))]
+        overlay::OverlayPlugin,
+        #[cfg(feature = "capture")]
+        capture::CapturePlugin,
+        #[cfg(not(feature = "no-debuging-heatmap"))]
+        heatmap::HitHeatmapPlugin,
+    ))
+    .add_plugins(MaterialPlugin::<EyeMouthMaterial>::default())
+    .add_plugins(UiMaterialPlugin::<SpeedLineMaterial>::default())
+    .add_plugins(UiMaterialPlugin::<VignetteMaterial>::default())
+    .add_plugins(UiMaterialPlugin::<PauseBlurMaterial>::default())
+    .add_plugins(CustomAssetPlugin)
+    .add_plugins(gizmo::GizmoPlugin)
+    .add_plugins(leak::LeakDetectorPlugin)
+    .add_plugins(diagnostics::FrameBudgetPlugin)
+    .add_plugins(scene::StatePlugin)
+    .init_state::<GameState>();
+
+    #[cfg(not(target_arch = "wasm32"))]
+    app.insert_resource(options);
+
+    app.run();
 }
@@ -0,0 +1,105 @@
+#![cfg(all(feature = "stream-overlay", not(target_arch = "wasm32")))]
+
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+use serde::Serialize;
+
+use crate::scene::{CurrentScore, CurrentState, ForwardMovement, GameState, Player, TrainFuel};
+
+/// The environment variable that overrides the output path of the overlay file.
+const OVERLAY_PATH_ENV: &str = "SHUPOGAKI_OVERLAY_PATH";
+/// The default output path of the overlay file, relative to the working directory.
+const DEFAULT_OVERLAY_PATH: &str = "overlay.json";
+/// The interval, in seconds, between two writes of the overlay file.
+const WRITE_INTERVAL: f32 = 1.0;
+
+// --- PLUGIN ---
+
+/// Periodically writes the live run data to a JSON file so that streaming
+/// software (e.g. OBS browser sources) can build an overlay from it.
+pub struct OverlayPlugin;
+
+impl Plugin for OverlayPlugin {
+    fn build(&self, app: &mut App) {
+        let path = std::env::var_os(OVERLAY_PATH_ENV)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_OVERLAY_PATH));
+
+        app.insert_resource(OverlayOutput {
+            path,
+            timer: Timer::from_seconds(WRITE_INTERVAL, TimerMode::Repeating),
+        })
+        .add_systems(
+            PostUpdate,
+            write_overlay_file.run_if(in_state(GameState::InGame)),
+        );
+    }
+}
+
+// --- RESOURCES ---
+
+#[derive(Resource)]
+struct OverlayOutput {
+    path: PathBuf,
+    timer: Timer,
+}
+
+// --- DATA ---
+
+/// A snapshot of the current run, serialized as the overlay file's content.
+#[derive(Serialize)]
+struct OverlaySnapshot {
+    score: u32,
+    speed: f32,
+    fuel: f32,
+    state: &'static str,
+}
+
+impl OverlaySnapshot {
+    fn state_name(state: &CurrentState) -> &'static str {
+        match state {
+            #[cfg(not(feature = "no-debuging-player"))]
+            CurrentState::Debug => "debug",
+            CurrentState::Idle => "idle",
+            CurrentState::Attacked { .. } => "attacked",
+            CurrentState::Invincible { .. } => "invincible",
+        }
+    }
+}
+
+// --- UPDATE SYSTEMS ---
+
+fn write_overlay_file(
+    mut output: ResMut<OverlayOutput>,
+    score: Res<CurrentScore>,
+    fuel: Res<TrainFuel>,
+    state: Res<CurrentState>,
+    query: Query<&ForwardMovement, With<Player>>,
+    time: Res<Time>,
+) {
+    if !output.timer.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    let snapshot = OverlaySnapshot {
+        score: score.get(),
+        speed: query.single().map(|f| f.get()).unwrap_or_default(),
+        fuel: fuel.get(),
+        state: OverlaySnapshot::state_name(&state),
+    };
+
+    // Write to a temporary file first so that readers never see a partially written file.
+    let result = serde_json::to_vec(&snapshot)
+        .map_err(anyhow::Error::from)
+        .and_then(|bytes| {
+            let temp = output.path.with_extension("json.tmp");
+            std::fs::write(&temp, bytes)?;
+            std::fs::rename(&temp, &output.path)?;
+            Ok(())
+        });
+
+    if let Err(e) = result {
+        warn!("Failed to write overlay file {:?}: {}", output.path, e);
+    }
+}
@@ -10,6 +10,12 @@ use const_format::concatcp;
 #[rustfmt::skip] pub const FONT_PATH_START: &str = concatcp!("fonts/ImgFont_Start.sprite", QUERY, VERSION);
 #[rustfmt::skip] pub const FONT_PATH_FINISH: &str = concatcp!("fonts/ImgFont_Finish.sprite", QUERY, VERSION);
 #[rustfmt::skip] pub const FONT_PATH_PAUSE: &str = concatcp!("fonts/ImgFont_Pause.sprite", QUERY, VERSION);
+#[rustfmt::skip] pub const FONT_PATH_START_JA: &str = concatcp!("fonts/ImgFont_Start_ja.sprite", QUERY, VERSION);
+#[rustfmt::skip] pub const FONT_PATH_START_KO: &str = concatcp!("fonts/ImgFont_Start_ko.sprite", QUERY, VERSION);
+#[rustfmt::skip] pub const FONT_PATH_FINISH_JA: &str = concatcp!("fonts/ImgFont_Finish_ja.sprite", QUERY, VERSION);
+#[rustfmt::skip] pub const FONT_PATH_FINISH_KO: &str = concatcp!("fonts/ImgFont_Finish_ko.sprite", QUERY, VERSION);
+#[rustfmt::skip] pub const FONT_PATH_PAUSE_JA: &str = concatcp!("fonts/ImgFont_Pause_ja.sprite", QUERY, VERSION);
+#[rustfmt::skip] pub const FONT_PATH_PAUSE_KO: &str = concatcp!("fonts/ImgFont_Pause_ko.sprite", QUERY, VERSION);
 #[rustfmt::skip] pub const FONT_PATH_NEW: &str = concatcp!("fonts/ImgFont_New.sprite", QUERY, VERSION);
 #[rustfmt::skip] pub const FONT_PATH_NUM_1: &str = concatcp!("fonts/ImgFont_1.sprite", QUERY, VERSION);
 #[rustfmt::skip] pub const FONT_PATH_NUM_2: &str = concatcp!("fonts/ImgFont_2.sprite", QUERY, VERSION);
@@ -72,6 +78,10 @@ use const_format::concatcp;
 #[rustfmt::skip] pub const ANIM_PATH_NOZOMI_IN_GAME: &str = concatcp!("animations/Nozomi_InGame.anim", QUERY, VERSION);
 #[rustfmt::skip] pub const ANIM_PATH_NOZOMI_VICTORY_START: &str = concatcp!("animations/Nozomi_Victory_Start_Interaction.anim", QUERY, VERSION);
 #[rustfmt::skip] pub const ANIM_PATH_NOZOMI_VICTORY_END: &str = concatcp!("animations/Nozomi_Victory_End_Interaction.anim", QUERY, VERSION);
+#[rustfmt::skip] pub const CAPTION_PATH_VOICE: &str = concatcp!("captions/Voice.captions", QUERY, VERSION);
+#[rustfmt::skip] pub const BEAT_MAP_PATH_BACKGROUND: &str = concatcp!("beats/Theme_253_Game.beats", QUERY, VERSION);
+#[rustfmt::skip] pub const CUTSCENE_PATH_DEPARTURE: &str = concatcp!("cutscenes/Departure.cutscene", QUERY, VERSION);
+#[rustfmt::skip] pub const CUTSCENE_PATH_RESULT: &str = concatcp!("cutscenes/Result.cutscene", QUERY, VERSION);
 #[rustfmt::skip] pub const MODEL_PATH_PLANE_0: &str = concatcp!("models/Plane_0.hierarchy", QUERY, VERSION);
 #[rustfmt::skip] pub const MODEL_PATH_PLANE_999: &str = concatcp!("models/Plane_999.hierarchy", QUERY, VERSION);
 #[rustfmt::skip] pub const MODEL_PATH_TOK9_TRAIN_00: &str = concatcp!("models/Tok9Train00.hierarchy", QUERY, VERSION);
@@ -89,6 +99,8 @@ use const_format::concatcp;
 #[rustfmt::skip] pub const MODEL_PATH_HIKARI: &str = concatcp!("models/Hikari.hierarchy", QUERY, VERSION);
 #[rustfmt::skip] pub const MODEL_PATH_NOZOMI: &str = concatcp!("models/Nozomi.hierarchy", QUERY, VERSION);
 #[rustfmt::skip] pub const TEXTURE_PATH_TRAIN_ICON: &str = concatcp!("textures/Train_Icon.sprite", QUERY, VERSION);
+#[rustfmt::skip] pub const TEXTURE_PATH_ICONS: &str = concatcp!("textures/Icons.sprite", QUERY, VERSION);
+#[rustfmt::skip] pub const ATLAS_PATH_ICONS: &str = concatcp!("textures/Icons.atlas", QUERY, VERSION);
 
 pub const NUM_SOUND_VO_TITLE: usize = 2;
 pub const SOUND_PATH_VO_TITLES: [&str; NUM_SOUND_VO_TITLE] =
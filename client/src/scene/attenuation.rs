@@ -0,0 +1,122 @@
+use bevy::{audio::Volume, prelude::*, transform::TransformSystem};
+
+use crate::asset::sound::SystemVolume;
+
+#[cfg(target_arch = "wasm32")]
+use crate::web::WebPlaybackSettings;
+
+use super::*;
+
+// --- PLUGIN ---
+
+/// Fades the sounds placed in the world with their distance to the player,
+/// following the rolloff curve of the audio settings.
+pub struct AttenuationPlugin;
+
+impl Plugin for AttenuationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            (
+                follow_sound_source.before(TransformSystem::TransformPropagate),
+                attenuate_world_sounds.after(TransformSystem::TransformPropagate),
+            ),
+        );
+    }
+}
+
+// --- COMPONENTS ---
+
+/// Scales the volume of a sound by its distance to the player.
+/// The sound plays at its category volume times `volume` when right next to the player.
+#[derive(Debug, Clone, Copy, Component)]
+#[require(Transform)]
+pub struct DistanceAttenuation {
+    pub volume: f32,
+}
+
+impl Default for DistanceAttenuation {
+    fn default() -> Self {
+        Self { volume: 1.0 }
+    }
+}
+
+/// Keeps a sound at the position of the entity that made it, so it moves along with the world.
+#[derive(Debug, Clone, Copy, Component)]
+#[require(Transform)]
+pub struct SoundSource(pub Entity);
+
+// --- POSTUPDATE SYSTEMS ---
+
+fn follow_sound_source(
+    source_query: Query<&GlobalTransform>,
+    mut query: Query<(&SoundSource, &mut Transform)>,
+) {
+    for (source, mut transform) in query.iter_mut() {
+        if let Ok(source_transform) = source_query.get(source.0) {
+            transform.translation = source_transform.translation();
+        }
+    }
+}
+
+fn attenuated_volume(
+    system_volume: &SystemVolume,
+    attenuation: &DistanceAttenuation,
+    is_voice: bool,
+    offset: Vec3,
+) -> Volume {
+    let category = match is_voice {
+        true => system_volume.voice_percentage(),
+        false => system_volume.effect_percentage(),
+    };
+    Volume::Linear(category * attenuation.volume * system_volume.rolloff.gain(offset))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[allow(clippy::type_complexity)]
+fn attenuate_world_sounds(
+    system_volume: Res<SystemVolume>,
+    player_query: Query<&GlobalTransform, With<Player>>,
+    mut query: Query<(
+        &GlobalTransform,
+        &DistanceAttenuation,
+        Has<VoiceSound>,
+        Option<&mut AudioSink>,
+        &mut PlaybackSettings,
+    )>,
+) {
+    let Ok(player) = player_query.single() else {
+        return;
+    };
+
+    for (transform, attenuation, is_voice, sink, mut settings) in query.iter_mut() {
+        let offset = transform.translation() - player.translation();
+        let volume = attenuated_volume(&system_volume, attenuation, is_voice, offset);
+        match sink {
+            Some(mut sink) => sink.set_volume(volume),
+            // Not playing yet; the sound starts at the attenuated volume.
+            None => settings.volume = volume,
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn attenuate_world_sounds(
+    system_volume: Res<SystemVolume>,
+    player_query: Query<&GlobalTransform, With<Player>>,
+    mut query: Query<(
+        &GlobalTransform,
+        &DistanceAttenuation,
+        Has<VoiceSound>,
+        &mut WebPlaybackSettings,
+    )>,
+) {
+    let Ok(player) = player_query.single() else {
+        return;
+    };
+
+    for (transform, attenuation, is_voice, mut settings) in query.iter_mut() {
+        let offset = transform.translation() - player.translation();
+        settings.volume = attenuated_volume(&system_volume, attenuation, is_voice, offset);
+    }
+}
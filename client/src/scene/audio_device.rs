@@ -0,0 +1,332 @@
+use bevy::{
+    audio::{AudioSinkPlayback, Decodable, PlaybackMode},
+    prelude::*,
+};
+use rodio::{
+    OutputStream, OutputStreamHandle, Sink, Source,
+    cpal::{
+        self, FromSample,
+        traits::{DeviceTrait, HostTrait},
+    },
+};
+
+use super::*;
+
+// --- PLUGIN ---
+
+/// Lets the player pick the device the sounds play on, and move them to it while the game runs.
+///
+/// Bevy plays on the default device of the system and keeps its stream to itself,
+/// so once a device is picked the sounds are taken from bevy as they are added,
+/// and played on a stream of this plugin instead.
+/// Playing sounds are stopped and played again on the new device: looping sounds start over,
+/// and one-shot sounds end.
+pub struct AudioDevicePlugin;
+
+impl Plugin for AudioDevicePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<OutputDevices>()
+            .insert_non_send_resource(DeviceOutput::default())
+            .add_event::<OutputDeviceSwitched>()
+            .add_observer(queue_on_output_device::<AudioSource>)
+            .add_observer(queue_on_output_device::<PannedAudio>)
+            .add_observer(queue_on_output_device::<LowPassAudio>)
+            .add_systems(OnEnter(GameState::Option), refresh_output_devices)
+            .add_systems(
+                PostUpdate,
+                (
+                    switch_output_device,
+                    (
+                        requeue_audio::<AudioSource>,
+                        requeue_audio::<PannedAudio>,
+                        requeue_audio::<LowPassAudio>,
+                    ),
+                    (
+                        play_queued_audio::<AudioSource>,
+                        play_queued_audio::<PannedAudio>,
+                        play_queued_audio::<LowPassAudio>,
+                    ),
+                    (
+                        cleanup_finished_audio::<AudioSource>,
+                        cleanup_finished_audio::<PannedAudio>,
+                        cleanup_finished_audio::<LowPassAudio>,
+                    ),
+                )
+                    .chain(),
+            );
+    }
+}
+
+// --- COMPONENTS ---
+
+/// A sound waiting to be played on the picked device, taken from its [`AudioPlayer`]
+/// so that bevy does not play it on its own stream.
+#[derive(Component)]
+pub struct QueuedAudio<T: Asset>(pub Handle<T>);
+
+/// Despawns the entity of a sound played on the picked device once it has finished.
+#[derive(Component)]
+struct DespawnWhenFinished;
+
+/// Removes the sound played on the picked device from its entity once it has finished.
+#[derive(Component)]
+struct RemoveWhenFinished;
+
+// --- EVENTS ---
+
+/// Sent when the sounds move to another output device.
+#[derive(Event)]
+struct OutputDeviceSwitched;
+
+// --- RESOURCES ---
+
+/// The output devices of the system, and the one picked in the options.
+#[derive(Default, Resource)]
+pub struct OutputDevices {
+    names: Vec<String>,
+    /// The name of the picked device, or `None` for the default device of the system.
+    selected: Option<String>,
+}
+
+impl OutputDevices {
+    /// The name of the picked device, or `None` for the default device of the system.
+    pub fn selected(&self) -> Option<&str> {
+        self.selected.as_deref()
+    }
+
+    /// The name of the picked device as written to the log.
+    fn label(&self) -> &str {
+        self.selected.as_deref().unwrap_or("System Default")
+    }
+
+    /// Picks the next device in the list, coming back to the default device after the last one.
+    pub fn select_next(&mut self) {
+        let next = match &self.selected {
+            None => self.names.first(),
+            Some(name) => self
+                .names
+                .iter()
+                .position(|other| other == name)
+                .and_then(|i| self.names.get(i + 1)),
+        };
+        self.selected = next.cloned();
+    }
+}
+
+/// The stream opened on the picked device. It holds nothing until a device is first picked.
+#[derive(Default)]
+struct DeviceOutput {
+    stream: Option<(OutputStream, OutputStreamHandle)>,
+    /// The name of the device the stream plays on, or `None` for the default device of the system.
+    name: Option<String>,
+}
+
+// --- ENTER SYSTEMS ---
+
+/// Lists the output devices again, so that devices plugged in since are offered.
+fn refresh_output_devices(mut devices: ResMut<OutputDevices>) {
+    let names: Vec<String> = match cpal::default_host().output_devices() {
+        Ok(iter) => iter.filter_map(|device| device.name().ok()).collect(),
+        Err(e) => {
+            warn!("Failed to list the output devices: {}", e);
+            Vec::new()
+        }
+    };
+    if devices.names != names {
+        devices.names = names;
+    }
+}
+
+// --- OBSERVERS ---
+
+/// Takes a sound added to an entity from bevy, to play it on the picked device instead.
+/// Sounds stay with bevy until a device is first picked, and spatial sounds always do.
+fn queue_on_output_device<T: Asset + Decodable>(
+    trigger: Trigger<OnAdd, AudioPlayer<T>>,
+    output: NonSend<DeviceOutput>,
+    query: Query<(&AudioPlayer<T>, &PlaybackSettings), Without<AudioSink>>,
+    mut commands: Commands,
+) {
+    if output.stream.is_none() {
+        return;
+    }
+
+    let entity = trigger.target();
+    if let Ok((player, settings)) = query.get(entity)
+        && !settings.spatial
+    {
+        commands
+            .entity(entity)
+            .insert(QueuedAudio(player.0.clone()))
+            .remove::<AudioPlayer<T>>();
+    }
+}
+
+// --- POSTUPDATE SYSTEMS ---
+
+/// Opens a stream on the picked device, and stops the sounds playing on the previous one.
+fn switch_output_device(
+    devices: Res<OutputDevices>,
+    mut output: NonSendMut<DeviceOutput>,
+    mut events: EventWriter<OutputDeviceSwitched>,
+    query: Query<&AudioSink>,
+) {
+    if !devices.is_changed() || devices.selected == output.name {
+        return;
+    }
+
+    let stream = match &devices.selected {
+        Some(name) => open_named_device(name),
+        None => OutputStream::try_default().map_err(|e| e.to_string()),
+    };
+    let stream = match stream {
+        Ok(stream) => stream,
+        Err(e) => {
+            warn!(
+                "Failed to open the output device {:?}: {}",
+                devices.label(),
+                e
+            );
+            return;
+        }
+    };
+    info!("Output device: {}", devices.label());
+    output.stream = Some(stream);
+    output.name = devices.selected.clone();
+
+    // Stopped one-shot sounds are cleaned up by their playback mode.
+    for sink in query.iter() {
+        sink.stop();
+    }
+    events.write(OutputDeviceSwitched);
+}
+
+/// Queues the looping sounds again after a switch, keeping the state of their stopped sinks,
+/// along with the sounds bevy has not started yet.
+#[allow(clippy::type_complexity)]
+fn requeue_audio<T: Asset + Decodable>(
+    mut events: EventReader<OutputDeviceSwitched>,
+    mut commands: Commands,
+    mut query: Query<
+        (
+            Entity,
+            &AudioPlayer<T>,
+            Option<&AudioSink>,
+            &mut PlaybackSettings,
+        ),
+        Without<SpatialAudioSink>,
+    >,
+) {
+    if events.read().count() == 0 {
+        return;
+    }
+
+    for (entity, player, sink, mut settings) in query.iter_mut() {
+        match sink {
+            Some(sink) if matches!(settings.mode, PlaybackMode::Loop) => {
+                settings.paused = sink.is_paused();
+                settings.muted = sink.is_muted();
+                settings.speed = sink.speed();
+                settings.volume = sink.volume();
+            }
+            None if !settings.spatial => {}
+            _ => continue,
+        }
+
+        commands
+            .entity(entity)
+            .insert(QueuedAudio(player.0.clone()))
+            .remove::<(AudioPlayer<T>, AudioSink)>();
+    }
+}
+
+/// Plays the queued sounds on the stream of the picked device, the same way bevy plays them on its own.
+fn play_queued_audio<T: Asset + Decodable>(
+    output: NonSend<DeviceOutput>,
+    sources: Res<Assets<T>>,
+    global_volume: Res<GlobalVolume>,
+    query: Query<(Entity, &QueuedAudio<T>, &PlaybackSettings)>,
+    mut commands: Commands,
+) where
+    f32: FromSample<T::DecoderItem>,
+{
+    let Some((_, handle)) = &output.stream else {
+        return;
+    };
+
+    for (entity, queued, settings) in query.iter() {
+        let Some(source) = sources.get(&queued.0) else {
+            continue;
+        };
+
+        let sink = match Sink::try_new(handle) {
+            Ok(sink) => sink,
+            Err(e) => {
+                warn!("Failed to create a sink: {}", e);
+                continue;
+            }
+        };
+        match settings.mode {
+            PlaybackMode::Loop => sink.append(source.decoder().repeat_infinite()),
+            _ => sink.append(source.decoder()),
+        }
+
+        let mut sink = AudioSink::new(sink);
+        if settings.muted {
+            sink.mute();
+        }
+        sink.set_speed(settings.speed);
+        sink.set_volume(settings.volume * global_volume.volume);
+        if settings.paused {
+            sink.pause();
+        }
+
+        // The sink is added with the player, so the observer leaves the sound alone.
+        let mut entity_commands = commands.entity(entity);
+        entity_commands
+            .insert((AudioPlayer(queued.0.clone()), sink))
+            .remove::<QueuedAudio<T>>();
+        match settings.mode {
+            PlaybackMode::Loop | PlaybackMode::Once => {}
+            PlaybackMode::Despawn => {
+                entity_commands.insert(DespawnWhenFinished);
+            }
+            PlaybackMode::Remove => {
+                entity_commands.insert(RemoveWhenFinished);
+            }
+        }
+    }
+}
+
+/// Despawns or removes the sounds played on the picked device once they have finished.
+#[allow(clippy::type_complexity)]
+fn cleanup_finished_audio<T: Asset + Decodable>(
+    mut commands: Commands,
+    despawn_query: Query<(Entity, &AudioSink), (With<DespawnWhenFinished>, With<AudioPlayer<T>>)>,
+    remove_query: Query<(Entity, &AudioSink), (With<RemoveWhenFinished>, With<AudioPlayer<T>>)>,
+) {
+    for (entity, sink) in despawn_query.iter() {
+        if sink.empty() {
+            commands.entity(entity).despawn();
+        }
+    }
+    for (entity, sink) in remove_query.iter() {
+        if sink.empty() {
+            commands.entity(entity).remove::<(
+                AudioPlayer<T>,
+                AudioSink,
+                PlaybackSettings,
+                RemoveWhenFinished,
+            )>();
+        }
+    }
+}
+
+fn open_named_device(name: &str) -> Result<(OutputStream, OutputStreamHandle), String> {
+    let device = cpal::default_host()
+        .output_devices()
+        .map_err(|e| e.to_string())?
+        .find(|device| device.name().is_ok_and(|other| other == name))
+        .ok_or_else(|| "the device is no longer connected".to_string())?;
+    OutputStream::try_from_device(&device).map_err(|e| e.to_string())
+}
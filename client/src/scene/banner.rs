@@ -0,0 +1,109 @@
+use bevy::{asset::LoadState, prelude::*};
+
+use crate::asset::locale::{CurrentLocale, Locale};
+
+use super::*;
+
+// --- PLUGIN ---
+
+/// Shows the image banners in the language of the game, and swaps them when the language changes.
+///
+/// A banner shows the English image until the image of the language has loaded,
+/// and keeps it if the language has no image of its own.
+pub struct BannerPlugin;
+
+impl Plugin for BannerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            (request_banner_images, swap_banner_images).chain(),
+        );
+    }
+}
+
+// --- COMPONENTS ---
+
+/// The image banners drawn with a font of their own, for every language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Banner {
+    Start,
+    Finish,
+    Pause,
+}
+
+impl Banner {
+    /// The path of the banner image for the language.
+    pub fn path(self, locale: Locale) -> &'static str {
+        match (self, locale) {
+            (Self::Start, Locale::En) => FONT_PATH_START,
+            (Self::Start, Locale::Ja) => FONT_PATH_START_JA,
+            (Self::Start, Locale::Ko) => FONT_PATH_START_KO,
+            (Self::Finish, Locale::En) => FONT_PATH_FINISH,
+            (Self::Finish, Locale::Ja) => FONT_PATH_FINISH_JA,
+            (Self::Finish, Locale::Ko) => FONT_PATH_FINISH_KO,
+            (Self::Pause, Locale::En) => FONT_PATH_PAUSE,
+            (Self::Pause, Locale::Ja) => FONT_PATH_PAUSE_JA,
+            (Self::Pause, Locale::Ko) => FONT_PATH_PAUSE_KO,
+        }
+    }
+}
+
+/// Marks an [`ImageNode`] that shows a banner, with the image of the language still loading.
+/// The node is spawned with the English image, which is always loaded with the other assets.
+#[derive(Component)]
+pub struct LocalizedBanner {
+    banner: Banner,
+    pending: Option<Handle<Image>>,
+}
+
+impl LocalizedBanner {
+    pub fn new(banner: Banner) -> Self {
+        Self {
+            banner,
+            pending: None,
+        }
+    }
+}
+
+// --- POSTUPDATE SYSTEMS ---
+
+/// Loads the image of the current language for new banners, and for every banner when the language changes.
+fn request_banner_images(
+    asset_server: Res<AssetServer>,
+    locale: Res<CurrentLocale>,
+    mut query: Query<&mut LocalizedBanner>,
+) {
+    for mut banner in query.iter_mut() {
+        if locale.is_changed() || banner.is_added() {
+            let handle = asset_server.load(banner.banner.path(locale.0));
+            banner.pending = Some(handle);
+        }
+    }
+}
+
+/// Shows the image of the language once it has loaded, or the English image if it could not be loaded.
+fn swap_banner_images(
+    asset_server: Res<AssetServer>,
+    mut query: Query<(&mut LocalizedBanner, &mut ImageNode)>,
+) {
+    for (mut banner, mut image) in query.iter_mut() {
+        let Some(handle) = &banner.pending else {
+            continue;
+        };
+
+        match asset_server.load_state(handle) {
+            LoadState::Loaded => {
+                image.image = handle.clone();
+            }
+            LoadState::Failed(_) => {
+                warn!(
+                    "No {:?} banner for the current language, showing the English one",
+                    banner.banner
+                );
+                image.image = asset_server.load(banner.banner.path(Locale::En));
+            }
+            _ => continue,
+        }
+        banner.pending = None;
+    }
+}
@@ -0,0 +1,200 @@
+use bevy::prelude::*;
+
+use super::GameplayConfig;
+
+/// How much a link rolls, in radians, per unit of lateral speed.
+const BANK_PER_LATERAL_SPEED: f32 = 0.03;
+/// How quickly the roll of a link catches up with its lateral speed, per second.
+const BANK_RESPONSE: f32 = 10.0;
+/// How much further than their car the riders lean into a lane change.
+const RIDER_LEAN_RATIO: f32 = 0.5;
+
+// --- COMPONENTS ---
+
+/// Marks the entity that a chain of [`ChainLink`]s follows.
+#[derive(Component)]
+pub struct FollowTarget {
+    /// How far ahead of the target the first link aims, so that it faces forward while going straight.
+    pub lead: f32,
+}
+
+/// A link of the chain that follows the [`FollowTarget`].
+#[derive(Component)]
+pub struct ChainLink {
+    /// The position of the link in the chain, starting at 0 for the link right behind the target.
+    pub index: usize,
+    /// The distance between this link and the target along the z axis.
+    pub offset: f32,
+}
+
+/// The roll of a link into a lane change, layered on top of the rotation set by [`follow_chain`].
+#[derive(Component, Default)]
+pub struct Bank {
+    pub angle: f32,
+    previous_x: Option<f32>,
+}
+
+/// Marks a child of a banking link that leans further into the turn than the link itself.
+#[derive(Component)]
+pub struct BankRider;
+
+// --- SYSTEMS ---
+
+/// Moves every link of the chain behind the follow target, in the order of the link indices.
+/// Each link takes the lateral position the link ahead of it had in the previous frame,
+/// which makes the chain bend smoothly when the target moves sideways.
+pub fn follow_chain(
+    target_query: Query<(&Transform, &FollowTarget)>,
+    mut link_query: Query<(&ChainLink, &mut Transform), Without<FollowTarget>>,
+) {
+    let Ok((target, follow)) = target_query.single() else {
+        return;
+    };
+
+    let mut links: Vec<_> = link_query.iter_mut().collect();
+    links.sort_unstable_by_key(|(link, _)| link.index);
+
+    let mut leader = target
+        .translation
+        .with_z(target.translation.z + follow.lead);
+    for (link, mut transform) in links {
+        let previous = transform.translation;
+        transform.rotation = link_rotation(previous, leader);
+        transform.translation = leader.with_z(target.translation.z - link.offset);
+        leader = previous;
+    }
+}
+
+/// Rolls every banking link into its sideways movement, after [`follow_chain`] has placed it,
+/// and leans its riders a bit further. Only the visual links turn, never the collider of the target.
+pub fn bank_chain(
+    mut link_query: Query<(&mut Transform, &mut Bank, Option<&Children>), With<ChainLink>>,
+    mut rider_query: Query<&mut Transform, (With<BankRider>, Without<ChainLink>)>,
+    config: Res<GameplayConfig>,
+    time: Res<Time>,
+) {
+    let delta = time.delta_secs();
+    if delta <= 0.0 {
+        return;
+    }
+
+    for (mut transform, mut bank, children) in link_query.iter_mut() {
+        let x = transform.translation.x;
+        let lateral_velocity = bank
+            .previous_x
+            .map_or(0.0, |previous| (x - previous) / delta);
+        bank.previous_x = Some(x);
+
+        let target = bank_angle(lateral_velocity, config.max_bank_angle);
+        bank.angle += (target - bank.angle) * (BANK_RESPONSE * delta).min(1.0);
+        transform.rotation *= Quat::from_rotation_z(bank.angle);
+
+        for child in children.into_iter().flat_map(|children| children.iter()) {
+            if let Ok(mut rider) = rider_query.get_mut(child) {
+                rider.rotation = Quat::from_rotation_z(bank.angle * RIDER_LEAN_RATIO);
+            }
+        }
+    }
+}
+
+/// Returns the roll of a link moving sideways at the given speed, limited to the given angle.
+/// A link faces backwards, so a positive roll about its z axis tips it toward positive x,
+/// the side it moves to.
+pub fn bank_angle(lateral_velocity: f32, max_angle: f32) -> f32 {
+    let max_angle = max_angle.max(0.0);
+    (lateral_velocity * BANK_PER_LATERAL_SPEED).clamp(-max_angle, max_angle)
+}
+
+/// Builds the rotation of a link whose local z axis points from the leader it follows to the link,
+/// keeping the link upright. Falls back to facing backwards when the direction is undefined.
+pub fn link_rotation(link: Vec3, leader: Vec3) -> Quat {
+    let z_axis = (link - leader).normalize_or(Vec3::NEG_Z);
+    // A leader straight above or below the link leaves no horizontal direction to face.
+    let Some(x_axis) = Vec3::Y.cross(z_axis).try_normalize() else {
+        return Quat::from_rotation_y(180f32.to_radians());
+    };
+    let y_axis = z_axis.cross(x_axis);
+    Quat::from_mat3(&Mat3::from_cols(x_axis, y_axis, z_axis))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f32 = 1e-5;
+
+    fn assert_upright_basis(rotation: Quat) {
+        assert!(rotation.is_normalized());
+
+        let x_axis = rotation * Vec3::X;
+        let y_axis = rotation * Vec3::Y;
+        let z_axis = rotation * Vec3::Z;
+        assert!(x_axis.dot(y_axis).abs() < EPSILON);
+        assert!(y_axis.dot(z_axis).abs() < EPSILON);
+        assert!(z_axis.dot(x_axis).abs() < EPSILON);
+        // The link never tilts sideways or turns upside down.
+        assert!(x_axis.y.abs() < EPSILON);
+        assert!(y_axis.y > 0.0);
+    }
+
+    #[test]
+    fn test_link_rotation_faces_away_from_leader() {
+        let link = Vec3::new(0.0, 0.0, -1.5);
+        let leader = Vec3::ZERO;
+        let rotation = link_rotation(link, leader);
+
+        assert_upright_basis(rotation);
+        assert!((rotation * Vec3::Z).abs_diff_eq(Vec3::NEG_Z, EPSILON));
+
+        // A leader off to the side turns the link toward it.
+        let leader = Vec3::new(1.5, 0.0, 0.0);
+        let rotation = link_rotation(link, leader);
+        let expected = (link - leader).normalize();
+        assert_upright_basis(rotation);
+        assert!((rotation * Vec3::Z).abs_diff_eq(expected, EPSILON));
+    }
+
+    #[test]
+    fn test_link_rotation_with_height_difference() {
+        // The leader is jumping, so the direction between them is not horizontal.
+        let link = Vec3::new(0.5, 0.0, -1.5);
+        let leader = Vec3::new(0.0, 1.0, 0.0);
+        let rotation = link_rotation(link, leader);
+
+        assert_upright_basis(rotation);
+        assert!((rotation * Vec3::Z).abs_diff_eq((link - leader).normalize(), EPSILON));
+    }
+
+    #[test]
+    fn test_bank_angle_is_limited() {
+        let max_angle = 10f32.to_radians();
+
+        // Going straight keeps the link upright.
+        assert_eq!(bank_angle(0.0, max_angle), 0.0);
+
+        // Slow lane changes roll in proportion to the speed, to either side.
+        let angle = bank_angle(1.0, max_angle);
+        assert!(angle > 0.0 && angle < max_angle);
+        assert!((bank_angle(-1.0, max_angle) + angle).abs() < EPSILON);
+
+        // Fast ones never roll past the limit.
+        assert_eq!(bank_angle(1000.0, max_angle), max_angle);
+        assert_eq!(bank_angle(-1000.0, max_angle), -max_angle);
+
+        // A limit of zero turns the roll off.
+        assert_eq!(bank_angle(1000.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn test_link_rotation_degenerate_cases() {
+        // The link sits on its leader.
+        let rotation = link_rotation(Vec3::ONE, Vec3::ONE);
+        assert_upright_basis(rotation);
+        assert!((rotation * Vec3::Z).abs_diff_eq(Vec3::NEG_Z, EPSILON));
+
+        // The leader is straight above the link.
+        let rotation = link_rotation(Vec3::ZERO, Vec3::Y);
+        assert_upright_basis(rotation);
+        assert!((rotation * Vec3::Z).abs_diff_eq(Vec3::NEG_Z, EPSILON));
+    }
+}
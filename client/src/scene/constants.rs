@@ -4,19 +4,34 @@ use bevy::{platform::collections::HashMap, prelude::*};
 use lazy_static::lazy_static;
 use rand::distr::weighted::WeightedIndex;
 
-use crate::collider::Collider;
-
 use super::*;
 
 #[cfg(target_arch = "wasm32")]
 pub const HIGH_SCORE_KEY: &str = "high_score";
 
+#[cfg(target_arch = "wasm32")]
+pub const ASSIST_HIGH_SCORE_KEY: &str = "assist_high_score";
+
+#[cfg(target_arch = "wasm32")]
+pub const HARDCORE_HIGH_SCORE_KEY: &str = "hardcore_high_score";
+
+#[cfg(target_arch = "wasm32")]
+pub const ASSIST_MODE_KEY: &str = "assist_mode";
+
 #[cfg(target_arch = "wasm32")]
 pub const SYSTEM_VOLUME_KEY: &str = "system_volume";
 
-pub const NUM_LANES: usize = 3;
-pub const MAX_LANE_INDEX: usize = NUM_LANES - 1;
-pub const LANE_POSITIONS: [f32; NUM_LANES] = [-3.0, 0.25, 3.5];
+#[cfg(target_arch = "wasm32")]
+pub const GRAPHICS_OPTIONS_KEY: &str = "graphics_options";
+
+#[cfg(target_arch = "wasm32")]
+pub const TRAIN_UPGRADES_KEY: &str = "train_upgrades";
+
+#[cfg(target_arch = "wasm32")]
+pub const BEST_PACE_KEY: &str = "best_pace";
+
+#[cfg(target_arch = "wasm32")]
+pub const RUN_HISTORY_KEY: &str = "run_history";
 
 pub const PLAYER_MIN_Z_POS: f32 = -20.0;
 pub const PLAYER_MAX_Z_POS: f32 = -7.5;
@@ -25,61 +40,145 @@ pub const FUEL_DECO_CYCLE: f32 = PI * 1.0;
 pub const ATTACKED_EFFECT_CYCLE: f32 = PI * 8.0;
 pub const MIN_INVINCIBLE_EFFECT_CYCLE: f32 = PI * 4.0;
 pub const MAX_INVINCIBLE_EFFECT_CYCLE: f32 = PI * 8.0;
+/// How fast an obstacle flashes once it is close to the despawn position.
+pub const OBSTACLE_EXPIRY_CYCLE: f32 = PI * 6.0;
+/// The distance before the despawn position at which an obstacle starts to flash.
+pub const OBSTACLE_EXPIRY_DISTANCE: f32 = 15.0;
 pub const PAUSE_TITLE_CYCLE: f32 = 1.5;
 
-pub const SCORE_LIMITS: u32 = 999_999;
-pub const FUEL_LIMITS: f32 = 100.0;
-pub const INPUT_DELAY_TIME: f32 = 0.25;
-pub const POINT_PER_DIST: f32 = 1.0;
-
-pub const MIN_PLAYER_SPEED: f32 = 20.0;
-pub const MAX_PLAYER_SPEED: f32 = 27.5;
-pub const LANE_SWITCH_SPEED: f32 = 5.0;
-pub const INVINCIBLE_SPEED: f32 = 2.0 * MAX_PLAYER_SPEED;
-pub const ACCELERATION: f32 = (MAX_PLAYER_SPEED - MIN_PLAYER_SPEED) / 30.0;
-pub const JUMP_STRENGTH: f32 = 12.5;
-pub const GRAVITY: f32 = -30.0;
-pub const FUEL_USAGE: f32 = 100.0 / 20.0;
-
-pub const ATTACKED_DURATION: f32 = 3.0;
-pub const INVINCIBLE_DURATION: f32 = 8.0;
 pub const PREPARE_ANIM_DURATION: f32 = 1.0;
 pub const FINISH_ANIM_DURATION: f32 = 1.0;
+pub const TALLY_DURATION: f32 = 2.0;
+pub const TALLY_FLASH_DURATION: f32 = 0.5;
+pub const TALLY_MAX_TICK_INTERVAL: f32 = 0.15;
+pub const TALLY_MIN_TICK_INTERVAL: f32 = 0.04;
 pub const WARNING_DURATION: f32 = 3.0;
+pub const MISS_INDICATOR_DURATION: f32 = 0.6;
+pub const FUEL_INDICATOR_DURATION: f32 = 0.8;
+/// How many fuel change labels may float above the train at once.
+pub const NUM_FUEL_INDICATORS: usize = 4;
+/// How far above the train a fuel change label appears, and how far it rises while fading out.
+pub const FUEL_INDICATOR_HEIGHT: f32 = 2.0;
+pub const FUEL_INDICATOR_RISE: f32 = 1.0;
+/// The smallest loss of fuel that flashes and shakes the fuel gauge.
+pub const FUEL_GAUGE_SHARP_DROP: f32 = 10.0;
+pub const FUEL_GAUGE_FLASH_DURATION: f32 = 0.35;
+/// The shake of the fuel gauge on a sharp drop, in viewport widths and radians per second.
+pub const FUEL_GAUGE_SHAKE_AMPLITUDE: f32 = 0.4;
+pub const FUEL_GAUGE_SHAKE_FREQUENCY: f32 = 60.0;
+pub const FUEL_GAUGE_SWEEP_DURATION: f32 = 0.45;
+/// The width of the refill sweep, as a percentage of the fuel gauge.
+pub const FUEL_GAUGE_SWEEP_WIDTH: f32 = 25.0;
+/// How long the ghost bar holds the fuel just lost, and how fast it then drains, in fuel per second.
+pub const FUEL_GAUGE_GHOST_HOLD: f32 = 0.5;
+pub const FUEL_GAUGE_GHOST_DRAIN_RATE: f32 = 40.0;
+/// How quickly the loading bar catches up with the loading progress, per second.
+pub const LOADING_BAR_EASE_RATE: f32 = 8.0;
 
-pub const DESPAWN_POSITION: f32 = -100.0;
-pub const SPAWN_POSITION: f32 = 100.0;
-pub const GROUND_SPAWN_INTERVAL: f32 = 30.0;
-
-pub const NUM_OBJECTS: usize = 5;
-pub const OBJECT_SPAWN_INTERVAL: f32 = 25.0;
-pub const OBJECT_SPAWN_OFFSET: RangeInclusive<f32> = -5.0..=5.0;
-pub const OBJECT_LIST: [Object; NUM_OBJECTS] = [
-    Object::Barricade,
-    Object::Stone,
-    Object::Fuel,
-    Object::Bell,
-    Object::Aoba,
-];
-
-pub const NUM_TOK9_TRAINS: usize = 2;
-pub const TOK9_TRAIN_CYCLE: f32 = 20.0;
-pub const TOK9_TRAIN_INIT_CYCLE: f32 = 30.0;
-pub const TOK9_TRAIN_OFFSET: RangeInclusive<f32> = -15.0..=10.0;
-pub const TOK9_TRAIN_SPEED: f32 = (SPAWN_POSITION - DESPAWN_POSITION) / 1.25;
-
-pub const NUM_BARRICADE_POSITIONS: usize = 7;
-pub const NUM_STONE_POSITIONS: usize = 7;
-pub const NUM_FUEL_POSITIONS: usize = 3;
-pub const NUM_BELL_POSITIONS: usize = 3;
-pub const NUM_AOBA_POSITIONS: usize = 3;
-pub const NUM_TOK9_TRAIN_POSITIONS: usize = 6;
-
-pub const TOK9_TRAIN_DAMAGE: f32 = 50.0;
-pub const BARRICADE_DAMAGE: f32 = 20.0;
-pub const STONE_DAMAGE: f32 = 30.0;
-pub const FUEL_HEALING: f32 = 30.0;
-pub const BELL_POINT: u32 = 500;
+pub const VOLUME_SLIDER_STEP: f32 = 0.05;
+pub const VOLUME_PREVIEW_INTERVAL: f32 = 0.2;
+/// The gamepad stick deflection that counts as a directional input.
+pub const STICK_THRESHOLD: f32 = 0.5;
+
+/// How far behind the player a pickup must scroll to count as missed. Clears the longest train.
+pub const MISS_DISTANCE: f32 = 8.0;
+/// The distance from the in-game camera at which the track starts to fade into the fog, on each tier.
+pub const FOG_START: f32 = 80.0;
+pub const MOBILE_FOG_START: f32 = 35.0;
+/// The depth from which objects are animated. Beyond the edge of the view in every camera preset.
+pub const ACTIVATION_POSITION: f32 = 30.0;
+/// The time, in seconds, a newly placed object takes to scale up from its start scale to its full size.
+pub const APPEARANCE_DURATION: f32 = 0.3;
+pub const APPEARANCE_START_SCALE: f32 = 0.8;
+
+/// The x position of the far track the passing trains run on, behind the lanes.
+pub const TRAFFIC_TRACK_X: f32 = -9.0;
+pub const TRAFFIC_CYCLE: f32 = 15.0;
+pub const TRAFFIC_INIT_CYCLE: f32 = 8.0;
+pub const TRAFFIC_OFFSET: RangeInclusive<f32> = -5.0..=10.0;
+pub const TRAFFIC_SPEED: f32 = 25.0;
+/// The relative volume of a passing train right next to the player.
+pub const TRAFFIC_SOUND_VOLUME: f32 = 0.6;
+/// The distance over which a passing train's pitch slides between its highest and lowest.
+pub const TRAFFIC_DOPPLER_DISTANCE: f32 = 30.0;
+/// How much a passing train's pitch rises while it approaches, and falls once it has passed.
+pub const TRAFFIC_DOPPLER_SHIFT: f32 = 0.12;
+
+/// The time, in seconds, within which the next bell keeps a chain of chimes going.
+pub const BELL_CHIME_GAP: f32 = 1.2;
+/// How many semitones each chime of a chain rises above the last.
+pub const BELL_CHIME_STEP: f32 = 2.0;
+/// The playback speed of the highest chime of a chain, an octave above the first.
+pub const BELL_CHIME_MAX_SPEED: f32 = 2.0;
+
+pub const SHIELD_PICKUP_SCALE: Vec3 = Vec3::new(0.6, 1.5, 0.6);
+pub const SHIELD_GLOW_SCALE: Vec3 = Vec3::new(2.0, 0.3, 3.5);
+/// The color of the glow ring while the shield is charged, bright enough to bloom.
+pub const SHIELD_GLOW_COLOR: Color = Color::srgb(0.6, 1.2, 1.6);
+
+pub const CLOCK_PICKUP_SCALE: Vec3 = Vec3::new(1.2, 0.4, 1.2);
+/// The color saturation of the world at the peak of the slow motion.
+pub const CLOCK_SATURATION: f32 = 0.3;
+/// The cutoff frequency of the low-pass filter on the background music during the slow motion.
+pub const CLOCK_BGM_LOW_PASS: f32 = 800.0;
+
+/// The fuel ratio below which the fuel alarm is raised.
+pub const FUEL_ALARM_RATIO: f32 = 0.2;
+/// The fuel ratio above which the fuel alarm is re-armed.
+pub const FUEL_ALARM_REARM_RATIO: f32 = 0.3;
+/// The blink of the fuel gauge frame while the fuel alarm is raised, in radians per second.
+pub const FUEL_ALARM_BLINK_CYCLE: f32 = PI * 6.0;
+
+/// How fast the color grading blends in and out, in full strength per second.
+pub const GRADING_BLEND_SPEED: f32 = 3.0;
+/// The color saturation of the world at the peak of the invincibility.
+pub const INVINCIBLE_SATURATION: f32 = 1.6;
+/// The bloom intensity at the peak of the invincibility.
+pub const INVINCIBLE_BLOOM_INTENSITY: f32 = 0.3;
+/// The fuel ratio below which the red vignette is shown.
+pub const LOW_FUEL_RATIO: f32 = 0.25;
+/// The color of the red vignette at the peak of its pulse.
+pub const LOW_FUEL_VIGNETTE_COLOR: Color = Color::srgb(0.9, 0.05, 0.05);
+/// The pulse of the red vignette, in radians per second.
+pub const LOW_FUEL_VIGNETTE_CYCLE: f32 = PI * 2.0;
+
+pub const WHISTLE_SPEED: f32 = 60.0;
+pub const WHISTLE_RADIUS: f32 = 0.3;
+/// The time, in seconds, the steam whistle takes to recharge after it is blown.
+pub const HORN_COOLDOWN: f32 = 8.0;
+/// How far along the track, ahead of or behind the player, the steam whistle startles an Aoba.
+pub const HORN_RANGE: f32 = 30.0;
+pub const HORN_SCARE_POINT: u32 = 300;
+/// How long, in seconds, a startled Aoba hops, and how high.
+pub const STARTLED_DURATION: f32 = 0.6;
+pub const STARTLED_HOP_HEIGHT: f32 = 1.5;
+
+/// The distance between the last passenger car and the tail car.
+pub const TRAIN_TAIL_SPACING: f32 = 1.25;
+
+/// How much the lead car stretches upward as it takes off, and squashes down as it lands.
+pub const JUMP_STRETCH_AMOUNT: f32 = 0.15;
+pub const JUMP_SQUASH_AMOUNT: f32 = 0.2;
+pub const SQUASH_STRETCH_DURATION: f32 = 0.25;
+pub const NUM_LANDING_DUST_PARTICLES: usize = 10;
+pub const LANDING_DUST_SIZE: f32 = 0.15;
+pub const LANDING_DUST_SPEED: RangeInclusive<f32> = 1.5..=3.0;
+pub const LANDING_DUST_DURATION: f32 = 0.5;
+/// How quickly the dust slows down, per second.
+pub const LANDING_DUST_DRAG: f32 = 4.0;
+/// The most time, in seconds, between two taps of a lane switch key for them to air-dash.
+pub const AIR_DASH_DOUBLE_TAP_WINDOW: f32 = 0.25;
+/// The heights of the streaks an air-dash leaves behind, one per streak.
+pub const AIR_DASH_STREAK_HEIGHTS: [f32; 3] = [0.3, 0.65, 1.0];
+pub const AIR_DASH_STREAK_THICKNESS: f32 = 0.06;
+pub const AIR_DASH_STREAK_DURATION: f32 = 0.3;
+
+/// The width and length of the landing marker quad laid on the ground under the player.
+pub const LANDING_MARKER_SIZE: Vec2 = Vec2::new(1.6, 1.6);
+/// Lifts the landing marker slightly above the ground to avoid z-fighting.
+pub const LANDING_MARKER_HEIGHT: f32 = 0.02;
+/// The time before landing, in seconds, over which the landing marker fades out.
+pub const LANDING_MARKER_FADE_TIME: f32 = 0.3;
 
 lazy_static! {
     pub static ref OBJECT_MODELS: HashMap<Object, &'static str> = {
@@ -89,50 +188,8 @@ lazy_static! {
             (Object::Fuel, MODEL_PATH_FUEL),
             (Object::Bell, MODEL_PATH_DOOR_BELL),
             (Object::Aoba, MODEL_PATH_AOBA),
-        ]
-        .into_iter()
-        .collect();
-
-        assert!(map.len() == NUM_OBJECTS);
-        map
-    };
-    pub static ref OBJECT_COLLIDER: HashMap<Object, Collider> = {
-        let map: HashMap<_, _> = [
-            (
-                Object::Barricade,
-                Collider::Aabb {
-                    offset: Vec3::new(0.0, 0.5, 0.0),
-                    size: Vec3::splat(1.0),
-                },
-            ),
-            (
-                Object::Stone,
-                Collider::Sphere {
-                    offset: Vec3::splat(0.0),
-                    radius: 1.0,
-                },
-            ),
-            (
-                Object::Fuel,
-                Collider::Aabb {
-                    offset: Vec3::new(0.0, 0.0, 0.0),
-                    size: Vec3::splat(0.5),
-                },
-            ),
-            (
-                Object::Bell,
-                Collider::Aabb {
-                    offset: Vec3::new(0.0, 0.0, 0.0),
-                    size: Vec3::splat(0.5),
-                },
-            ),
-            (
-                Object::Aoba,
-                Collider::Aabb {
-                    offset: Vec3::new(0.0, 0.5, 0.0),
-                    size: Vec3::new(0.5, 1.0, 0.5),
-                },
-            ),
+            (Object::Shield, MODEL_PATH_GLOW),
+            (Object::Clock, MODEL_PATH_GLOW),
         ]
         .into_iter()
         .collect();
@@ -151,29 +208,6 @@ lazy_static! {
         .into_iter()
         .collect();
 
-        assert!(map.len() == NUM_TOK9_TRAINS);
-        map
-    };
-    pub static ref TOK9_TRAIN_COLLIDER: HashMap<Tok9Train, Collider> = {
-        let map: HashMap<_, _> = [
-            (
-                Tok9Train::Blue,
-                Collider::Aabb {
-                    offset: Vec3::new(0.0, 1.5, 16.725),
-                    size: Vec3::new(2.0, 3.0, 44.0),
-                },
-            ),
-            (
-                Tok9Train::Orange,
-                Collider::Aabb {
-                    offset: Vec3::new(0.0, 1.5, 20.5),
-                    size: Vec3::new(2.0, 3.0, 49.0),
-                },
-            ),
-        ]
-        .into_iter()
-        .collect();
-
         assert!(map.len() == NUM_TOK9_TRAINS);
         map
     };
@@ -184,59 +218,52 @@ lazy_static! {
         const WEIGHTS: [u32; NUM_SOUND_VO_DAMAGED] = [5, 5, 5, 5, 1, 1];
         WeightedIndex::new(WEIGHTS).unwrap()
     };
-    pub static ref SPAWN_WEIGHTS: WeightedIndex<u32> = {
-        const WEIGHTS: [u32; NUM_OBJECTS] = [400, 300, 200, 95, 5];
-        WeightedIndex::new(WEIGHTS).unwrap()
-    };
-    pub static ref BARRICADE_WEIGHTS: WeightedIndex<u32> = {
-        const WEIGHTS: [u32; NUM_BARRICADE_POSITIONS] = [3, 3, 2, 3, 2, 2, 1];
-        WeightedIndex::new(WEIGHTS).unwrap()
-    };
-    pub static ref STONE_WEIGHTS: WeightedIndex<u32> = {
-        const WEIGHTS: [u32; NUM_STONE_POSITIONS] = [3, 3, 2, 3, 2, 2, 1];
-        WeightedIndex::new(WEIGHTS).unwrap()
-    };
-    pub static ref TOK9_TRAIN_WEIGHTS: WeightedIndex<u32> = {
-        const WEIGHTS: [u32; NUM_TOK9_TRAIN_POSITIONS] = [5, 5, 1, 5, 1, 1];
-        WeightedIndex::new(WEIGHTS).unwrap()
-    };
-}
-
-lazy_static! {
-    #[rustfmt::skip]
-    pub static ref BARRICADE_POSITION_INDICES: [Vec<usize>; NUM_BARRICADE_POSITIONS] = [
-        vec![0], vec![1], vec![0, 1], vec![2], vec![0, 2], vec![1, 2], vec![0, 1, 2],
-    ];
-
-    #[rustfmt::skip]
-    pub static ref STONE_POSITION_INDICES: [Vec<usize>; NUM_STONE_POSITIONS] = [
-        vec![0], vec![1], vec![0, 1], vec![2], vec![0, 2], vec![1, 2], vec![0, 1, 2],
-    ];
-
-    #[rustfmt::skip]
-    pub static ref TOK9_TRAIN_POSITION_INDICES: [Vec<usize>; NUM_TOK9_TRAIN_POSITIONS] = [
-        vec![0], vec![1], vec![0, 1], vec![2], vec![0, 2], vec![1, 2]
-    ];
 }
 
-pub const FUEL_POSITION_INDICES: [usize; NUM_FUEL_POSITIONS] = [0, 1, 2];
-pub const BELL_POSITION_INDICES: [usize; NUM_BELL_POSITIONS] = [0, 1, 2];
-pub const AOBA_POSITION_INDICES: [usize; NUM_AOBA_POSITIONS] = [0, 1, 2];
-
 pub const LANGUAGE_BTN_COLOR: Color = Color::srgb(0.8, 0.8, 0.8);
+pub const GRAPHICS_BTN_COLOR: Color = Color::srgb(0.8, 0.8, 0.8);
+pub const MUTE_BTN_COLOR: Color = Color::srgb(0.8, 0.8, 0.8);
 pub const SLIDER_RAIL_COLOR: Color = Color::srgb(0.8, 0.8, 0.8);
 pub const SLIDER_HANDLE_COLOR: Color = Color::srgb(0.7, 0.7, 0.7);
+pub const FOCUS_RING_COLOR: Color = Color::srgb(1.0, 0.75, 0.1);
+pub const CAPTION_BG_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.6);
+pub const LANDING_MARKER_COLOR: Color = Color::srgba(1.0, 0.85, 0.2, 0.6);
+pub const LANDING_DUST_COLOR: Color = Color::srgb(0.85, 0.8, 0.7);
+pub const PACE_AHEAD_COLOR: Color = Color::srgb(0.2, 0.8, 0.3);
+pub const PACE_BEHIND_COLOR: Color = Color::srgb(0.9, 0.25, 0.2);
 pub const CLEAR_COLOR: Color = Color::srgb(0.48627, 0.81568, 1.0);
 pub const LOADING_BAR_COLOR: Color = Color::srgb(0.2, 0.8, 0.2);
 pub const RESUME_BTN_COLOR: Color = Color::WHITE;
 pub const OPTION_BTN_COLOR: Color = Color::WHITE;
 pub const RESTART_BTN_COLOR: Color = Color::WHITE;
+pub const SEED_BTN_COLOR: Color = Color::srgb(0.8, 0.8, 0.8);
+pub const COMPARE_BTN_COLOR: Color = Color::srgb(0.8, 0.8, 0.8);
+pub const ASSIST_BADGE_COLOR: Color = Color::srgb(0.2, 0.5, 0.9);
+pub const SEED_CHIP_COLOR: Color = Color::srgb(0.2, 0.2, 0.25);
+pub const MODE_CHIP_COLOR: Color = Color::srgb(0.35, 0.35, 0.4);
+pub const MUTATOR_CHIP_COLOR: Color = Color::srgb(0.85, 0.35, 0.2);
+pub const SCORE_MULTIPLIER_COLOR: Color = Color::srgb(1.0, 0.85, 0.2);
+pub const RHYTHM_LABEL_COLOR: Color = Color::srgb(1.0, 0.6, 0.85);
+pub const MISS_INDICATOR_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.7);
+pub const FUEL_GAIN_COLOR: Color = Color::srgb(0.3, 0.9, 0.3);
+pub const FUEL_LOSS_COLOR: Color = Color::srgb(0.95, 0.3, 0.25);
 pub const EXIT_BTN_COLOR: Color = Color::srgb(0.98039, 0.37254, 0.33333);
 pub const BACK_BTN_COLOR: Color = Color::srgb(0.98039, 0.37254, 0.33333);
 pub const PAUSE_BG_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.8);
 pub const PAUSE_BTN_COLOR: Color = Color::WHITE;
 pub const PAUSE_ICON_COLOR: Color = Color::srgb(0.8, 0.8, 0.8);
+pub const THROW_BTN_COLOR: Color = Color::WHITE;
+pub const HORN_BTN_COLOR: Color = Color::srgb(1.0, 0.9, 0.6);
+pub const HORN_COOLDOWN_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.45);
+pub const AIR_DASH_GAUGE_BG_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.4);
+pub const AIR_DASH_CHARGING_COLOR: Color = Color::srgb(0.6, 0.6, 0.6);
+pub const AIR_DASH_READY_COLOR: Color = Color::srgb(0.3, 0.75, 1.0);
+pub const AIR_DASH_STREAK_COLOR: Color = Color::srgb(0.85, 0.95, 1.0);
+pub const WHISTLE_COLOR: Color = Color::srgb(0.95, 0.8, 0.2);
 pub const FUEL_COLOR: Color = Color::srgb(0.18823, 0.21568, 0.27450);
 pub const FUEL_GOOD_GAUGE_COLOR: Color = Color::srgb(0.2, 0.8, 0.2);
 pub const FUEL_FAIR_GAUGE_COLOR: Color = Color::srgb(0.8, 0.8, 0.2);
 pub const FUEL_POOR_GAUGE_COLOR: Color = Color::srgb(0.8, 0.2, 0.2);
+pub const FUEL_GHOST_GAUGE_COLOR: Color = Color::srgb(0.95, 0.85, 0.8);
+pub const FUEL_SWEEP_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.6);
+pub const TALLY_FLASH_COLOR: Color = Color::srgb(1.0, 0.75, 0.1);
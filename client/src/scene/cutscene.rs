@@ -0,0 +1,308 @@
+use bevy::{audio::Volume, ecs::system::SystemParam, prelude::*};
+use serde::Deserialize;
+
+use crate::{
+    asset::{cutscene_track::CutsceneTrack, material::EyeMouthMaterial, sound::SystemVolume},
+    shader::face_mouth::EyeMouth,
+};
+
+#[cfg(target_arch = "wasm32")]
+use crate::web::{WebAudioPlayer, WebPlaybackSettings};
+
+use super::*;
+
+/// How the camera moves from the previous keyframe into a keyframe.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum Easing {
+    Linear,
+    /// Starts and stops gently.
+    #[default]
+    Smooth,
+    /// Starts slowly and arrives at full speed.
+    In,
+    /// Starts at full speed and slows down to a stop.
+    Out,
+    /// Holds the previous pose, then cuts to the keyframe.
+    Cut,
+}
+
+impl Easing {
+    /// Maps the linear progress between two keyframes (0.0 to 1.0) to the eased progress.
+    pub fn apply(self, t: f32) -> f32 {
+        match self {
+            Self::Linear => t,
+            Self::Smooth => t * t * (3.0 - 2.0 * t),
+            Self::In => t * t,
+            Self::Out => 1.0 - (1.0 - t) * (1.0 - t),
+            Self::Cut => 0.0,
+        }
+    }
+}
+
+/// A camera pose at a point in time on a cutscene's camera track.
+/// The rotation of the camera is given by the point it looks at.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct CameraKeyframe {
+    pub time: f32,
+    pub position: Vec3,
+    pub look_at: Vec3,
+    /// The zoom factor relative to the scene's default framing (1.0 = default).
+    /// A perspective camera divides its field of view by it, and an orthographic camera its scale.
+    pub zoom: f32,
+    /// How the camera moves into this keyframe from the previous one.
+    #[serde(default)]
+    pub ease: Easing,
+}
+
+impl CameraKeyframe {
+    pub const fn new(time: f32, position: Vec3, look_at: Vec3, zoom: f32) -> Self {
+        Self {
+            time,
+            position,
+            look_at,
+            zoom,
+            ease: Easing::Smooth,
+        }
+    }
+
+    /// Samples a camera track, sorted by time, at the given time.
+    /// Poses between two keyframes are eased with the curve of the later keyframe.
+    pub fn sample(keyframes: &[CameraKeyframe], time: f32) -> Option<CameraKeyframe> {
+        let next = keyframes.iter().position(|key| key.time > time);
+
+        match next {
+            Some(0) => keyframes.first().copied(),
+            Some(index) => {
+                let a = keyframes[index - 1];
+                let b = keyframes[index];
+                let t = b.ease.apply((time - a.time) / (b.time - a.time));
+                Some(CameraKeyframe {
+                    time,
+                    position: a.position.lerp(b.position, t),
+                    look_at: a.look_at.lerp(b.look_at, t),
+                    zoom: a.zoom + (b.zoom - a.zoom) * t,
+                    ease: b.ease,
+                })
+            }
+            None => keyframes.last().copied(),
+        }
+    }
+}
+
+/// The characters a cue can act on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum CutsceneActor {
+    Hikari,
+    Nozomi,
+}
+
+/// An event fired once when a cutscene's playback reaches its time.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub enum CutsceneCue {
+    /// Plays a sound effect, given by its asset path without the version query.
+    Sound(String),
+    /// Changes the mouth expression of the characters.
+    Mouth(u32),
+    /// Shows a character.
+    Show(CutsceneActor),
+    /// Hides a character.
+    Hide(CutsceneActor),
+    /// Plays the animation of a character again from the start.
+    Replay(CutsceneActor),
+}
+
+impl CutsceneCue {
+    /// Checks if the cue leaves the scene in a state that lasts, rather than starting something.
+    /// Such cues are fired again when the playback jumps, so that the scene matches the new time.
+    #[cfg_attr(feature = "no-debuging-scrubber", allow(dead_code))]
+    pub fn sets_state(&self) -> bool {
+        matches!(self, Self::Mouth(_) | Self::Show(_) | Self::Hide(_))
+    }
+}
+
+/// Plays a cutscene: a keyframed camera track and timed cues, evaluated at the playback time.
+#[derive(Resource)]
+pub struct Cutscene {
+    elapsed_time: f32,
+    camera: Vec<CameraKeyframe>,
+    cues: Vec<(f32, CutsceneCue)>,
+    next_cue: usize,
+}
+
+impl Cutscene {
+    /// Creates a new cutscene from a track asset.
+    pub fn from_track(track: &CutsceneTrack) -> Self {
+        debug_assert!(track.camera.is_sorted_by(|a, b| a.time <= b.time));
+        debug_assert!(track.cues.is_sorted_by(|a, b| a.time <= b.time));
+        Self {
+            elapsed_time: 0.0,
+            camera: track.camera.clone(),
+            cues: track
+                .cues
+                .iter()
+                .map(|key| (key.time, key.cue.clone()))
+                .collect(),
+            next_cue: 0,
+        }
+    }
+
+    /// Adds a keyframe after the last one, for a pose only known when the cutscene starts.
+    pub fn then(mut self, key: CameraKeyframe) -> Self {
+        debug_assert!(self.camera.last().is_none_or(|last| last.time <= key.time));
+        self.camera.push(key);
+        self
+    }
+
+    /// Advances the playback and returns the cues that were reached.
+    pub fn tick(&mut self, elapsed: f32) -> Vec<CutsceneCue> {
+        self.elapsed_time += elapsed;
+
+        let mut fired = Vec::new();
+        while let Some((time, cue)) = self.cues.get(self.next_cue)
+            && *time <= self.elapsed_time
+        {
+            fired.push(cue.clone());
+            self.next_cue += 1;
+        }
+        fired
+    }
+
+    /// Returns the playback time, in seconds.
+    #[cfg_attr(feature = "no-debuging-scrubber", allow(dead_code))]
+    pub fn elapsed_sec(&self) -> f32 {
+        self.elapsed_time
+    }
+
+    /// Returns the time of the last keyframe or cue.
+    #[cfg_attr(feature = "no-debuging-scrubber", allow(dead_code))]
+    pub fn duration(&self) -> f32 {
+        let camera = self.camera.last().map_or(0.0, |key| key.time);
+        let cues = self.cues.last().map_or(0.0, |(time, _)| *time);
+        camera.max(cues)
+    }
+
+    /// Moves the playback to the given time, backwards or forwards, and returns every cue
+    /// before it in order, so that the state the cues set can be rebuilt without playing them.
+    #[cfg_attr(feature = "no-debuging-scrubber", allow(dead_code))]
+    pub fn seek(&mut self, time: f32) -> Vec<CutsceneCue> {
+        self.elapsed_time = 0.0;
+        self.next_cue = 0;
+        self.tick(time)
+    }
+
+    /// Samples the camera track at the current playback time.
+    pub fn camera(&self) -> Option<CameraKeyframe> {
+        CameraKeyframe::sample(&self.camera, self.elapsed_time)
+    }
+
+    /// Returns the last pose of the camera track.
+    pub fn final_camera(&self) -> Option<CameraKeyframe> {
+        self.camera.last().copied()
+    }
+}
+
+// --- SYSTEM PARAMS ---
+
+/// What the cues of a cutscene act on. `S` marks the characters of the scene playing it.
+#[allow(clippy::type_complexity)]
+#[derive(SystemParam)]
+pub struct CutsceneStage<'w, 's, S: Component> {
+    commands: Commands<'w, 's>,
+    asset_server: Res<'w, AssetServer>,
+    system_volume: Res<'w, SystemVolume>,
+    materials: ResMut<'w, Assets<EyeMouthMaterial>>,
+    mouth_query: Query<'w, 's, &'static EyeMouth>,
+    actor_query: Query<
+        'w,
+        's,
+        (
+            &'static mut Visibility,
+            Option<&'static mut AnimationPlayer>,
+            Has<Hikari>,
+        ),
+        (With<S>, Or<(With<Hikari>, With<Nozomi>)>),
+    >,
+}
+
+impl<S: Component> CutsceneStage<'_, '_, S> {
+    /// Carries out a cue. The sounds it plays are spawned with `marker`, so that they are
+    /// cleaned up with the scene.
+    pub fn fire(&mut self, cue: &CutsceneCue, marker: impl Bundle) {
+        match cue {
+            CutsceneCue::Sound(path) => {
+                let path = format!("{path}{QUERY}{VERSION}");
+                let sound = play_cutscene_sound(
+                    &mut self.commands,
+                    &self.asset_server,
+                    &self.system_volume,
+                    path,
+                );
+                self.commands.entity(sound).insert(marker);
+            }
+            CutsceneCue::Mouth(index) => {
+                for mouth in self.mouth_query.iter() {
+                    if let Some(material) = self.materials.get_mut(&mouth.0) {
+                        material.extension.uniform.index.x = *index;
+                    }
+                }
+            }
+            CutsceneCue::Show(actor) | CutsceneCue::Hide(actor) | CutsceneCue::Replay(actor) => {
+                for (mut visibility, player, is_hikari) in self.actor_query.iter_mut() {
+                    let is_actor = match actor {
+                        CutsceneActor::Hikari => is_hikari,
+                        CutsceneActor::Nozomi => !is_hikari,
+                    };
+                    if !is_actor {
+                        continue;
+                    }
+
+                    match cue {
+                        CutsceneCue::Show(_) => *visibility = Visibility::Inherited,
+                        CutsceneCue::Hide(_) => *visibility = Visibility::Hidden,
+                        _ => {
+                            if let Some(mut player) = player {
+                                for (_, animation) in player.playing_animations_mut() {
+                                    animation.replay();
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn play_cutscene_sound(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    system_volume: &SystemVolume,
+    path: String,
+) -> Entity {
+    commands
+        .spawn((
+            AudioPlayer::new(asset_server.load(path)),
+            PlaybackSettings::DESPAWN
+                .with_volume(Volume::Linear(system_volume.effect_percentage())),
+            EffectSound,
+        ))
+        .id()
+}
+
+#[cfg(target_arch = "wasm32")]
+fn play_cutscene_sound(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    system_volume: &SystemVolume,
+    path: String,
+) -> Entity {
+    commands
+        .spawn((
+            WebAudioPlayer::new(asset_server.load(path)),
+            WebPlaybackSettings::DESPAWN
+                .with_volume(Volume::Linear(system_volume.effect_percentage())),
+            EffectSound,
+        ))
+        .id()
+}
@@ -0,0 +1,151 @@
+use bevy::prelude::*;
+
+use super::*;
+
+/// The time, in seconds, a touch has to be held to restart.
+const HOLD_DURATION: f32 = 0.8;
+/// The color of the gauge that fills while a touch is held.
+const GAUGE_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.8);
+
+// --- PLUGIN ---
+
+/// Lets the player skip the rest of the results and start the next run right away,
+/// by pressing R or by holding a touch, from the end of a run until the result screen.
+pub struct FastRestartPlugin;
+
+impl Plugin for FastRestartPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RestartHold>()
+            .add_systems(Startup, spawn_restart_gauge)
+            .add_systems(OnEnter(GameState::WrapUpInGame), reset_restart_hold)
+            .add_systems(
+                Update,
+                (handle_restart_input, update_restart_gauge)
+                    .chain()
+                    .run_if(in_result_sequence),
+            )
+            .add_systems(OnEnter(GameState::RestartResult), hide_restart_gauge);
+    }
+}
+
+// --- COMPONENTS ---
+
+/// The bar that fills while a touch is held to restart.
+#[derive(Component)]
+struct RestartGauge;
+
+// --- RESOURCES ---
+
+/// How long the current touch has been held, or `None` while no touch started during the results.
+#[derive(Default, Resource)]
+struct RestartHold(Option<f32>);
+
+// --- RUN CONDITIONS ---
+
+fn in_result_sequence(state: Res<State<GameState>>) -> bool {
+    matches!(
+        state.get(),
+        GameState::WrapUpInGame
+            | GameState::FinishedInGame
+            | GameState::CleanUpInGame
+            | GameState::StartResult
+            | GameState::Start2End
+            | GameState::EndResult
+    )
+}
+
+// --- STARTUP SYSTEMS ---
+
+fn spawn_restart_gauge(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Vh(1.5),
+                left: Val::Vw(35.0),
+                width: Val::Vw(30.0),
+                height: Val::Vh(1.0),
+                ..Default::default()
+            },
+            BorderRadius::all(Val::Vh(0.5)),
+            BackgroundColor(CAPTION_BG_COLOR),
+            Visibility::Hidden,
+            UiLayer::Overlay,
+            Pickable::IGNORE,
+            Persistent,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Node {
+                    width: Val::Percent(0.0),
+                    height: Val::Percent(100.0),
+                    ..Default::default()
+                },
+                BorderRadius::all(Val::Vh(0.5)),
+                BackgroundColor(GAUGE_COLOR),
+                Visibility::Inherited,
+                RestartGauge,
+            ));
+        });
+}
+
+// --- SETUP SYSTEMS ---
+
+/// Forgets a touch carried over from the run, so only a new touch can restart.
+fn reset_restart_hold(mut hold: ResMut<RestartHold>) {
+    hold.0 = None;
+}
+
+fn hide_restart_gauge(
+    mut hold: ResMut<RestartHold>,
+    gauge_query: Query<&ChildOf, With<RestartGauge>>,
+    mut visibility_query: Query<&mut Visibility>,
+) {
+    hold.0 = None;
+    for child_of in gauge_query.iter() {
+        if let Ok(mut visibility) = visibility_query.get_mut(child_of.parent()) {
+            *visibility = Visibility::Hidden;
+        }
+    }
+}
+
+// --- UPDATE SYSTEMS ---
+
+fn handle_restart_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    touches: Res<Touches>,
+    mut hold: ResMut<RestartHold>,
+    mut transition: EventWriter<RequestTransition>,
+    time: Res<Time<Real>>,
+) {
+    hold.0 = match hold.0 {
+        _ if touches.iter().next().is_none() => None,
+        Some(elapsed) => Some(elapsed + time.delta_secs()),
+        None if touches.any_just_pressed() => Some(0.0),
+        None => None,
+    };
+
+    let held = hold.0.is_some_and(|elapsed| elapsed >= HOLD_DURATION);
+    if keyboard.just_pressed(KeyCode::KeyR) || held {
+        info!("Fast restart requested");
+        hold.0 = None;
+        transition.write(RequestTransition::iris(GameState::RestartResult));
+    }
+}
+
+fn update_restart_gauge(
+    hold: Res<RestartHold>,
+    mut gauge_query: Query<(&ChildOf, &mut Node), With<RestartGauge>>,
+    mut visibility_query: Query<&mut Visibility>,
+) {
+    for (child_of, mut node) in gauge_query.iter_mut() {
+        let ratio = hold.0.map(|elapsed| (elapsed / HOLD_DURATION).min(1.0));
+        node.width = Val::Percent(ratio.unwrap_or(0.0) * 100.0);
+        if let Ok(mut visibility) = visibility_query.get_mut(child_of.parent()) {
+            visibility.set_if_neq(match ratio {
+                Some(_) => Visibility::Visible,
+                None => Visibility::Hidden,
+            });
+        }
+    }
+}
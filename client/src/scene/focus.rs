@@ -0,0 +1,257 @@
+use bevy::{diagnostic::FrameCount, prelude::*, ui::UiSystem};
+
+use super::*;
+
+// --- PLUGIN ---
+
+/// Lets the menus be used without a pointer: directional navigation between
+/// [`Focusable`] nodes with the keyboard or a gamepad, a focus ring, and
+/// confirm/cancel actions that press the focused (or cancel) button.
+pub struct FocusPlugin;
+
+impl Plugin for FocusPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<UiFocus>()
+            .add_systems(
+                PreUpdate,
+                (navigate_focus, confirm_focus, cancel_focus)
+                    .chain()
+                    .after(UiSystem::Focus)
+                    .run_if(
+                        in_state(GameState::Title)
+                            .or(in_state(GameState::Pause))
+                            .or(in_state(GameState::Option))
+                            .or(in_state(GameState::EndResult)),
+                    ),
+            )
+            .add_systems(PostUpdate, update_focus_ring)
+            .add_systems(Last, release_pressed_button);
+    }
+}
+
+// --- COMPONENTS ---
+
+/// Marks a button or slider that can receive the focus from the keyboard or a gamepad.
+#[derive(Component)]
+pub struct Focusable;
+
+/// Marks the button that is pressed by the cancel action while it is visible.
+#[derive(Component)]
+pub struct FocusCancel;
+
+// --- RESOURCES ---
+
+/// The focused node, and the button pressed by the focus that has yet to be released.
+#[derive(Default, Resource)]
+pub struct UiFocus {
+    focused: Option<Entity>,
+    pressed: Option<(Entity, u32)>,
+}
+
+impl UiFocus {
+    pub fn get(&self) -> Option<Entity> {
+        self.focused
+    }
+}
+
+/// Reads the direction requested by the keyboard or a gamepad in the current frame.
+/// UI coordinates grow downwards, so "up" is the negative y direction.
+fn read_direction(
+    keyboard_input: &ButtonInput<KeyCode>,
+    gamepads: &Query<&Gamepad>,
+    stick_latched: &mut bool,
+) -> Option<Vec2> {
+    for (key, button, direction) in [
+        (KeyCode::ArrowUp, GamepadButton::DPadUp, Vec2::NEG_Y),
+        (KeyCode::ArrowDown, GamepadButton::DPadDown, Vec2::Y),
+        (KeyCode::ArrowLeft, GamepadButton::DPadLeft, Vec2::NEG_X),
+        (KeyCode::ArrowRight, GamepadButton::DPadRight, Vec2::X),
+    ] {
+        if keyboard_input.just_pressed(key)
+            || gamepads.iter().any(|gamepad| gamepad.just_pressed(button))
+        {
+            return Some(direction);
+        }
+    }
+
+    let stick = gamepads
+        .iter()
+        .map(|gamepad| gamepad.left_stick())
+        .find(|stick| stick.length() > STICK_THRESHOLD);
+    match stick {
+        Some(stick) if !*stick_latched => {
+            *stick_latched = true;
+            if stick.x.abs() > stick.y.abs() {
+                Some(Vec2::new(stick.x.signum(), 0.0))
+            } else {
+                // The stick's y axis points upwards.
+                Some(Vec2::new(0.0, -stick.y.signum()))
+            }
+        }
+        // Wait until the stick returns to neutral before moving again.
+        Some(_) => None,
+        None => {
+            *stick_latched = false;
+            None
+        }
+    }
+}
+
+fn just_pressed_any_gamepad(gamepads: &Query<&Gamepad>, button: GamepadButton) -> bool {
+    gamepads.iter().any(|gamepad| gamepad.just_pressed(button))
+}
+
+// --- PREUPDATE SYSTEMS ---
+
+/// Moves the focus to the nearest visible focusable node in the requested direction.
+/// Left and right are left to a focused slider, which adjusts its value instead.
+fn navigate_focus(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    mut stick_latched: Local<bool>,
+    mut focus: ResMut<UiFocus>,
+    mut focused_slider: ResMut<FocusedSlider>,
+    slider_query: Query<(), With<Slider>>,
+    query: Query<(Entity, &GlobalTransform, &InheritedVisibility), With<Focusable>>,
+) {
+    let Some(direction) = read_direction(&keyboard_input, &gamepads, &mut stick_latched) else {
+        return;
+    };
+    if direction.y == 0.0 && focused_slider.get().is_some() {
+        return;
+    }
+
+    let candidates: Vec<_> = query
+        .iter()
+        .filter(|(_, _, visibility)| visibility.get())
+        .map(|(entity, transform, _)| (entity, transform.translation().truncate()))
+        .collect();
+
+    let current = focus
+        .focused
+        .and_then(|focused| candidates.iter().find(|(e, _)| *e == focused).copied());
+
+    let next = match current {
+        Some((entity, origin)) => candidates
+            .iter()
+            .filter(|(e, _)| *e != entity)
+            .filter_map(|&(e, position)| {
+                let offset = position - origin;
+                let along = offset.dot(direction);
+                // Prefer nodes that are straight ahead over nodes off to the side.
+                let across = offset.perp_dot(direction).abs();
+                (along > 0.0).then_some((e, along + across * 2.0))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(e, _)| e),
+        // Without a valid focus, start from the top-left node.
+        None => candidates
+            .iter()
+            .min_by(|a, b| a.1.y.total_cmp(&b.1.y).then(a.1.x.total_cmp(&b.1.x)))
+            .map(|(e, _)| *e),
+    };
+
+    if let Some(next) = next {
+        focus.focused = Some(next);
+        if slider_query.contains(next) {
+            focused_slider.set(next);
+        } else {
+            focused_slider.clear();
+        }
+    }
+}
+
+/// Presses the focused button when the confirm action is requested.
+#[allow(clippy::type_complexity)]
+fn confirm_focus(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    frame: Res<FrameCount>,
+    mut focus: ResMut<UiFocus>,
+    mut query: Query<(&mut Interaction, &InheritedVisibility), (With<Focusable>, Without<Slider>)>,
+) {
+    let confirmed =
+        keyboard_input.any_just_pressed([KeyCode::Enter, KeyCode::NumpadEnter, KeyCode::Space])
+            || just_pressed_any_gamepad(&gamepads, GamepadButton::South);
+
+    if confirmed
+        && let Some(entity) = focus.focused
+        && let Ok((mut interaction, visibility)) = query.get_mut(entity)
+        && visibility.get()
+    {
+        *interaction = Interaction::Pressed;
+        focus.pressed = Some((entity, frame.0));
+    }
+}
+
+/// Presses the visible cancel button when the cancel action is requested.
+/// While a slider is being edited, the cancel action reverts the slider instead.
+fn cancel_focus(
+    gamepads: Query<&Gamepad>,
+    frame: Res<FrameCount>,
+    focused_slider: Res<FocusedSlider>,
+    mut focus: ResMut<UiFocus>,
+    mut query: Query<(Entity, &mut Interaction, &InheritedVisibility), With<FocusCancel>>,
+) {
+    if !just_pressed_any_gamepad(&gamepads, GamepadButton::East) || focused_slider.is_editing() {
+        return;
+    }
+
+    if let Some((entity, mut interaction, _)) =
+        query.iter_mut().find(|(_, _, visibility)| visibility.get())
+    {
+        *interaction = Interaction::Pressed;
+        focus.pressed = Some((entity, frame.0));
+    }
+}
+
+// --- POSTUPDATE SYSTEMS ---
+
+/// Draws an outline around the focused node and removes it from any other node.
+fn update_focus_ring(
+    mut commands: Commands,
+    mut focus: ResMut<UiFocus>,
+    query: Query<&InheritedVisibility, With<Focusable>>,
+    outlined: Query<Entity, (With<Focusable>, With<Outline>)>,
+) {
+    // Drop the focus once the focused node is hidden or gone, e.g. after leaving its screen.
+    if let Some(entity) = focus.focused
+        && !query.get(entity).is_ok_and(|visibility| visibility.get())
+    {
+        focus.focused = None;
+    }
+
+    for entity in outlined.iter() {
+        if Some(entity) != focus.focused {
+            commands.entity(entity).remove::<Outline>();
+        }
+    }
+
+    if let Some(entity) = focus.focused
+        && !outlined.contains(entity)
+    {
+        commands
+            .entity(entity)
+            .insert(Outline::new(Val::Px(3.0), Val::Px(2.0), FOCUS_RING_COLOR));
+    }
+}
+
+// --- LAST SYSTEMS ---
+
+/// Releases a button pressed by the focus once every system has had a frame to react to it.
+fn release_pressed_button(
+    frame: Res<FrameCount>,
+    mut focus: ResMut<UiFocus>,
+    mut query: Query<&mut Interaction>,
+) {
+    if let Some((entity, pressed_frame)) = focus.pressed
+        && frame.0 != pressed_frame
+    {
+        if let Ok(mut interaction) = query.get_mut(entity)
+            && *interaction == Interaction::Pressed
+        {
+            *interaction = Interaction::None;
+        }
+        focus.pressed = None;
+    }
+}
@@ -0,0 +1,65 @@
+use bevy::prelude::*;
+
+use super::*;
+
+/// The color of the sunlight scattered in the fog when atmospheric scattering is on.
+const SCATTERING_COLOR: Color = Color::srgba(1.0, 0.92, 0.78, 0.6);
+/// How tightly the scattered sunlight gathers around the direction of the sun.
+const SCATTERING_EXPONENT: f32 = 24.0;
+
+// --- PLUGIN ---
+
+/// Fades the end of the track into the sky, so that objects do not pop in at the spawn position.
+/// The fog takes the clear color, and follows the fog options and the track horizon of the run.
+pub struct FogPlugin;
+
+impl Plugin for FogPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PostUpdate, apply_distance_fog);
+    }
+}
+
+// --- POSTUPDATE SYSTEMS ---
+
+/// Adds, updates or removes the fog of the in-game camera when the camera is spawned
+/// or the options, the clear color or the track horizon change.
+fn apply_distance_fog(
+    mut commands: Commands,
+    options: Res<GraphicsOptions>,
+    clear_color: Res<ClearColor>,
+    horizon: Option<Res<TrackHorizon>>,
+    query: Query<(Entity, Ref<CameraRig>)>,
+) {
+    let Some(horizon) = horizon else {
+        return;
+    };
+
+    for (entity, rig) in query.iter() {
+        if !rig.is_added()
+            && !options.is_changed()
+            && !clear_color.is_changed()
+            && !horizon.is_changed()
+        {
+            continue;
+        }
+
+        match options.fog_mode.falloff(&horizon) {
+            Some(falloff) => {
+                let (directional_light_color, directional_light_exponent) =
+                    match options.atmospheric_scattering {
+                        true => (SCATTERING_COLOR, SCATTERING_EXPONENT),
+                        false => (Color::NONE, SCATTERING_EXPONENT),
+                    };
+                commands.entity(entity).insert(DistanceFog {
+                    color: clear_color.0,
+                    directional_light_color,
+                    directional_light_exponent,
+                    falloff,
+                });
+            }
+            None => {
+                commands.entity(entity).remove::<DistanceFog>();
+            }
+        }
+    }
+}
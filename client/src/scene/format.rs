@@ -0,0 +1,72 @@
+use crate::asset::locale::Locale;
+
+/// Returns the character placed between each group of three digits in the given locale.
+pub const fn digit_group_separator(locale: Locale) -> char {
+    match locale {
+        Locale::En => ',',
+        Locale::Ja => ',',
+        Locale::Ko => ',',
+    }
+}
+
+/// Formats a count with its digits grouped the way the given locale writes them, such as `12,345`.
+pub fn format_number(value: u32, locale: Locale) -> String {
+    let digits = value.to_string();
+    let separator = digit_group_separator(locale);
+
+    let mut formatted = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            formatted.push(separator);
+        }
+        formatted.push(digit);
+    }
+    formatted
+}
+
+/// Formats the difference between two counts with an arrow pointing the way it went, such as `▲1,250`.
+pub fn format_delta(delta: i64, locale: Locale) -> String {
+    let value = u32::try_from(delta.unsigned_abs()).unwrap_or(u32::MAX);
+    match delta.signum() {
+        1 => format!("▲{}", format_number(value, locale)),
+        -1 => format!("▼{}", format_number(value, locale)),
+        _ => "±0".to_string(),
+    }
+}
+
+/// Formats a duration in milliseconds as minutes, seconds and tenths of a second, such as `03:07.4`.
+/// Minutes keep counting past an hour rather than wrapping around.
+pub fn format_play_time(millis: u128) -> String {
+    let tenths = millis / 100;
+    let minutes = tenths / (10 * 60);
+    let seconds = (tenths / 10) % 60;
+    format!("{:02}:{:02}.{}", minutes, seconds, tenths % 10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_number_groups_digits() {
+        assert_eq!(format_number(0, Locale::En), "0");
+        assert_eq!(format_number(999, Locale::En), "999");
+        assert_eq!(format_number(1_000, Locale::Ja), "1,000");
+        assert_eq!(format_number(123_456, Locale::Ko), "123,456");
+        assert_eq!(format_number(u32::MAX, Locale::En), "4,294,967,295");
+    }
+
+    #[test]
+    fn test_format_delta_points_the_way_it_went() {
+        assert_eq!(format_delta(1_250, Locale::En), "▲1,250");
+        assert_eq!(format_delta(-120, Locale::Ja), "▼120");
+        assert_eq!(format_delta(0, Locale::Ko), "±0");
+    }
+
+    #[test]
+    fn test_format_play_time_rounds_down_to_tenths() {
+        assert_eq!(format_play_time(0), "00:00.0");
+        assert_eq!(format_play_time(187_499), "03:07.4");
+        assert_eq!(format_play_time(3_600_000), "60:00.0");
+    }
+}
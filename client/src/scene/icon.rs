@@ -0,0 +1,56 @@
+use bevy::prelude::*;
+
+use super::*;
+
+// --- PLUGIN ---
+
+/// Draws the icons of the interface from a single atlas, so that a button only names the icon it shows.
+pub struct IconPlugin;
+
+impl Plugin for IconPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(apply_icon);
+    }
+}
+
+// --- COMPONENTS ---
+
+/// The icons of the icon atlas, in the order of the rectangles of its `.atlas` file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Icon {
+    Pause,
+    Settings,
+    Audio,
+    Trophy,
+    Share,
+}
+
+impl Icon {
+    /// The index of the icon in the icon atlas.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// An [`ImageNode`] that shows an icon of the icon atlas.
+/// The image is set when the icon is inserted, keeping the color of the node to tint the icon.
+#[derive(Component)]
+#[require(ImageNode)]
+pub struct IconNode(pub Icon);
+
+// --- OBSERVERS ---
+
+/// Points the image of an icon node at its icon in the icon atlas.
+fn apply_icon(
+    trigger: Trigger<OnInsert, IconNode>,
+    asset_server: Res<AssetServer>,
+    mut query: Query<(&IconNode, &mut ImageNode)>,
+) {
+    if let Ok((icon, mut image)) = query.get_mut(trigger.target()) {
+        image.image = asset_server.load(TEXTURE_PATH_ICONS);
+        image.texture_atlas = Some(TextureAtlas {
+            layout: asset_server.load(ATLAS_PATH_ICONS),
+            index: icon.0.index(),
+        });
+    }
+}
@@ -20,7 +20,9 @@ impl Plugin for StatePlugin {
                 remove_effect_sounds,
                 remove_voice_sounds,
             ),
-        );
+        )
+        // A fast restart can skip this state, leaving the player effect on the shared materials.
+        .add_systems(OnEnter(GameState::RestartResult), clear_player_effect);
     }
 }
 
@@ -49,13 +51,8 @@ fn remove_voice_sounds(mut commands: Commands, query: Query<Entity, With<VoiceSo
     }
 }
 
-#[allow(clippy::type_complexity)]
 fn clear_player_effect(
-    mut set: ParamSet<(
-        Query<Entity, With<ToyTrain0>>,
-        Query<Entity, With<ToyTrain1>>,
-        Query<Entity, With<ToyTrain2>>,
-    )>,
+    car_query: Query<Entity, With<TrainCar>>,
     children_query: Query<&Children>,
     base_color_query: Query<&BaseColor>,
     standard_material_query: Query<&MeshMaterial3d<StandardMaterial>>,
@@ -63,31 +60,7 @@ fn clear_player_effect(
     mut standard_materials: ResMut<Assets<StandardMaterial>>,
     mut extended_materials: ResMut<Assets<EyeMouthMaterial>>,
 ) {
-    if let Ok(entity) = set.p0().single() {
-        clear_player_effect_recursive(
-            entity,
-            &children_query,
-            &base_color_query,
-            &standard_material_query,
-            &extented_material_query,
-            &mut standard_materials,
-            &mut extended_materials,
-        );
-    }
-
-    if let Ok(entity) = set.p1().single() {
-        clear_player_effect_recursive(
-            entity,
-            &children_query,
-            &base_color_query,
-            &standard_material_query,
-            &extented_material_query,
-            &mut standard_materials,
-            &mut extended_materials,
-        );
-    }
-
-    if let Ok(entity) = set.p2().single() {
+    for entity in car_query.iter() {
         clear_player_effect_recursive(
             entity,
             &children_query,
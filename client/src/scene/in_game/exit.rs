@@ -35,15 +35,26 @@ fn debug_label(mut next_state: ResMut<NextState<GameState>>) {
 fn remove_resource(mut commands: Commands) {
     commands.remove_resource::<Attacked>();
     commands.remove_resource::<PlayTime>();
+    commands.remove_resource::<RunPace>();
     commands.remove_resource::<TrainFuel>();
     commands.remove_resource::<InputDelay>();
     commands.remove_resource::<CurrentScore>();
     commands.remove_resource::<IsPlayerJumping>();
     commands.remove_resource::<CurrentState>();
+    commands.remove_resource::<PlayerShield>();
+    commands.remove_resource::<BellChime>();
+    commands.remove_resource::<SoundRotation>();
+    commands.remove_resource::<TrainHorn>();
+    commands.remove_resource::<TimeScale>();
+    commands.remove_resource::<GradingState>();
+    commands.remove_resource::<FuelAlarmState>();
     commands.remove_resource::<RetiredGrounds>();
     commands.remove_resource::<ObjectSpawner>();
     commands.remove_resource::<Tok9TrainSpawner>();
+    commands.remove_resource::<TrafficSpawner>();
+    commands.remove_resource::<TrackHorizon>();
     commands.remove_resource::<InGameAssets>();
+    commands.remove_resource::<ResultPreload>();
 }
 
 fn remove_entities(
@@ -72,13 +83,8 @@ fn remove_voice_sounds(mut commands: Commands, query: Query<Entity, With<VoiceSo
     }
 }
 
-#[allow(clippy::type_complexity)]
 fn clear_player_effect(
-    mut set: ParamSet<(
-        Query<Entity, With<ToyTrain0>>,
-        Query<Entity, With<ToyTrain1>>,
-        Query<Entity, With<ToyTrain2>>,
-    )>,
+    car_query: Query<Entity, With<TrainCar>>,
     children_query: Query<&Children>,
     base_color_query: Query<&BaseColor>,
     standard_material_query: Query<&MeshMaterial3d<StandardMaterial>>,
@@ -86,31 +92,7 @@ fn clear_player_effect(
     mut standard_materials: ResMut<Assets<StandardMaterial>>,
     mut extended_materials: ResMut<Assets<EyeMouthMaterial>>,
 ) {
-    if let Ok(entity) = set.p0().single() {
-        clear_player_effect_recursive(
-            entity,
-            &children_query,
-            &base_color_query,
-            &standard_material_query,
-            &extented_material_query,
-            &mut standard_materials,
-            &mut extended_materials,
-        );
-    }
-
-    if let Ok(entity) = set.p1().single() {
-        clear_player_effect_recursive(
-            entity,
-            &children_query,
-            &base_color_query,
-            &standard_material_query,
-            &extented_material_query,
-            &mut standard_materials,
-            &mut extended_materials,
-        );
-    }
-
-    if let Ok(entity) = set.p2().single() {
+    for entity in car_query.iter() {
         clear_player_effect_recursive(
             entity,
             &children_query,
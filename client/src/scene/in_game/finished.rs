@@ -112,14 +112,16 @@ fn end_timer(mut commands: Commands) {
 
 // --- UPDATE SYSTEMS ---
 
+/// Moves on to the result once the finish label has been shown, and the result scene has loaded.
 fn update_scene_timer(
-    mut next_state: ResMut<NextState<GameState>>,
+    mut transition: EventWriter<RequestTransition>,
     mut timer: ResMut<SceneTimer>,
+    preload: Res<ResultPreload>,
     time: Res<Time>,
 ) {
     timer.tick(time.delta_secs());
-    if timer.elapsed_sec() >= SCENE_DURATION {
-        next_state.set(GameState::CleanUpInGame);
+    if timer.elapsed_sec() >= SCENE_DURATION && preload.is_spawned() {
+        transition.write(RequestTransition::fade(GameState::CleanUpInGame));
     }
 }
 
@@ -128,6 +130,7 @@ fn update_ground_position(
     mut ground_entities: Query<(Entity, &mut Transform), With<Ground>>,
     mut retired: ResMut<RetiredGrounds>,
     time: Res<Time>,
+    horizon: Res<TrackHorizon>,
 ) {
     let player_velocity = player_query
         .single()
@@ -137,7 +140,7 @@ fn update_ground_position(
     for (entity, mut transform) in ground_entities.iter_mut() {
         transform.translation.z -= player_velocity * time.delta_secs();
 
-        if transform.translation.z <= DESPAWN_POSITION {
+        if transform.translation.z <= horizon.despawn {
             retired.push(entity);
         }
     }
@@ -148,6 +151,7 @@ fn update_object_position(
     mut object_entities: Query<(Entity, &mut Transform), With<Object>>,
     player_query: Query<&ForwardMovement, With<Player>>,
     time: Res<Time>,
+    horizon: Res<TrackHorizon>,
 ) {
     let player_velocity = player_query
         .single()
@@ -157,7 +161,7 @@ fn update_object_position(
     for (entity, mut transform) in object_entities.iter_mut() {
         transform.translation.z -= player_velocity * time.delta_secs();
 
-        if transform.translation.z <= DESPAWN_POSITION {
+        if transform.translation.z <= horizon.despawn {
             commands.entity(entity).despawn();
         }
     }
@@ -190,15 +194,26 @@ fn fade_in_animation(
 
 // --- POSTUPDATE SYSTEMS ---
 
-fn spawn_grounds(mut commands: Commands, mut retired: ResMut<RetiredGrounds>) {
+fn spawn_grounds(
+    mut commands: Commands,
+    mut retired: ResMut<RetiredGrounds>,
+    ground_query: Query<&Transform, With<Ground>>,
+    horizon: Res<TrackHorizon>,
+) {
+    let grounds = horizon.grounds();
+    let mut front = ground_query
+        .iter()
+        .map(|transform| transform.translation.z)
+        .fold(horizon.despawn, f32::max);
     while let Some(entity) = retired.pop() {
+        front = grounds.recycled_depth(front);
         commands
             .entity(entity)
             .entry::<Transform>()
-            .and_modify(|mut transform| {
-                transform.translation.z += SPAWN_POSITION - DESPAWN_POSITION;
+            .and_modify(move |mut transform| {
+                transform.translation.z = front;
             })
-            .or_insert(Transform::from_xyz(0.0, 0.0, SPAWN_POSITION));
+            .or_insert(Transform::from_xyz(0.0, 0.0, front));
     }
 }
 
@@ -1,5 +1,9 @@
 // Import necessary Bevy modules.
-use bevy::{audio::Volume, prelude::*};
+use bevy::{
+    audio::Volume,
+    pbr::{NotShadowCaster, NotShadowReceiver},
+    prelude::*,
+};
 
 use crate::{
     asset::{
@@ -7,7 +11,7 @@ use crate::{
         sound::SystemVolume,
         spawner::{SpawnModel, TranslatableText},
     },
-    collider::Collider,
+    shader::{speed_lines::SpeedLineMaterial, vignette::VignetteMaterial},
 };
 
 #[cfg(target_arch = "wasm32")]
@@ -25,7 +29,10 @@ impl Plugin for StatePlugin {
             OnEnter(GameState::InitInGame),
             (debug_label, play_loading_sound, spawn_entities),
         )
-        .add_systems(OnExit(GameState::InitInGame), remove_resource)
+        .add_systems(
+            OnExit(GameState::InitInGame),
+            (remove_resource, remove_entities),
+        )
         .add_systems(
             Update,
             (
@@ -70,9 +77,41 @@ fn play_loading_sound(
     ));
 }
 
-fn spawn_entities(mut commands: Commands, asset_server: Res<AssetServer>) {
+#[allow(clippy::too_many_arguments)]
+fn spawn_entities(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut materials: ResMut<Assets<SpeedLineMaterial>>,
+    mut vignette_materials: ResMut<Assets<VignetteMaterial>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut standard_materials: ResMut<Assets<StandardMaterial>>,
+    upgrades: Res<TrainUpgrades>,
+    options: Res<GraphicsOptions>,
+) {
+    // The horizon is kept until the scene is unloaded, since the grounds are laid for it.
+    let horizon = options.device_tier.horizon();
+    commands.insert_resource(horizon);
+
     let mut loading_entities = LoadingEntities::default();
-    spawn_in_game_entities(&mut commands, &asset_server, &mut loading_entities);
+    spawn_in_game_entities(
+        &mut commands,
+        &asset_server,
+        &mut loading_entities,
+        &upgrades,
+        &horizon,
+    );
+    spawn_speed_line_entity(&mut commands, &mut materials, &mut loading_entities);
+    spawn_vignette_entity(
+        &mut commands,
+        &mut vignette_materials,
+        &mut loading_entities,
+    );
+    spawn_landing_marker_entity(
+        &mut commands,
+        &mut meshes,
+        &mut standard_materials,
+        &mut loading_entities,
+    );
     spawn_in_game_ui_entities(&mut commands, &asset_server, &mut loading_entities);
     spawn_pause_ui_entities(&mut commands, &asset_server, &mut loading_entities);
     commands.insert_resource(loading_entities);
@@ -82,18 +121,19 @@ fn spawn_in_game_entities(
     commands: &mut Commands,
     asset_server: &AssetServer,
     loading_entities: &mut LoadingEntities,
+    upgrades: &TrainUpgrades,
+    horizon: &TrackHorizon,
 ) {
     let entity = commands
         .spawn((
             Transform::from_xyz(LANE_POSITIONS[NUM_LANES / 2], 0.0, PLAYER_MAX_Z_POS),
-            Collider::Aabb {
-                offset: Vec3::new(0.0, 0.5, -1.5),
-                size: Vec3::new(0.9, 1.0, 3.6),
-            },
+            upgrades.collider(),
             Acceleration::new(ACCELERATION),
             ForwardMovement::new(MIN_PLAYER_SPEED),
             VerticalMovement::new(0.0),
+            AirDash::default(),
             Lane::default(),
+            FollowTarget { lead: 1.5 },
             SpawnRequest,
             Player,
         ))
@@ -101,8 +141,7 @@ fn spawn_in_game_entities(
     loading_entities.handles.push(entity);
 
     let model = asset_server.load(MODEL_PATH_PLANE_0);
-    let mut plane_location = DESPAWN_POSITION;
-    while plane_location <= SPAWN_POSITION {
+    for plane_location in horizon.grounds().depths() {
         let entity = commands
             .spawn((
                 SpawnModel(model.clone()),
@@ -113,7 +152,6 @@ fn spawn_in_game_entities(
             ))
             .id();
         loading_entities.handles.push(entity);
-        plane_location += GROUND_SPAWN_INTERVAL;
     }
 
     let model = asset_server.load(MODEL_PATH_TOY_TRAIN_00);
@@ -123,19 +161,37 @@ fn spawn_in_game_entities(
             Transform::IDENTITY,
             Visibility::Hidden,
             SpawnRequest,
-            ToyTrain0,
+            TrainCar,
+            ChainLink {
+                index: 0,
+                offset: 0.0,
+            },
         ))
+        .with_children(|parent| {
+            parent.spawn((
+                SpawnModel(asset_server.load(MODEL_PATH_GLOW)),
+                Transform::from_xyz(0.0, 0.5, 0.0).with_scale(SHIELD_GLOW_SCALE),
+                Visibility::Hidden,
+                GlowRoot,
+                BillBoard,
+                ShieldGlow,
+            ));
+        })
         .id();
     loading_entities.handles.push(entity);
 
     let model = asset_server.load(MODEL_PATH_TOY_TRAIN_01);
     let entity = commands
         .spawn((
-            SpawnModel(model),
+            SpawnModel(model.clone()),
             Transform::IDENTITY,
             Visibility::Hidden,
             SpawnRequest,
-            ToyTrain1,
+            TrainCar,
+            ChainLink {
+                index: 1,
+                offset: TRAIN_CAR_SPACING,
+            },
         ))
         .with_children(|parent| {
             let clip = asset_server.load(ANIM_PATH_HIKARI_IN_GAME);
@@ -146,12 +202,30 @@ fn spawn_in_game_entities(
                 Transform::from_xyz(0.0, 0.8775, 0.0),
                 Visibility::Inherited,
                 InGameStateEntity,
+                BankRider,
                 Hikari,
             ));
         })
         .id();
     loading_entities.handles.push(entity);
 
+    // The bought cars are empty passenger cars coupled between Hikari's car and the tail car.
+    let mut offset = TRAIN_CAR_SPACING;
+    for index in 2..2 + upgrades.extra_cars {
+        offset += TRAIN_CAR_SPACING;
+        let entity = commands
+            .spawn((
+                SpawnModel(model.clone()),
+                Transform::IDENTITY,
+                Visibility::Hidden,
+                SpawnRequest,
+                TrainCar,
+                ChainLink { index, offset },
+            ))
+            .id();
+        loading_entities.handles.push(entity);
+    }
+
     let model = asset_server.load(MODEL_PATH_TOY_TRAIN_02);
     let entity = commands
         .spawn((
@@ -159,7 +233,11 @@ fn spawn_in_game_entities(
             Transform::IDENTITY,
             Visibility::Hidden,
             SpawnRequest,
-            ToyTrain2,
+            TrainCar,
+            ChainLink {
+                index: 2 + upgrades.extra_cars,
+                offset: offset + TRAIN_TAIL_SPACING,
+            },
         ))
         .with_children(|parent| {
             let clip = asset_server.load(ANIM_PATH_NOZOMI_IN_GAME);
@@ -170,6 +248,7 @@ fn spawn_in_game_entities(
                 Transform::from_xyz(0.0, 0.5, 0.375),
                 Visibility::Inherited,
                 InGameStateEntity,
+                BankRider,
                 Nozomi,
             ));
         })
@@ -177,6 +256,85 @@ fn spawn_in_game_entities(
     loading_entities.handles.push(entity);
 }
 
+fn spawn_speed_line_entity(
+    commands: &mut Commands,
+    materials: &mut Assets<SpeedLineMaterial>,
+    loading_entities: &mut LoadingEntities,
+) {
+    let entity = commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                ..Default::default()
+            },
+            MaterialNode(materials.add(SpeedLineMaterial::default())),
+            Pickable::IGNORE,
+            SpawnRequest,
+            SpeedLines,
+            UiLayer::World,
+        ))
+        .id();
+    loading_entities.handles.push(entity);
+}
+
+fn spawn_vignette_entity(
+    commands: &mut Commands,
+    materials: &mut Assets<VignetteMaterial>,
+    loading_entities: &mut LoadingEntities,
+) {
+    let entity = commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                ..Default::default()
+            },
+            MaterialNode(materials.add(VignetteMaterial::default())),
+            Pickable::IGNORE,
+            SpawnRequest,
+            LowFuelVignette,
+            UiLayer::World,
+        ))
+        .id();
+    loading_entities.handles.push(entity);
+}
+
+fn spawn_landing_marker_entity(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    loading_entities: &mut LoadingEntities,
+) {
+    let entity = commands
+        .spawn((
+            Mesh3d(
+                meshes.add(
+                    Plane3d::default()
+                        .mesh()
+                        .size(LANDING_MARKER_SIZE.x, LANDING_MARKER_SIZE.y),
+                ),
+            ),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                // Fully transparent until the player jumps.
+                base_color: LANDING_MARKER_COLOR.with_alpha(0.0),
+                alpha_mode: AlphaMode::Blend,
+                unlit: true,
+                ..Default::default()
+            })),
+            Transform::from_xyz(0.0, LANDING_MARKER_HEIGHT, 0.0),
+            NotShadowCaster,
+            NotShadowReceiver,
+            Visibility::Hidden,
+            SpawnRequest,
+            LandingMarker,
+        ))
+        .id();
+    loading_entities.handles.push(entity);
+}
+
 fn spawn_in_game_ui_entities(
     commands: &mut Commands,
     asset_server: &AssetServer,
@@ -194,6 +352,7 @@ fn spawn_in_game_ui_entities(
                 ..Default::default()
             },
             SpawnRequest,
+            UiLayer::Overlay,
         ))
         .with_children(|parent| {
             let texture = asset_server.load(FONT_PATH_START);
@@ -206,7 +365,7 @@ fn spawn_in_game_ui_entities(
                 },
                 Visibility::Hidden,
                 UI::StartLabel,
-                ZIndex(4),
+                LocalizedBanner::new(Banner::Start),
             ));
         })
         .id();
@@ -224,6 +383,7 @@ fn spawn_in_game_ui_entities(
                 ..Default::default()
             },
             SpawnRequest,
+            UiLayer::Overlay,
         ))
         .with_children(|parent| {
             let texture = asset_server.load(FONT_PATH_FINISH);
@@ -236,7 +396,82 @@ fn spawn_in_game_ui_entities(
                 },
                 Visibility::Hidden,
                 UI::FinishLabel,
-                ZIndex(4),
+                LocalizedBanner::new(Banner::Finish),
+            ));
+        })
+        .id();
+    loading_entities.handles.push(entity);
+
+    // --- Fuel Indicators ---
+    let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
+    for _ in 0..NUM_FUEL_INDICATORS {
+        let entity = commands
+            .spawn((
+                Text::default(),
+                TextFont::from_font(font.clone()),
+                TextLayout::new_with_justify(JustifyText::Center),
+                TextColor(FUEL_GAIN_COLOR),
+                ResizableFont::vertical(1280.0, 36.0),
+                Node {
+                    position_type: PositionType::Absolute,
+                    ..Default::default()
+                },
+                Pickable::IGNORE,
+                Visibility::Hidden,
+                SpawnRequest,
+                UiLayer::Overlay,
+                FuelIndicator { remaining: 0.0 },
+            ))
+            .id();
+        loading_entities.handles.push(entity);
+    }
+
+    // --- Pace Clock ---
+    let entity = commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Vh(1.5),
+                left: Val::Vw(35.0),
+                width: Val::Vw(30.0),
+                height: Val::Vw(3.0),
+                flex_direction: FlexDirection::Row,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                column_gap: Val::Vw(1.0),
+                ..Default::default()
+            },
+            Pickable::IGNORE,
+            SpawnRequest,
+            Visibility::Hidden,
+            UI::PaceClock,
+            UiLayer::Hud,
+        ))
+        .with_children(|parent| {
+            let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
+            parent.spawn((
+                Text::new(format_play_time(0)),
+                TextFont::from_font(font.clone()),
+                TextLayout::new_with_justify(JustifyText::Center),
+                TextColor::WHITE,
+                TextShadow::default(),
+                ResizableFont::vertical(1280.0, 40.0),
+                Node::default(),
+                Visibility::Inherited,
+                UI::PaceTime,
+            ));
+
+            // The pace is filled in once the run can be compared with the best run.
+            parent.spawn((
+                Text::new(""),
+                TextFont::from_font(font),
+                TextLayout::new_with_justify(JustifyText::Center),
+                TextColor::WHITE,
+                TextShadow::default(),
+                ResizableFont::vertical(1280.0, 32.0),
+                Node::default(),
+                Visibility::Inherited,
+                UI::PaceDelta,
             ));
         })
         .id();
@@ -251,6 +486,8 @@ fn spawn_in_game_ui_entities(
                 right: Val::Vw(1.5),
                 width: Val::Vw(4.5),
                 height: Val::Vw(4.5),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
                 ..Default::default()
             },
             BackgroundColor(PAUSE_BTN_COLOR),
@@ -258,38 +495,133 @@ fn spawn_in_game_ui_entities(
             SpawnRequest,
             Visibility::Hidden,
             UI::PauseButton,
-            ZIndex(1),
+            UiLayer::Hud,
             Button,
         ))
         .with_children(|parent| {
             parent.spawn((
+                IconNode(Icon::Pause),
+                ImageNode::default().with_color(PAUSE_ICON_COLOR),
                 Node {
-                    top: Val::Percent(20.0),
-                    left: Val::Percent(30.0),
-                    width: Val::Percent(15.0),
+                    width: Val::Percent(60.0),
                     height: Val::Percent(60.0),
                     ..Default::default()
                 },
-                BorderRadius::all(Val::Percent(50.0)),
-                BackgroundColor(PAUSE_ICON_COLOR),
                 Visibility::Inherited,
-                ZIndex(2),
+                UiLayer::Hud,
             ));
+        })
+        .id();
+    loading_entities.handles.push(entity);
 
+    // --- Throw Button ---
+    let entity = commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Vh(1.5),
+                left: Val::Vw(1.5),
+                width: Val::Vw(14.0),
+                height: Val::Vw(4.5),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            BackgroundColor(THROW_BTN_COLOR),
+            BorderRadius::all(Val::Percent(30.0)),
+            SpawnRequest,
+            Visibility::Hidden,
+            UI::ThrowButton,
+            UiLayer::Hud,
+            Button,
+        ))
+        .with_children(|parent| {
+            let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
             parent.spawn((
-                Node {
-                    position_type: PositionType::Absolute,
-                    top: Val::Percent(20.0),
-                    right: Val::Percent(30.0),
-                    width: Val::Percent(15.0),
-                    height: Val::Percent(60.0),
-                    ..Default::default()
-                },
-                BorderRadius::all(Val::Percent(50.0)),
-                BackgroundColor(PAUSE_ICON_COLOR),
+                // The number of whistles that can be thrown is filled in during the run.
+                Text::new(""),
+                TextFont::from_font(font),
+                TextLayout::new_with_justify(JustifyText::Center),
+                TextColor::BLACK,
+                ResizableFont::vertical(1280.0, 36.0),
+                Node::default(),
                 Visibility::Inherited,
-                ZIndex(2),
+                UI::ThrowText,
+                UiLayer::Hud,
             ));
+
+            // The air-dash gauge sits just above the button, so it slides in and out with it.
+            parent
+                .spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        bottom: Val::Percent(110.0),
+                        width: Val::Percent(100.0),
+                        height: Val::Vh(1.0),
+                        ..Default::default()
+                    },
+                    BackgroundColor(AIR_DASH_GAUGE_BG_COLOR),
+                    BorderRadius::all(Val::Percent(50.0)),
+                    Visibility::Inherited,
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Node {
+                            width: Val::Percent(100.0),
+                            height: Val::Percent(100.0),
+                            ..Default::default()
+                        },
+                        BackgroundColor(AIR_DASH_READY_COLOR),
+                        BorderRadius::all(Val::Percent(50.0)),
+                        Visibility::Inherited,
+                        UI::AirDashGauge,
+                    ));
+                });
+
+            // The steam whistle button sits beside the throw button, shaded while it recharges.
+            parent
+                .spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Percent(110.0),
+                        height: Val::Percent(100.0),
+                        aspect_ratio: Some(1.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        overflow: Overflow::clip(),
+                        ..Default::default()
+                    },
+                    BackgroundColor(HORN_BTN_COLOR),
+                    BorderRadius::all(Val::Percent(30.0)),
+                    Visibility::Inherited,
+                    UI::HornButton,
+                    Button,
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        IconNode(Icon::Audio),
+                        ImageNode::default().with_color(Color::BLACK),
+                        Node {
+                            width: Val::Percent(60.0),
+                            height: Val::Percent(60.0),
+                            ..Default::default()
+                        },
+                        Visibility::Inherited,
+                    ));
+                    parent.spawn((
+                        Node {
+                            position_type: PositionType::Absolute,
+                            bottom: Val::Px(0.0),
+                            width: Val::Percent(100.0),
+                            height: Val::Percent(0.0),
+                            ..Default::default()
+                        },
+                        BackgroundColor(HORN_COOLDOWN_COLOR),
+                        Pickable::IGNORE,
+                        Visibility::Inherited,
+                        UI::HornCooldown,
+                    ));
+                });
         })
         .id();
     loading_entities.handles.push(entity);
@@ -311,7 +643,7 @@ fn spawn_in_game_ui_entities(
             SpawnRequest,
             Visibility::Hidden,
             UI::Score,
-            ZIndex(1),
+            UiLayer::Hud,
         ))
         .with_children(|parent| {
             let texture = asset_server.load(FONT_PATH_NUMBER);
@@ -404,7 +736,7 @@ fn spawn_in_game_ui_entities(
             SpawnRequest,
             Visibility::Hidden,
             UI::Fuel,
-            ZIndex(1),
+            UiLayer::Hud,
         ))
         .with_children(|parent| {
             // --- Fuel Deco ---
@@ -433,7 +765,7 @@ fn spawn_in_game_ui_entities(
                             ..Default::default()
                         },
                         Visibility::Inherited,
-                        ZIndex(2),
+                        UiLayer::Hud,
                         FuelDeco,
                     ));
                 });
@@ -452,21 +784,52 @@ fn spawn_in_game_ui_entities(
                     BorderColor(FUEL_COLOR),
                     BorderRadius::all(Val::Percent(50.0)),
                     Visibility::Inherited,
-                    ZIndex(2),
+                    UiLayer::Hud,
+                    FuelGaugeFrame,
                 ))
                 .with_children(|parent| {
                     parent.spawn((
                         Node {
+                            position_type: PositionType::Absolute,
                             width: Val::Percent(100.0),
                             height: Val::Percent(100.0),
                             ..Default::default()
                         },
-                        BackgroundColor(FUEL_GOOD_GAUGE_COLOR),
+                        BackgroundColor(FUEL_GHOST_GAUGE_COLOR),
                         BorderRadius::all(Val::Percent(50.0)),
                         Visibility::Inherited,
-                        ZIndex(3),
-                        FuelGauge,
+                        UiLayer::Hud,
+                        FuelGaugeGhost,
                     ));
+
+                    parent
+                        .spawn((
+                            Node {
+                                width: Val::Percent(100.0),
+                                height: Val::Percent(100.0),
+                                overflow: Overflow::clip(),
+                                ..Default::default()
+                            },
+                            BackgroundColor(FUEL_GOOD_GAUGE_COLOR),
+                            BorderRadius::all(Val::Percent(50.0)),
+                            Visibility::Inherited,
+                            UiLayer::Overlay,
+                            FuelGauge,
+                            FuelGaugeTween::default(),
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn((
+                                Node {
+                                    position_type: PositionType::Absolute,
+                                    width: Val::Percent(FUEL_GAUGE_SWEEP_WIDTH),
+                                    height: Val::Percent(100.0),
+                                    ..Default::default()
+                                },
+                                BackgroundColor(FUEL_SWEEP_COLOR),
+                                Visibility::Hidden,
+                                FuelGaugeSweep,
+                            ));
+                        });
                 });
         })
         .id();
@@ -492,6 +855,7 @@ fn spawn_pause_ui_entities(
             SpawnRequest,
             Visibility::Hidden,
             UI::ResumeCount1,
+            UiLayer::Overlay,
         ))
         .with_children(|parent| {
             let texture = asset_server.load(FONT_PATH_NUM_1);
@@ -503,7 +867,6 @@ fn spawn_pause_ui_entities(
                     ..Default::default()
                 },
                 Visibility::Inherited,
-                ZIndex(5),
             ));
         })
         .id();
@@ -523,6 +886,7 @@ fn spawn_pause_ui_entities(
             SpawnRequest,
             Visibility::Hidden,
             UI::ResumeCount2,
+            UiLayer::Overlay,
         ))
         .with_children(|parent| {
             let texture = asset_server.load(FONT_PATH_NUM_2);
@@ -534,7 +898,6 @@ fn spawn_pause_ui_entities(
                     ..Default::default()
                 },
                 Visibility::Inherited,
-                ZIndex(5),
             ));
         })
         .id();
@@ -554,6 +917,7 @@ fn spawn_pause_ui_entities(
             SpawnRequest,
             Visibility::Hidden,
             UI::ResumeCount3,
+            UiLayer::Overlay,
         ))
         .with_children(|parent| {
             let texture = asset_server.load(FONT_PATH_NUM_3);
@@ -565,7 +929,6 @@ fn spawn_pause_ui_entities(
                     ..Default::default()
                 },
                 Visibility::Inherited,
-                ZIndex(5),
             ));
         })
         .id();
@@ -586,9 +949,21 @@ fn spawn_pause_ui_entities(
             SpawnRequest,
             Visibility::Hidden,
             UI::Pause,
-            ZIndex(5),
+            UiLayer::Modal,
         ))
         .with_children(|parent| {
+            // --- Backdrop ---
+            parent.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    ..Default::default()
+                },
+                Visibility::Inherited,
+                PauseBackdrop,
+            ));
+
             // --- Title Label ---
             let texture = asset_server.load(FONT_PATH_PAUSE);
             parent.spawn((
@@ -601,6 +976,7 @@ fn spawn_pause_ui_entities(
                 },
                 Visibility::Inherited,
                 PauseTitle,
+                LocalizedBanner::new(Banner::Pause),
             ));
 
             // --- Space ---
@@ -640,6 +1016,8 @@ fn spawn_pause_ui_entities(
                             Visibility::Inherited,
                             UI::ResumeButton,
                             Button,
+                            Focusable,
+                            FocusCancel,
                         ))
                         .with_children(|parent| {
                             let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
@@ -680,8 +1058,21 @@ fn spawn_pause_ui_entities(
                             Visibility::Inherited,
                             UI::OptionButton,
                             Button,
+                            Focusable,
                         ))
                         .with_children(|parent| {
+                            parent.spawn((
+                                IconNode(Icon::Settings),
+                                ImageNode::default().with_color(Color::BLACK),
+                                Node {
+                                    height: Val::Percent(40.0),
+                                    aspect_ratio: Some(1.0),
+                                    margin: UiRect::right(Val::Vw(0.5)),
+                                    ..Default::default()
+                                },
+                                Visibility::Inherited,
+                            ));
+
                             let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
                             parent.spawn((
                                 Text::new("Settings"),
@@ -719,7 +1110,9 @@ fn spawn_pause_ui_entities(
                             BackgroundColor(EXIT_BTN_COLOR),
                             Visibility::Inherited,
                             UI::InGameExitButton,
+                            UiSound::Back,
                             Button,
+                            Focusable,
                         ))
                         .with_children(|parent| {
                             let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
@@ -749,6 +1142,12 @@ fn remove_resource(mut commands: Commands) {
     commands.remove_resource::<LoadingEntities>();
 }
 
+fn remove_entities(mut commands: Commands, query: Query<Entity, With<LoadingStateRoot>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
 // --- UPDATE SYSTEMS ---
 
 fn handle_spawn_request(mut commands: Commands, query: Query<Entity, Added<SpawnRequest>>) {
@@ -771,16 +1170,16 @@ fn check_loading_progress(
         .all(|entity| !query.contains(*entity));
 
     if all_loaded {
-        next_state.set(GameState::InitResult);
+        next_state.set(GameState::PrepareInGame);
     }
 }
 
 fn update_loading_bar(
     loading_entitis: Res<LoadingEntities>,
     request_query: Query<(), With<SpawnRequest>>,
-    mut query: Query<&mut Node, With<LoadingBar>>,
+    mut query: Query<&mut LoadingProgress, With<LoadingBar>>,
 ) {
-    if let Ok(mut node) = query.single_mut() {
+    if let Ok(mut bar) = query.single_mut() {
         let loaded_count = loading_entitis
             .handles
             .iter()
@@ -794,6 +1193,6 @@ fn update_loading_bar(
             1.0
         };
 
-        node.width = Val::Percent(progress * 100.0);
+        bar.set(progress);
     }
 }
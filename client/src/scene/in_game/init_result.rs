@@ -1,138 +1,175 @@
 // Import necessary Bevy modules.
-use bevy::{audio::Volume, prelude::*, render::view::NoFrustumCulling};
+use bevy::{prelude::*, render::view::NoFrustumCulling};
 
 use crate::asset::{
     animation::AnimationClipHandle,
-    sound::SystemVolume,
+    cutscene_track::CutsceneTrack,
+    model::ModelAsset,
     spawner::{SpawnModel, TranslatableText},
 };
 
-#[cfg(target_arch = "wasm32")]
-use crate::web::{WebAudioPlayer, WebPlaybackSettings};
-
 use super::*;
 
+// --- CONSTANTS ---
+/// The play time, in seconds, before the result scene starts loading,
+/// so that the first seconds of a run are left to the game.
+const RESULT_PRELOAD_DELAY: f32 = 3.0;
+
 // --- PLUGIN ---
 
+/// Loads the result scene in the background while the run is played, instead of before it starts.
+///
+/// Loading starts a few seconds into the run, or as soon as the run ends if that comes first.
+/// The finished state waits for the result scene before it moves on.
 pub struct StatePlugin;
 
 impl Plugin for StatePlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            OnEnter(GameState::InitResult),
-            (debug_label, play_loading_sound, spawn_entities),
-        )
-        .add_systems(
-            OnExit(GameState::InitResult),
-            (remove_entities, remove_resource),
-        )
-        .add_systems(
-            Update,
-            (
-                disable_frustum_culling,
-                handle_spawn_request,
-                check_loading_progress,
-                update_loading_bar,
-            )
-                .run_if(in_state(GameState::InitResult)),
-        );
+        app.add_systems(OnEnter(GameState::PrepareInGame), start_result_preload)
+            .add_systems(OnEnter(GameState::WrapUpInGame), hurry_result_preload)
+            .add_systems(
+                Update,
+                (
+                    wait_result_preload.run_if(in_state(GameState::InGame)),
+                    spawn_when_loaded,
+                    disable_frustum_culling,
+                )
+                    .chain()
+                    .run_if(resource_exists::<ResultPreload>),
+            );
     }
 }
 
 // --- SETUP SYSTEMS ---
 
-fn debug_label() {
-    info!("Current State: InitResult");
+fn start_result_preload(mut commands: Commands) {
+    commands.insert_resource(ResultPreload::default());
 }
 
-#[cfg(not(target_arch = "wasm32"))]
-fn play_loading_sound(
-    mut commands: Commands,
+/// Starts loading the result scene at once when the run ends before the delay.
+fn hurry_result_preload(mut preload: ResMut<ResultPreload>, asset_server: Res<AssetServer>) {
+    if matches!(*preload, ResultPreload::Waiting { .. }) {
+        info!("Result scene requested before the preload delay");
+        *preload = ResultPreload::Loading(load_result_assets(&asset_server));
+    }
+}
+
+// --- UPDATE SYSTEMS ---
+
+fn wait_result_preload(
+    mut preload: ResMut<ResultPreload>,
     asset_server: Res<AssetServer>,
-    system_volume: Res<SystemVolume>,
+    time: Res<Time>,
 ) {
-    commands.spawn((
-        AudioPlayer::new(asset_server.load(SOUND_PATH_UI_LOADING)),
-        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.effect_percentage())),
-        EffectSound,
-    ));
+    if let ResultPreload::Waiting { elapsed } = &mut *preload {
+        *elapsed += time.delta_secs();
+        if *elapsed >= RESULT_PRELOAD_DELAY {
+            *preload = ResultPreload::Loading(load_result_assets(&asset_server));
+        }
+    }
 }
 
-#[cfg(target_arch = "wasm32")]
-fn play_loading_sound(
+/// Spawns the entities of the result scene, hidden, once its assets have loaded.
+fn spawn_when_loaded(
     mut commands: Commands,
+    mut preload: ResMut<ResultPreload>,
+    mut in_game_assets: ResMut<InGameAssets>,
     asset_server: Res<AssetServer>,
-    system_volume: Res<SystemVolume>,
 ) {
-    commands.spawn((
-        WebAudioPlayer::new(asset_server.load(SOUND_PATH_UI_LOADING)),
-        WebPlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.effect_percentage())),
-        EffectSound,
-    ));
-}
+    let ResultPreload::Loading(handles) = &mut *preload else {
+        return;
+    };
+    if !handles
+        .iter()
+        .all(|handle| asset_server.is_loaded_with_dependencies(handle.id()))
+    {
+        return;
+    }
 
-fn spawn_entities(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let mut loading_entities = LoadingEntities::default();
-    spawn_result_entities(&mut commands, &asset_server, &mut loading_entities);
-    spawn_result_ui_entities(&mut commands, &asset_server, &mut loading_entities);
-    commands.insert_resource(loading_entities);
+    info!("Result scene loaded in the background");
+    in_game_assets.handles.append(handles);
+    spawn_result_entities(&mut commands, &asset_server);
+    spawn_result_ui_entities(&mut commands, &asset_server);
+    *preload = ResultPreload::Spawned;
 }
 
-fn spawn_result_entities(
-    commands: &mut Commands,
-    asset_server: &AssetServer,
-    loading_entities: &mut LoadingEntities,
+/// Draws the models of the result scene whatever the in-game camera sees.
+fn disable_frustum_culling(
+    mut commands: Commands,
+    query: Query<Entity, Added<Mesh3d>>,
+    parent_query: Query<&ChildOf>,
+    root_query: Query<(), With<ResultStateRoot>>,
 ) {
+    for entity in query.iter() {
+        if parent_query
+            .iter_ancestors(entity)
+            .any(|ancestor| root_query.contains(ancestor))
+        {
+            commands.entity(entity).insert(NoFrustumCulling);
+        }
+    }
+}
+
+/// Starts loading the assets only the result scene uses.
+fn load_result_assets(asset_server: &AssetServer) -> Vec<UntypedHandle> {
+    let mut handles = Vec::new();
+
+    let model: Handle<ModelAsset> = asset_server.load(MODEL_PATH_PLANE_999);
+    handles.push(model.into());
+
+    for path in [
+        ANIM_PATH_HIKARI_VICTORY_START,
+        ANIM_PATH_HIKARI_VICTORY_END,
+        ANIM_PATH_NOZOMI_VICTORY_START,
+        ANIM_PATH_NOZOMI_VICTORY_END,
+    ] {
+        let clip: Handle<AnimationClip> = asset_server.load(path);
+        handles.push(clip.into());
+    }
+
+    let track: Handle<CutsceneTrack> = asset_server.load(CUTSCENE_PATH_RESULT);
+    handles.push(track.into());
+    handles
+}
+
+fn spawn_result_entities(commands: &mut Commands, asset_server: &AssetServer) {
     let model = asset_server.load(MODEL_PATH_PLANE_999);
-    let entity = commands
-        .spawn((
-            SpawnModel(model),
-            Transform::from_xyz(0.0, 0.0, 0.0),
-            Visibility::Hidden,
-            SpawnRequest,
-        ))
-        .id();
-    loading_entities.handles.push(entity);
+    commands.spawn((
+        SpawnModel(model),
+        Transform::from_xyz(0.0, 0.0, 0.0),
+        Visibility::Hidden,
+        ResultStateRoot,
+    ));
 
     let clip = asset_server.load(ANIM_PATH_HIKARI_VICTORY_START);
     let model = asset_server.load(MODEL_PATH_HIKARI);
-    let entity = commands
-        .spawn((
-            SpawnModel(model),
-            AnimationClipHandle(clip),
-            Transform::from_translation(result::HIKARI_POSITION)
-                .looking_to(result::STUDENT_DIRECTION, Vec3::Y),
-            Visibility::Hidden,
-            SpawnRequest,
-            ResultStateEntity,
-            Hikari,
-        ))
-        .id();
-    loading_entities.handles.push(entity);
+    commands.spawn((
+        SpawnModel(model),
+        AnimationClipHandle(clip),
+        Transform::from_translation(result::HIKARI_POSITION)
+            .looking_to(result::STUDENT_DIRECTION, Vec3::Y),
+        Visibility::Hidden,
+        ResultStateRoot,
+        ResultStateEntity,
+        Hikari,
+    ));
 
     let clip = asset_server.load(ANIM_PATH_NOZOMI_VICTORY_START);
     let model = asset_server.load(MODEL_PATH_NOZOMI);
-    let entity = commands
-        .spawn((
-            SpawnModel(model),
-            AnimationClipHandle(clip),
-            Transform::from_translation(result::NOZOMI_POSITION)
-                .looking_to(result::STUDENT_DIRECTION, Vec3::Y),
-            Visibility::Hidden,
-            SpawnRequest,
-            ResultStateEntity,
-            Nozomi,
-        ))
-        .id();
-    loading_entities.handles.push(entity);
+    commands.spawn((
+        SpawnModel(model),
+        AnimationClipHandle(clip),
+        Transform::from_translation(result::NOZOMI_POSITION)
+            .looking_to(result::STUDENT_DIRECTION, Vec3::Y),
+        Visibility::Hidden,
+        ResultStateRoot,
+        ResultStateEntity,
+        Nozomi,
+    ));
 }
 
-fn spawn_result_ui_entities(
-    commands: &mut Commands,
-    asset_server: &AssetServer,
-    loading_entities: &mut LoadingEntities,
-) {
-    let entity = commands
+fn spawn_result_ui_entities(commands: &mut Commands, asset_server: &AssetServer) {
+    commands
         .spawn((
             Node {
                 width: Val::Percent(100.0),
@@ -143,7 +180,8 @@ fn spawn_result_ui_entities(
                 align_items: AlignItems::Center,
                 ..Default::default()
             },
-            SpawnRequest,
+            ResultStateRoot,
+            UiLayer::Hud,
         ))
         .with_children(|parent| {
             parent
@@ -409,7 +447,48 @@ fn spawn_result_ui_entities(
                         });
 
                     parent.spawn(Node {
-                        height: Val::Percent(65.0),
+                        height: Val::Percent(2.0),
+                        ..Default::default()
+                    });
+
+                    let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
+                    parent.spawn((
+                        // The collection percentages are filled in once the result is shown.
+                        Text::new(""),
+                        TextFont::from_font(font),
+                        TextLayout::new_with_justify(JustifyText::Center),
+                        TextColor::BLACK,
+                        ResizableFont::vertical(1280.0, 36.0),
+                        Node::default(),
+                        Visibility::Hidden,
+                        UI::CollectionStats,
+                    ));
+
+                    parent.spawn(Node {
+                        height: Val::Percent(2.0),
+                        ..Default::default()
+                    });
+
+                    // The comparison with the previous or best run is added once the result is shown.
+                    parent.spawn((
+                        Node {
+                            width: Val::Percent(90.0),
+                            height: Val::Percent(6.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            column_gap: Val::Vw(1.0),
+                            ..Default::default()
+                        },
+                        BorderRadius::all(Val::Percent(10.0)),
+                        BackgroundColor(COMPARE_BTN_COLOR.with_alpha(0.0)),
+                        Visibility::Hidden,
+                        UI::RunComparison,
+                        Button,
+                        Focusable,
+                    ));
+
+                    parent.spawn(Node {
+                        height: Val::Percent(50.0),
                         ..Default::default()
                     });
                 });
@@ -439,7 +518,71 @@ fn spawn_result_ui_entities(
                     });
 
                     parent.spawn(Node {
-                        height: Val::Percent(50.0),
+                        height: Val::Percent(28.0),
+                        ..Default::default()
+                    });
+
+                    // The chips of the run's mode and modifiers are added once the result is shown.
+                    parent.spawn((
+                        Node {
+                            width: Val::Percent(90.0),
+                            height: Val::Percent(10.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            column_gap: Val::Vw(0.5),
+                            ..Default::default()
+                        },
+                        Visibility::Hidden,
+                        UI::RunChips,
+                    ));
+
+                    parent
+                        .spawn((
+                            Node {
+                                width: Val::Percent(60.0),
+                                height: Val::Percent(8.0),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..Default::default()
+                            },
+                            BorderRadius::all(Val::Percent(10.0)),
+                            BackgroundColor(SEED_BTN_COLOR.with_alpha(0.0)),
+                            Visibility::Hidden,
+                            UI::SeedButton,
+                            Button,
+                            Focusable,
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn((
+                                IconNode(Icon::Share),
+                                ImageNode::default().with_color(Color::BLACK),
+                                Node {
+                                    height: Val::Percent(50.0),
+                                    aspect_ratio: Some(1.0),
+                                    margin: UiRect::right(Val::Vw(0.5)),
+                                    ..Default::default()
+                                },
+                                Visibility::Inherited,
+                            ));
+
+                            let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
+                            parent.spawn((
+                                Text::new("Copy Seed"),
+                                TranslatableText("copy_seed".into()),
+                                TextFont::from_font(font),
+                                TextLayout::new_with_justify(JustifyText::Center),
+                                ResizableFont::Vertical {
+                                    base: 1280.0,
+                                    size: 36.0,
+                                },
+                                TextColor::BLACK,
+                                Node::default(),
+                                Visibility::Inherited,
+                            ));
+                        });
+
+                    parent.spawn(Node {
+                        height: Val::Percent(4.0),
                         ..Default::default()
                     });
 
@@ -457,6 +600,7 @@ fn spawn_result_ui_entities(
                             Visibility::Hidden,
                             UI::RestartButton,
                             Button,
+                            Focusable,
                         ))
                         .with_children(|parent| {
                             let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
@@ -493,7 +637,9 @@ fn spawn_result_ui_entities(
                             BackgroundColor(EXIT_BTN_COLOR.with_alpha(0.0)),
                             Visibility::Hidden,
                             UI::ResultExitButton,
+                            UiSound::Back,
                             Button,
+                            Focusable,
                         ))
                         .with_children(|parent| {
                             let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
@@ -517,74 +663,5 @@ fn spawn_result_ui_entities(
                         ..Default::default()
                     });
                 });
-        })
-        .id();
-    loading_entities.handles.push(entity);
-}
-
-// --- CLEANUP SYSTEMS ---
-
-fn remove_resource(mut commands: Commands) {
-    commands.remove_resource::<LoadingEntities>();
-}
-
-fn remove_entities(mut commands: Commands, query: Query<Entity, With<LoadingStateRoot>>) {
-    for entity in query.iter() {
-        commands.entity(entity).despawn();
-    }
-}
-
-// --- UPDATE SYSTEMS ---
-
-fn disable_frustum_culling(mut commands: Commands, query: Query<Entity, Added<Mesh3d>>) {
-    for entity in query.iter() {
-        commands.entity(entity).insert(NoFrustumCulling);
-    }
-}
-
-fn handle_spawn_request(mut commands: Commands, query: Query<Entity, Added<SpawnRequest>>) {
-    for entity in query.iter() {
-        commands
-            .entity(entity)
-            .remove::<SpawnRequest>()
-            .insert(ResultStateRoot);
-    }
-}
-
-fn check_loading_progress(
-    mut next_state: ResMut<NextState<GameState>>,
-    loading_entitis: Res<LoadingEntities>,
-    query: Query<(), With<SpawnRequest>>,
-) {
-    let all_loaded = loading_entitis
-        .handles
-        .iter()
-        .all(|entity| !query.contains(*entity));
-
-    if all_loaded {
-        next_state.set(GameState::PrepareInGame);
-    }
-}
-
-fn update_loading_bar(
-    loading_entitis: Res<LoadingEntities>,
-    request_query: Query<(), With<SpawnRequest>>,
-    mut query: Query<&mut Node, With<LoadingBar>>,
-) {
-    if let Ok(mut node) = query.single_mut() {
-        let loaded_count = loading_entitis
-            .handles
-            .iter()
-            .filter(|&&entity| !request_query.contains(entity))
-            .count();
-
-        let total_count = loading_entitis.handles.len();
-        let progress = if total_count > 0 {
-            loaded_count as f32 / total_count as f32
-        } else {
-            1.0
-        };
-
-        node.width = Val::Percent(progress * 100.0);
-    }
+        });
 }
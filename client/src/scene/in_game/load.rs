@@ -1,7 +1,7 @@
 // Import necessary Bevy modules.
 use bevy::prelude::*;
 
-use crate::asset::model::ModelAsset;
+use crate::asset::{cutscene_track::CutsceneTrack, model::ModelAsset, size::loaded_fraction};
 
 use super::*;
 
@@ -165,13 +165,20 @@ fn load_assets(commands: &mut Commands, asset_server: &AssetServer) {
     let texture: Handle<Image> = asset_server.load(TEXTURE_PATH_TRAIN_ICON);
     loading_assets.handles.push(texture.into());
 
+    let texture: Handle<Image> = asset_server.load(TEXTURE_PATH_ICONS);
+    loading_assets.handles.push(texture.into());
+
+    let atlas: Handle<TextureAtlasLayout> = asset_server.load(ATLAS_PATH_ICONS);
+    loading_assets.handles.push(atlas.into());
+
+    // --- Cutscene Loading ---
+    let track: Handle<CutsceneTrack> = asset_server.load(CUTSCENE_PATH_DEPARTURE);
+    loading_assets.handles.push(track.into());
+
     // --- Ground Loading ---
     let model: Handle<ModelAsset> = asset_server.load(MODEL_PATH_PLANE_0);
     loading_assets.handles.push(model.into());
 
-    let model: Handle<ModelAsset> = asset_server.load(MODEL_PATH_PLANE_999);
-    loading_assets.handles.push(model.into());
-
     // --- Obstacle Loading ---
     let model: Handle<ModelAsset> = asset_server.load(MODEL_PATH_BARRICADE);
     loading_assets.handles.push(model.into());
@@ -227,20 +234,10 @@ fn load_assets(commands: &mut Commands, asset_server: &AssetServer) {
     let clip: Handle<AnimationClip> = asset_server.load(ANIM_PATH_HIKARI_IN_GAME);
     loading_assets.handles.push(clip.into());
 
-    let clip: Handle<AnimationClip> = asset_server.load(ANIM_PATH_HIKARI_VICTORY_START);
-    loading_assets.handles.push(clip.into());
-
-    let clip: Handle<AnimationClip> = asset_server.load(ANIM_PATH_HIKARI_VICTORY_END);
-    loading_assets.handles.push(clip.into());
-
     let clip: Handle<AnimationClip> = asset_server.load(ANIM_PATH_NOZOMI_IN_GAME);
     loading_assets.handles.push(clip.into());
 
-    let clip: Handle<AnimationClip> = asset_server.load(ANIM_PATH_NOZOMI_VICTORY_START);
-    loading_assets.handles.push(clip.into());
-
-    let clip: Handle<AnimationClip> = asset_server.load(ANIM_PATH_NOZOMI_VICTORY_END);
-    loading_assets.handles.push(clip.into());
+    // The assets of the result scene are loaded in the background while the run is played.
 
     // --- Resource Insertion ---
     commands.insert_resource(loading_assets);
@@ -264,6 +261,7 @@ fn setup_loading_screen(mut commands: Commands, asset_server: Res<AssetServer>)
                 ..Default::default()
             },
             LoadingStateRoot,
+            UiLayer::Overlay,
         ))
         .with_children(|parent| {
             parent
@@ -285,7 +283,7 @@ fn setup_loading_screen(mut commands: Commands, asset_server: Res<AssetServer>)
                         ResizableFont::vertical(1280.0, 24.0),
                         Node::default(),
                         LoadingText,
-                        ZIndex(2),
+                        UiLayer::Overlay,
                     ));
                 });
 
@@ -310,7 +308,7 @@ fn setup_loading_screen(mut commands: Commands, asset_server: Res<AssetServer>)
                         BorderRadius::all(Val::Percent(50.0)),
                         BackgroundColor(LOADING_BAR_COLOR),
                         LoadingBar,
-                        ZIndex(1),
+                        UiLayer::Hud,
                     ));
                 });
         });
@@ -350,23 +348,10 @@ fn check_loading_progress(
 fn update_loading_progress(
     asset_server: Res<AssetServer>,
     loading_assets: Res<InGameAssets>,
-    mut query: Query<&mut Node, With<LoadingBar>>,
+    mut query: Query<&mut LoadingProgress, With<LoadingBar>>,
 ) {
-    if let Ok(mut node) = query.single_mut() {
-        let loaded_count = loading_assets
-            .handles
-            .iter()
-            .filter(|handle| asset_server.is_loaded_with_dependencies(handle.id()))
-            .count();
-
-        let total_count = loading_assets.handles.len();
-        let progress = if total_count > 0 {
-            loaded_count as f32 / total_count as f32
-        } else {
-            1.0
-        };
-
-        node.width = Val::Percent(progress * 100.0);
+    if let Ok(mut progress) = query.single_mut() {
+        progress.set(loaded_fraction(&asset_server, &loading_assets.handles));
     }
 }
 
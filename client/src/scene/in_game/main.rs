@@ -1,17 +1,24 @@
-use std::f32::consts::TAU;
+use std::f32::consts::PI;
 
 // Import necessary Bevy modules.
 use bevy::{
     audio::Volume,
+    core_pipeline::bloom::Bloom,
     pbr::{NotShadowCaster, NotShadowReceiver},
     prelude::*,
+    render::view::ColorGrading,
 };
 use bevy_tweening::{Animator, TweenCompleted};
-use rand::seq::IndexedRandom;
+use rand::{Rng, seq::IndexedRandom};
+use shupogaki_core::collider::Collider;
 
 use crate::{
-    asset::{animation::AnimationClipHandle, material::EyeMouthMaterial, sound::SystemVolume},
-    collider::Collider,
+    asset::{
+        animation::AnimationClipHandle, locale::CurrentLocale, sound::SystemVolume,
+        spawner::TranslatableText,
+    },
+    diagnostics::FrameBudgetGroup,
+    shader::{speed_lines::SpeedLineMaterial, vignette::VignetteMaterial},
 };
 
 #[cfg(target_arch = "wasm32")]
@@ -25,8 +32,35 @@ pub struct StatePlugin;
 
 impl Plugin for StatePlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(GameState::InGame), debug_label)
-            .add_systems(OnExit(GameState::InGame), hide_in_game_interface)
+        app.add_event::<ThrowWhistle>()
+            .add_event::<BlowHorn>()
+            .add_event::<FuelAlarm>()
+            .add_event::<FuelChanged>()
+            .add_event::<ObstacleHit>()
+            .add_event::<AirDashed>()
+            .add_event::<BeatBellCollected>()
+            .add_event::<JumpPhase>()
+            .add_systems(Startup, setup_whistle_assets)
+            .add_systems(
+                OnEnter(GameState::InGame),
+                (debug_label, apply_camera_preset),
+            )
+            .add_systems(
+                OnExit(GameState::InGame),
+                (
+                    hide_in_game_interface,
+                    clear_speed_lines,
+                    clear_color_grading,
+                    hide_landing_marker,
+                    hide_fuel_indicators,
+                    hide_pace_clock,
+                ),
+            )
+            .add_systems(
+                OnEnter(GameState::WrapUpInGame),
+                (clear_time_scale, restore_time_scale_effects).chain(),
+            )
+            .add_systems(OnEnter(GameState::ExitInGame), restore_time_scale_effects)
             .add_systems(
                 PreUpdate,
                 (
@@ -46,47 +80,113 @@ impl Plugin for StatePlugin {
                     update_tok9_train_delay_time,
                     update_danger_zone_bg_delay_time,
                     update_danger_zone_delay_time,
+                    update_lateral_drift,
+                    play_appearance,
+                    update_player_shield,
+                    update_bell_chime,
+                    (update_train_horn, blow_horn).chain(),
+                    update_startled_aobas,
+                    update_time_scale,
+                    throw_whistle,
+                    update_whistle_position.after(throw_whistle),
+                    play_fuel_alarm_sound.after(check_fuel_alarm),
+                    update_passing_train_position,
+                    update_passing_train_sound.after(update_passing_train_position),
                 )
                     .run_if(in_state(GameState::InGame)),
             )
             .add_systems(
                 Update,
                 (
-                    update_play_time,
+                    (update_play_time, update_run_pace).chain(),
                     update_input_delay,
                     update_player_state,
                     update_score,
-                    update_train_fuel,
-                    update_player_position,
+                    (
+                        consume_train_fuel,
+                        update_train_fuel,
+                        check_fuel_alarm,
+                        update_grading_state,
+                    )
+                        .chain(),
+                    (update_player_position, send_jump_phase).chain(),
+                    update_landing_marker.after(update_player_position),
                     update_ground_position,
-                    update_object_position,
+                    (update_object_position, activate_objects).chain(),
                     update_tok9_train_position.after(update_tok9_train_delay_time),
                     play_aoba_animation.after(update_object_position),
                     setup_no_shadow_casting,
                     rotate_animation,
                     fade_in_out_animation,
+                    update_miss_indicators,
                     cleanup_ui_animation,
                     button_system,
                     play_train_sound,
-                    update_train_sound,
+                    update_train_sound.after(send_jump_phase),
                     update_train_volume,
                 )
                     .run_if(in_state(GameState::InGame)),
             )
+            .add_systems(
+                Update,
+                update_camera_rig
+                    .run_if(in_state(GameState::InGame).or(in_state(GameState::WrapUpInGame))),
+            )
+            .configure_sets(
+                PostUpdate,
+                (
+                    InGameSet::Spawn,
+                    InGameSet::Collision,
+                    InGameSet::Feedback,
+                    InGameSet::HudSync,
+                )
+                    .chain(),
+            )
             .add_systems(
                 PostUpdate,
                 (
-                    update_toy_trains,
-                    spawn_grounds,
-                    spawn_objects,
-                    spawn_tok9_trains,
-                    check_for_collisions,
-                    check_tok9_train_collisions.after(check_for_collisions),
-                    update_score_ui,
-                    update_fuel_deco,
-                    update_fuel_gauge,
-                    update_player_effect,
-                    update_player_speed,
+                    (follow_chain, bank_chain)
+                        .chain()
+                        .in_set(FrameBudgetGroup::TrainChain),
+                    (
+                        spawn_grounds,
+                        spawn_objects,
+                        spawn_tok9_trains,
+                        spawn_traffic,
+                    )
+                        .in_set(FrameBudgetGroup::Spawner)
+                        .in_set(InGameSet::Spawn),
+                    (
+                        check_for_collisions,
+                        check_tok9_train_collisions.after(check_for_collisions),
+                        check_whistle_collisions,
+                    )
+                        .in_set(FrameBudgetGroup::Collision)
+                        .in_set(InGameSet::Collision),
+                    update_player_speed
+                        .after(check_tok9_train_collisions)
+                        .in_set(InGameSet::Collision),
+                    (
+                        update_player_effect,
+                        update_speed_lines,
+                        apply_color_grading,
+                        tick_fuel_gauge_tween,
+                        show_fuel_indicators,
+                    )
+                        .in_set(InGameSet::Feedback),
+                    (
+                        update_throw_text,
+                        update_score_ui,
+                        update_pace_clock,
+                        update_air_dash_gauge,
+                        update_horn_cooldown,
+                        update_fuel_deco,
+                        (update_fuel_gauge, update_fuel_gauge_effects).chain(),
+                        blink_fuel_gauge_frame,
+                        update_fuel_indicators,
+                    )
+                        .in_set(FrameBudgetGroup::Ui)
+                        .in_set(InGameSet::HudSync),
                 )
                     .run_if(in_state(GameState::InGame)),
             );
@@ -95,10 +195,36 @@ impl Plugin for StatePlugin {
 
 // --- SETUP SYSTEMS ---
 
+fn setup_whistle_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.insert_resource(WhistleAssets {
+        mesh: meshes.add(Sphere::new(WHISTLE_RADIUS)),
+        material: materials.add(StandardMaterial {
+            base_color: WHISTLE_COLOR,
+            ..Default::default()
+        }),
+    });
+}
+
 fn debug_label() {
     info!("Current State: InGame");
 }
 
+/// Places the camera at the preset chosen in the options modal.
+fn apply_camera_preset(
+    options: Res<GraphicsOptions>,
+    mut query: Query<(&mut CameraRig, &mut Transform)>,
+) {
+    let view = CameraView::of(options.camera_preset);
+    for (mut rig, mut transform) in query.iter_mut() {
+        rig.preset = options.camera_preset;
+        *transform = Transform::from_translation(view.position).looking_at(view.look_at, Vec3::Y);
+    }
+}
+
 // --- CLEANUP SYSTEMS ---
 
 fn hide_in_game_interface(mut query: Query<(&mut Visibility, &UI)>) {
@@ -110,6 +236,60 @@ fn hide_in_game_interface(mut query: Query<(&mut Visibility, &UI)>) {
     }
 }
 
+fn clear_speed_lines(
+    mut materials: ResMut<Assets<SpeedLineMaterial>>,
+    query: Query<&MaterialNode<SpeedLineMaterial>, With<SpeedLines>>,
+    time: Res<Time>,
+) {
+    if let Ok(node) = query.single()
+        && let Some(material) = materials.get_mut(node.id())
+    {
+        material.set(0.0, time.elapsed_secs());
+    }
+}
+
+/// Takes the invincibility and low-fuel grading off the camera, e.g. while the game is paused.
+fn clear_color_grading(
+    mut commands: Commands,
+    mut camera_query: Query<(Entity, &mut ColorGrading), With<CameraRig>>,
+    mut materials: ResMut<Assets<VignetteMaterial>>,
+    vignette_query: Query<&MaterialNode<VignetteMaterial>, With<LowFuelVignette>>,
+) {
+    for (entity, mut grading) in camera_query.iter_mut() {
+        for section in grading.all_sections_mut() {
+            section.saturation = 1.0;
+        }
+        commands.entity(entity).remove::<Bloom>();
+    }
+
+    if let Ok(node) = vignette_query.single()
+        && let Some(material) = materials.get_mut(node.id())
+    {
+        material.set(LOW_FUEL_VIGNETTE_COLOR, 0.0);
+    }
+}
+
+fn hide_landing_marker(mut query: Query<&mut Visibility, With<LandingMarker>>) {
+    for mut visibility in query.iter_mut() {
+        *visibility = Visibility::Hidden;
+    }
+}
+
+fn hide_fuel_indicators(mut query: Query<(&mut Visibility, &mut FuelIndicator)>) {
+    for (mut visibility, mut indicator) in query.iter_mut() {
+        *visibility = Visibility::Hidden;
+        indicator.remaining = 0.0;
+    }
+}
+
+fn hide_pace_clock(mut query: Query<(&UI, &mut Visibility)>) {
+    for (&ui, mut visibility) in query.iter_mut() {
+        if ui == UI::PaceClock {
+            *visibility = Visibility::Hidden;
+        }
+    }
+}
+
 // --- PREUPDATE SYSTEMS ---
 #[cfg(not(feature = "no-debuging-player"))]
 pub fn handle_player(
@@ -130,21 +310,136 @@ pub fn handle_player(
     }
 }
 
+/// Moves the player one lane to the left of the screen.
+/// The world is shown mirrored with the mirror lanes mutator, so the player moves to the next lane instead.
+fn steer_left(lane: &mut Lane, config: &GameplayConfig) {
+    match config.mutators.is_active(Mutator::MirrorLanes) {
+        true => lane.inc(),
+        false => lane.dec(),
+    }
+}
+
+/// Moves the player one lane to the right of the screen.
+fn steer_right(lane: &mut Lane, config: &GameplayConfig) {
+    match config.mutators.is_active(Mutator::MirrorLanes) {
+        true => lane.dec(),
+        false => lane.inc(),
+    }
+}
+
+/// Carries out the lane switch kept while the input delay was running, once it has expired.
+fn steer_queued(lane: &mut Lane, delay: &mut InputDelay, config: &GameplayConfig) {
+    if let Some(left) = delay.take_queued() {
+        match left {
+            true => steer_left(lane, config),
+            false => steer_right(lane, config),
+        }
+        delay.reset();
+    }
+}
+
+/// Returns whether a lane switch tap toward the given side follows another one quickly enough to air-dash,
+/// and remembers the tap for the next one.
+fn is_double_tap(last_tap: &mut Option<(bool, f32)>, left: bool, now: f32) -> bool {
+    let double = last_tap
+        .is_some_and(|(last_left, at)| last_left == left && now - at <= AIR_DASH_DOUBLE_TAP_WINDOW);
+    *last_tap = match double {
+        true => None,
+        false => Some((left, now)),
+    };
+    double
+}
+
+/// Shifts the airborne player one lane toward the given side at once, if the air-dash has recharged.
+/// Returns whether the player dashed.
+fn try_air_dash(
+    lane: &mut Lane,
+    transform: &Transform,
+    dash: &mut AirDash,
+    delay: &mut InputDelay,
+    config: &GameplayConfig,
+    left: bool,
+    dashes: &mut EventWriter<AirDashed>,
+) -> bool {
+    if transform.translation.y <= 0.0 || !dash.is_ready() {
+        return false;
+    }
+
+    let from = lane.get();
+    match left {
+        true => steer_left(lane, config),
+        false => steer_right(lane, config),
+    }
+    if lane.get() == from {
+        return false;
+    }
+
+    dash.start();
+    delay.reset();
+    dashes.write(AirDashed {
+        from: transform.translation,
+        to_x: LANE_POSITIONS[lane.get()],
+    });
+    true
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn handle_player_input(
     keyboard_input: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    time: Res<Time<Real>>,
+    config: Res<GameplayConfig>,
+    mut last_tap: Local<Option<(bool, f32)>>,
+    (mut throws, mut dashes, mut horns): (
+        EventWriter<ThrowWhistle>,
+        EventWriter<AirDashed>,
+        EventWriter<BlowHorn>,
+    ),
     mut delay: ResMut<InputDelay>,
     mut is_jumping: ResMut<IsPlayerJumping>,
-    mut player_query: Query<(&mut Lane, &Transform, &mut VerticalMovement), With<Player>>,
+    mut player_query: Query<
+        (&mut Lane, &Transform, &mut VerticalMovement, &mut AirDash),
+        With<Player>,
+    >,
 ) {
-    if let Ok((mut lane, transform, mut vert_move)) = player_query.single_mut() {
+    if let Ok((mut lane, transform, mut vert_move, mut dash)) = player_query.single_mut() {
+        // A double tap of a lane switch key or a shoulder button air-dashes toward its side.
+        let now = time.elapsed_secs();
+        let shoulder = |button| gamepads.iter().any(|gamepad| gamepad.just_pressed(button));
+        let dash_left = shoulder(GamepadButton::LeftTrigger)
+            || (keyboard_input.any_just_pressed([KeyCode::KeyA, KeyCode::ArrowLeft])
+                && is_double_tap(&mut last_tap, true, now));
+        let dash_right = shoulder(GamepadButton::RightTrigger)
+            || (keyboard_input.any_just_pressed([KeyCode::KeyD, KeyCode::ArrowRight])
+                && is_double_tap(&mut last_tap, false, now));
+        let dashed = dash_left != dash_right
+            && try_air_dash(
+                &mut lane,
+                transform,
+                &mut dash,
+                &mut delay,
+                &config,
+                dash_left,
+                &mut dashes,
+            );
+
+        if !dashed && !delay.is_expired() {
+            if keyboard_input.any_just_pressed([KeyCode::KeyA, KeyCode::ArrowLeft]) {
+                delay.queue(true);
+            } else if keyboard_input.any_just_pressed([KeyCode::KeyD, KeyCode::ArrowRight]) {
+                delay.queue(false);
+            }
+        }
+        steer_queued(&mut lane, &mut delay, &config);
+
         if delay.is_expired() && !keyboard_input.all_pressed([KeyCode::KeyA, KeyCode::KeyD]) {
             if keyboard_input.pressed(KeyCode::KeyA) || keyboard_input.pressed(KeyCode::ArrowLeft) {
-                lane.dec();
+                steer_left(&mut lane, &config);
                 delay.reset();
             } else if keyboard_input.pressed(KeyCode::KeyD)
                 || keyboard_input.pressed(KeyCode::ArrowRight)
             {
-                lane.inc();
+                steer_right(&mut lane, &config);
                 delay.reset();
             }
         }
@@ -154,24 +449,47 @@ pub fn handle_player_input(
             vert_move.set(JUMP_STRENGTH);
             is_jumping.jump();
         }
+
+        if keyboard_input.any_just_pressed([KeyCode::KeyW, KeyCode::ArrowUp]) {
+            throws.write(ThrowWhistle);
+        }
+
+        if keyboard_input.just_pressed(KeyCode::KeyH)
+            || gamepads
+                .iter()
+                .any(|gamepad| gamepad.just_pressed(GamepadButton::North))
+        {
+            horns.write(BlowHorn);
+        }
     }
 }
 
+#[allow(clippy::too_many_arguments)]
+// The touch areas share their bounds, so a touch taken by one must not fall through to the next.
+#[allow(clippy::collapsible_match)]
 pub fn handle_player_input_for_moblie(
     windows: Query<&Window>,
     touches: Res<Touches>,
+    time: Res<Time<Real>>,
+    config: Res<GameplayConfig>,
+    mut last_tap: Local<Option<(bool, f32)>>,
+    mut dashes: EventWriter<AirDashed>,
     mut delay: ResMut<InputDelay>,
     mut is_jumping: ResMut<IsPlayerJumping>,
-    mut player_query: Query<(&mut Lane, &Transform, &mut VerticalMovement), With<Player>>,
+    mut player_query: Query<
+        (&mut Lane, &Transform, &mut VerticalMovement, &mut AirDash),
+        With<Player>,
+    >,
 ) {
     let Ok(window) = windows.single() else { return };
     let window_width = window.width();
     let window_height = window.height();
 
-    let Ok((mut lane, transform, mut vert_move)) = player_query.single_mut() else {
+    let Ok((mut lane, transform, mut vert_move, mut dash)) = player_query.single_mut() else {
         return;
     };
     let is_grounded = transform.translation.y <= 0.0;
+    let now = time.elapsed_secs();
 
     for touch in touches.iter_just_pressed() {
         let position = touch.position();
@@ -179,9 +497,22 @@ pub fn handle_player_input_for_moblie(
         let p_horizontal = position.x / window_width;
         match (p_vertical, p_horizontal) {
             (0.3..=0.7, 0.0..=0.3) => {
-                if delay.is_expired() {
-                    lane.dec();
+                // A double tap on a side air-dashes toward it.
+                let dashed = is_double_tap(&mut last_tap, true, now)
+                    && try_air_dash(
+                        &mut lane,
+                        transform,
+                        &mut dash,
+                        &mut delay,
+                        &config,
+                        true,
+                        &mut dashes,
+                    );
+                if !dashed && delay.is_expired() {
+                    steer_left(&mut lane, &config);
                     delay.reset();
+                } else if !dashed {
+                    delay.queue(true);
                 }
             }
             (0.0..=1.0, 0.3..=0.7) => {
@@ -191,14 +522,27 @@ pub fn handle_player_input_for_moblie(
                 }
             }
             (0.3..=0.7, 0.7..=1.0) => {
-                if delay.is_expired() {
-                    lane.inc();
+                let dashed = is_double_tap(&mut last_tap, false, now)
+                    && try_air_dash(
+                        &mut lane,
+                        transform,
+                        &mut dash,
+                        &mut delay,
+                        &config,
+                        false,
+                        &mut dashes,
+                    );
+                if !dashed && delay.is_expired() {
+                    steer_right(&mut lane, &config);
                     delay.reset();
+                } else if !dashed {
+                    delay.queue(false);
                 }
             }
             _ => { /* empty */ }
         }
     }
+    steer_queued(&mut lane, &mut delay, &config);
 }
 
 fn handle_pause_input(
@@ -225,10 +569,12 @@ fn update_tok9_train_delay_time(
     mut train_entities: Query<(Entity, &mut DelayTime), With<Tok9Train>>,
     asset_server: Res<AssetServer>,
     system_volume: Res<SystemVolume>,
+    time_scale: Res<TimeScale>,
     time: Res<Time>,
 ) {
+    let elapsed = time.delta_secs() * time_scale.get();
     for (entity, mut delay_time) in train_entities.iter_mut() {
-        delay_time.on_advanced(time.delta_secs());
+        delay_time.on_advanced(elapsed);
         if delay_time.is_expired() {
             commands.entity(entity).remove::<DelayTime>();
             play_tok9_train_sound(&mut commands, &asset_server, &system_volume);
@@ -282,10 +628,12 @@ fn update_danger_zone_bg_delay_time(
     mut commands: Commands,
     mut spawner: ResMut<Tok9TrainSpawner>,
     mut zone_bg_entities: Query<(Entity, &mut DelayTime), With<DangerZoneBackground>>,
+    time_scale: Res<TimeScale>,
     time: Res<Time>,
 ) {
+    let elapsed = time.delta_secs() * time_scale.get();
     for (entity, mut delay_time) in zone_bg_entities.iter_mut() {
-        delay_time.on_advanced(time.delta_secs());
+        delay_time.on_advanced(elapsed);
         if delay_time.is_expired() {
             spawner.drain_danger_zone(&mut commands, entity);
         }
@@ -296,10 +644,12 @@ fn update_danger_zone_delay_time(
     mut commands: Commands,
     mut spawner: ResMut<Tok9TrainSpawner>,
     mut zone_entities: Query<(Entity, &mut Transform, &mut DelayTime), With<DangerZone>>,
+    time_scale: Res<TimeScale>,
     time: Res<Time>,
 ) {
+    let elapsed = time.delta_secs() * time_scale.get();
     for (entity, mut transform, mut delay_time) in zone_entities.iter_mut() {
-        delay_time.on_advanced(time.delta_secs());
+        delay_time.on_advanced(elapsed);
         if delay_time.is_expired() {
             spawner.drain_danger_zone(&mut commands, entity);
         } else {
@@ -311,60 +661,78 @@ fn update_danger_zone_delay_time(
     }
 }
 
-fn update_player_state(mut state: ResMut<CurrentState>, time: Res<Time>) {
-    match &mut *state {
-        CurrentState::Attacked { remaining } => {
-            *remaining -= time.delta_secs();
-            if *remaining <= 0.0 {
-                *state = CurrentState::Idle;
-            }
-        }
-        CurrentState::Invincible { remaining } => {
-            *remaining -= time.delta_secs();
-            if *remaining <= 0.0 {
-                *state = CurrentState::Attacked {
-                    remaining: ATTACKED_DURATION,
-                };
-            }
-        }
-        _ => { /* empty */ }
+fn update_train_fuel(mut next_state: ResMut<NextState<GameState>>, fuel: Res<TrainFuel>) {
+    if fuel.is_empty() {
+        next_state.set(GameState::WrapUpInGame);
     }
 }
 
-fn update_score(
-    mut score: ResMut<CurrentScore>,
-    player_query: Query<&ForwardMovement, With<Player>>,
-    time: Res<Time>,
+/// Sends a [`FuelAlarm`] whenever the fuel crosses one of the alarm thresholds.
+fn check_fuel_alarm(
+    mut alarm: ResMut<FuelAlarmState>,
+    mut events: EventWriter<FuelAlarm>,
+    fuel: Res<TrainFuel>,
 ) {
-    if let Ok(forward_move) = player_query.single() {
-        score.on_advanced(forward_move, time.delta_secs());
+    if let Some(event) = alarm.update(fuel.get() / FUEL_LIMITS) {
+        events.write(event);
     }
 }
 
-fn update_train_fuel(
-    mut next_state: ResMut<NextState<GameState>>,
-    mut fuel: ResMut<TrainFuel>,
-    state: Res<CurrentState>,
-    time: Res<Time>,
+#[cfg(not(target_arch = "wasm32"))]
+fn play_fuel_alarm_sound(
+    mut commands: Commands,
+    mut events: EventReader<FuelAlarm>,
+    asset_server: Res<AssetServer>,
+    system_volume: Res<SystemVolume>,
 ) {
-    if !state.is_invincible() {
-        fuel.dec(time.delta_secs() * FUEL_USAGE);
+    for _ in events.read().filter(|&&event| event == FuelAlarm::Raised) {
+        commands.spawn((
+            AudioPlayer::new(asset_server.load(SOUND_PATH_SFX_TRAIN_ALARM)),
+            PlaybackSettings::DESPAWN
+                .with_volume(Volume::Linear(system_volume.effect_percentage())),
+            InGameStateRoot,
+            EffectSound,
+        ));
     }
+}
 
-    if fuel.is_empty() {
-        next_state.set(GameState::WrapUpInGame);
+#[cfg(target_arch = "wasm32")]
+fn play_fuel_alarm_sound(
+    mut commands: Commands,
+    mut events: EventReader<FuelAlarm>,
+    asset_server: Res<AssetServer>,
+    system_volume: Res<SystemVolume>,
+) {
+    for _ in events.read().filter(|&&event| event == FuelAlarm::Raised) {
+        commands.spawn((
+            WebAudioPlayer::new(asset_server.load(SOUND_PATH_SFX_TRAIN_ALARM)),
+            WebPlaybackSettings::DESPAWN
+                .with_volume(Volume::Linear(system_volume.effect_percentage())),
+            InGameStateRoot,
+            EffectSound,
+        ));
     }
 }
 
 fn update_player_position(
     mut is_jumping: ResMut<IsPlayerJumping>,
-    mut player_query: Query<(&Lane, &mut Transform, &mut VerticalMovement), With<Player>>,
+    mut player_query: Query<
+        (&Lane, &mut Transform, &mut VerticalMovement, &mut AirDash),
+        With<Player>,
+    >,
     time: Res<Time>,
 ) {
-    if let Ok((lane, mut transform, mut vert_move)) = player_query.single_mut() {
+    if let Ok((lane, mut transform, mut vert_move, mut dash)) = player_query.single_mut() {
+        dash.on_advanced(time.delta_secs());
+
+        // An air-dash reaches its lane at once, rather than easing into it.
         let target_x = LANE_POSITIONS[lane.get()];
-        transform.translation.x +=
-            (target_x - transform.translation.x) * LANE_SWITCH_SPEED * time.delta_secs();
+        if dash.take_snap() {
+            transform.translation.x = target_x;
+        } else {
+            transform.translation.x +=
+                (target_x - transform.translation.x) * LANE_SWITCH_SPEED * time.delta_secs();
+        }
 
         let mut velocity = vert_move.get();
         velocity += GRAVITY * time.delta_secs();
@@ -374,159 +742,823 @@ fn update_player_position(
         if transform.translation.y <= 0.0 {
             transform.translation.y = 0.0;
             vert_move.set(0.0);
-            is_jumping.reset();
+            // Only the landing itself changes the resource, so that change detection sees it once.
+            if is_jumping.get() {
+                is_jumping.reset();
+            }
         }
     }
 }
 
+/// Sends a [`JumpPhase`] on the frame the train takes off or lands,
+/// so the sound, the squash and stretch and the dust all fire together.
+fn send_jump_phase(is_jumping: Res<IsPlayerJumping>, mut events: EventWriter<JumpPhase>) {
+    if !is_jumping.is_changed() || is_jumping.is_added() {
+        return;
+    }
+
+    events.write(if is_jumping.get() {
+        JumpPhase::TakeOff
+    } else {
+        JumpPhase::Landing
+    });
+}
+
+/// Projects where the jumping player will land onto the target lane and fades the marker out
+/// just before landing. In reduce-motion mode the marker keeps a constant opacity instead.
+#[allow(clippy::type_complexity)]
+fn update_landing_marker(
+    options: Res<GraphicsOptions>,
+    player_query: Query<
+        (&Transform, &Lane, &ForwardMovement, &VerticalMovement),
+        (With<Player>, Without<LandingMarker>),
+    >,
+    mut marker_query: Query<
+        (
+            &mut Transform,
+            &mut Visibility,
+            &MeshMaterial3d<StandardMaterial>,
+        ),
+        With<LandingMarker>,
+    >,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    time_scale: Res<TimeScale>,
+) {
+    let Ok((mut transform, mut visibility, material)) = marker_query.single_mut() else {
+        return;
+    };
+
+    let landing = player_query
+        .single()
+        .ok()
+        .filter(|(p_trans, ..)| options.landing_marker && p_trans.translation.y > 0.0);
+    let Some((p_trans, lane, forward_move, vert_move)) = landing else {
+        *visibility = Visibility::Hidden;
+        return;
+    };
+
+    let time = vert_move.time_to_land(p_trans.translation.y);
+    transform.translation = Vec3::new(
+        LANE_POSITIONS[lane.get()],
+        LANDING_MARKER_HEIGHT,
+        // The train moves forward slowed down by the time scale, but falls at its usual pace.
+        p_trans.translation.z + forward_move.get() * time_scale.get() * time,
+    );
+    *visibility = Visibility::Visible;
+
+    let fade = if options.reduce_motion {
+        1.0
+    } else {
+        (time / LANDING_MARKER_FADE_TIME).min(1.0)
+    };
+    if let Some(material) = materials.get_mut(material.id()) {
+        material.base_color = LANDING_MARKER_COLOR.with_alpha(LANDING_MARKER_COLOR.alpha() * fade);
+    }
+}
+
 fn update_ground_position(
     player_query: Query<&ForwardMovement, With<Player>>,
     mut ground_entities: Query<(Entity, &mut Transform), With<Ground>>,
     mut retired: ResMut<RetiredGrounds>,
+    time_scale: Res<TimeScale>,
     time: Res<Time>,
+    horizon: Res<TrackHorizon>,
 ) {
+    let elapsed = time.delta_secs() * time_scale.get();
     let player_velocity = player_query
         .single()
         .map(|forward_move| forward_move.get())
         .unwrap_or(0.0);
 
     for (entity, mut transform) in ground_entities.iter_mut() {
-        transform.translation.z -= player_velocity * time.delta_secs();
+        transform.translation.z -= player_velocity * elapsed;
 
-        if transform.translation.z <= DESPAWN_POSITION {
+        if transform.translation.z <= horizon.despawn {
             retired.push(entity);
         }
     }
 }
 
+#[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments)]
 fn update_object_position(
     mut commands: Commands,
+    asset_server: Res<AssetServer>,
     mut object_spawner: ResMut<ObjectSpawner>,
-    mut object_entities: Query<(Entity, &mut Transform, &Object)>,
-    player_query: Query<&ForwardMovement, With<Player>>,
+    mut stats: ResMut<RunStats>,
+    mut object_entities: Query<(Entity, &mut Transform, &Object, Has<MaterialEffect>)>,
+    player_query: Query<(&ForwardMovement, &Transform), (With<Player>, Without<Object>)>,
+    time_scale: Res<TimeScale>,
     time: Res<Time>,
+    horizon: Res<TrackHorizon>,
 ) {
-    let player_velocity = player_query
-        .single()
-        .map(|forward_move| forward_move.get())
+    let elapsed = time.delta_secs() * time_scale.get();
+    let player = player_query.single().ok();
+    let player_velocity = player
+        .map(|(forward_move, _)| forward_move.get())
         .unwrap_or(0.0);
+    let miss_line = player.map(|(_, p_trans)| p_trans.translation.z - MISS_DISTANCE);
+
+    for (entity, mut transform, &obj, flashing) in object_entities.iter_mut() {
+        let prev_z = transform.translation.z;
+        transform.translation.z -= player_velocity * elapsed;
+
+        if !obj.is_pickup()
+            && !flashing
+            && transform.translation.z <= horizon.despawn + OBSTACLE_EXPIRY_DISTANCE
+        {
+            commands
+                .entity(entity)
+                .insert(MaterialEffect::new(MaterialEffectKind::Flash {
+                    cycle: OBSTACLE_EXPIRY_CYCLE,
+                }));
+        }
 
-    for (entity, mut transform, &obj) in object_entities.iter_mut() {
-        transform.translation.z -= player_velocity * time.delta_secs();
+        // Collected fuel and bells are drained on collision, so any crossing the line was missed.
+        if matches!(obj, Object::Fuel | Object::Bell)
+            && let Some(miss_line) = miss_line
+            && prev_z >= miss_line
+            && transform.translation.z < miss_line
+        {
+            stats.miss(obj);
+            spawn_miss_indicator(&mut commands, &asset_server);
+        }
 
-        if transform.translation.z <= DESPAWN_POSITION {
+        if transform.translation.z <= horizon.despawn {
             object_spawner.drain(&mut commands, entity, obj);
         }
     }
 }
 
+/// Activates the objects that come within reach of the view, and the models under them.
 #[allow(clippy::type_complexity)]
-fn update_tok9_train_position(
+fn activate_objects(
     mut commands: Commands,
-    mut train_spawner: ResMut<Tok9TrainSpawner>,
-    mut train_entities: Query<
-        (Entity, &mut Transform, &ForwardMovement, &Tok9Train),
-        Without<DelayTime>,
-    >,
-    player_query: Query<&ForwardMovement, With<Player>>,
-    time: Res<Time>,
+    query: Query<(Entity, &Transform, Option<&Children>), (With<Object>, Without<Activated>)>,
 ) {
-    let player_velocity = player_query
-        .single()
-        .map(|forward_move| forward_move.get())
-        .unwrap_or(0.0);
-
-    for (entity, mut transform, forward_move, &train) in train_entities.iter_mut() {
-        transform.translation.z -= player_velocity * time.delta_secs();
-        transform.translation.z -= forward_move.get() * time.delta_secs();
-
-        if transform.translation.z <= DESPAWN_POSITION {
-            train_spawner.drain(&mut commands, entity, train);
+    for (entity, transform, children) in query.iter() {
+        if transform.translation.z > ACTIVATION_POSITION {
+            continue;
         }
-    }
-}
 
-fn rotate_animation(mut query: Query<(&mut Transform, &RotateAnimation)>, time: Res<Time>) {
-    for (mut transform, animation) in query.iter_mut() {
-        let axis = animation.axis;
-        let angle = animation.radian_per_sec * time.delta_secs();
-        let rotation = Quat::from_axis_angle(axis, angle);
-        transform.rotate(rotation);
+        commands.entity(entity).insert(Activated);
+        for child in children.into_iter().flat_map(|children| children.iter()) {
+            commands.entity(child).insert(Activated);
+        }
     }
 }
 
-fn fade_in_out_animation(
+/// Starts the appearance of the objects placed since the last frame, and scales up those appearing.
+fn play_appearance(
     mut commands: Commands,
-    mut query: Query<(Entity, &mut ImageNode, &mut FadeInOutAnimation)>,
+    mut query: Query<(Entity, Ref<Object>, &mut Transform, Option<&mut Appearing>)>,
     time: Res<Time>,
 ) {
-    for (entity, mut image_node, mut fade_in_out) in query.iter_mut() {
-        fade_in_out.tick(time.delta_secs());
-        if fade_in_out.is_expired() {
-            commands.entity(entity).despawn();
-        } else {
-            image_node.color = fade_in_out.color();
+    for (entity, obj, mut transform, appearing) in query.iter_mut() {
+        if obj.is_added() {
+            let appearing = Appearing::new(transform.scale);
+            transform.scale = appearing.scale();
+            commands.entity(entity).insert(appearing);
+            continue;
+        }
+
+        if let Some(mut appearing) = appearing {
+            appearing.on_advanced(time.delta_secs());
+            transform.scale = appearing.scale();
+            if appearing.is_finished() {
+                commands.entity(entity).remove::<Appearing>();
+            }
         }
     }
 }
 
-fn cleanup_ui_animation(
+/// Counts down the shield's grace time and makes the glow ring glow while the shield is charged.
+/// Once the shield is used up, the ring fades out over the grace time.
+fn update_player_shield(
     mut commands: Commands,
-    mut reader: EventReader<TweenCompleted>,
-    query: Query<(), With<Animator<Node>>>,
+    mut shield: ResMut<PlayerShield>,
+    mut query: Query<(Entity, &mut Visibility, Option<&MaterialEffect>), With<ShieldGlow>>,
+    time: Res<Time>,
 ) {
-    for event in reader.read() {
-        let entity = event.entity;
-        if query.contains(entity) {
-            commands.entity(entity).remove::<Animator<Node>>();
-            info!("Animator removed!");
+    shield.on_advanced(time.delta_secs());
+    let kind = match (shield.is_charged(), shield.is_absorbing()) {
+        (true, _) => Some(MaterialEffectKind::Tint(SHIELD_GLOW_COLOR)),
+        (false, true) => Some(MaterialEffectKind::Fade {
+            duration: SHIELD_GRACE_DURATION,
+        }),
+        (false, false) => None,
+    };
+    for (entity, mut visibility, effect) in query.iter_mut() {
+        *visibility = match kind.is_some() {
+            true => Visibility::Inherited,
+            false => Visibility::Hidden,
+        };
+        if effect.map(MaterialEffect::kind) == kind {
+            continue;
         }
+        match kind {
+            Some(kind) => commands.entity(entity).insert(MaterialEffect::new(kind)),
+            None => commands.entity(entity).remove::<MaterialEffect>(),
+        };
     }
 }
 
-#[allow(clippy::type_complexity)]
-fn button_system(
+/// Counts the time since the last bell, which ends the chain of chimes once it is too long.
+fn update_bell_chime(mut chime: ResMut<BellChime>, time: Res<Time>) {
+    chime.on_advanced(time.delta_secs());
+}
+
+fn update_train_horn(mut horn: ResMut<TrainHorn>, time: Res<Time>) {
+    horn.on_advanced(time.delta_secs());
+}
+
+/// Blows the steam whistle if it has recharged, startling the Aobas within its range for bonus points.
+#[allow(clippy::too_many_arguments)]
+fn blow_horn(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
-    system_volume: ResMut<SystemVolume>,
-    mut query: Query<
-        (&UI, &Interaction, &mut BackgroundColor),
-        (Changed<Interaction>, With<Button>),
-    >,
-    mut next_state: ResMut<NextState<GameState>>,
+    system_volume: Res<SystemVolume>,
+    mut rng: ResMut<RngService>,
+    mut events: EventReader<BlowHorn>,
+    mut horn: ResMut<TrainHorn>,
+    mut score: ResMut<CurrentScore>,
+    player_query: Query<&Transform, (With<Player>, Without<Object>)>,
+    aoba_query: Query<(Entity, &Object, &GlobalTransform), Without<Startled>>,
 ) {
-    for (ui, interaction, mut color) in query.iter_mut() {
-        match (*ui, *interaction) {
-            (UI::PauseButton, Interaction::Hovered) => {
-                color.0 = PAUSE_BTN_COLOR.darker(0.25);
-                play_button_sound_when_hovered(&mut commands, &asset_server, &system_volume);
-            }
-            (UI::PauseButton, Interaction::Pressed) => {
-                color.0 = PAUSE_BTN_COLOR.darker(0.5);
-                play_button_sound_when_pressed(&mut commands, &asset_server, &system_volume);
-                next_state.set(GameState::Pause);
-            }
-            (UI::PauseButton, Interaction::None) => {
-                color.0 = PAUSE_BTN_COLOR;
+    for _ in events.read() {
+        let Ok(p_trans) = player_query.single() else {
+            return;
+        };
+        if !horn.is_ready() {
+            continue;
+        }
+        horn.blow();
+        play_horn_sound(&mut commands, &asset_server, &system_volume);
+
+        for (entity, &obj, transform) in aoba_query.iter() {
+            let position = transform.translation();
+            if obj != Object::Aoba || (position.z - p_trans.translation.z).abs() > HORN_RANGE {
+                continue;
             }
-            _ => { /* empty */ }
+
+            info!("Startled Aoba {:?}", entity);
+            score.inc(HORN_SCARE_POINT);
+            commands.entity(entity).insert(Startled::default());
+            play_aoba_sound(
+                &mut commands,
+                &asset_server,
+                &system_volume,
+                rng.audio(),
+                entity,
+                position,
+            );
         }
     }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
-fn play_train_sound(
-    mut commands: Commands,
-    asset_server: Res<AssetServer>,
-    system_volume: Res<SystemVolume>,
-    player_query: Query<&ForwardMovement, With<Player>>,
-    removed: RemovedComponents<TrainSoundStart>,
+fn play_horn_sound(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    system_volume: &SystemVolume,
 ) {
-    if !removed.is_empty()
-        && let Ok(forward_move) = player_query.single()
-    {
-        let velocity = forward_move.get();
-        let t = (velocity - MIN_PLAYER_SPEED) / (MAX_PLAYER_SPEED - MIN_PLAYER_SPEED);
+    commands.spawn((
+        AudioPlayer::new(asset_server.load(SOUND_PATH_SFX_TRAIN_STEAM_WHISTLE)),
+        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.effect_percentage())),
+        InGameStateRoot,
+        EffectSound,
+    ));
+}
+
+#[cfg(target_arch = "wasm32")]
+fn play_horn_sound(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    system_volume: &SystemVolume,
+) {
+    commands.spawn((
+        WebAudioPlayer::new(asset_server.load(SOUND_PATH_SFX_TRAIN_STEAM_WHISTLE)),
+        WebPlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.effect_percentage())),
+        InGameStateRoot,
+        EffectSound,
+    ));
+}
+
+/// Makes the startled Aobas hop once.
+fn update_startled_aobas(
+    mut query: Query<(&mut Startled, &Children)>,
+    mut model_query: Query<&mut Transform, With<InGameStateEntity>>,
+    time: Res<Time>,
+) {
+    for (mut startled, children) in query.iter_mut() {
+        if startled.elapsed >= STARTLED_DURATION {
+            continue;
+        }
+
+        startled.elapsed = (startled.elapsed + time.delta_secs()).min(STARTLED_DURATION);
+        let t = startled.elapsed / STARTLED_DURATION;
+        for &child in children {
+            if let Ok(mut transform) = model_query.get_mut(child) {
+                transform.translation.y = STARTLED_HOP_HEIGHT * (PI * t).sin();
+            }
+        }
+    }
+}
+
+/// Counts down the slow motion and blends its effects: the world loses its colors
+/// and the background music is muffled.
+fn update_time_scale(
+    mut time_scale: ResMut<TimeScale>,
+    mut camera_query: Query<&mut ColorGrading, With<Camera3d>>,
+    #[cfg(not(target_arch = "wasm32"))] mut bgm_query: Query<&mut LowPass, With<BackgroundSound>>,
+    #[cfg(target_arch = "wasm32")] mut bgm_query: Query<
+        &mut WebPlaybackSettings,
+        With<BackgroundSound>,
+    >,
+    time: Res<Time>,
+) {
+    let was_active = time_scale.is_active();
+    time_scale.on_advanced(time.delta_secs());
+    if !was_active {
+        return;
+    }
+
+    let strength = time_scale.strength();
+    for mut grading in camera_query.iter_mut() {
+        grading.global.post_saturation = 1.0 - (1.0 - CLOCK_SATURATION) * strength;
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    for mut low_pass in bgm_query.iter_mut() {
+        low_pass.set_if_neq(LowPass(
+            time_scale.is_active().then_some(CLOCK_BGM_LOW_PASS),
+        ));
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    for mut settings in bgm_query.iter_mut() {
+        let low_pass = time_scale.is_active().then_some(CLOCK_BGM_LOW_PASS);
+        if settings.low_pass != low_pass {
+            settings.low_pass = low_pass;
+        }
+    }
+}
+
+/// Ends the slow motion when the run is over.
+fn clear_time_scale(mut time_scale: ResMut<TimeScale>) {
+    time_scale.clear();
+}
+
+/// Restores the colors and the background music changed by the slow motion.
+fn restore_time_scale_effects(
+    mut camera_query: Query<&mut ColorGrading, With<Camera3d>>,
+    #[cfg(not(target_arch = "wasm32"))] mut bgm_query: Query<&mut LowPass, With<BackgroundSound>>,
+    #[cfg(target_arch = "wasm32")] mut bgm_query: Query<
+        &mut WebPlaybackSettings,
+        With<BackgroundSound>,
+    >,
+) {
+    for mut grading in camera_query.iter_mut() {
+        grading.global.post_saturation = 1.0;
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    for mut low_pass in bgm_query.iter_mut() {
+        low_pass.set_if_neq(LowPass(None));
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    for mut settings in bgm_query.iter_mut() {
+        settings.low_pass = None;
+    }
+}
+
+/// Spends bells to throw a toy whistle at the nearest barricade ahead in the player's lane.
+/// Nothing is thrown, and no bell is spent, if there is no barricade to throw it at.
+#[allow(clippy::type_complexity)]
+fn throw_whistle(
+    mut commands: Commands,
+    mut events: EventReader<ThrowWhistle>,
+    mut bells: ResMut<CollectedBells>,
+    assets: Res<WhistleAssets>,
+    player_query: Query<(&Lane, &Transform), (With<Player>, Without<Object>)>,
+    object_query: Query<(Entity, &Object, &Lane, &Transform)>,
+) {
+    for _ in events.read() {
+        let Ok((lane, p_trans)) = player_query.single() else {
+            return;
+        };
+        if **bells < WHISTLE_COST {
+            continue;
+        }
+
+        let Some(target) = object_query
+            .iter()
+            .filter(|(_, obj, o_lane, o_trans)| {
+                **obj == Object::Barricade
+                    && o_lane.get() == lane.get()
+                    && o_trans.translation.z > p_trans.translation.z
+            })
+            .min_by(|(.., a), (.., b)| a.translation.z.total_cmp(&b.translation.z))
+            .map(|(entity, ..)| entity)
+        else {
+            continue;
+        };
+        **bells -= WHISTLE_COST;
+
+        info!("Throw whistle at {:?}", target);
+        commands.spawn((
+            Mesh3d(assets.mesh.clone()),
+            MeshMaterial3d(assets.material.clone()),
+            Transform::from_xyz(
+                LANE_POSITIONS[lane.get()],
+                p_trans.translation.y + 1.0,
+                p_trans.translation.z + 1.0,
+            ),
+            Collider::Sphere {
+                offset: Vec3::ZERO,
+                radius: WHISTLE_RADIUS,
+            },
+            NotShadowCaster,
+            InGameStateRoot,
+            Whistle {
+                lane: lane.get(),
+                target: Some(target),
+            },
+        ));
+    }
+}
+
+/// Flies the whistles toward their targets, or straight ahead if there is none.
+fn update_whistle_position(
+    mut commands: Commands,
+    mut whistle_query: Query<(Entity, &mut Transform, &mut Whistle), Without<Object>>,
+    object_query: Query<&Transform, With<Object>>,
+    time: Res<Time>,
+    horizon: Res<TrackHorizon>,
+) {
+    let step = WHISTLE_SPEED * time.delta_secs();
+    for (entity, mut transform, mut whistle) in whistle_query.iter_mut() {
+        // The target may have been drained since the whistle was thrown.
+        let destination = whistle
+            .target
+            .and_then(|target| object_query.get(target).ok())
+            .map(|o_trans| o_trans.translation + Vec3::Y * 0.5);
+        match destination {
+            Some(destination) => {
+                transform.translation = transform.translation.move_towards(destination, step);
+            }
+            None => {
+                whistle.target = None;
+                transform.translation.z += step;
+            }
+        }
+
+        if transform.translation.z >= horizon.spawn {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Sways the wandering obstacles between lanes and keeps their lane up to date for collisions.
+#[allow(clippy::type_complexity)]
+fn update_lateral_drift(
+    mut query: Query<
+        (&mut Transform, &mut Lane, &mut LateralDrift),
+        (With<Object>, With<Activated>),
+    >,
+    player_query: Query<&Transform, (With<Player>, Without<Object>)>,
+    time_scale: Res<TimeScale>,
+    time: Res<Time>,
+) {
+    let Ok(p_trans) = player_query.single() else {
+        return;
+    };
+    for (mut transform, mut lane, mut drift) in query.iter_mut() {
+        let distance = transform.translation.z - p_trans.translation.z;
+        drift.on_advanced(time.delta_secs() * time_scale.get(), distance);
+        transform.translation.x = drift.x();
+        *lane = Lane::new(drift.nearest_lane());
+    }
+}
+
+fn spawn_miss_indicator(commands: &mut Commands, asset_server: &AssetServer) {
+    let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
+    commands.spawn((
+        Text::new("Miss"),
+        TranslatableText("miss".into()),
+        TextFont::from_font(font),
+        TextLayout::new_with_justify(JustifyText::Center),
+        TextColor(MISS_INDICATOR_COLOR),
+        ResizableFont::vertical(1280.0, 48.0),
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Vw(2.0),
+            bottom: Val::Vh(12.0),
+            ..Default::default()
+        },
+        Pickable::IGNORE,
+        UiLayer::Hud,
+        InGameStateRoot,
+        MissIndicator {
+            remaining: MISS_INDICATOR_DURATION,
+        },
+    ));
+}
+
+/// Fades the miss labels out and removes them once they are invisible.
+fn update_miss_indicators(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut TextColor, &mut MissIndicator)>,
+    time: Res<Time>,
+) {
+    for (entity, mut color, mut indicator) in query.iter_mut() {
+        indicator.remaining -= time.delta_secs();
+        if indicator.remaining <= 0.0 {
+            commands.entity(entity).despawn();
+        } else {
+            let alpha =
+                MISS_INDICATOR_COLOR.alpha() * indicator.remaining / MISS_INDICATOR_DURATION;
+            color.0 = MISS_INDICATOR_COLOR.with_alpha(alpha);
+        }
+    }
+}
+
+/// Shows each fuel change on a free label from the pool, or on the oldest one if none is free.
+fn show_fuel_indicators(
+    mut events: EventReader<FuelChanged>,
+    mut query: Query<(
+        &mut Text,
+        &mut TextColor,
+        &mut Visibility,
+        &mut FuelIndicator,
+    )>,
+) {
+    for &FuelChanged(amount) in events.read() {
+        // Rounds toward zero so a clamped change near the limits never reads as more than it was.
+        let amount = amount.trunc();
+        if amount == 0.0 {
+            continue;
+        }
+
+        let Some((mut text, mut color, mut visibility, mut indicator)) = query
+            .iter_mut()
+            .min_by(|a, b| a.3.remaining.total_cmp(&b.3.remaining))
+        else {
+            return;
+        };
+
+        let base_color = if amount > 0.0 {
+            FUEL_GAIN_COLOR
+        } else {
+            FUEL_LOSS_COLOR
+        };
+        text.0 = format!("{amount:+}");
+        color.0 = base_color;
+        *visibility = Visibility::Visible;
+        indicator.remaining = FUEL_INDICATOR_DURATION;
+    }
+}
+
+/// Keeps the fuel change labels above the train as they rise and fade out.
+fn update_fuel_indicators(
+    mut query: Query<(
+        &mut Node,
+        &mut TextColor,
+        &mut Visibility,
+        &ComputedNode,
+        &mut FuelIndicator,
+    )>,
+    player_query: Query<&Transform, With<Player>>,
+    camera_query: Query<(&Camera, &GlobalTransform, Has<MirroredView>), With<CameraRig>>,
+    time: Res<Time>,
+) {
+    let (Ok(p_trans), Ok((camera, camera_transform, mirrored))) =
+        (player_query.single(), camera_query.single())
+    else {
+        return;
+    };
+
+    for (mut node, mut color, mut visibility, computed, mut indicator) in query.iter_mut() {
+        if indicator.remaining <= 0.0 {
+            continue;
+        }
+
+        indicator.remaining -= time.delta_secs();
+        let t = 1.0 - (indicator.remaining / FUEL_INDICATOR_DURATION).max(0.0);
+        let height = FUEL_INDICATOR_HEIGHT + FUEL_INDICATOR_RISE * t;
+        let position = camera
+            .world_to_viewport(camera_transform, p_trans.translation + Vec3::Y * height)
+            .ok();
+
+        match position {
+            Some(mut position) if indicator.remaining > 0.0 => {
+                // The mirrored world is flipped after it is rendered, so the label is flipped too.
+                if mirrored && let Some(size) = camera.logical_viewport_size() {
+                    position.x = size.x - position.x;
+                }
+                let size = computed.size() * computed.inverse_scale_factor();
+                node.left = Val::Px(position.x - size.x * 0.5);
+                node.top = Val::Px(position.y - size.y * 0.5);
+                color.0 = color.0.with_alpha(1.0 - t);
+            }
+            _ => {
+                *visibility = Visibility::Hidden;
+            }
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn update_tok9_train_position(
+    mut commands: Commands,
+    mut train_spawner: ResMut<Tok9TrainSpawner>,
+    mut train_entities: Query<
+        (Entity, &mut Transform, &ForwardMovement, &Tok9Train),
+        Without<DelayTime>,
+    >,
+    player_query: Query<&ForwardMovement, With<Player>>,
+    time_scale: Res<TimeScale>,
+    time: Res<Time>,
+    horizon: Res<TrackHorizon>,
+) {
+    let elapsed = time.delta_secs() * time_scale.get();
+    let player_velocity = player_query
+        .single()
+        .map(|forward_move| forward_move.get())
+        .unwrap_or(0.0);
+
+    for (entity, mut transform, forward_move, &train) in train_entities.iter_mut() {
+        transform.translation.z -= player_velocity * elapsed;
+        transform.translation.z -= forward_move.get() * elapsed;
+
+        if transform.translation.z <= horizon.despawn {
+            train_spawner.drain(&mut commands, entity, train);
+        }
+    }
+}
+
+fn update_passing_train_position(
+    mut commands: Commands,
+    mut spawner: ResMut<TrafficSpawner>,
+    mut train_entities: Query<(Entity, &mut Transform, &ForwardMovement), With<PassingTrain>>,
+    player_query: Query<&ForwardMovement, (With<Player>, Without<PassingTrain>)>,
+    time_scale: Res<TimeScale>,
+    time: Res<Time>,
+    horizon: Res<TrackHorizon>,
+) {
+    let elapsed = time.delta_secs() * time_scale.get();
+    let player_velocity = player_query
+        .single()
+        .map(|forward_move| forward_move.get())
+        .unwrap_or(0.0);
+
+    for (entity, mut transform, forward_move) in train_entities.iter_mut() {
+        transform.translation.z -= (player_velocity + forward_move.get()) * elapsed;
+
+        if transform.translation.z <= horizon.despawn {
+            spawner.drain(&mut commands, entity);
+        }
+    }
+}
+
+/// Returns the playback speed of a passing train at `distance` ahead of the player,
+/// higher while it approaches and lower once it has passed.
+fn passing_train_speed(distance: f32) -> f32 {
+    1.0 + TRAFFIC_DOPPLER_SHIFT * (distance / TRAFFIC_DOPPLER_DISTANCE).clamp(-1.0, 1.0)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn update_passing_train_sound(
+    query: Query<(&Transform, &AudioSink), With<PassingTrain>>,
+    player_query: Query<&Transform, (With<Player>, Without<PassingTrain>)>,
+) {
+    let player_z = player_query
+        .single()
+        .map(|transform| transform.translation.z)
+        .unwrap_or(0.0);
+
+    for (transform, sink) in query.iter() {
+        sink.set_speed(passing_train_speed(transform.translation.z - player_z));
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn update_passing_train_sound(
+    mut query: Query<(&Transform, &mut WebPlaybackSettings), With<PassingTrain>>,
+    player_query: Query<&Transform, (With<Player>, Without<PassingTrain>)>,
+) {
+    let player_z = player_query
+        .single()
+        .map(|transform| transform.translation.z)
+        .unwrap_or(0.0);
+
+    for (transform, mut settings) in query.iter_mut() {
+        settings.speed = passing_train_speed(transform.translation.z - player_z);
+    }
+}
+
+fn rotate_animation(
+    mut query: Query<(&mut Transform, &RotateAnimation), With<Activated>>,
+    time: Res<Time>,
+) {
+    for (mut transform, animation) in query.iter_mut() {
+        let axis = animation.axis;
+        let angle = animation.radian_per_sec * time.delta_secs();
+        let rotation = Quat::from_axis_angle(axis, angle);
+        transform.rotate(rotation);
+    }
+}
+
+fn fade_in_out_animation(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut ImageNode, &mut FadeInOutAnimation)>,
+    time: Res<Time>,
+) {
+    for (entity, mut image_node, mut fade_in_out) in query.iter_mut() {
+        fade_in_out.tick(time.delta_secs());
+        if fade_in_out.is_expired() {
+            commands.entity(entity).despawn();
+        } else {
+            image_node.color = fade_in_out.color();
+        }
+    }
+}
+
+fn cleanup_ui_animation(
+    mut commands: Commands,
+    mut reader: EventReader<TweenCompleted>,
+    query: Query<(), With<Animator<Node>>>,
+) {
+    for event in reader.read() {
+        let entity = event.entity;
+        if query.contains(entity) {
+            commands.entity(entity).remove::<Animator<Node>>();
+            info!("Animator removed!");
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn button_system(
+    mut query: Query<
+        (&UI, &Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut throws: EventWriter<ThrowWhistle>,
+    mut horns: EventWriter<BlowHorn>,
+) {
+    for (ui, interaction, mut color) in query.iter_mut() {
+        match (*ui, *interaction) {
+            (UI::PauseButton, Interaction::Hovered) => {
+                color.0 = PAUSE_BTN_COLOR.darker(0.25);
+            }
+            (UI::PauseButton, Interaction::Pressed) => {
+                color.0 = PAUSE_BTN_COLOR.darker(0.5);
+                next_state.set(GameState::Pause);
+            }
+            (UI::PauseButton, Interaction::None) => {
+                color.0 = PAUSE_BTN_COLOR;
+            }
+            (UI::ThrowButton, Interaction::Hovered) => {
+                color.0 = THROW_BTN_COLOR.darker(0.25);
+            }
+            (UI::ThrowButton, Interaction::Pressed) => {
+                color.0 = THROW_BTN_COLOR.darker(0.5);
+                throws.write(ThrowWhistle);
+            }
+            (UI::ThrowButton, Interaction::None) => {
+                color.0 = THROW_BTN_COLOR;
+            }
+            (UI::HornButton, Interaction::Hovered) => {
+                color.0 = HORN_BTN_COLOR.darker(0.25);
+            }
+            (UI::HornButton, Interaction::Pressed) => {
+                color.0 = HORN_BTN_COLOR.darker(0.5);
+                horns.write(BlowHorn);
+            }
+            (UI::HornButton, Interaction::None) => {
+                color.0 = HORN_BTN_COLOR;
+            }
+            _ => { /* empty */ }
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn play_train_sound(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    system_volume: Res<SystemVolume>,
+    player_query: Query<&ForwardMovement, With<Player>>,
+    removed: RemovedComponents<TrainSoundStart>,
+) {
+    if !removed.is_empty()
+        && let Ok(forward_move) = player_query.single()
+    {
+        let velocity = forward_move.get();
+        let t = (velocity - MIN_PLAYER_SPEED) / (MAX_PLAYER_SPEED - MIN_PLAYER_SPEED);
         let t = t.clamp(0.0, 1.0);
 
         let volume = system_volume.effect_percentage() * (1.0 - t);
@@ -590,17 +1622,17 @@ fn update_train_sound(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     system_volume: Res<SystemVolume>,
-    is_jumping: Res<IsPlayerJumping>,
+    mut events: EventReader<JumpPhase>,
     mut set: ParamSet<(
         Query<&mut AudioSink, With<TrainSoundLoop1>>,
         Query<&mut AudioSink, With<TrainSoundLoop2>>,
     )>,
 ) {
-    if !is_jumping.changed() {
+    let Some(&phase) = events.read().last() else {
         return;
-    }
+    };
 
-    if is_jumping.get() {
+    if phase == JumpPhase::TakeOff {
         if let Ok(mut sink) = set.p0().single_mut() {
             sink.mute();
         }
@@ -634,15 +1666,15 @@ fn update_train_sound(
     asset_server: Res<AssetServer>,
     system_volume: Res<SystemVolume>,
     player_query: Query<&ForwardMovement, With<Player>>,
-    is_jumping: Res<IsPlayerJumping>,
+    mut events: EventReader<JumpPhase>,
     mut set: ParamSet<(
         Query<&mut WebPlaybackSettings, With<TrainSoundLoop1>>,
         Query<&mut WebPlaybackSettings, With<TrainSoundLoop2>>,
     )>,
 ) {
-    if !is_jumping.changed() {
+    let Some(&phase) = events.read().last() else {
         return;
-    }
+    };
 
     let Ok(forward_move) = player_query.single() else {
         return;
@@ -651,7 +1683,7 @@ fn update_train_sound(
     let t = (velocity - MIN_PLAYER_SPEED) / (MAX_PLAYER_SPEED - MIN_PLAYER_SPEED);
     let t = t.clamp(0.0, 1.0);
 
-    if is_jumping.get() {
+    if phase == JumpPhase::TakeOff {
         if let Ok(mut settings) = set.p0().single_mut() {
             *settings = settings.with_volume(Volume::Linear(0.0));
         }
@@ -744,8 +1776,12 @@ fn play_aoba_animation(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     system_volume: Res<SystemVolume>,
+    mut rng: ResMut<RngService>,
     mut graphs: ResMut<Assets<AnimationGraph>>,
-    aoba_query: Query<(Entity, &AnimationClipHandle, &GlobalTransform), With<InGameStateEntity>>,
+    aoba_query: Query<
+        (Entity, &AnimationClipHandle, &GlobalTransform),
+        (With<InGameStateEntity>, With<Activated>),
+    >,
 ) {
     for (entity, clip, transform) in aoba_query.iter() {
         if transform.translation().z <= 17.5 {
@@ -759,7 +1795,14 @@ fn play_aoba_animation(
                 .insert((AnimationGraphHandle(graphs.add(graph)), player))
                 .remove::<AnimationClipHandle>();
 
-            play_aoba_sound(&mut commands, &asset_server, &system_volume);
+            play_aoba_sound(
+                &mut commands,
+                &asset_server,
+                &system_volume,
+                rng.audio(),
+                entity,
+                transform.translation(),
+            );
         }
     }
 }
@@ -795,140 +1838,175 @@ fn apply_to_descendants(
 
 // --- POSTUPDATE SYSTEMS ---
 
-#[allow(clippy::type_complexity)]
-fn update_toy_trains(
-    mut set: ParamSet<(
-        Query<&Transform, With<Player>>,
-        Query<&mut Transform, With<ToyTrain0>>,
-        Query<&mut Transform, With<ToyTrain1>>,
-        Query<&mut Transform, With<ToyTrain2>>,
-    )>,
+fn spawn_grounds(
+    mut commands: Commands,
+    mut retired: ResMut<RetiredGrounds>,
+    ground_query: Query<&Transform, With<Ground>>,
+    horizon: Res<TrackHorizon>,
 ) {
-    let data = set
-        .p0()
-        .single()
-        .map(|transform| transform.translation.with_z(transform.translation.z + 1.5))
-        .ok();
-
-    if let Some(p_pos) = data {
-        let mut position = p_pos;
-
-        if let Ok(mut transform) = set.p1().single_mut() {
-            let z_axis = (transform.translation - position).normalize_or(Vec3::NEG_Z);
-            let y_axis = Vec3::Y;
-            let x_axis = y_axis.cross(z_axis);
-            let y_axis = z_axis.cross(x_axis);
-            let rotation = Quat::from_mat3(&Mat3::from_cols(x_axis, y_axis, z_axis));
-
-            let temp = transform.translation;
-
-            transform.translation.x = position.x;
-            transform.translation.y = position.y;
-            transform.translation.z = p_pos.z - 1.5;
-            transform.rotation = rotation;
-
-            position = temp;
-        }
-
-        if let Ok(mut transform) = set.p2().single_mut() {
-            let z_axis = (transform.translation - position).normalize_or(Vec3::NEG_Z);
-            let y_axis = Vec3::Y;
-            let x_axis = y_axis.cross(z_axis);
-            let y_axis = z_axis.cross(x_axis);
-            let rotation = Quat::from_mat3(&Mat3::from_cols(x_axis, y_axis, z_axis));
-
-            let temp = transform.translation;
-            transform.translation.x = position.x;
-            transform.translation.y = position.y;
-            transform.translation.z = p_pos.z - 3.0;
-            transform.rotation = rotation;
-
-            position = temp;
-        }
-
-        if let Ok(mut transform) = set.p3().single_mut() {
-            let z_axis = (transform.translation - position).normalize_or(Vec3::NEG_Z);
-            let y_axis = Vec3::Y;
-            let x_axis = y_axis.cross(z_axis);
-            let y_axis = z_axis.cross(x_axis);
-            let rotation = Quat::from_mat3(&Mat3::from_cols(x_axis, y_axis, z_axis));
-
-            transform.translation.x = position.x;
-            transform.translation.y = position.y;
-            transform.translation.z = p_pos.z - 4.25;
-            transform.rotation = rotation;
-        }
-    }
-}
-
-fn spawn_grounds(mut commands: Commands, mut retired: ResMut<RetiredGrounds>) {
+    let grounds = horizon.grounds();
+    let mut front = ground_query
+        .iter()
+        .map(|transform| transform.translation.z)
+        .fold(horizon.despawn, f32::max);
     while let Some(entity) = retired.pop() {
+        front = grounds.recycled_depth(front);
         commands
             .entity(entity)
             .entry::<Transform>()
-            .and_modify(|mut transform| {
-                transform.translation.z += SPAWN_POSITION - DESPAWN_POSITION;
+            .and_modify(move |mut transform| {
+                transform.translation.z = front;
             })
-            .or_insert(Transform::from_xyz(0.0, 0.0, SPAWN_POSITION));
+            .or_insert(Transform::from_xyz(0.0, 0.0, front));
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn spawn_objects(
     mut commands: Commands,
     mut spawner: ResMut<ObjectSpawner>,
+    mut rng: ResMut<RngService>,
     player_query: Query<&ForwardMovement, With<Player>>,
     asset_server: Res<AssetServer>,
+    state: Res<CurrentState>,
+    time_scale: Res<TimeScale>,
     time: Res<Time>,
 ) {
+    let elapsed = time.delta_secs() * time_scale.get();
     let Ok(forward_move) = player_query.single() else {
         return;
     };
 
+    // Walls spawned during invincibility would reach the player just after it ends.
+    if state.is_invincible() {
+        spawner.exclude(PatternTag::Wall, WALL_GRACE_DURATION);
+    }
+
     spawner.on_advanced(
         &mut commands,
         &asset_server,
         forward_move,
-        time.delta_secs(),
+        rng.spawning(),
+        elapsed,
     );
 }
 
 fn spawn_tok9_trains(
     mut commands: Commands,
     mut spawner: ResMut<Tok9TrainSpawner>,
+    mut rng: ResMut<RngService>,
     system_volume: Res<SystemVolume>,
     asset_server: Res<AssetServer>,
+    time_scale: Res<TimeScale>,
     time: Res<Time>,
 ) {
+    let elapsed = time.delta_secs() * time_scale.get();
     spawner.on_advanced(
         &mut commands,
         &asset_server,
         &system_volume,
-        time.delta_secs(),
+        rng.spawning(),
+        elapsed,
     );
 }
 
+fn spawn_traffic(
+    mut commands: Commands,
+    mut spawner: ResMut<TrafficSpawner>,
+    mut rng: ResMut<RngService>,
+    asset_server: Res<AssetServer>,
+    time_scale: Res<TimeScale>,
+    time: Res<Time>,
+) {
+    let elapsed = time.delta_secs() * time_scale.get();
+    spawner.on_advanced(&mut commands, &asset_server, rng.cosmetic(), elapsed);
+}
+
+#[allow(clippy::type_complexity)]
 #[allow(clippy::too_many_arguments)]
 fn check_for_collisions(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     system_volume: Res<SystemVolume>,
-    mut fuel: ResMut<TrainFuel>,
+    (mut rng, mut rotation): (ResMut<RngService>, ResMut<SoundRotation>),
+    (mut fuel, mut fuel_changed, mut obstacle_hit, mut beat_collected): (
+        ResMut<TrainFuel>,
+        EventWriter<FuelChanged>,
+        EventWriter<ObstacleHit>,
+        EventWriter<BeatBellCollected>,
+    ),
     mut state: ResMut<CurrentState>,
     mut score: ResMut<CurrentScore>,
+    (mut bells, mut chime): (ResMut<CollectedBells>, ResMut<BellChime>),
+    mut stats: ResMut<RunStats>,
+    mut shield: ResMut<PlayerShield>,
+    mut time_scale: ResMut<TimeScale>,
     mut attacked: ResMut<Attacked>,
     mut spawner: ResMut<ObjectSpawner>,
+    config: Res<GameplayConfig>,
     mut player_query: Query<(&Collider, &Transform, &mut ForwardMovement), With<Player>>,
-    object_query: Query<(Entity, &Object, &Collider, &Transform)>,
+    object_query: Query<
+        (
+            Entity,
+            &Object,
+            &Lane,
+            &Collider,
+            &Transform,
+            Option<&SpawnPattern>,
+            Option<&BeatBell>,
+        ),
+        Without<Appearing>,
+    >,
 ) {
-    for (entity, &obj, o_collider, o_trans) in object_query.iter() {
+    // Shields do not save a hardcore run, so any hit ends it.
+    let hardcore = config.mutators.is_active(Mutator::Hardcore);
+    for (entity, &obj, lane, o_collider, o_trans, pattern, beat) in object_query.iter() {
         if let Ok((p_collider, p_trans, mut forward_move)) = player_query.single_mut()
             && p_collider.intersects(p_trans, o_collider, o_trans)
         {
+            // Ignore obstacles in a lane the player is mostly out of while switching lanes.
+            if matches!(obj, Object::Barricade | Object::Stone)
+                && lane.occupancy(p_trans.translation.x) < config.min_lane_overlap
+            {
+                continue;
+            }
+
             info!("Collision detected!");
+            if obj.is_pickup() {
+                stats.collect(obj);
+            }
+
             match (*state, obj) {
+                (CurrentState::Idle, Object::Barricade | Object::Stone)
+                    if !hardcore && shield.absorb() =>
+                {
+                    info!("Shield absorbed the hit!");
+                }
+                (_, Object::Shield) => {
+                    shield.charge();
+                    spawner.drain(&mut commands, entity, obj);
+                }
+                (_, Object::Clock) => {
+                    time_scale.slow_down(CLOCK_DURATION);
+                    spawner.drain(&mut commands, entity, obj);
+                }
                 (CurrentState::Idle, Object::Barricade) => {
-                    play_damaged_sound(&mut commands, &asset_server, &system_volume);
-                    fuel.dec(BARRICADE_DAMAGE);
+                    play_damaged_sound(
+                        &mut commands,
+                        &asset_server,
+                        &system_volume,
+                        &mut rotation,
+                        rng.audio(),
+                        o_trans.translation,
+                    );
+                    let change = fuel.dec(config.mutators.damage(BARRICADE_DAMAGE));
+                    fuel_changed.write(FuelChanged(change));
+                    obstacle_hit.write(ObstacleHit {
+                        lane: lane.get(),
+                        pattern: pattern.map(|pattern| pattern.0),
+                        object: Some(obj),
+                        fatal: fuel.is_empty(),
+                    });
                     forward_move.set(MIN_PLAYER_SPEED);
                     attacked.add();
                     *state = CurrentState::Attacked {
@@ -936,8 +2014,22 @@ fn check_for_collisions(
                     };
                 }
                 (CurrentState::Idle, Object::Stone) => {
-                    play_damaged_sound(&mut commands, &asset_server, &system_volume);
-                    fuel.dec(STONE_DAMAGE);
+                    play_damaged_sound(
+                        &mut commands,
+                        &asset_server,
+                        &system_volume,
+                        &mut rotation,
+                        rng.audio(),
+                        o_trans.translation,
+                    );
+                    let change = fuel.dec(config.mutators.damage(STONE_DAMAGE));
+                    fuel_changed.write(FuelChanged(change));
+                    obstacle_hit.write(ObstacleHit {
+                        lane: lane.get(),
+                        pattern: pattern.map(|pattern| pattern.0),
+                        object: Some(obj),
+                        fatal: fuel.is_empty(),
+                    });
                     forward_move.set(MIN_PLAYER_SPEED);
                     attacked.add();
                     *state = CurrentState::Attacked {
@@ -945,17 +2037,41 @@ fn check_for_collisions(
                     };
                 }
                 (CurrentState::Idle, Object::Fuel) => {
-                    play_healing_sound(&mut commands, &asset_server, &system_volume);
-                    fuel.inc(FUEL_HEALING);
+                    play_healing_sound(
+                        &mut commands,
+                        &asset_server,
+                        &system_volume,
+                        &mut rotation,
+                        rng.audio(),
+                    );
+                    let change = fuel.inc(FUEL_HEALING);
+                    fuel_changed.write(FuelChanged(change));
                     spawner.drain(&mut commands, entity, obj);
                 }
                 (CurrentState::Idle, Object::Bell) => {
-                    play_door_bell_sound(&mut commands, &asset_server, &system_volume);
+                    play_door_bell_sound(
+                        &mut commands,
+                        &asset_server,
+                        &system_volume,
+                        rng.audio(),
+                        chime.chime(),
+                        o_trans.translation,
+                    );
                     score.inc(BELL_POINT);
+                    **bells += 1;
+                    if let Some(beat) = beat {
+                        beat_collected.write(BeatBellCollected(beat.time));
+                    }
                     spawner.drain(&mut commands, entity, obj);
                 }
                 (CurrentState::Idle, Object::Aoba) => {
-                    play_invincible_sound(&mut commands, &asset_server, &system_volume);
+                    play_invincible_sound(
+                        &mut commands,
+                        &asset_server,
+                        &system_volume,
+                        &mut rotation,
+                        rng.audio(),
+                    );
                     forward_move.set(INVINCIBLE_SPEED);
                     spawner.drain(&mut commands, entity, obj);
                     *state = CurrentState::Invincible {
@@ -963,16 +2079,34 @@ fn check_for_collisions(
                     };
                 }
                 (CurrentState::Attacked { .. }, Object::Fuel) => {
-                    fuel.inc(FUEL_HEALING);
+                    let change = fuel.inc(FUEL_HEALING);
+                    fuel_changed.write(FuelChanged(change));
                     spawner.drain(&mut commands, entity, obj);
                 }
                 (CurrentState::Attacked { .. }, Object::Bell) => {
-                    play_door_bell_sound(&mut commands, &asset_server, &system_volume);
+                    play_door_bell_sound(
+                        &mut commands,
+                        &asset_server,
+                        &system_volume,
+                        rng.audio(),
+                        chime.chime(),
+                        o_trans.translation,
+                    );
                     score.inc(BELL_POINT);
+                    **bells += 1;
+                    if let Some(beat) = beat {
+                        beat_collected.write(BeatBellCollected(beat.time));
+                    }
                     spawner.drain(&mut commands, entity, obj);
                 }
                 (CurrentState::Attacked { .. }, Object::Aoba) => {
-                    play_invincible_sound(&mut commands, &asset_server, &system_volume);
+                    play_invincible_sound(
+                        &mut commands,
+                        &asset_server,
+                        &system_volume,
+                        &mut rotation,
+                        rng.audio(),
+                    );
                     forward_move.set(INVINCIBLE_SPEED);
                     spawner.drain(&mut commands, entity, obj);
                     *state = CurrentState::Invincible {
@@ -980,16 +2114,34 @@ fn check_for_collisions(
                     };
                 }
                 (CurrentState::Invincible { .. }, Object::Fuel) => {
-                    fuel.inc(FUEL_HEALING);
+                    let change = fuel.inc(FUEL_HEALING);
+                    fuel_changed.write(FuelChanged(change));
                     spawner.drain(&mut commands, entity, obj);
                 }
                 (CurrentState::Invincible { .. }, Object::Bell) => {
-                    play_door_bell_sound(&mut commands, &asset_server, &system_volume);
+                    play_door_bell_sound(
+                        &mut commands,
+                        &asset_server,
+                        &system_volume,
+                        rng.audio(),
+                        chime.chime(),
+                        o_trans.translation,
+                    );
                     score.inc(BELL_POINT);
+                    **bells += 1;
+                    if let Some(beat) = beat {
+                        beat_collected.write(BeatBellCollected(beat.time));
+                    }
                     spawner.drain(&mut commands, entity, obj);
                 }
                 (CurrentState::Invincible { .. }, Object::Aoba) => {
-                    play_invincible_sound(&mut commands, &asset_server, &system_volume);
+                    play_invincible_sound(
+                        &mut commands,
+                        &asset_server,
+                        &system_volume,
+                        &mut rotation,
+                        rng.audio(),
+                    );
                     forward_move.set(INVINCIBLE_SPEED);
                     spawner.drain(&mut commands, entity, obj);
                     *state = CurrentState::Invincible {
@@ -1009,20 +2161,44 @@ fn check_tok9_train_collisions(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     system_volume: Res<SystemVolume>,
+    mut rng: ResMut<RngService>,
+    mut rotation: ResMut<SoundRotation>,
     mut fuel: ResMut<TrainFuel>,
+    mut fuel_changed: EventWriter<FuelChanged>,
+    mut obstacle_hit: EventWriter<ObstacleHit>,
     mut state: ResMut<CurrentState>,
     mut attacked: ResMut<Attacked>,
+    mut shield: ResMut<PlayerShield>,
+    config: Res<GameplayConfig>,
     mut player_query: Query<(&Collider, &Transform, &mut ForwardMovement), With<Player>>,
-    train_entities: Query<(&Collider, &Transform), (With<Tok9Train>, Without<DelayTime>)>,
+    train_entities: Query<(&Collider, &Transform, &Lane), (With<Tok9Train>, Without<DelayTime>)>,
 ) {
-    for (o_collider, o_trans) in train_entities.iter() {
+    let hardcore = config.mutators.is_active(Mutator::Hardcore);
+    for (o_collider, o_trans, lane) in train_entities.iter() {
         if let Ok((p_collider, p_trans, mut forward_move)) = player_query.single_mut()
             && p_collider.intersects(p_trans, o_collider, o_trans)
         {
             match *state {
+                CurrentState::Idle if !hardcore && shield.absorb() => {
+                    info!("Shield absorbed the hit!");
+                }
                 CurrentState::Idle => {
-                    play_damaged_sound(&mut commands, &asset_server, &system_volume);
-                    fuel.dec(TOK9_TRAIN_DAMAGE);
+                    play_damaged_sound(
+                        &mut commands,
+                        &asset_server,
+                        &system_volume,
+                        &mut rotation,
+                        rng.audio(),
+                        o_trans.translation,
+                    );
+                    let change = fuel.dec(config.mutators.damage(TOK9_TRAIN_DAMAGE));
+                    fuel_changed.write(FuelChanged(change));
+                    obstacle_hit.write(ObstacleHit {
+                        lane: lane.get(),
+                        pattern: None,
+                        object: None,
+                        fatal: fuel.is_empty(),
+                    });
                     forward_move.set(MIN_PLAYER_SPEED);
                     attacked.add();
                     *state = CurrentState::Attacked {
@@ -1035,149 +2211,98 @@ fn check_tok9_train_collisions(
     }
 }
 
-#[cfg(not(target_arch = "wasm32"))]
-fn play_damaged_sound(
-    commands: &mut Commands,
-    asset_server: &AssetServer,
-    system_volume: &SystemVolume,
-) {
-    if rand::random_ratio(2, 3) {
-        let path = SOUND_PATH_VO_DAMAGEDS
-            .choose(&mut rand::rng())
-            .copied()
-            .unwrap();
-        commands.spawn((
-            AudioPlayer::new(asset_server.load(path)),
-            PlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.voice_percentage())),
-            InGameStateRoot,
-            VoiceSound,
-        ));
-    }
-}
-
-#[cfg(target_arch = "wasm32")]
 fn play_damaged_sound(
     commands: &mut Commands,
     asset_server: &AssetServer,
     system_volume: &SystemVolume,
+    rotation: &mut SoundRotation,
+    rng: &mut impl Rng,
+    position: Vec3,
 ) {
-    if rand::random_ratio(2, 3) {
-        let path = SOUND_PATH_VO_DAMAGEDS
-            .choose(&mut rand::rng())
-            .copied()
-            .unwrap();
-        commands.spawn((
-            WebAudioPlayer::new(asset_server.load(path)),
-            WebPlaybackSettings::DESPAWN
-                .with_volume(Volume::Linear(system_volume.voice_percentage())),
-            InGameStateRoot,
-            VoiceSound,
-        ));
-    }
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-fn play_healing_sound(
-    commands: &mut Commands,
-    asset_server: &AssetServer,
-    system_volume: &SystemVolume,
-) {
-    if rand::random_ratio(1, 3) {
-        let path = SOUND_PATH_VO_HEALINGS
-            .choose(&mut rand::rng())
-            .copied()
-            .unwrap();
-        commands.spawn((
-            AudioPlayer::new(asset_server.load(path)),
-            PlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.voice_percentage())),
+    if rng.random_ratio(2, 3) {
+        let path = rotation.pick(SoundSet::Damaged, rng);
+        let variation = SoundVariation::roll(rng).occluded_at(position);
+        play_voice_at(commands, asset_server, system_volume, path, position).insert((
+            variation.settings(system_volume.voice_percentage()),
             InGameStateRoot,
-            VoiceSound,
         ));
     }
 }
 
-#[cfg(target_arch = "wasm32")]
 fn play_healing_sound(
     commands: &mut Commands,
     asset_server: &AssetServer,
     system_volume: &SystemVolume,
+    rotation: &mut SoundRotation,
+    rng: &mut impl Rng,
 ) {
-    if rand::random_ratio(1, 3) {
-        let path = SOUND_PATH_VO_HEALINGS
-            .choose(&mut rand::rng())
-            .copied()
-            .unwrap();
-        commands.spawn((
-            WebAudioPlayer::new(asset_server.load(path)),
-            WebPlaybackSettings::DESPAWN
-                .with_volume(Volume::Linear(system_volume.voice_percentage())),
+    if rng.random_ratio(1, 3) {
+        let path = rotation.pick(SoundSet::Healing, rng);
+        let variation = SoundVariation::roll(rng);
+        play_voice(commands, asset_server, system_volume, path).insert((
+            variation.settings(system_volume.voice_percentage()),
             InGameStateRoot,
-            VoiceSound,
         ));
     }
 }
 
+/// Plays the chime of a bell at the given speed. The chime sets the pitch, so only the volume is varied.
 #[cfg(not(target_arch = "wasm32"))]
 fn play_door_bell_sound(
     commands: &mut Commands,
     asset_server: &AssetServer,
     system_volume: &SystemVolume,
+    rng: &mut impl Rng,
+    speed: f32,
+    position: Vec3,
 ) {
+    let variation = SoundVariation {
+        speed,
+        ..SoundVariation::roll(rng).occluded_at(position)
+    };
     commands.spawn((
         AudioPlayer::new(asset_server.load(SOUND_PATH_SFX_DOOR_BELL)),
-        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.voice_percentage())),
+        variation.settings(system_volume.voice_percentage()),
         InGameStateRoot,
         VoiceSound,
     ));
 }
 
+/// Plays the chime of a bell at the given speed. The chime sets the pitch, so only the volume is varied.
 #[cfg(target_arch = "wasm32")]
 fn play_door_bell_sound(
     commands: &mut Commands,
     asset_server: &AssetServer,
     system_volume: &SystemVolume,
+    rng: &mut impl Rng,
+    speed: f32,
+    position: Vec3,
 ) {
+    let variation = SoundVariation {
+        speed,
+        ..SoundVariation::roll(rng).occluded_at(position)
+    };
     commands.spawn((
         WebAudioPlayer::new(asset_server.load(SOUND_PATH_SFX_DOOR_BELL)),
-        WebPlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.voice_percentage())),
-        InGameStateRoot,
-        VoiceSound,
-    ));
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-fn play_aoba_sound(
-    commands: &mut Commands,
-    asset_server: &AssetServer,
-    system_volume: &SystemVolume,
-) {
-    let path = SOUND_PATH_VO_AOBAS
-        .choose(&mut rand::rng())
-        .copied()
-        .unwrap();
-    commands.spawn((
-        AudioPlayer::new(asset_server.load(path)),
-        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.voice_percentage())),
+        variation.settings(system_volume.voice_percentage()),
         InGameStateRoot,
         VoiceSound,
     ));
 }
 
-#[cfg(target_arch = "wasm32")]
 fn play_aoba_sound(
     commands: &mut Commands,
     asset_server: &AssetServer,
     system_volume: &SystemVolume,
+    rng: &mut impl Rng,
+    source: Entity,
+    position: Vec3,
 ) {
-    let path = SOUND_PATH_VO_AOBAS
-        .choose(&mut rand::rng())
-        .copied()
-        .unwrap();
-    commands.spawn((
-        WebAudioPlayer::new(asset_server.load(path)),
-        WebPlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.voice_percentage())),
+    let path = SOUND_PATH_VO_AOBAS.choose(rng).copied().unwrap();
+    play_voice_at(commands, asset_server, system_volume, path, position).insert((
         InGameStateRoot,
-        VoiceSound,
+        SoundSource(source),
+        DistanceAttenuation::default(),
     ));
 }
 
@@ -1186,27 +2311,21 @@ fn play_invincible_sound(
     commands: &mut Commands,
     asset_server: &AssetServer,
     system_volume: &SystemVolume,
+    rotation: &mut SoundRotation,
+    rng: &mut impl Rng,
 ) {
-    let mut rng = rand::rng();
-    let path = SOUND_PATH_VO_AOBA_HITS.choose(&mut rng).copied().unwrap();
-    commands.spawn((
-        AudioPlayer::new(asset_server.load(path)),
-        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.voice_percentage())),
-        InGameStateRoot,
-        VoiceSound,
-    ));
-
-    let path = SOUND_PATH_VO_INVINCIBLES.choose(&mut rng).copied().unwrap();
-    commands.spawn((
-        AudioPlayer::new(asset_server.load(path)),
-        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.voice_percentage())),
-        InGameStateRoot,
-        VoiceSound,
-    ));
+    for set in [SoundSet::AobaHit, SoundSet::Invincible] {
+        let path = rotation.pick(set, rng);
+        let variation = SoundVariation::roll(rng);
+        play_voice(commands, asset_server, system_volume, path).insert((
+            variation.settings(system_volume.voice_percentage()),
+            InGameStateRoot,
+        ));
+    }
 
     commands.spawn((
         AudioPlayer::new(asset_server.load(SOUND_PATH_SFX_TRAIN_INVINCIBLE)),
-        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.effect_percentage())),
+        SoundVariation::roll(rng).settings(system_volume.effect_percentage()),
         InGameStateRoot,
         VoiceSound,
     ));
@@ -1217,32 +2336,153 @@ fn play_invincible_sound(
     commands: &mut Commands,
     asset_server: &AssetServer,
     system_volume: &SystemVolume,
+    rotation: &mut SoundRotation,
+    rng: &mut impl Rng,
 ) {
-    let mut rng = rand::rng();
-    let path = SOUND_PATH_VO_AOBA_HITS.choose(&mut rng).copied().unwrap();
-    commands.spawn((
-        WebAudioPlayer::new(asset_server.load(path)),
-        WebPlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.voice_percentage())),
-        InGameStateRoot,
-        VoiceSound,
-    ));
-
-    let path = SOUND_PATH_VO_INVINCIBLES.choose(&mut rng).copied().unwrap();
-    commands.spawn((
-        WebAudioPlayer::new(asset_server.load(path)),
-        WebPlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.voice_percentage())),
-        InGameStateRoot,
-        VoiceSound,
-    ));
+    for set in [SoundSet::AobaHit, SoundSet::Invincible] {
+        let path = rotation.pick(set, rng);
+        let variation = SoundVariation::roll(rng);
+        play_voice(commands, asset_server, system_volume, path).insert((
+            variation.settings(system_volume.voice_percentage()),
+            InGameStateRoot,
+        ));
+    }
 
     commands.spawn((
         WebAudioPlayer::new(asset_server.load(SOUND_PATH_SFX_TRAIN_INVINCIBLE)),
-        WebPlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.effect_percentage())),
+        SoundVariation::roll(rng).settings(system_volume.effect_percentage()),
         InGameStateRoot,
         VoiceSound,
     ));
 }
 
+/// Destroys the barricades hit by a whistle, along with the whistle.
+#[allow(clippy::type_complexity)]
+fn check_whistle_collisions(
+    mut commands: Commands,
+    mut spawner: ResMut<ObjectSpawner>,
+    whistle_query: Query<(Entity, &Whistle, &Collider, &Transform)>,
+    object_query: Query<(Entity, &Object, &Lane, &Collider, &Transform), Without<Whistle>>,
+) {
+    for (whistle_entity, whistle, w_collider, w_trans) in whistle_query.iter() {
+        let hit = object_query
+            .iter()
+            .find(|(_, obj, lane, o_collider, o_trans)| {
+                **obj == Object::Barricade
+                    && lane.get() == whistle.lane
+                    && w_collider.intersects(w_trans, o_collider, o_trans)
+            });
+
+        if let Some((entity, &obj, ..)) = hit {
+            info!("Whistle hit!");
+            spawner.drain(&mut commands, entity, obj);
+            commands.entity(whistle_entity).despawn();
+        }
+    }
+}
+
+/// Shows how many whistles the collected bells can pay for.
+fn update_throw_text(
+    bells: Res<CollectedBells>,
+    locale: Res<CurrentLocale>,
+    mut query: Query<(&UI, &mut Text)>,
+) {
+    if !bells.is_changed() && !locale.is_changed() {
+        return;
+    }
+
+    for (&ui, mut text) in query.iter_mut() {
+        if ui == UI::ThrowText {
+            *text = Text::new(format!(
+                "Whistle x{}",
+                format_number(**bells / WHISTLE_COST, locale.0)
+            ));
+        }
+    }
+}
+
+/// Shrinks the shade over the steam whistle button as the whistle recharges.
+fn update_horn_cooldown(horn: Res<TrainHorn>, mut query: Query<(&UI, &mut Node)>) {
+    for (&ui, mut node) in query.iter_mut() {
+        if ui != UI::HornCooldown {
+            continue;
+        }
+
+        let height = Val::Percent((1.0 - horn.charge()) * 100.0);
+        if node.height != height {
+            node.height = height;
+        }
+    }
+}
+
+/// Fills the air-dash gauge as the dash recharges, and lights it up once the dash is ready.
+fn update_air_dash_gauge(
+    player_query: Query<&AirDash, With<Player>>,
+    mut query: Query<(&UI, &mut Node, &mut BackgroundColor)>,
+) {
+    let Ok(dash) = player_query.single() else {
+        return;
+    };
+
+    for (&ui, mut node, mut color) in query.iter_mut() {
+        if ui != UI::AirDashGauge {
+            continue;
+        }
+
+        let width = Val::Percent(dash.charge() * 100.0);
+        if node.width != width {
+            node.width = width;
+        }
+        let fill = match dash.is_ready() {
+            true => AIR_DASH_READY_COLOR,
+            false => AIR_DASH_CHARGING_COLOR,
+        };
+        if color.0 != fill {
+            color.0 = fill;
+        }
+    }
+}
+
+/// Shows the play time, and how far ahead of or behind the best run the train is at the same distance.
+fn update_pace_clock(
+    options: Res<GraphicsOptions>,
+    play_time: Res<PlayTime>,
+    pace: Res<RunPace>,
+    best_pace: Res<BestPace>,
+    mut query: Query<(
+        &UI,
+        &mut Visibility,
+        Option<&mut Text>,
+        Option<&mut TextColor>,
+    )>,
+) {
+    let delta = pace.delta(&best_pace, &play_time);
+    for (&ui, mut visibility, text, color) in query.iter_mut() {
+        match (ui, text, color) {
+            (UI::PaceClock, _, _) => {
+                visibility.set_if_neq(match options.pace_clock {
+                    true => Visibility::Visible,
+                    false => Visibility::Hidden,
+                });
+            }
+            (UI::PaceTime, Some(mut text), _) => {
+                text.0 = format_play_time(play_time.millis());
+            }
+            (UI::PaceDelta, Some(mut text), Some(mut color)) => match delta {
+                Some(delta) => {
+                    text.0 = format!("{delta:+.1}");
+                    color.0 = match delta <= 0.0 {
+                        true => PACE_AHEAD_COLOR,
+                        false => PACE_BEHIND_COLOR,
+                    };
+                }
+                None => text.0.clear(),
+            },
+            _ => { /* empty */ }
+        }
+    }
+}
+
 #[allow(clippy::type_complexity)]
 fn update_score_ui(
     score: Res<CurrentScore>,
@@ -1312,164 +2552,119 @@ fn update_fuel_deco(mut query: Query<&mut Node, With<FuelDeco>>, time: Res<Time>
         node.top = Val::Percent(12.5 + 2.5 * t.sin());
     }
 }
+/// Starts the animations of the fuel gauge for each fuel change, and advances them.
+fn tick_fuel_gauge_tween(
+    mut events: EventReader<FuelChanged>,
+    mut query: Query<&mut FuelGaugeTween>,
+    fuel: Res<TrainFuel>,
+    time: Res<Time>,
+) {
+    let Ok(mut tween) = query.single_mut() else {
+        events.clear();
+        return;
+    };
+
+    for &FuelChanged(amount) in events.read() {
+        tween.on_changed(amount, fuel.get());
+    }
+    tween.tick(time.delta_secs(), fuel.get());
+}
+
 fn update_fuel_gauge(
-    mut query: Query<(&mut Node, &mut BackgroundColor), With<FuelGauge>>,
+    mut query: Query<(&mut Node, &mut BackgroundColor, &FuelGaugeTween), With<FuelGauge>>,
     fuel: Res<TrainFuel>,
 ) {
-    if let Ok((mut node, mut color)) = query.single_mut() {
+    if let Ok((mut node, mut color, tween)) = query.single_mut() {
         node.width = Val::Percent(fuel.get());
-        color.0 = match fuel.get() {
+        let base = match fuel.get() {
             50.0..=100.0 => FUEL_GOOD_GAUGE_COLOR,
             25.0..=50.0 => FUEL_FAIR_GAUGE_COLOR,
             _ => FUEL_POOR_GAUGE_COLOR,
         };
+        color.0 = base.mix(&Color::WHITE, tween.flash());
     }
 }
 
+/// Shakes the fuel gauge on a sharp drop, drains the ghost bar, and moves the refill sweep.
+/// In reduce-motion mode the gauge does not shake.
 #[allow(clippy::type_complexity)]
-#[allow(clippy::too_many_arguments)]
-fn update_player_effect(
-    mut set: ParamSet<(
-        Query<Entity, With<ToyTrain0>>,
-        Query<Entity, With<ToyTrain1>>,
-        Query<Entity, With<ToyTrain2>>,
-    )>,
-    children_query: Query<&Children>,
-    base_color_query: Query<&BaseColor>,
-    standard_material_query: Query<&MeshMaterial3d<StandardMaterial>>,
-    extented_material_query: Query<&MeshMaterial3d<EyeMouthMaterial>>,
-    mut standard_materials: ResMut<Assets<StandardMaterial>>,
-    mut extended_materials: ResMut<Assets<EyeMouthMaterial>>,
-    mut state: ResMut<CurrentState>,
+fn update_fuel_gauge_effects(
+    tween_query: Query<&FuelGaugeTween>,
+    mut frame_query: Query<&mut Node, With<FuelGaugeFrame>>,
+    mut ghost_query: Query<&mut Node, (With<FuelGaugeGhost>, Without<FuelGaugeFrame>)>,
+    mut sweep_query: Query<
+        (&mut Node, &mut Visibility),
+        (
+            With<FuelGaugeSweep>,
+            Without<FuelGaugeFrame>,
+            Without<FuelGaugeGhost>,
+        ),
+    >,
+    options: Res<GraphicsOptions>,
 ) {
-    if let Ok(entity) = set.p0().single() {
-        update_player_effect_recursive(
-            entity,
-            &children_query,
-            &base_color_query,
-            &standard_material_query,
-            &extented_material_query,
-            &mut standard_materials,
-            &mut extended_materials,
-            &mut state,
-        );
-    }
-
-    if let Ok(entity) = set.p1().single() {
-        update_player_effect_recursive(
-            entity,
-            &children_query,
-            &base_color_query,
-            &standard_material_query,
-            &extented_material_query,
-            &mut standard_materials,
-            &mut extended_materials,
-            &mut state,
-        );
-    }
-
-    if let Ok(entity) = set.p2().single() {
-        update_player_effect_recursive(
-            entity,
-            &children_query,
-            &base_color_query,
-            &standard_material_query,
-            &extented_material_query,
-            &mut standard_materials,
-            &mut extended_materials,
-            &mut state,
-        );
+    let Ok(tween) = tween_query.single() else {
+        return;
+    };
+
+    if let Ok(mut node) = frame_query.single_mut() {
+        node.left = match options.reduce_motion {
+            true => Val::Px(0.0),
+            false => Val::Vw(tween.shake()),
+        };
     }
-}
 
-#[allow(clippy::too_many_arguments)]
-fn update_player_effect_recursive(
-    entity: Entity,
-    children_query: &Query<&Children>,
-    base_color_query: &Query<&BaseColor>,
-    standard_material_query: &Query<&MeshMaterial3d<StandardMaterial>>,
-    extented_material_query: &Query<&MeshMaterial3d<EyeMouthMaterial>>,
-    standard_materials: &mut ResMut<Assets<StandardMaterial>>,
-    extended_materials: &mut ResMut<Assets<EyeMouthMaterial>>,
-    state: &mut CurrentState,
-) {
-    if let Ok(handle) = standard_material_query.get(entity)
-        && let Some(material) = standard_materials.get_mut(handle.id())
-    {
-        match &mut *state {
-            #[cfg(not(feature = "no-debuging-player"))]
-            CurrentState::Debug => {
-                material.base_color = Color::BLACK;
-            }
-            CurrentState::Idle => {
-                material.base_color = base_color_query
-                    .get(entity)
-                    .map(|c| c.0)
-                    .unwrap_or(Color::WHITE);
-            }
-            CurrentState::Attacked { remaining } => {
-                let t = *remaining * ATTACKED_EFFECT_CYCLE;
-                let fill = 0.5 * t.cos() + 0.5;
-                material.base_color = Color::srgba(fill, fill, fill, material.base_color.alpha());
-            }
-            CurrentState::Invincible { remaining } => {
-                let t = ((INVINCIBLE_DURATION - *remaining) / INVINCIBLE_DURATION).max(0.0);
-                let cycle =
-                    MIN_INVINCIBLE_EFFECT_CYCLE * (1.0 - t) + MAX_INVINCIBLE_EFFECT_CYCLE * t;
-                let red = 0.5 * (t * cycle).sin() + 0.5;
-                let green = 0.5 * (TAU / 3.0 * t * cycle).sin() + 0.5;
-                let blue = 0.5 * (2.0 * TAU / 3.0 * t * cycle).sin() + 0.5;
-                material.base_color = Color::srgba(red, green, blue, material.base_color.alpha());
-            }
-        }
+    if let Ok(mut node) = ghost_query.single_mut() {
+        node.width = Val::Percent(tween.ghost());
     }
 
-    if let Ok(handle) = extented_material_query.get(entity)
-        && let Some(material) = extended_materials.get_mut(handle.id())
-    {
-        match &mut *state {
-            #[cfg(not(feature = "no-debuging-player"))]
-            CurrentState::Debug => {
-                material.base.base_color = Color::BLACK;
-            }
-            CurrentState::Idle => {
-                material.base.base_color = base_color_query
-                    .get(entity)
-                    .map(|c| c.0)
-                    .unwrap_or(Color::WHITE);
-            }
-            CurrentState::Attacked { remaining } => {
-                let t = *remaining * ATTACKED_EFFECT_CYCLE;
-                let fill = 0.5 * t.cos() + 0.5;
-                material.base.base_color =
-                    Color::srgba(fill, fill, fill, material.base.base_color.alpha());
-            }
-            CurrentState::Invincible { remaining } => {
-                let t = ((INVINCIBLE_DURATION - *remaining) / INVINCIBLE_DURATION).max(0.0);
-                let cycle =
-                    MIN_INVINCIBLE_EFFECT_CYCLE * (1.0 - t) + MAX_INVINCIBLE_EFFECT_CYCLE * t;
-                let red = 0.5 * (t * cycle).sin() + 0.5;
-                let green = 0.5 * (TAU / 3.0 * t * cycle).sin() + 0.5;
-                let blue = 0.5 * (2.0 * TAU / 3.0 * t * cycle).sin() + 0.5;
-                material.base.base_color =
-                    Color::srgba(red, green, blue, material.base.base_color.alpha());
+    if let Ok((mut node, mut visibility)) = sweep_query.single_mut() {
+        match tween.sweep() {
+            Some(t) => {
+                let travel = 100.0 + FUEL_GAUGE_SWEEP_WIDTH;
+                node.left = Val::Percent(t * travel - FUEL_GAUGE_SWEEP_WIDTH);
+                *visibility = Visibility::Inherited;
             }
+            None => *visibility = Visibility::Hidden,
         }
     }
+}
 
-    if let Ok(children) = children_query.get(entity) {
-        for child in children.iter() {
-            update_player_effect_recursive(
-                child,
-                children_query,
-                base_color_query,
-                standard_material_query,
-                extented_material_query,
-                standard_materials,
-                extended_materials,
-                state,
-            );
+/// Blinks the border of the fuel gauge while the fuel alarm is raised.
+/// In reduce-motion mode the border stays lit instead.
+fn blink_fuel_gauge_frame(
+    mut query: Query<&mut BorderColor, With<FuelGaugeFrame>>,
+    alarm: Res<FuelAlarmState>,
+    options: Res<GraphicsOptions>,
+    time: Res<Time>,
+) {
+    let Ok(mut border) = query.single_mut() else {
+        return;
+    };
+
+    let lit = alarm.is_raised()
+        && (options.reduce_motion || (time.elapsed_secs() * FUEL_ALARM_BLINK_CYCLE).sin() > 0.0);
+    border.0 = match lit {
+        true => FUEL_POOR_GAUGE_COLOR,
+        false => FUEL_COLOR,
+    };
+}
+
+/// Shows the state of the player on the train: black while debugging,
+/// a flash while attacked and the colors of the rainbow while invincible.
+fn update_player_effect(
+    mut commands: Commands,
+    car_query: Query<(Entity, Option<&MaterialEffect>), With<TrainCar>>,
+    state: Res<CurrentState>,
+) {
+    let kind = player_material_effect(&state);
+    for (entity, effect) in car_query.iter() {
+        if effect.map(MaterialEffect::kind) == kind {
+            continue;
         }
+        match kind {
+            Some(kind) => commands.entity(entity).insert(MaterialEffect::new(kind)),
+            None => commands.entity(entity).remove::<MaterialEffect>(),
+        };
     }
 }
 
@@ -1488,54 +2683,132 @@ pub fn update_player_speed(
     }
 }
 
-#[cfg(not(target_arch = "wasm32"))]
-fn play_button_sound_when_hovered(
-    commands: &mut Commands,
-    asset_server: &AssetServer,
-    system_volume: &SystemVolume,
+/// Zooms out and pulls the in-game camera back as the player speeds up,
+/// and briefly punches in when the player becomes invincible.
+///
+/// This system owns the camera's base pose; offset effects (e.g. camera shake)
+/// must be applied after it so they are not overwritten.
+pub fn update_camera_rig(
+    mut query: Query<(&mut CameraRig, &mut Projection, &mut Transform)>,
+    player_query: Query<&ForwardMovement, With<Player>>,
+    state: Res<CurrentState>,
+    options: Res<GraphicsOptions>,
+    time: Res<Time>,
 ) {
-    commands.spawn((
-        AudioPlayer::new(asset_server.load(SOUND_PATH_UI_LOADING)),
-        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.effect_percentage())),
-        EffectSound,
-    ));
+    let Ok((mut rig, mut projection, mut transform)) = query.single_mut() else {
+        return;
+    };
+
+    let is_invincible = matches!(*state, CurrentState::Invincible { .. });
+    if is_invincible && !rig.was_invincible && !options.reduce_motion {
+        rig.punch = 1.0;
+    }
+    rig.was_invincible = is_invincible;
+
+    let target = match player_query.single() {
+        Ok(forward_move) if !options.reduce_motion => {
+            let t = (forward_move.get() - MIN_PLAYER_SPEED) / (INVINCIBLE_SPEED - MIN_PLAYER_SPEED);
+            t.clamp(0.0, 1.0)
+        }
+        _ => 0.0,
+    };
+
+    let delta = time.delta_secs();
+    rig.zoom += (target - rig.zoom) * (IN_GAME_CAMERA_ZOOM_SPEED * delta).min(1.0);
+    rig.punch = (rig.punch - IN_GAME_CAMERA_PUNCH_DECAY * delta).max(0.0);
+
+    let view = CameraView::of(rig.preset);
+    if let Projection::Orthographic(orthographic) = &mut *projection {
+        orthographic.scale = view.scale
+            + (IN_GAME_CAMERA_MAX_SCALE - IN_GAME_CAMERA_MIN_SCALE) * rig.zoom
+            - IN_GAME_CAMERA_PUNCH_IN * rig.punch
+            - IN_GAME_CAMERA_PUSH_IN * rig.push_in;
+    }
+    transform.translation = view.position + IN_GAME_CAMERA_PULL_BACK * rig.zoom;
 }
 
-#[cfg(target_arch = "wasm32")]
-fn play_button_sound_when_hovered(
-    commands: &mut Commands,
-    asset_server: &AssetServer,
-    system_volume: &SystemVolume,
+/// Eases the color grading toward the player's state: boosted while invincible,
+/// and warning while the fuel is low.
+pub fn update_grading_state(
+    mut grading: ResMut<GradingState>,
+    state: Res<CurrentState>,
+    fuel: Res<TrainFuel>,
+    time: Res<Time>,
 ) {
-    commands.spawn((
-        WebAudioPlayer::new(asset_server.load(SOUND_PATH_UI_LOADING)),
-        WebPlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.effect_percentage())),
-        EffectSound,
-    ));
+    let boost = match *state {
+        CurrentState::Invincible { .. } => 1.0,
+        _ => 0.0,
+    };
+    let warning = match fuel.get() < FUEL_LIMITS * LOW_FUEL_RATIO && !fuel.is_empty() {
+        true => 1.0,
+        false => 0.0,
+    };
+    grading.ease(boost, warning, GRADING_BLEND_SPEED * time.delta_secs());
 }
 
-#[cfg(not(target_arch = "wasm32"))]
-fn play_button_sound_when_pressed(
-    commands: &mut Commands,
-    asset_server: &AssetServer,
-    system_volume: &SystemVolume,
+/// Applies the [`GradingState`] to the in-game camera and the low-fuel vignette.
+/// The vignette holds still in reduce-motion mode instead of pulsing.
+#[allow(clippy::type_complexity)]
+pub fn apply_color_grading(
+    mut commands: Commands,
+    mut camera_query: Query<(Entity, &mut ColorGrading, Option<&mut Bloom>), With<CameraRig>>,
+    mut materials: ResMut<Assets<VignetteMaterial>>,
+    vignette_query: Query<&MaterialNode<VignetteMaterial>, With<LowFuelVignette>>,
+    grading: Res<GradingState>,
+    options: Res<GraphicsOptions>,
+    time: Res<Time>,
 ) {
-    commands.spawn((
-        AudioPlayer::new(asset_server.load(SOUND_PATH_UI_BUTTON_TOUCH)),
-        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.effect_percentage())),
-        EffectSound,
-    ));
+    for (entity, mut color_grading, bloom) in camera_query.iter_mut() {
+        let saturation = 1.0 + (INVINCIBLE_SATURATION - 1.0) * grading.boost;
+        for section in color_grading.all_sections_mut() {
+            section.saturation = saturation;
+        }
+
+        match (bloom, grading.boost > 0.0) {
+            (Some(mut bloom), true) => bloom.intensity = INVINCIBLE_BLOOM_INTENSITY * grading.boost,
+            (None, true) => {
+                commands.entity(entity).insert(Bloom {
+                    intensity: INVINCIBLE_BLOOM_INTENSITY * grading.boost,
+                    ..Bloom::NATURAL
+                });
+            }
+            (Some(_), false) => {
+                commands.entity(entity).remove::<Bloom>();
+            }
+            (None, false) => { /* empty */ }
+        }
+    }
+
+    if let Ok(node) = vignette_query.single()
+        && let Some(material) = materials.get_mut(node.id())
+    {
+        let pulse = match options.reduce_motion {
+            true => 1.0,
+            false => 0.75 + 0.25 * (time.elapsed_secs() * LOW_FUEL_VIGNETTE_CYCLE).sin(),
+        };
+        material.set(LOW_FUEL_VIGNETTE_COLOR, grading.warning * pulse);
+    }
 }
 
-#[cfg(target_arch = "wasm32")]
-fn play_button_sound_when_pressed(
-    commands: &mut Commands,
-    asset_server: &AssetServer,
-    system_volume: &SystemVolume,
+/// Scales the intensity of the speed-line overlay with the player's forward speed.
+pub fn update_speed_lines(
+    mut materials: ResMut<Assets<SpeedLineMaterial>>,
+    player_query: Query<&ForwardMovement, With<Player>>,
+    query: Query<&MaterialNode<SpeedLineMaterial>, With<SpeedLines>>,
+    options: Res<GraphicsOptions>,
+    time: Res<Time>,
 ) {
-    commands.spawn((
-        WebAudioPlayer::new(asset_server.load(SOUND_PATH_UI_BUTTON_TOUCH)),
-        WebPlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.effect_percentage())),
-        EffectSound,
-    ));
+    let Ok(node) = query.single() else { return };
+    let Some(material) = materials.get_mut(node.id()) else {
+        return;
+    };
+
+    let intensity = match player_query.single() {
+        Ok(forward_move) if options.speed_lines_enabled() => {
+            let t = (forward_move.get() - MIN_PLAYER_SPEED) / (INVINCIBLE_SPEED - MIN_PLAYER_SPEED);
+            t.clamp(0.0, 1.0)
+        }
+        _ => 0.0,
+    };
+    material.set(intensity, time.elapsed_secs());
 }
@@ -19,11 +19,81 @@ use super::*;
 // --- CONSTANTS ---
 pub const IN_GAME_CAMERA_POS: Vec3 = Vec3::new(12.0, 9.0, 12.0);
 pub const IN_GAME_CAMERA_LOOK_AT: Vec3 = Vec3::new(0.0, 1.5, 0.0);
+pub const IN_GAME_CAMERA_PULL_BACK: Vec3 = Vec3::new(0.0, 0.0, 2.0);
+pub const IN_GAME_CAMERA_MIN_SCALE: f32 = 1.25;
+pub const IN_GAME_CAMERA_MAX_SCALE: f32 = 1.45;
+pub const IN_GAME_CAMERA_PUNCH_IN: f32 = 0.15;
+pub const IN_GAME_CAMERA_PUNCH_DECAY: f32 = 2.0;
+pub const IN_GAME_CAMERA_ZOOM_SPEED: f32 = 2.0;
+pub const IN_GAME_CAMERA_PUSH_IN: f32 = 0.35;
+/// The framing of each [`CameraPreset`], in the order of [`CameraPreset::ALL`].
+pub const IN_GAME_CAMERA_VIEWS: [CameraView; 3] = [
+    CameraView {
+        position: IN_GAME_CAMERA_POS,
+        look_at: IN_GAME_CAMERA_LOOK_AT,
+        scale: IN_GAME_CAMERA_MIN_SCALE,
+    },
+    CameraView {
+        position: Vec3::new(13.0, 5.0, 11.0),
+        look_at: Vec3::new(0.0, 1.5, 1.0),
+        scale: 1.15,
+    },
+    CameraView {
+        position: Vec3::new(9.0, 14.0, 11.0),
+        look_at: Vec3::new(0.0, 1.0, 1.5),
+        scale: 1.4,
+    },
+];
 lazy_static! {
     pub static ref IN_GAME_AOBA_DIR: Vec3 =
         (IN_GAME_CAMERA_LOOK_AT - IN_GAME_CAMERA_POS.with_y(0.0)).normalize();
 }
 
+// --- TYPES ---
+
+/// Where the in-game camera sits, what it looks at, and the scale of its projection before any zoom.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CameraView {
+    pub position: Vec3,
+    pub look_at: Vec3,
+    pub scale: f32,
+}
+
+impl CameraView {
+    pub fn of(preset: CameraPreset) -> &'static Self {
+        &IN_GAME_CAMERA_VIEWS[preset as usize]
+    }
+}
+
+/// The stages of the in-game `PostUpdate` work, in the order they run.
+/// Systems of the same stage that do not share data run in parallel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemSet)]
+pub enum InGameSet {
+    /// Spawns the ground, objects and trains ahead of the player.
+    Spawn,
+    /// Checks the collisions of the player and resolves its speed.
+    Collision,
+    /// Reacts to what happened this frame with effects on the player and the screen.
+    Feedback,
+    /// Brings the HUD up to date with the state of the frame.
+    HudSync,
+}
+
+/// The effect shown on the train in the state of the player, if any.
+pub fn player_material_effect(state: &CurrentState) -> Option<MaterialEffectKind> {
+    match state {
+        #[cfg(not(feature = "no-debuging-player"))]
+        CurrentState::Debug => Some(MaterialEffectKind::Tint(Color::BLACK)),
+        CurrentState::Idle => None,
+        CurrentState::Attacked { .. } => Some(MaterialEffectKind::Flash {
+            cycle: ATTACKED_EFFECT_CYCLE,
+        }),
+        CurrentState::Invincible { .. } => Some(MaterialEffectKind::Rainbow {
+            duration: INVINCIBLE_DURATION,
+        }),
+    }
+}
+
 // --- PLUGIN ---
 
 pub struct StatePlugin;
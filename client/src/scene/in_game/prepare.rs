@@ -2,7 +2,10 @@
 use bevy::{audio::Volume, prelude::*, render::camera::ScalingMode};
 
 use crate::{
-    asset::{animation::AnimationClipHandle, material::EyeMouthMaterial, sound::SystemVolume},
+    asset::{
+        animation::AnimationClipHandle, cutscene_track::CutsceneTrack, material::EyeMouthMaterial,
+        sound::SystemVolume,
+    },
     shader::face_mouth::EyeMouth,
 };
 
@@ -32,13 +35,19 @@ impl Plugin for StatePlugin {
                 spawn_camera_and_light,
                 play_animation,
                 setup_mouth_expression,
+                start_cutscene,
             ),
         )
-        .add_systems(OnExit(GameState::PrepareInGame), end_timer)
+        .add_systems(OnExit(GameState::PrepareInGame), (end_timer, end_cutscene))
+        .add_systems(
+            PreUpdate,
+            skip_cutscene.run_if(in_state(GameState::PrepareInGame)),
+        )
         .add_systems(
             Update,
             (
                 update_scene_timer,
+                play_cutscene.run_if(resource_exists::<Cutscene>),
                 update_ground_position,
                 update_object_position,
             )
@@ -48,7 +57,7 @@ impl Plugin for StatePlugin {
             PostUpdate,
             (
                 update_player_position,
-                update_toy_trains.after(update_player_position),
+                follow_chain.after(update_player_position),
                 spawn_grounds,
                 spawn_objects,
             )
@@ -97,17 +106,57 @@ fn play_train_sound(
     ));
 }
 
-fn insert_resource(mut commands: Commands) {
+#[allow(clippy::too_many_arguments)]
+fn insert_resource(
+    mut commands: Commands,
+    mut rng: ResMut<RngService>,
+    assist: Res<AssistMode>,
+    mutators: Res<Mutators>,
+    content: Res<ContentRegistry>,
+    asset_server: Res<AssetServer>,
+    horizon: Res<TrackHorizon>,
+    upgrades: Res<TrainUpgrades>,
+) {
+    rng.start_run();
+
+    // The rules are chosen when the run starts, so toggling assist mode cannot affect a run in progress.
+    let config = match assist.0 {
+        true => GameplayConfig::assist(),
+        false => GameplayConfig::default(),
+    }
+    .with_mutators(*mutators);
+
     commands.insert_resource(Attacked::default());
     commands.insert_resource(PlayTime::default());
+    commands.insert_resource(RunPace::default());
     commands.insert_resource(TrainFuel::default());
-    commands.insert_resource(InputDelay::default());
-    commands.insert_resource(CurrentScore::default());
+    commands.insert_resource(InputDelay::new(config.input_delay).with_buffer(config.input_buffer));
+    commands.insert_resource(TimeScale::new(config.game_speed));
+    commands.insert_resource(CurrentScore::with_multiplier(
+        config.mutators.score_multiplier(),
+    ));
+    commands.insert_resource(ObjectSpawner::new(
+        &config,
+        &upgrades,
+        &content,
+        &asset_server,
+        &horizon,
+    ));
+    commands.insert_resource(config);
+    commands.insert_resource(CollectedBells::default());
+    commands.insert_resource(RhythmBonus::default());
+    commands.insert_resource(RunStats::default());
     commands.insert_resource(IsPlayerJumping::default());
     commands.insert_resource(CurrentState::default());
+    commands.insert_resource(PlayerShield::default());
+    commands.insert_resource(BellChime::default());
+    commands.insert_resource(SoundRotation::default());
+    commands.insert_resource(TrainHorn::default());
+    commands.insert_resource(GradingState::default());
+    commands.insert_resource(FuelAlarmState::default());
     commands.insert_resource(RetiredGrounds::default());
-    commands.insert_resource(ObjectSpawner::default());
     commands.insert_resource(Tok9TrainSpawner::default());
+    commands.insert_resource(TrafficSpawner::new(&horizon));
 }
 
 fn show_entities(mut query: Query<&mut Visibility, (With<InGameStateRoot>, Without<UI>)>) {
@@ -121,7 +170,7 @@ fn spawn_camera_and_light(
     light_query: Query<(), With<DirectionalLight>>,
     camera_query: Query<(), With<Camera3d>>,
 ) {
-    if light_query.single().is_err() {
+    if light_query.is_empty() {
         commands.spawn((
             DirectionalLight {
                 illuminance: 10_000.0,
@@ -133,9 +182,14 @@ fn spawn_camera_and_light(
         ));
     }
 
-    if camera_query.single().is_err() {
+    if camera_query.is_empty() {
         commands.spawn((
             Camera3d::default(),
+            // The bloom played during the invincibility needs a high dynamic range.
+            Camera {
+                hdr: true,
+                ..Default::default()
+            },
             Projection::from(OrthographicProjection {
                 near: 0.1,
                 far: 100.0,
@@ -143,11 +197,12 @@ fn spawn_camera_and_light(
                     width: 16.0,
                     height: 9.0,
                 },
-                scale: 1.25,
+                scale: IN_GAME_CAMERA_MIN_SCALE,
                 ..OrthographicProjection::default_3d()
             }),
             Transform::from_translation(IN_GAME_CAMERA_POS)
                 .looking_at(IN_GAME_CAMERA_LOOK_AT, Vec3::Y),
+            CameraRig::default(),
             InGameStateRoot,
         ));
     }
@@ -183,12 +238,90 @@ fn setup_mouth_expression(
     }
 }
 
+/// Starts the departure sequence: the camera starts close to the train at the platform
+/// and swings out to the in-game view while the train whistles and leaves.
+fn start_cutscene(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    tracks: Res<Assets<CutsceneTrack>>,
+    options: Res<GraphicsOptions>,
+) {
+    let Some(track) = tracks.get(&asset_server.load(CUTSCENE_PATH_DEPARTURE)) else {
+        warn!("The departure cutscene is not loaded");
+        return;
+    };
+
+    // The track ends at the in-game view of the camera preset the player chose.
+    let view = CameraView::of(options.camera_preset);
+    commands.insert_resource(Cutscene::from_track(track).then(CameraKeyframe::new(
+        SCENE_DURATION,
+        view.position,
+        view.look_at,
+        IN_GAME_CAMERA_MIN_SCALE / view.scale,
+    )));
+}
+
 // --- CLEANUP SYSTEMS ---
 
 fn end_timer(mut commands: Commands) {
     commands.remove_resource::<SceneTimer>();
 }
 
+/// Removes the cutscene and leaves the camera and characters in their in-game pose,
+/// even if the cutscene was skipped.
+fn end_cutscene(
+    mut commands: Commands,
+    cutscene: Option<Res<Cutscene>>,
+    mut materials: ResMut<Assets<EyeMouthMaterial>>,
+    mut query: Query<(&mut Projection, &mut Transform), With<CameraRig>>,
+    mouth_query: Query<&EyeMouth>,
+) {
+    if let Some(cutscene) = cutscene
+        && let Some(key) = cutscene.final_camera()
+        && let Ok((mut projection, mut transform)) = query.single_mut()
+    {
+        apply_camera_keyframe(&key, &mut projection, &mut transform);
+    }
+
+    for mouth in mouth_query.iter() {
+        if let Some(material) = materials.get_mut(&mouth.0) {
+            material.extension.uniform.index.x = 1;
+        }
+    }
+
+    commands.remove_resource::<Cutscene>();
+}
+
+// --- PREUPDATE SYSTEMS ---
+
+/// Lets repeat players skip the departure sequence with any input.
+fn skip_cutscene(
+    mut next_state: ResMut<NextState<GameState>>,
+    mut query: Query<&mut Transform, With<Player>>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
+    high_score: Res<HighScore>,
+    #[cfg(not(feature = "no-debuging-scrubber"))] scrubber: Res<CutsceneScrubber>,
+) {
+    #[cfg(not(feature = "no-debuging-scrubber"))]
+    if scrubber.captures(&keyboard_input) {
+        return;
+    }
+
+    let is_repeat_player = high_score.0 > 0;
+    let pressed = keyboard_input.get_just_pressed().next().is_some()
+        || mouse_input.get_just_pressed().next().is_some()
+        || touches.any_just_pressed();
+
+    if is_repeat_player && pressed {
+        if let Ok(mut transform) = query.single_mut() {
+            transform.translation.z = PLAYER_MAX_Z_POS;
+        }
+        next_state.set(GameState::StartInGame);
+    }
+}
+
 // --- UPDATE SYSTEMS ---
 
 fn update_scene_timer(
@@ -202,11 +335,48 @@ fn update_scene_timer(
     }
 }
 
+fn play_cutscene(
+    mut cutscene: ResMut<Cutscene>,
+    mut stage: CutsceneStage<InGameStateEntity>,
+    mut camera_query: Query<(&mut Projection, &mut Transform), With<CameraRig>>,
+    options: Res<GraphicsOptions>,
+    time: Res<Time>,
+) {
+    for cue in cutscene.tick(time.delta_secs()) {
+        stage.fire(&cue, InGameStateRoot);
+    }
+
+    // In reduce-motion mode the camera stays at the in-game view.
+    let key = if options.reduce_motion {
+        cutscene.final_camera()
+    } else {
+        cutscene.camera()
+    };
+
+    if let Some(key) = key
+        && let Ok((mut projection, mut transform)) = camera_query.single_mut()
+    {
+        apply_camera_keyframe(&key, &mut projection, &mut transform);
+    }
+}
+
+fn apply_camera_keyframe(
+    key: &CameraKeyframe,
+    projection: &mut Projection,
+    transform: &mut Transform,
+) {
+    if let Projection::Orthographic(orthographic) = projection {
+        orthographic.scale = IN_GAME_CAMERA_MIN_SCALE / key.zoom;
+    }
+    *transform = Transform::from_translation(key.position).looking_at(key.look_at, Vec3::Y);
+}
+
 fn update_ground_position(
     player_query: Query<&ForwardMovement, With<Player>>,
     mut ground_entities: Query<(Entity, &mut Transform), With<Ground>>,
     mut retired: ResMut<RetiredGrounds>,
     time: Res<Time>,
+    horizon: Res<TrackHorizon>,
 ) {
     let player_velocity = player_query
         .single()
@@ -216,7 +386,7 @@ fn update_ground_position(
     for (entity, mut transform) in ground_entities.iter_mut() {
         transform.translation.z -= player_velocity * time.delta_secs();
 
-        if transform.translation.z <= DESPAWN_POSITION {
+        if transform.translation.z <= horizon.despawn {
             retired.push(entity);
         }
     }
@@ -228,6 +398,7 @@ fn update_object_position(
     mut object_entities: Query<(Entity, &mut Transform, &Object)>,
     player_query: Query<&ForwardMovement, With<Player>>,
     time: Res<Time>,
+    horizon: Res<TrackHorizon>,
 ) {
     let player_velocity = player_query
         .single()
@@ -237,7 +408,7 @@ fn update_object_position(
     for (entity, mut transform, &obj) in object_entities.iter_mut() {
         transform.translation.z -= player_velocity * time.delta_secs();
 
-        if transform.translation.z <= DESPAWN_POSITION {
+        if transform.translation.z <= horizon.despawn {
             object_spawner.drain(&mut commands, entity, obj);
         }
     }
@@ -253,87 +424,33 @@ fn update_player_position(mut query: Query<&mut Transform, With<Player>>, timer:
     }
 }
 
-#[allow(clippy::type_complexity)]
-fn update_toy_trains(
-    mut set: ParamSet<(
-        Query<&Transform, With<Player>>,
-        Query<&mut Transform, With<ToyTrain0>>,
-        Query<&mut Transform, With<ToyTrain1>>,
-        Query<&mut Transform, With<ToyTrain2>>,
-    )>,
+fn spawn_grounds(
+    mut commands: Commands,
+    mut retired: ResMut<RetiredGrounds>,
+    ground_query: Query<&Transform, With<Ground>>,
+    horizon: Res<TrackHorizon>,
 ) {
-    let data = set
-        .p0()
-        .single()
-        .map(|transform| transform.translation.with_z(transform.translation.z + 1.5))
-        .ok();
-
-    if let Some(p_pos) = data {
-        let mut position = p_pos;
-
-        if let Ok(mut transform) = set.p1().single_mut() {
-            let z_axis = (transform.translation - position).normalize_or(Vec3::NEG_Z);
-            let y_axis = Vec3::Y;
-            let x_axis = y_axis.cross(z_axis);
-            let y_axis = z_axis.cross(x_axis);
-            let rotation = Quat::from_mat3(&Mat3::from_cols(x_axis, y_axis, z_axis));
-
-            let temp = transform.translation;
-
-            transform.translation.x = position.x;
-            transform.translation.y = position.y;
-            transform.translation.z = p_pos.z - 1.5;
-            transform.rotation = rotation;
-
-            position = temp;
-        }
-
-        if let Ok(mut transform) = set.p2().single_mut() {
-            let z_axis = (transform.translation - position).normalize_or(Vec3::NEG_Z);
-            let y_axis = Vec3::Y;
-            let x_axis = y_axis.cross(z_axis);
-            let y_axis = z_axis.cross(x_axis);
-            let rotation = Quat::from_mat3(&Mat3::from_cols(x_axis, y_axis, z_axis));
-
-            let temp = transform.translation;
-            transform.translation.x = position.x;
-            transform.translation.y = position.y;
-            transform.translation.z = p_pos.z - 3.0;
-            transform.rotation = rotation;
-
-            position = temp;
-        }
-
-        if let Ok(mut transform) = set.p3().single_mut() {
-            let z_axis = (transform.translation - position).normalize_or(Vec3::NEG_Z);
-            let y_axis = Vec3::Y;
-            let x_axis = y_axis.cross(z_axis);
-            let y_axis = z_axis.cross(x_axis);
-            let rotation = Quat::from_mat3(&Mat3::from_cols(x_axis, y_axis, z_axis));
-
-            transform.translation.x = position.x;
-            transform.translation.y = position.y;
-            transform.translation.z = p_pos.z - 4.25;
-            transform.rotation = rotation;
-        }
-    }
-}
-
-fn spawn_grounds(mut commands: Commands, mut retired: ResMut<RetiredGrounds>) {
+    let grounds = horizon.grounds();
+    let mut front = ground_query
+        .iter()
+        .map(|transform| transform.translation.z)
+        .fold(horizon.despawn, f32::max);
     while let Some(entity) = retired.pop() {
+        front = grounds.recycled_depth(front);
         commands
             .entity(entity)
             .entry::<Transform>()
-            .and_modify(|mut transform| {
-                transform.translation.z += SPAWN_POSITION - DESPAWN_POSITION;
+            .and_modify(move |mut transform| {
+                transform.translation.z = front;
             })
-            .or_insert(Transform::from_xyz(0.0, 0.0, SPAWN_POSITION));
+            .or_insert(Transform::from_xyz(0.0, 0.0, front));
     }
 }
 
 fn spawn_objects(
     mut commands: Commands,
     mut spawner: ResMut<ObjectSpawner>,
+    mut rng: ResMut<RngService>,
     player_query: Query<&ForwardMovement, With<Player>>,
     asset_server: Res<AssetServer>,
     time: Res<Time>,
@@ -346,6 +463,7 @@ fn spawn_objects(
         &mut commands,
         &asset_server,
         forward_move,
+        rng.spawning(),
         time.delta_secs(),
     );
 }
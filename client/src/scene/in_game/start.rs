@@ -3,6 +3,7 @@ use std::time::Duration;
 // Import necessary Bevy modules.
 use bevy::{audio::Volume, prelude::*};
 use bevy_tweening::{Animator, Tween, lens::UiPositionLens};
+use rand::Rng;
 
 use crate::asset::sound::SystemVolume;
 
@@ -40,7 +41,7 @@ fn debug_label(mut next_state: ResMut<NextState<GameState>>) {
 fn show_in_game_interface(mut query: Query<(&mut Visibility, &UI)>) {
     for (mut visibility, &ui) in query.iter_mut() {
         match ui {
-            UI::StartLabel | UI::PauseButton | UI::Score | UI::Fuel => {
+            UI::StartLabel | UI::PauseButton | UI::ThrowButton | UI::Score | UI::Fuel => {
                 *visibility = Visibility::Visible
             }
             _ => { /* empty */ }
@@ -76,36 +77,15 @@ fn play_start_sound(
     ));
 }
 
-#[cfg(not(target_arch = "wasm32"))]
-fn play_start_voice(
-    mut commands: Commands,
-    asset_server: Res<AssetServer>,
-    system_volume: Res<SystemVolume>,
-) {
-    let index = rand::random_range(0..NUM_SOUND_VO_START);
-    let path = SOUND_PATH_VO_STARTS[index];
-    commands.spawn((
-        AudioPlayer::new(asset_server.load(path)),
-        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.voice_percentage())),
-        InGameStateRoot,
-        VoiceSound,
-    ));
-}
-
-#[cfg(target_arch = "wasm32")]
 fn play_start_voice(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     system_volume: Res<SystemVolume>,
+    mut rng: ResMut<RngService>,
 ) {
-    let index = rand::random_range(0..NUM_SOUND_VO_START);
+    let index = rng.audio().random_range(0..NUM_SOUND_VO_START);
     let path = SOUND_PATH_VO_STARTS[index];
-    commands.spawn((
-        WebAudioPlayer::new(asset_server.load(path)),
-        WebPlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.voice_percentage())),
-        InGameStateRoot,
-        VoiceSound,
-    ));
+    play_voice(&mut commands, &asset_server, &system_volume, path).insert(InGameStateRoot);
 }
 
 fn play_ui_animation(mut commands: Commands, query: Query<(Entity, &UI)>) {
@@ -156,6 +136,26 @@ fn play_ui_animation(mut commands: Commands, query: Query<(Entity, &UI)>) {
                     },
                 )));
             }
+            UI::ThrowButton => {
+                commands.entity(entity).insert(Animator::new(Tween::new(
+                    EaseFunction::SmoothStep,
+                    Duration::from_secs_f32(PREPARE_ANIM_DURATION),
+                    UiPositionLens {
+                        start: UiRect {
+                            top: Val::Auto,
+                            left: Val::Vw(1.5),
+                            bottom: Val::Vh(-20.0),
+                            right: Val::Auto,
+                        },
+                        end: UiRect {
+                            top: Val::Auto,
+                            left: Val::Vw(1.5),
+                            bottom: Val::Vh(1.5),
+                            right: Val::Auto,
+                        },
+                    },
+                )));
+            }
             UI::Fuel => {
                 commands.entity(entity).insert(Animator::new(Tween::new(
                     EaseFunction::SmoothStep,
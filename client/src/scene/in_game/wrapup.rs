@@ -1,10 +1,10 @@
-use std::{f32::consts::TAU, time::Duration};
+use std::time::Duration;
 
 // Import necessary Bevy modules.
 use bevy::{audio::Volume, prelude::*};
 use bevy_tweening::{Animator, Tween, TweenCompleted, lens::UiPositionLens};
 
-use crate::asset::{material::EyeMouthMaterial, sound::SystemVolume};
+use crate::asset::sound::SystemVolume;
 
 #[cfg(target_arch = "wasm32")]
 use crate::web::{WebAudioPlayer, WebPlaybackSettings};
@@ -12,6 +12,11 @@ use crate::web::{WebAudioPlayer, WebPlaybackSettings};
 use super::*;
 
 // --- CONSTANTS ---
+/// The slow-motion coast at the start of the scene, at the end of which the final whistle plays.
+const COAST_DURATION: f32 = 2.0;
+/// The time over which the world comes back up to speed after the coast.
+const COAST_RELEASE_DURATION: f32 = 0.5;
+/// The time the train takes to roll to a stop after the coast.
 const SCENE_DURATION: f32 = 2.5;
 
 // --- PLUGIN ---
@@ -27,6 +32,7 @@ impl Plugin for StatePlugin {
                 switch_train_sounds,
                 start_timer,
                 play_ui_animation,
+                remove_whistles,
             ),
         )
         .add_systems(
@@ -37,10 +43,12 @@ impl Plugin for StatePlugin {
             Update,
             (
                 update_scene_timer,
-                update_player_state,
+                update_finish_coast.after(update_scene_timer),
+                update_player_damage,
                 update_ground_position,
                 update_object_position,
                 update_tok9_train_position,
+                update_passing_train_position,
                 rotate_animation,
                 cleanup_ui_animation,
             )
@@ -50,7 +58,7 @@ impl Plugin for StatePlugin {
             PreUpdate,
             (
                 update_player_position,
-                update_toy_trains,
+                follow_chain,
                 spawn_grounds,
                 update_player_effect,
                 update_player_speed,
@@ -165,6 +173,26 @@ fn play_ui_animation(mut commands: Commands, query: Query<(Entity, &UI)>) {
                     },
                 )));
             }
+            UI::ThrowButton => {
+                commands.entity(entity).insert(Animator::new(Tween::new(
+                    EaseFunction::SmoothStep,
+                    Duration::from_secs_f32(FINISH_ANIM_DURATION),
+                    UiPositionLens {
+                        end: UiRect {
+                            top: Val::Auto,
+                            left: Val::Vw(1.5),
+                            bottom: Val::Vh(-20.0),
+                            right: Val::Auto,
+                        },
+                        start: UiRect {
+                            top: Val::Auto,
+                            left: Val::Vw(1.5),
+                            bottom: Val::Vh(1.5),
+                            right: Val::Auto,
+                        },
+                    },
+                )));
+            }
             UI::Fuel => {
                 commands.entity(entity).insert(Animator::new(Tween::new(
                     EaseFunction::SmoothStep,
@@ -190,6 +218,13 @@ fn play_ui_animation(mut commands: Commands, query: Query<(Entity, &UI)>) {
     }
 }
 
+/// The run is over, so whistles still in flight are removed instead of freezing mid-air.
+fn remove_whistles(mut commands: Commands, query: Query<Entity, With<Whistle>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
 // --- CLEANUP SYSTEMS ---
 
 fn end_timer(mut commands: Commands) {
@@ -199,7 +234,7 @@ fn end_timer(mut commands: Commands) {
 fn hide_in_game_interface(mut query: Query<(&mut Visibility, &UI)>) {
     for (mut visibility, &ui) in query.iter_mut() {
         match ui {
-            UI::StartLabel | UI::PauseButton | UI::Score | UI::Fuel => {
+            UI::StartLabel | UI::PauseButton | UI::ThrowButton | UI::Score | UI::Fuel => {
                 *visibility = Visibility::Hidden
             }
             _ => { /* empty */ }
@@ -215,38 +250,93 @@ fn update_scene_timer(
     time: Res<Time>,
 ) {
     timer.tick(time.delta_secs());
-    if timer.elapsed_sec() >= SCENE_DURATION {
+    if timer.elapsed_sec() >= COAST_DURATION + SCENE_DURATION {
         next_state.set(GameState::FinishedInGame);
     }
 }
 
-fn update_player_state(mut state: ResMut<CurrentState>, time: Res<Time>) {
-    match &mut *state {
-        CurrentState::Attacked { remaining } => {
-            *remaining -= time.delta_secs();
-            if *remaining <= 0.0 {
-                *state = CurrentState::Idle;
+/// Slows the world down and pushes the camera in on the train, then plays the final whistle
+/// and lets the world come back up to speed.
+#[allow(clippy::too_many_arguments)]
+fn update_finish_coast(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    system_volume: Res<SystemVolume>,
+    mut time_scale: ResMut<TimeScale>,
+    mut rig_query: Query<&mut CameraRig>,
+    mut whistled: Local<bool>,
+    options: Res<GraphicsOptions>,
+    timer: Res<SceneTimer>,
+) {
+    let elapsed = timer.elapsed_sec();
+    let strength = match elapsed < COAST_DURATION {
+        true => {
+            *whistled = false;
+            let t = elapsed / COAST_DURATION;
+            t * t * (3.0 - 2.0 * t)
+        }
+        false => {
+            if !*whistled {
+                *whistled = true;
+                play_final_whistle(&mut commands, &asset_server, &system_volume);
             }
+            1.0 - ((elapsed - COAST_DURATION) / COAST_RELEASE_DURATION).min(1.0)
         }
-        _ => { /* empty */ }
+    };
+
+    time_scale.set_coast(strength);
+    for mut rig in rig_query.iter_mut() {
+        rig.push_in = match options.reduce_motion {
+            true => 0.0,
+            false => strength,
+        };
     }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+fn play_final_whistle(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    system_volume: &SystemVolume,
+) {
+    commands.spawn((
+        AudioPlayer::new(asset_server.load(SOUND_PATH_SFX_TRAIN_STEAM_WHISTLE)),
+        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.effect_percentage())),
+        EffectSound,
+    ));
+}
+
+#[cfg(target_arch = "wasm32")]
+fn play_final_whistle(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    system_volume: &SystemVolume,
+) {
+    commands.spawn((
+        WebAudioPlayer::new(asset_server.load(SOUND_PATH_SFX_TRAIN_STEAM_WHISTLE)),
+        WebPlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.effect_percentage())),
+        EffectSound,
+    ));
+}
+
 fn update_ground_position(
     player_query: Query<&ForwardMovement, With<Player>>,
     mut ground_entities: Query<(Entity, &mut Transform), With<Ground>>,
     mut retired: ResMut<RetiredGrounds>,
+    time_scale: Res<TimeScale>,
     time: Res<Time>,
+    horizon: Res<TrackHorizon>,
 ) {
+    let elapsed = time.delta_secs() * time_scale.get();
     let player_velocity = player_query
         .single()
         .map(|forward_move| forward_move.get())
         .unwrap_or(0.0);
 
     for (entity, mut transform) in ground_entities.iter_mut() {
-        transform.translation.z -= player_velocity * time.delta_secs();
+        transform.translation.z -= player_velocity * elapsed;
 
-        if transform.translation.z <= DESPAWN_POSITION {
+        if transform.translation.z <= horizon.despawn {
             retired.push(entity);
         }
     }
@@ -256,17 +346,20 @@ fn update_object_position(
     mut commands: Commands,
     mut object_entities: Query<(Entity, &mut Transform), With<Object>>,
     player_query: Query<&ForwardMovement, With<Player>>,
+    time_scale: Res<TimeScale>,
     time: Res<Time>,
+    horizon: Res<TrackHorizon>,
 ) {
+    let elapsed = time.delta_secs() * time_scale.get();
     let player_velocity = player_query
         .single()
         .map(|forward_move| forward_move.get())
         .unwrap_or(0.0);
 
     for (entity, mut transform) in object_entities.iter_mut() {
-        transform.translation.z -= player_velocity * time.delta_secs();
+        transform.translation.z -= player_velocity * elapsed;
 
-        if transform.translation.z <= DESPAWN_POSITION {
+        if transform.translation.z <= horizon.despawn {
             commands.entity(entity).despawn();
         }
     }
@@ -281,23 +374,50 @@ fn update_tok9_train_position(
         Without<DelayTime>,
     >,
     player_query: Query<&ForwardMovement, With<Player>>,
+    time_scale: Res<TimeScale>,
     time: Res<Time>,
+    horizon: Res<TrackHorizon>,
 ) {
+    let elapsed = time.delta_secs() * time_scale.get();
     let player_velocity = player_query
         .single()
         .map(|forward_move| forward_move.get())
         .unwrap_or(0.0);
 
     for (entity, mut transform, forward_move, &train) in train_entities.iter_mut() {
-        transform.translation.z -= player_velocity * time.delta_secs();
-        transform.translation.z -= forward_move.get() * time.delta_secs();
+        transform.translation.z -= player_velocity * elapsed;
+        transform.translation.z -= forward_move.get() * elapsed;
 
-        if transform.translation.z <= DESPAWN_POSITION {
+        if transform.translation.z <= horizon.despawn {
             train_spawner.drain(&mut commands, entity, train);
         }
     }
 }
 
+fn update_passing_train_position(
+    mut commands: Commands,
+    mut spawner: ResMut<TrafficSpawner>,
+    mut train_entities: Query<(Entity, &mut Transform, &ForwardMovement), With<PassingTrain>>,
+    player_query: Query<&ForwardMovement, (With<Player>, Without<PassingTrain>)>,
+    time_scale: Res<TimeScale>,
+    time: Res<Time>,
+    horizon: Res<TrackHorizon>,
+) {
+    let elapsed = time.delta_secs() * time_scale.get();
+    let player_velocity = player_query
+        .single()
+        .map(|forward_move| forward_move.get())
+        .unwrap_or(0.0);
+
+    for (entity, mut transform, forward_move) in train_entities.iter_mut() {
+        transform.translation.z -= (player_velocity + forward_move.get()) * elapsed;
+
+        if transform.translation.z <= horizon.despawn {
+            spawner.drain(&mut commands, entity);
+        }
+    }
+}
+
 fn rotate_animation(mut query: Query<(&mut Transform, &RotateAnimation)>, time: Res<Time>) {
     for (mut transform, animation) in query.iter_mut() {
         let axis = animation.axis;
@@ -343,239 +463,58 @@ fn update_player_position(
             vert_move.set(0.0);
         }
 
-        let t = timer.elapsed_sec() / SCENE_DURATION;
+        // The train holds its place during the coast, and only then falls back.
+        let t = ((timer.elapsed_sec() - COAST_DURATION) / SCENE_DURATION).max(0.0);
         let z_pos = PLAYER_MAX_Z_POS * (1.0 - t) + PLAYER_MIN_Z_POS * t;
         transform.translation.z = z_pos;
     }
 }
 
-#[allow(clippy::type_complexity)]
-fn update_toy_trains(
-    mut set: ParamSet<(
-        Query<&Transform, With<Player>>,
-        Query<&mut Transform, With<ToyTrain0>>,
-        Query<&mut Transform, With<ToyTrain1>>,
-        Query<&mut Transform, With<ToyTrain2>>,
-    )>,
+fn spawn_grounds(
+    mut commands: Commands,
+    mut retired: ResMut<RetiredGrounds>,
+    ground_query: Query<&Transform, With<Ground>>,
+    horizon: Res<TrackHorizon>,
 ) {
-    let data = set
-        .p0()
-        .single()
-        .map(|transform| transform.translation.with_z(transform.translation.z + 1.5))
-        .ok();
-
-    if let Some(p_pos) = data {
-        let mut position = p_pos;
-
-        if let Ok(mut transform) = set.p1().single_mut() {
-            let z_axis = (transform.translation - position).normalize_or(Vec3::NEG_Z);
-            let y_axis = Vec3::Y;
-            let x_axis = y_axis.cross(z_axis);
-            let y_axis = z_axis.cross(x_axis);
-            let rotation = Quat::from_mat3(&Mat3::from_cols(x_axis, y_axis, z_axis));
-
-            let temp = transform.translation;
-
-            transform.translation.x = position.x;
-            transform.translation.y = position.y;
-            transform.translation.z = p_pos.z - 1.5;
-            transform.rotation = rotation;
-
-            position = temp;
-        }
-
-        if let Ok(mut transform) = set.p2().single_mut() {
-            let z_axis = (transform.translation - position).normalize_or(Vec3::NEG_Z);
-            let y_axis = Vec3::Y;
-            let x_axis = y_axis.cross(z_axis);
-            let y_axis = z_axis.cross(x_axis);
-            let rotation = Quat::from_mat3(&Mat3::from_cols(x_axis, y_axis, z_axis));
-
-            let temp = transform.translation;
-            transform.translation.x = position.x;
-            transform.translation.y = position.y;
-            transform.translation.z = p_pos.z - 3.0;
-            transform.rotation = rotation;
-
-            position = temp;
-        }
-
-        if let Ok(mut transform) = set.p3().single_mut() {
-            let z_axis = (transform.translation - position).normalize_or(Vec3::NEG_Z);
-            let y_axis = Vec3::Y;
-            let x_axis = y_axis.cross(z_axis);
-            let y_axis = z_axis.cross(x_axis);
-            let rotation = Quat::from_mat3(&Mat3::from_cols(x_axis, y_axis, z_axis));
-
-            transform.translation.x = position.x;
-            transform.translation.y = position.y;
-            transform.translation.z = p_pos.z - 4.25;
-            transform.rotation = rotation;
-        }
-    }
-}
-
-fn spawn_grounds(mut commands: Commands, mut retired: ResMut<RetiredGrounds>) {
+    let grounds = horizon.grounds();
+    let mut front = ground_query
+        .iter()
+        .map(|transform| transform.translation.z)
+        .fold(horizon.despawn, f32::max);
     while let Some(entity) = retired.pop() {
+        front = grounds.recycled_depth(front);
         commands
             .entity(entity)
             .entry::<Transform>()
-            .and_modify(|mut transform| {
-                transform.translation.z += SPAWN_POSITION - DESPAWN_POSITION;
+            .and_modify(move |mut transform| {
+                transform.translation.z = front;
             })
-            .or_insert(Transform::from_xyz(0.0, 0.0, SPAWN_POSITION));
+            .or_insert(Transform::from_xyz(0.0, 0.0, front));
     }
 }
 
-#[allow(clippy::type_complexity)]
-#[allow(clippy::too_many_arguments)]
+/// Shows the state of the player on the train: black while debugging,
+/// a flash while attacked and the colors of the rainbow while invincible.
 fn update_player_effect(
-    mut set: ParamSet<(
-        Query<Entity, With<ToyTrain0>>,
-        Query<Entity, With<ToyTrain1>>,
-        Query<Entity, With<ToyTrain2>>,
-    )>,
-    children_query: Query<&Children>,
-    base_color_query: Query<&BaseColor>,
-    standard_material_query: Query<&MeshMaterial3d<StandardMaterial>>,
-    extented_material_query: Query<&MeshMaterial3d<EyeMouthMaterial>>,
-    mut standard_materials: ResMut<Assets<StandardMaterial>>,
-    mut extended_materials: ResMut<Assets<EyeMouthMaterial>>,
-    mut state: ResMut<CurrentState>,
-) {
-    if let Ok(entity) = set.p0().single() {
-        update_player_effect_recursive(
-            entity,
-            &children_query,
-            &base_color_query,
-            &standard_material_query,
-            &extented_material_query,
-            &mut standard_materials,
-            &mut extended_materials,
-            &mut state,
-        );
-    }
-
-    if let Ok(entity) = set.p1().single() {
-        update_player_effect_recursive(
-            entity,
-            &children_query,
-            &base_color_query,
-            &standard_material_query,
-            &extented_material_query,
-            &mut standard_materials,
-            &mut extended_materials,
-            &mut state,
-        );
-    }
-
-    if let Ok(entity) = set.p2().single() {
-        update_player_effect_recursive(
-            entity,
-            &children_query,
-            &base_color_query,
-            &standard_material_query,
-            &extented_material_query,
-            &mut standard_materials,
-            &mut extended_materials,
-            &mut state,
-        );
-    }
-}
-
-#[allow(clippy::too_many_arguments)]
-fn update_player_effect_recursive(
-    entity: Entity,
-    children_query: &Query<&Children>,
-    base_color_query: &Query<&BaseColor>,
-    standard_material_query: &Query<&MeshMaterial3d<StandardMaterial>>,
-    extented_material_query: &Query<&MeshMaterial3d<EyeMouthMaterial>>,
-    standard_materials: &mut ResMut<Assets<StandardMaterial>>,
-    extended_materials: &mut ResMut<Assets<EyeMouthMaterial>>,
-    state: &mut CurrentState,
+    mut commands: Commands,
+    car_query: Query<(Entity, Option<&MaterialEffect>), With<TrainCar>>,
+    state: Res<CurrentState>,
 ) {
-    if let Ok(handle) = standard_material_query.get(entity)
-        && let Some(material) = standard_materials.get_mut(handle.id())
-    {
-        match &mut *state {
-            #[cfg(not(feature = "no-debuging-player"))]
-            CurrentState::Debug => {
-                material.base_color = Color::BLACK;
-            }
-            CurrentState::Idle => {
-                material.base_color = base_color_query
-                    .get(entity)
-                    .map(|c| c.0)
-                    .unwrap_or(Color::WHITE);
-            }
-            CurrentState::Attacked { remaining } => {
-                let t = *remaining * ATTACKED_EFFECT_CYCLE;
-                let fill = 0.5 * t.cos() + 0.5;
-                material.base_color = Color::srgba(fill, fill, fill, material.base_color.alpha());
-            }
-            CurrentState::Invincible { remaining } => {
-                let t = ((INVINCIBLE_DURATION - *remaining) / INVINCIBLE_DURATION).max(0.0);
-                let cycle =
-                    MIN_INVINCIBLE_EFFECT_CYCLE * (1.0 - t) + MAX_INVINCIBLE_EFFECT_CYCLE * t;
-                let red = 0.5 * (t * cycle).sin() + 0.5;
-                let green = 0.5 * (TAU / 3.0 * t * cycle).sin() + 0.5;
-                let blue = 0.5 * (2.0 * TAU / 3.0 * t * cycle).sin() + 0.5;
-                material.base_color = Color::srgba(red, green, blue, material.base_color.alpha());
-            }
-        }
-    }
-
-    if let Ok(handle) = extented_material_query.get(entity)
-        && let Some(material) = extended_materials.get_mut(handle.id())
-    {
-        match &mut *state {
-            #[cfg(not(feature = "no-debuging-player"))]
-            CurrentState::Debug => {
-                material.base.base_color = Color::BLACK;
-            }
-            CurrentState::Idle => {
-                material.base.base_color = base_color_query
-                    .get(entity)
-                    .map(|c| c.0)
-                    .unwrap_or(Color::WHITE);
-            }
-            CurrentState::Attacked { remaining } => {
-                let t = *remaining * ATTACKED_EFFECT_CYCLE;
-                let fill = 0.5 * t.cos() + 0.5;
-                material.base.base_color =
-                    Color::srgba(fill, fill, fill, material.base.base_color.alpha());
-            }
-            CurrentState::Invincible { remaining } => {
-                let t = ((INVINCIBLE_DURATION - *remaining) / INVINCIBLE_DURATION).max(0.0);
-                let cycle =
-                    MIN_INVINCIBLE_EFFECT_CYCLE * (1.0 - t) + MAX_INVINCIBLE_EFFECT_CYCLE * t;
-                let red = 0.5 * (t * cycle).sin() + 0.5;
-                let green = 0.5 * (TAU / 3.0 * t * cycle).sin() + 0.5;
-                let blue = 0.5 * (2.0 * TAU / 3.0 * t * cycle).sin() + 0.5;
-                material.base.base_color =
-                    Color::srgba(red, green, blue, material.base.base_color.alpha());
-            }
-        }
-    }
-
-    if let Ok(children) = children_query.get(entity) {
-        for child in children.iter() {
-            update_player_effect_recursive(
-                child,
-                children_query,
-                base_color_query,
-                standard_material_query,
-                extented_material_query,
-                standard_materials,
-                extended_materials,
-                state,
-            );
+    let kind = player_material_effect(&state);
+    for (entity, effect) in car_query.iter() {
+        if effect.map(MaterialEffect::kind) == kind {
+            continue;
         }
+        match kind {
+            Some(kind) => commands.entity(entity).insert(MaterialEffect::new(kind)),
+            None => commands.entity(entity).remove::<MaterialEffect>(),
+        };
     }
 }
 
 pub fn update_player_speed(
     mut player_query: Query<&mut ForwardMovement, With<Player>>,
+    time_scale: Res<TimeScale>,
     time: Res<Time>,
 ) {
     let Ok(mut forward_move) = player_query.single_mut() else {
@@ -583,7 +522,7 @@ pub fn update_player_speed(
     };
 
     let mut velocity = forward_move.get();
-    velocity -= ACCELERATION * time.delta_secs();
+    velocity -= ACCELERATION * time.delta_secs() * time_scale.get();
     velocity = velocity.max(0.0);
 
     forward_move.set(velocity);
@@ -8,6 +8,17 @@ use crate::web::{WebAudioPlayer, WebPlaybackSettings};
 
 use super::*;
 
+/// The space above and below the rows of the options modal, and between its groups of rows.
+/// Natively the modal also holds the output device row, which takes its room from these spaces.
+#[cfg(target_arch = "wasm32")]
+const OPTION_MARGIN: f32 = 3.0;
+#[cfg(target_arch = "wasm32")]
+const OPTION_GROUP_GAP: f32 = 2.0;
+#[cfg(not(target_arch = "wasm32"))]
+const OPTION_MARGIN: f32 = 1.0;
+#[cfg(not(target_arch = "wasm32"))]
+const OPTION_GROUP_GAP: f32 = 1.0;
+
 // --- PLUGIN ---
 
 pub struct StatePlugin;
@@ -90,6 +101,7 @@ fn spawn_entities(
             },
             // Mark this entity with a request to be processed later.
             SpawnRequest,
+            UiLayer::Modal,
         ))
         .with_children(|parent| {
             // Create the main modal panel.
@@ -97,7 +109,7 @@ fn spawn_entities(
                 .spawn((
                     Node {
                         width: Val::Percent(50.0),
-                        height: Val::Percent(50.0),
+                        height: Val::Percent(78.0),
                         flex_direction: FlexDirection::Column,
                         justify_content: JustifyContent::Center,
                         align_content: AlignContent::Center,
@@ -111,17 +123,34 @@ fn spawn_entities(
                 ))
                 .with_children(|parent| {
                     // Add UI elements to the modal.
-                    add_vertical_space(parent, 10.0);
-                    add_bgm_volume_controller(parent, &asset_server, &system_volume, 100.0, 10.0);
-                    add_vertical_space(parent, 4.0);
-                    add_sfx_volume_controller(parent, &asset_server, &system_volume, 100.0, 10.0);
-                    add_vertical_space(parent, 4.0);
-                    add_voice_volume_controller(parent, &asset_server, &system_volume, 100.0, 10.0);
-                    add_vertical_space(parent, 10.0);
-                    add_locale_button(parent, &asset_server, 100.0, 16.0);
-                    add_vertical_space(parent, 10.0);
-                    add_back_button(parent, &asset_server, 100.0, 16.0);
-                    add_vertical_space(parent, 4.0);
+                    add_vertical_space(parent, OPTION_MARGIN);
+                    add_master_volume_controller(parent, &asset_server, &system_volume, 100.0, 7.0);
+                    add_vertical_space(parent, 1.0);
+                    add_bgm_volume_controller(parent, &asset_server, &system_volume, 100.0, 7.0);
+                    add_vertical_space(parent, 1.0);
+                    add_sfx_volume_controller(parent, &asset_server, &system_volume, 100.0, 7.0);
+                    add_vertical_space(parent, 1.0);
+                    add_voice_volume_controller(parent, &asset_server, &system_volume, 100.0, 7.0);
+                    #[cfg(not(target_arch = "wasm32"))]
+                    {
+                        add_vertical_space(parent, OPTION_GROUP_GAP);
+                        add_audio_device_button(parent, &asset_server, 100.0, 7.0);
+                    }
+                    add_vertical_space(parent, OPTION_GROUP_GAP);
+                    add_locale_button(parent, &asset_server, 100.0, 8.0);
+                    add_vertical_space(parent, OPTION_GROUP_GAP);
+                    add_graphics_button(parent, &asset_server, 100.0, 7.0);
+                    add_vertical_space(parent, 2.0);
+                    add_camera_button(parent, &asset_server, 100.0, 7.0);
+                    add_vertical_space(parent, 1.0);
+                    add_render_scale_button(parent, &asset_server, 100.0, 7.0);
+                    add_vertical_space(parent, 1.0);
+                    add_device_tier_button(parent, &asset_server, 100.0, 7.0);
+                    add_vertical_space(parent, 1.0);
+                    add_fog_button(parent, &asset_server, 100.0, 7.0);
+                    add_vertical_space(parent, OPTION_MARGIN);
+                    add_back_button(parent, &asset_server, 100.0, 8.0);
+                    add_vertical_space(parent, OPTION_MARGIN);
                 });
         })
         .id();
@@ -148,7 +177,141 @@ fn add_horizontal_space<'a>(parent: &mut RelatedSpawnerCommands<'a, ChildOf>, w:
     });
 }
 
-/// Helper function to build and add the BGM volume control UI (label, slider, value).
+/// Helper function to build and add the master volume control UI (label, slider, value).
+fn add_master_volume_controller<'a>(
+    parent: &mut RelatedSpawnerCommands<'a, ChildOf>,
+    asset_server: &AssetServer,
+    system_volume: &SystemVolume,
+    w: f32,
+    h: f32,
+) {
+    let percentage = system_volume.master_level() * 100.0;
+    parent
+        .spawn(Node {
+            width: Val::Percent(w),
+            height: Val::Percent(h),
+            flex_direction: FlexDirection::Row,
+            justify_content: JustifyContent::Center,
+            align_content: AlignContent::Center,
+            align_items: AlignItems::Center,
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            parent
+                .spawn(Node {
+                    width: Val::Percent(30.0),
+                    height: Val::Percent(100.0),
+                    justify_content: JustifyContent::Center,
+                    align_content: AlignContent::Center,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Vw(0.5),
+                    ..Default::default()
+                })
+                .with_children(|parent| {
+                    parent.spawn((
+                        IconNode(Icon::Audio),
+                        ImageNode::default().with_color(Color::BLACK),
+                        Node {
+                            height: Val::Percent(40.0),
+                            aspect_ratio: Some(1.0),
+                            ..Default::default()
+                        },
+                        Visibility::Hidden,
+                        UI::MasterLabel,
+                    ));
+
+                    let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
+                    parent.spawn((
+                        Text::new("Master"),
+                        TranslatableText("master".into()),
+                        TextFont::from_font(font),
+                        TextLayout::new_with_justify(JustifyText::Center),
+                        TextColor::BLACK,
+                        ResizableFont::vertical(1280.0, 42.0),
+                        Node::default(),
+                        Visibility::Hidden,
+                        UI::MasterLabel,
+                    ));
+                });
+
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(40.0),
+                        height: Val::Percent(15.0),
+                        justify_content: JustifyContent::Center,
+                        align_content: AlignContent::Center,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    BackgroundColor(SLIDER_RAIL_COLOR),
+                    Visibility::Hidden,
+                    UI::SliderRail,
+                    Slider::new(system_volume.master_level(), VOLUME_SLIDER_STEP),
+                    Focusable,
+                    VolumeChannel::Master,
+                ))
+                .with_children(|parent| {
+                    parent
+                        .spawn(Node {
+                            width: Val::Percent(100.0),
+                            align_content: AlignContent::Center,
+                            ..Default::default()
+                        })
+                        .with_children(|parent| {
+                            parent
+                                .spawn((
+                                    Node {
+                                        left: Val::Percent(percentage),
+                                        ..Default::default()
+                                    },
+                                    SliderThumb,
+                                ))
+                                .with_children(|parent| {
+                                    parent.spawn((
+                                        Node {
+                                            left: Val::VMin(-1.5),
+                                            width: Val::VMin(3.0),
+                                            height: Val::VMin(3.0),
+                                            ..Default::default()
+                                        },
+                                        BackgroundColor(SLIDER_HANDLE_COLOR),
+                                        BorderRadius::all(Val::Px(12.0)),
+                                        UI::MasterVolumeCursor,
+                                        UiSound::Silent,
+                                        Visibility::Hidden,
+                                        Button,
+                                    ));
+                                });
+                        });
+                });
+
+            parent
+                .spawn(Node {
+                    width: Val::Percent(30.0),
+                    height: Val::Percent(100.0),
+                    justify_content: JustifyContent::Center,
+                    align_content: AlignContent::Center,
+                    align_items: AlignItems::Center,
+                    ..Default::default()
+                })
+                .with_children(|parent| {
+                    let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
+                    parent.spawn((
+                        Text::new(format!("{}", percentage.round())),
+                        TextFont::from_font(font),
+                        TextLayout::new_with_justify(JustifyText::Center),
+                        TextColor::BLACK,
+                        ResizableFont::vertical(1280.0, 42.0),
+                        Node::default(),
+                        Visibility::Hidden,
+                        UI::MasterVolume,
+                    ));
+                });
+        });
+}
+
+/// Helper function to build and add the BGM volume control UI (label, slider, value, mute).
 fn add_bgm_volume_controller<'a>(
     parent: &mut RelatedSpawnerCommands<'a, ChildOf>,
     asset_server: &AssetServer,
@@ -156,7 +319,7 @@ fn add_bgm_volume_controller<'a>(
     w: f32,
     h: f32,
 ) {
-    let percentage = system_volume.background_percentage() * 100.0;
+    let percentage = system_volume.background_level() * 100.0;
     parent
         .spawn(Node {
             width: Val::Percent(w),
@@ -205,6 +368,9 @@ fn add_bgm_volume_controller<'a>(
                     BackgroundColor(SLIDER_RAIL_COLOR),
                     Visibility::Hidden,
                     UI::SliderRail,
+                    Slider::new(system_volume.background_level(), VOLUME_SLIDER_STEP),
+                    Focusable,
+                    VolumeChannel::Background,
                 ))
                 .with_children(|parent| {
                     parent
@@ -215,10 +381,13 @@ fn add_bgm_volume_controller<'a>(
                         })
                         .with_children(|parent| {
                             parent
-                                .spawn((Node {
-                                    left: Val::Percent(percentage),
-                                    ..Default::default()
-                                },))
+                                .spawn((
+                                    Node {
+                                        left: Val::Percent(percentage),
+                                        ..Default::default()
+                                    },
+                                    SliderThumb,
+                                ))
                                 .with_children(|parent| {
                                     parent.spawn((
                                         Node {
@@ -230,6 +399,7 @@ fn add_bgm_volume_controller<'a>(
                                         BackgroundColor(SLIDER_HANDLE_COLOR),
                                         BorderRadius::all(Val::Px(12.0)),
                                         UI::BgmVolumeCursor,
+                                        UiSound::Silent,
                                         Visibility::Hidden,
                                         Button,
                                     ));
@@ -239,7 +409,7 @@ fn add_bgm_volume_controller<'a>(
 
             parent
                 .spawn(Node {
-                    width: Val::Percent(30.0),
+                    width: Val::Percent(15.0),
                     height: Val::Percent(100.0),
                     justify_content: JustifyContent::Center,
                     align_content: AlignContent::Center,
@@ -249,7 +419,7 @@ fn add_bgm_volume_controller<'a>(
                 .with_children(|parent| {
                     let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
                     parent.spawn((
-                        Text::new(format!("{}", percentage as u32)),
+                        Text::new(format!("{}", percentage.round())),
                         TextFont::from_font(font),
                         TextLayout::new_with_justify(JustifyText::Center),
                         TextColor::BLACK,
@@ -259,10 +429,42 @@ fn add_bgm_volume_controller<'a>(
                         UI::BgmVolume,
                     ));
                 });
+
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(15.0),
+                        height: Val::Percent(80.0),
+                        border: UiRect::all(Val::Percent(0.5)),
+                        justify_content: JustifyContent::Center,
+                        align_content: AlignContent::Center,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    BackgroundColor(MUTE_BTN_COLOR),
+                    BorderRadius::all(Val::Percent(20.0)),
+                    Visibility::Hidden,
+                    UI::BgmMuteButton,
+                    Button,
+                    Focusable,
+                ))
+                .with_children(|parent| {
+                    let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
+                    parent.spawn((
+                        Text::new("Mute"),
+                        TranslatableText("mute".into()),
+                        TextFont::from_font(font),
+                        TextLayout::new_with_justify(JustifyText::Center),
+                        TextColor::BLACK,
+                        ResizableFont::vertical(1280.0, 32.0),
+                        Node::default(),
+                        Visibility::Inherited,
+                    ));
+                });
         });
 }
 
-/// Helper function to build and add the SFX volume control UI (label, slider, value).
+/// Helper function to build and add the SFX volume control UI (label, slider, value, mute).
 fn add_sfx_volume_controller<'a>(
     parent: &mut RelatedSpawnerCommands<'a, ChildOf>,
     asset_server: &AssetServer,
@@ -270,7 +472,7 @@ fn add_sfx_volume_controller<'a>(
     w: f32,
     h: f32,
 ) {
-    let percentage = system_volume.effect_percentage() * 100.0;
+    let percentage = system_volume.effect_level() * 100.0;
     parent
         .spawn(Node {
             width: Val::Percent(w),
@@ -319,6 +521,9 @@ fn add_sfx_volume_controller<'a>(
                     BackgroundColor(SLIDER_RAIL_COLOR),
                     Visibility::Hidden,
                     UI::SliderRail,
+                    Slider::new(system_volume.effect_level(), VOLUME_SLIDER_STEP),
+                    Focusable,
+                    VolumeChannel::Effect,
                 ))
                 .with_children(|parent| {
                     parent
@@ -329,10 +534,13 @@ fn add_sfx_volume_controller<'a>(
                         })
                         .with_children(|parent| {
                             parent
-                                .spawn((Node {
-                                    left: Val::Percent(percentage),
-                                    ..Default::default()
-                                },))
+                                .spawn((
+                                    Node {
+                                        left: Val::Percent(percentage),
+                                        ..Default::default()
+                                    },
+                                    SliderThumb,
+                                ))
                                 .with_children(|parent| {
                                     parent.spawn((
                                         Node {
@@ -344,6 +552,7 @@ fn add_sfx_volume_controller<'a>(
                                         BackgroundColor(SLIDER_HANDLE_COLOR),
                                         BorderRadius::all(Val::Px(12.0)),
                                         UI::SfxVolumeCursor,
+                                        UiSound::Silent,
                                         Visibility::Hidden,
                                         Button,
                                     ));
@@ -353,7 +562,7 @@ fn add_sfx_volume_controller<'a>(
 
             parent
                 .spawn(Node {
-                    width: Val::Percent(30.0),
+                    width: Val::Percent(15.0),
                     height: Val::Percent(100.0),
                     justify_content: JustifyContent::Center,
                     align_content: AlignContent::Center,
@@ -363,7 +572,7 @@ fn add_sfx_volume_controller<'a>(
                 .with_children(|parent| {
                     let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
                     parent.spawn((
-                        Text::new(format!("{}", percentage as u32)),
+                        Text::new(format!("{}", percentage.round())),
                         TextFont::from_font(font),
                         TextLayout::new_with_justify(JustifyText::Center),
                         ResizableFont::vertical(1280.0, 42.0),
@@ -373,6 +582,38 @@ fn add_sfx_volume_controller<'a>(
                         UI::SfxVolume,
                     ));
                 });
+
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(15.0),
+                        height: Val::Percent(80.0),
+                        border: UiRect::all(Val::Percent(0.5)),
+                        justify_content: JustifyContent::Center,
+                        align_content: AlignContent::Center,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    BackgroundColor(MUTE_BTN_COLOR),
+                    BorderRadius::all(Val::Percent(20.0)),
+                    Visibility::Hidden,
+                    UI::SfxMuteButton,
+                    Button,
+                    Focusable,
+                ))
+                .with_children(|parent| {
+                    let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
+                    parent.spawn((
+                        Text::new("Mute"),
+                        TranslatableText("mute".into()),
+                        TextFont::from_font(font),
+                        TextLayout::new_with_justify(JustifyText::Center),
+                        TextColor::BLACK,
+                        ResizableFont::vertical(1280.0, 32.0),
+                        Node::default(),
+                        Visibility::Inherited,
+                    ));
+                });
         });
 }
 
@@ -384,7 +625,7 @@ fn add_voice_volume_controller<'a>(
     w: f32,
     h: f32,
 ) {
-    let percentage = system_volume.voice_percentage() * 100.0;
+    let percentage = system_volume.voice_level() * 100.0;
     parent
         .spawn(Node {
             width: Val::Percent(w),
@@ -433,6 +674,9 @@ fn add_voice_volume_controller<'a>(
                     BackgroundColor(SLIDER_RAIL_COLOR),
                     Visibility::Hidden,
                     UI::SliderRail,
+                    Slider::new(system_volume.voice_level(), VOLUME_SLIDER_STEP),
+                    Focusable,
+                    VolumeChannel::Voice,
                 ))
                 .with_children(|parent| {
                     parent
@@ -443,10 +687,13 @@ fn add_voice_volume_controller<'a>(
                         })
                         .with_children(|parent| {
                             parent
-                                .spawn((Node {
-                                    left: Val::Percent(percentage),
-                                    ..Default::default()
-                                },))
+                                .spawn((
+                                    Node {
+                                        left: Val::Percent(percentage),
+                                        ..Default::default()
+                                    },
+                                    SliderThumb,
+                                ))
                                 .with_children(|parent| {
                                     parent.spawn((
                                         Node {
@@ -458,6 +705,7 @@ fn add_voice_volume_controller<'a>(
                                         BackgroundColor(SLIDER_HANDLE_COLOR),
                                         BorderRadius::all(Val::Px(12.0)),
                                         UI::VoiceVolumeCursor,
+                                        UiSound::Silent,
                                         Visibility::Hidden,
                                         Button,
                                     ));
@@ -467,7 +715,7 @@ fn add_voice_volume_controller<'a>(
 
             parent
                 .spawn(Node {
-                    width: Val::Percent(30.0),
+                    width: Val::Percent(15.0),
                     height: Val::Percent(100.0),
                     justify_content: JustifyContent::Center,
                     align_content: AlignContent::Center,
@@ -477,7 +725,7 @@ fn add_voice_volume_controller<'a>(
                 .with_children(|parent| {
                     let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
                     parent.spawn((
-                        Text::new(format!("{}", percentage as u32)),
+                        Text::new(format!("{}", percentage.round())),
                         TextFont::from_font(font),
                         TextLayout::new_with_justify(JustifyText::Center),
                         TextColor::BLACK,
@@ -487,6 +735,117 @@ fn add_voice_volume_controller<'a>(
                         UI::VoiceVolume,
                     ));
                 });
+
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(15.0),
+                        height: Val::Percent(80.0),
+                        border: UiRect::all(Val::Percent(0.5)),
+                        justify_content: JustifyContent::Center,
+                        align_content: AlignContent::Center,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    BackgroundColor(MUTE_BTN_COLOR),
+                    BorderRadius::all(Val::Percent(20.0)),
+                    Visibility::Hidden,
+                    UI::VoiceMuteButton,
+                    Button,
+                    Focusable,
+                ))
+                .with_children(|parent| {
+                    let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
+                    parent.spawn((
+                        Text::new("Mute"),
+                        TranslatableText("mute".into()),
+                        TextFont::from_font(font),
+                        TextLayout::new_with_justify(JustifyText::Center),
+                        TextColor::BLACK,
+                        ResizableFont::vertical(1280.0, 32.0),
+                        Node::default(),
+                        Visibility::Inherited,
+                    ));
+                });
+        });
+}
+
+/// Helper function to build and add the output device row (label, device button).
+/// Pressing the button switches to the next output device.
+#[cfg(not(target_arch = "wasm32"))]
+fn add_audio_device_button<'a>(
+    parent: &mut RelatedSpawnerCommands<'a, ChildOf>,
+    asset_server: &AssetServer,
+    w: f32,
+    h: f32,
+) {
+    parent
+        .spawn(Node {
+            width: Val::Percent(w),
+            height: Val::Percent(h),
+            flex_direction: FlexDirection::Row,
+            justify_content: JustifyContent::Center,
+            align_content: AlignContent::Center,
+            align_items: AlignItems::Center,
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            parent
+                .spawn(Node {
+                    width: Val::Percent(30.0),
+                    height: Val::Percent(100.0),
+                    justify_content: JustifyContent::Center,
+                    align_content: AlignContent::Center,
+                    align_items: AlignItems::Center,
+                    ..Default::default()
+                })
+                .with_children(|parent| {
+                    let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
+                    parent.spawn((
+                        Text::new("Output"),
+                        TranslatableText("output".into()),
+                        TextFont::from_font(font),
+                        TextLayout::new_with_justify(JustifyText::Center),
+                        TextColor::BLACK,
+                        ResizableFont::vertical(1280.0, 42.0),
+                        Node::default(),
+                        Visibility::Hidden,
+                        UI::AudioDeviceLabel,
+                    ));
+                });
+
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(60.0),
+                        height: Val::Percent(80.0),
+                        border: UiRect::all(Val::Percent(0.5)),
+                        justify_content: JustifyContent::Center,
+                        align_content: AlignContent::Center,
+                        align_items: AlignItems::Center,
+                        overflow: Overflow::clip(),
+                        ..Default::default()
+                    },
+                    BackgroundColor(GRAPHICS_BTN_COLOR),
+                    BorderRadius::all(Val::Percent(20.0)),
+                    Visibility::Hidden,
+                    UI::AudioDeviceButton,
+                    Button,
+                    Focusable,
+                ))
+                .with_children(|parent| {
+                    let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
+                    parent.spawn((
+                        Text::default(),
+                        TextFont::from_font(font),
+                        TextLayout::new_with_justify(JustifyText::Center),
+                        TextColor::BLACK,
+                        ResizableFont::vertical(1280.0, 32.0),
+                        Node::default(),
+                        Visibility::Inherited,
+                        AudioDeviceName,
+                    ));
+                });
         });
 }
 
@@ -524,6 +883,7 @@ fn add_locale_button<'a>(
                     Visibility::Hidden,
                     UI::LanguageEn,
                     Button,
+                    Focusable,
                 ))
                 .with_children(|parent| {
                     let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
@@ -556,6 +916,7 @@ fn add_locale_button<'a>(
                     Visibility::Hidden,
                     UI::LanguageJa,
                     Button,
+                    Focusable,
                 ))
                 .with_children(|parent| {
                     let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
@@ -588,6 +949,7 @@ fn add_locale_button<'a>(
                     Visibility::Hidden,
                     UI::LanguageKo,
                     Button,
+                    Focusable,
                 ))
                 .with_children(|parent| {
                     let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
@@ -604,8 +966,8 @@ fn add_locale_button<'a>(
         });
 }
 
-/// Helper function to build and add the 'Back' button for the options modal.
-fn add_back_button<'a>(
+/// Helper function to build and add the graphics option toggle buttons.
+fn add_graphics_button<'a>(
     parent: &mut RelatedSpawnerCommands<'a, ChildOf>,
     asset_server: &AssetServer,
     w: f32,
@@ -625,18 +987,482 @@ fn add_back_button<'a>(
             parent
                 .spawn((
                     Node {
-                        width: Val::Percent(30.0),
+                        width: Val::Percent(18.0),
                         height: Val::Percent(100.0),
+                        border: UiRect::all(Val::Percent(0.5)),
                         justify_content: JustifyContent::Center,
                         align_content: AlignContent::Center,
                         align_items: AlignItems::Center,
                         ..Default::default()
                     },
-                    BackgroundColor(BACK_BTN_COLOR),
+                    BackgroundColor(GRAPHICS_BTN_COLOR),
                     BorderRadius::all(Val::Percent(20.0)),
                     Visibility::Hidden,
-                    UI::BackButton,
+                    UI::SpeedLineButton,
                     Button,
+                    Focusable,
+                ))
+                .with_children(|parent| {
+                    let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
+                    parent.spawn((
+                        Text::new("Speed Lines"),
+                        TranslatableText("speed_lines".into()),
+                        TextFont::from_font(font.clone()),
+                        TextLayout::new_with_justify(JustifyText::Center),
+                        TextColor::BLACK,
+                        ResizableFont::vertical(1280.0, 36.0),
+                        Node::default(),
+                        Visibility::Inherited,
+                    ));
+                });
+
+            add_horizontal_space(parent, 2.5);
+
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(18.0),
+                        height: Val::Percent(100.0),
+                        border: UiRect::all(Val::Percent(0.5)),
+                        justify_content: JustifyContent::Center,
+                        align_content: AlignContent::Center,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    BackgroundColor(GRAPHICS_BTN_COLOR),
+                    BorderRadius::all(Val::Percent(20.0)),
+                    Visibility::Hidden,
+                    UI::ReduceMotionButton,
+                    Button,
+                    Focusable,
+                ))
+                .with_children(|parent| {
+                    let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
+                    parent.spawn((
+                        Text::new("Reduce Motion"),
+                        TranslatableText("reduce_motion".into()),
+                        TextFont::from_font(font.clone()),
+                        TextLayout::new_with_justify(JustifyText::Center),
+                        TextColor::BLACK,
+                        ResizableFont::vertical(1280.0, 36.0),
+                        Node::default(),
+                        Visibility::Inherited,
+                    ));
+                });
+
+            add_horizontal_space(parent, 2.5);
+
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(18.0),
+                        height: Val::Percent(100.0),
+                        border: UiRect::all(Val::Percent(0.5)),
+                        justify_content: JustifyContent::Center,
+                        align_content: AlignContent::Center,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    BackgroundColor(GRAPHICS_BTN_COLOR),
+                    BorderRadius::all(Val::Percent(20.0)),
+                    Visibility::Hidden,
+                    UI::CaptionButton,
+                    Button,
+                    Focusable,
+                ))
+                .with_children(|parent| {
+                    let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
+                    parent.spawn((
+                        Text::new("Captions"),
+                        TranslatableText("captions".into()),
+                        TextFont::from_font(font.clone()),
+                        TextLayout::new_with_justify(JustifyText::Center),
+                        TextColor::BLACK,
+                        ResizableFont::vertical(1280.0, 36.0),
+                        Node::default(),
+                        Visibility::Inherited,
+                    ));
+                });
+
+            add_horizontal_space(parent, 2.5);
+
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(18.0),
+                        height: Val::Percent(100.0),
+                        border: UiRect::all(Val::Percent(0.5)),
+                        justify_content: JustifyContent::Center,
+                        align_content: AlignContent::Center,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    BackgroundColor(GRAPHICS_BTN_COLOR),
+                    BorderRadius::all(Val::Percent(20.0)),
+                    Visibility::Hidden,
+                    UI::LandingMarkerButton,
+                    Button,
+                    Focusable,
+                ))
+                .with_children(|parent| {
+                    let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
+                    parent.spawn((
+                        Text::new("Jump Guide"),
+                        TranslatableText("jump_guide".into()),
+                        TextFont::from_font(font.clone()),
+                        TextLayout::new_with_justify(JustifyText::Center),
+                        TextColor::BLACK,
+                        ResizableFont::vertical(1280.0, 36.0),
+                        Node::default(),
+                        Visibility::Inherited,
+                    ));
+                });
+
+            add_horizontal_space(parent, 2.5);
+
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(18.0),
+                        height: Val::Percent(100.0),
+                        border: UiRect::all(Val::Percent(0.5)),
+                        justify_content: JustifyContent::Center,
+                        align_content: AlignContent::Center,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    BackgroundColor(GRAPHICS_BTN_COLOR),
+                    BorderRadius::all(Val::Percent(20.0)),
+                    Visibility::Hidden,
+                    UI::PaceClockButton,
+                    Button,
+                    Focusable,
+                ))
+                .with_children(|parent| {
+                    let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
+                    parent.spawn((
+                        Text::new("Pace Clock"),
+                        TranslatableText("pace_clock".into()),
+                        TextFont::from_font(font.clone()),
+                        TextLayout::new_with_justify(JustifyText::Center),
+                        TextColor::BLACK,
+                        ResizableFont::vertical(1280.0, 36.0),
+                        Node::default(),
+                        Visibility::Inherited,
+                    ));
+                });
+        });
+}
+
+/// Helper function to build and add the camera preset buttons.
+fn add_camera_button<'a>(
+    parent: &mut RelatedSpawnerCommands<'a, ChildOf>,
+    asset_server: &AssetServer,
+    w: f32,
+    h: f32,
+) {
+    parent
+        .spawn(Node {
+            width: Val::Percent(w),
+            height: Val::Percent(h),
+            flex_direction: FlexDirection::Row,
+            justify_content: JustifyContent::Center,
+            align_content: AlignContent::Center,
+            align_items: AlignItems::Center,
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            for (i, preset) in CameraPreset::ALL.into_iter().enumerate() {
+                if i > 0 {
+                    add_horizontal_space(parent, 10.0);
+                }
+
+                parent
+                    .spawn((
+                        Node {
+                            width: Val::Percent(20.0),
+                            height: Val::Percent(100.0),
+                            border: UiRect::all(Val::Percent(0.5)),
+                            justify_content: JustifyContent::Center,
+                            align_content: AlignContent::Center,
+                            align_items: AlignItems::Center,
+                            ..Default::default()
+                        },
+                        BackgroundColor(GRAPHICS_BTN_COLOR),
+                        BorderRadius::all(Val::Percent(20.0)),
+                        Visibility::Hidden,
+                        UI::CameraPresetButton,
+                        CameraPresetChoice(preset),
+                        Button,
+                        Focusable,
+                    ))
+                    .with_children(|parent| {
+                        let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
+                        parent.spawn((
+                            Text::default(),
+                            TranslatableText(preset.label().into()),
+                            TextFont::from_font(font),
+                            TextLayout::new_with_justify(JustifyText::Center),
+                            TextColor::BLACK,
+                            ResizableFont::vertical(1280.0, 36.0),
+                            Node::default(),
+                            Visibility::Inherited,
+                        ));
+                    });
+            }
+        });
+}
+
+/// Helper function to build and add the render scale limit buttons.
+fn add_render_scale_button<'a>(
+    parent: &mut RelatedSpawnerCommands<'a, ChildOf>,
+    asset_server: &AssetServer,
+    w: f32,
+    h: f32,
+) {
+    parent
+        .spawn(Node {
+            width: Val::Percent(w),
+            height: Val::Percent(h),
+            flex_direction: FlexDirection::Row,
+            justify_content: JustifyContent::Center,
+            align_content: AlignContent::Center,
+            align_items: AlignItems::Center,
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            for (i, limit) in RenderScaleLimit::ALL.into_iter().enumerate() {
+                if i > 0 {
+                    add_horizontal_space(parent, 10.0);
+                }
+
+                parent
+                    .spawn((
+                        Node {
+                            width: Val::Percent(20.0),
+                            height: Val::Percent(100.0),
+                            border: UiRect::all(Val::Percent(0.5)),
+                            justify_content: JustifyContent::Center,
+                            align_content: AlignContent::Center,
+                            align_items: AlignItems::Center,
+                            ..Default::default()
+                        },
+                        BackgroundColor(GRAPHICS_BTN_COLOR),
+                        BorderRadius::all(Val::Percent(20.0)),
+                        Visibility::Hidden,
+                        UI::RenderScaleButton,
+                        RenderScaleChoice(limit),
+                        Button,
+                        Focusable,
+                    ))
+                    .with_children(|parent| {
+                        let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
+                        parent.spawn((
+                            Text::default(),
+                            TranslatableText(limit.label().into()),
+                            TextFont::from_font(font),
+                            TextLayout::new_with_justify(JustifyText::Center),
+                            TextColor::BLACK,
+                            ResizableFont::vertical(1280.0, 36.0),
+                            Node::default(),
+                            Visibility::Inherited,
+                        ));
+                    });
+            }
+        });
+}
+
+/// Helper function to build and add the device tier buttons.
+fn add_device_tier_button<'a>(
+    parent: &mut RelatedSpawnerCommands<'a, ChildOf>,
+    asset_server: &AssetServer,
+    w: f32,
+    h: f32,
+) {
+    parent
+        .spawn(Node {
+            width: Val::Percent(w),
+            height: Val::Percent(h),
+            flex_direction: FlexDirection::Row,
+            justify_content: JustifyContent::Center,
+            align_content: AlignContent::Center,
+            align_items: AlignItems::Center,
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            for (i, tier) in DeviceTier::ALL.into_iter().enumerate() {
+                if i > 0 {
+                    add_horizontal_space(parent, 10.0);
+                }
+
+                parent
+                    .spawn((
+                        Node {
+                            width: Val::Percent(20.0),
+                            height: Val::Percent(100.0),
+                            border: UiRect::all(Val::Percent(0.5)),
+                            justify_content: JustifyContent::Center,
+                            align_content: AlignContent::Center,
+                            align_items: AlignItems::Center,
+                            ..Default::default()
+                        },
+                        BackgroundColor(GRAPHICS_BTN_COLOR),
+                        BorderRadius::all(Val::Percent(20.0)),
+                        Visibility::Hidden,
+                        UI::DeviceTierButton,
+                        DeviceTierChoice(tier),
+                        Button,
+                        Focusable,
+                    ))
+                    .with_children(|parent| {
+                        let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
+                        parent.spawn((
+                            Text::default(),
+                            TranslatableText(tier.label().into()),
+                            TextFont::from_font(font),
+                            TextLayout::new_with_justify(JustifyText::Center),
+                            TextColor::BLACK,
+                            ResizableFont::vertical(1280.0, 36.0),
+                            Node::default(),
+                            Visibility::Inherited,
+                        ));
+                    });
+            }
+        });
+}
+
+/// Helper function to build and add the fog mode buttons and the atmospheric scattering toggle.
+fn add_fog_button<'a>(
+    parent: &mut RelatedSpawnerCommands<'a, ChildOf>,
+    asset_server: &AssetServer,
+    w: f32,
+    h: f32,
+) {
+    parent
+        .spawn(Node {
+            width: Val::Percent(w),
+            height: Val::Percent(h),
+            flex_direction: FlexDirection::Row,
+            justify_content: JustifyContent::Center,
+            align_content: AlignContent::Center,
+            align_items: AlignItems::Center,
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            for (i, mode) in FogMode::ALL.into_iter().enumerate() {
+                if i > 0 {
+                    add_horizontal_space(parent, 4.0);
+                }
+
+                parent
+                    .spawn((
+                        Node {
+                            width: Val::Percent(18.0),
+                            height: Val::Percent(100.0),
+                            border: UiRect::all(Val::Percent(0.5)),
+                            justify_content: JustifyContent::Center,
+                            align_content: AlignContent::Center,
+                            align_items: AlignItems::Center,
+                            ..Default::default()
+                        },
+                        BackgroundColor(GRAPHICS_BTN_COLOR),
+                        BorderRadius::all(Val::Percent(20.0)),
+                        Visibility::Hidden,
+                        UI::FogModeButton,
+                        FogModeChoice(mode),
+                        Button,
+                        Focusable,
+                    ))
+                    .with_children(|parent| {
+                        let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
+                        parent.spawn((
+                            Text::default(),
+                            TranslatableText(mode.label().into()),
+                            TextFont::from_font(font),
+                            TextLayout::new_with_justify(JustifyText::Center),
+                            TextColor::BLACK,
+                            ResizableFont::vertical(1280.0, 36.0),
+                            Node::default(),
+                            Visibility::Inherited,
+                        ));
+                    });
+            }
+
+            add_horizontal_space(parent, 4.0);
+
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(18.0),
+                        height: Val::Percent(100.0),
+                        border: UiRect::all(Val::Percent(0.5)),
+                        justify_content: JustifyContent::Center,
+                        align_content: AlignContent::Center,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    BackgroundColor(GRAPHICS_BTN_COLOR),
+                    BorderRadius::all(Val::Percent(20.0)),
+                    Visibility::Hidden,
+                    UI::ScatteringButton,
+                    Button,
+                    Focusable,
+                ))
+                .with_children(|parent| {
+                    let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
+                    parent.spawn((
+                        Text::new("Scattering"),
+                        TranslatableText("scattering".into()),
+                        TextFont::from_font(font),
+                        TextLayout::new_with_justify(JustifyText::Center),
+                        TextColor::BLACK,
+                        ResizableFont::vertical(1280.0, 36.0),
+                        Node::default(),
+                        Visibility::Inherited,
+                    ));
+                });
+        });
+}
+
+/// Helper function to build and add the 'Back' button for the options modal.
+fn add_back_button<'a>(
+    parent: &mut RelatedSpawnerCommands<'a, ChildOf>,
+    asset_server: &AssetServer,
+    w: f32,
+    h: f32,
+) {
+    parent
+        .spawn(Node {
+            width: Val::Percent(w),
+            height: Val::Percent(h),
+            flex_direction: FlexDirection::Row,
+            justify_content: JustifyContent::Center,
+            align_content: AlignContent::Center,
+            align_items: AlignItems::Center,
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            add_profile_button(parent, asset_server, UI::ExportButton, "Export", "export");
+            add_horizontal_space(parent, 5.0);
+
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(30.0),
+                        height: Val::Percent(100.0),
+                        justify_content: JustifyContent::Center,
+                        align_content: AlignContent::Center,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    BackgroundColor(BACK_BTN_COLOR),
+                    BorderRadius::all(Val::Percent(20.0)),
+                    Visibility::Hidden,
+                    UI::BackButton,
+                    UiSound::Back,
+                    Button,
+                    Focusable,
+                    FocusCancel,
                 ))
                 .with_children(|parent| {
                     let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
@@ -651,6 +1477,53 @@ fn add_back_button<'a>(
                         Visibility::Inherited,
                     ));
                 });
+
+            add_horizontal_space(parent, 5.0);
+            add_profile_button(parent, asset_server, UI::ImportButton, "Import", "import");
+        });
+}
+
+/// Helper function to build and add a button that exports or imports the save profile.
+fn add_profile_button<'a>(
+    parent: &mut RelatedSpawnerCommands<'a, ChildOf>,
+    asset_server: &AssetServer,
+    ui: UI,
+    label: &str,
+    key: &str,
+) {
+    parent
+        .spawn((
+            Node {
+                width: Val::Percent(20.0),
+                height: Val::Percent(80.0),
+                border: UiRect::all(Val::Percent(0.5)),
+                justify_content: JustifyContent::Center,
+                align_content: AlignContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            BackgroundColor(GRAPHICS_BTN_COLOR),
+            BorderRadius::all(Val::Percent(20.0)),
+            Visibility::Hidden,
+            ui,
+            Button,
+            Focusable,
+        ))
+        .with_children(|parent| {
+            let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
+            let mut text = parent.spawn((
+                Text::new(label),
+                TextFont::from_font(font),
+                TextLayout::new_with_justify(JustifyText::Center),
+                TextColor::BLACK,
+                TranslatableText(key.into()),
+                ResizableFont::vertical(1280.0, 36.0),
+                Node::default(),
+                Visibility::Inherited,
+            ));
+            if ui == UI::ImportButton {
+                text.insert(ImportLabel);
+            }
         });
 }
 
@@ -697,9 +1570,9 @@ fn check_loading_progress(
 fn update_loading_bar(
     loading_entitis: Res<LoadingEntities>,
     request_query: Query<(), With<SpawnRequest>>,
-    mut query: Query<&mut Node, With<LoadingBar>>,
+    mut query: Query<&mut LoadingProgress, With<LoadingBar>>,
 ) {
-    if let Ok(mut node) = query.single_mut() {
+    if let Ok(mut bar) = query.single_mut() {
         // Count how many entities have been fully processed (no longer have `SpawnRequest`).
         let loaded_count = loading_entitis
             .handles
@@ -715,7 +1588,7 @@ fn update_loading_bar(
             1.0 // Avoid division by zero.
         };
 
-        // Update the width of the loading bar node.
-        node.width = Val::Percent(progress * 100.0);
+        // Update the progress shown by the loading bar.
+        bar.set(progress);
     }
 }
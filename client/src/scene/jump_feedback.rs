@@ -0,0 +1,290 @@
+use std::f32::consts::PI;
+
+use bevy::{
+    pbr::{NotShadowCaster, NotShadowReceiver},
+    prelude::*,
+};
+use rand::Rng;
+
+use super::*;
+
+// --- PLUGIN ---
+
+/// Squashes and stretches the lead car as the train takes off and lands, and kicks up dust on landing.
+/// Both follow the [`JumpPhase`] events, so they fire on the same frame as the landing sound.
+/// An [`AirDashed`] leaves streaks across the lanes the train dashed over.
+pub struct JumpFeedbackPlugin;
+
+impl Plugin for JumpFeedbackPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, (setup_dust_assets, setup_streak_assets))
+            .add_systems(OnExit(GameState::InGame), settle_squash_stretch)
+            .add_systems(
+                PostUpdate,
+                (
+                    start_squash_stretch,
+                    update_squash_stretch.after(start_squash_stretch),
+                    spawn_landing_dust,
+                    update_landing_dust,
+                    spawn_air_dash_streaks,
+                    update_air_dash_streaks,
+                )
+                    .run_if(in_state(GameState::InGame)),
+            );
+    }
+}
+
+// --- COMPONENTS ---
+
+/// A squash or stretch playing on a car. A positive amount stretches it upward, a negative one squashes it.
+#[derive(Component)]
+pub struct SquashStretch {
+    amount: f32,
+    remaining: f32,
+}
+
+/// A puff of dust thrown up by a landing.
+#[derive(Component)]
+pub struct DustParticle {
+    velocity: Vec3,
+    /// The speed the ground scrolls past at, which the dust is carried along with.
+    scroll: f32,
+    remaining: f32,
+}
+
+/// A streak left in the air by an air-dash.
+#[derive(Component)]
+pub struct DashStreak {
+    /// The speed the ground scrolls past at, which the streak is carried along with.
+    scroll: f32,
+    remaining: f32,
+}
+
+// --- RESOURCES ---
+
+/// The mesh and material shared by every dust particle.
+#[derive(Resource)]
+struct DustAssets {
+    mesh: Handle<Mesh>,
+    material: Handle<StandardMaterial>,
+}
+
+/// The mesh and material shared by every air-dash streak.
+#[derive(Resource)]
+struct StreakAssets {
+    mesh: Handle<Mesh>,
+    material: Handle<StandardMaterial>,
+}
+
+// --- STARTUP SYSTEMS ---
+
+fn setup_dust_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.insert_resource(DustAssets {
+        mesh: meshes.add(Sphere::new(LANDING_DUST_SIZE)),
+        material: materials.add(StandardMaterial {
+            base_color: LANDING_DUST_COLOR,
+            unlit: true,
+            ..Default::default()
+        }),
+    });
+}
+
+fn setup_streak_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.insert_resource(StreakAssets {
+        mesh: meshes.add(Cuboid::from_length(1.0)),
+        material: materials.add(StandardMaterial {
+            base_color: AIR_DASH_STREAK_COLOR,
+            unlit: true,
+            ..Default::default()
+        }),
+    });
+}
+
+// --- CLEANUP SYSTEMS ---
+
+fn settle_squash_stretch(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Transform), With<SquashStretch>>,
+) {
+    for (entity, mut transform) in query.iter_mut() {
+        transform.scale = Vec3::ONE;
+        commands.entity(entity).remove::<SquashStretch>();
+    }
+}
+
+// --- POSTUPDATE SYSTEMS ---
+
+fn start_squash_stretch(
+    mut commands: Commands,
+    mut events: EventReader<JumpPhase>,
+    options: Res<GraphicsOptions>,
+    query: Query<(Entity, &ChainLink), With<TrainCar>>,
+) {
+    let Some(&phase) = events.read().last() else {
+        return;
+    };
+    if options.reduce_motion {
+        return;
+    }
+
+    let amount = match phase {
+        JumpPhase::TakeOff => JUMP_STRETCH_AMOUNT,
+        JumpPhase::Landing => -JUMP_SQUASH_AMOUNT,
+    };
+    for (entity, _) in query.iter().filter(|(_, link)| link.index == 0) {
+        commands.entity(entity).insert(SquashStretch {
+            amount,
+            remaining: SQUASH_STRETCH_DURATION,
+        });
+    }
+}
+
+/// Scales the cars along their height while keeping their volume, then settles them back.
+fn update_squash_stretch(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Transform, &mut SquashStretch)>,
+    time: Res<Time>,
+) {
+    for (entity, mut transform, mut squash) in query.iter_mut() {
+        squash.remaining -= time.delta_secs();
+        if squash.remaining <= 0.0 {
+            transform.scale = Vec3::ONE;
+            commands.entity(entity).remove::<SquashStretch>();
+            continue;
+        }
+
+        let t = 1.0 - squash.remaining / SQUASH_STRETCH_DURATION;
+        let height = 1.0 + squash.amount * (t * PI).sin();
+        let width = height.recip().sqrt();
+        transform.scale = Vec3::new(width, height, width);
+    }
+}
+
+fn spawn_landing_dust(
+    mut commands: Commands,
+    mut events: EventReader<JumpPhase>,
+    mut rng: ResMut<RngService>,
+    assets: Res<DustAssets>,
+    query: Query<(&Transform, &ForwardMovement), With<Player>>,
+) {
+    if !events.read().any(|&phase| phase == JumpPhase::Landing) {
+        return;
+    }
+    let Ok((p_trans, forward_move)) = query.single() else {
+        return;
+    };
+
+    let rng = rng.cosmetic();
+    for _ in 0..NUM_LANDING_DUST_PARTICLES {
+        let angle = rng.random_range(0.0..2.0 * PI);
+        let speed = rng.random_range(LANDING_DUST_SPEED);
+        let direction = Vec3::new(angle.cos(), 0.0, angle.sin());
+        let translation = p_trans.translation + direction * 0.5;
+        commands.spawn((
+            Mesh3d(assets.mesh.clone()),
+            MeshMaterial3d(assets.material.clone()),
+            Transform::from_translation(translation.with_y(LANDING_DUST_SIZE)),
+            NotShadowCaster,
+            NotShadowReceiver,
+            InGameStateRoot,
+            DustParticle {
+                velocity: direction * speed + Vec3::Y * speed * 0.5,
+                scroll: forward_move.get(),
+                remaining: LANDING_DUST_DURATION,
+            },
+        ));
+    }
+}
+
+/// Spreads the dust out and shrinks it away, slowing down as it goes.
+fn update_landing_dust(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Transform, &mut DustParticle)>,
+    time_scale: Res<TimeScale>,
+    time: Res<Time>,
+) {
+    let elapsed = time.delta_secs() * time_scale.get();
+    for (entity, mut transform, mut particle) in query.iter_mut() {
+        particle.remaining -= time.delta_secs();
+        if particle.remaining <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        transform.translation += particle.velocity * time.delta_secs();
+        transform.translation.z -= particle.scroll * elapsed;
+        particle.velocity *= 1.0 - (LANDING_DUST_DRAG * time.delta_secs()).min(1.0);
+        transform.scale = Vec3::splat(particle.remaining / LANDING_DUST_DURATION);
+    }
+}
+
+/// Draws a streak across the lanes the train dashed over at each of the streak heights.
+/// In reduce-motion mode no streaks are drawn.
+fn spawn_air_dash_streaks(
+    mut commands: Commands,
+    mut events: EventReader<AirDashed>,
+    options: Res<GraphicsOptions>,
+    assets: Res<StreakAssets>,
+    query: Query<&ForwardMovement, With<Player>>,
+) {
+    let Ok(forward_move) = query.single() else {
+        return;
+    };
+
+    for dash in events.read() {
+        if options.reduce_motion {
+            continue;
+        }
+
+        let center = dash.from.with_x((dash.from.x + dash.to_x) * 0.5);
+        let length = (dash.to_x - dash.from.x).abs();
+        for height in AIR_DASH_STREAK_HEIGHTS {
+            commands.spawn((
+                Mesh3d(assets.mesh.clone()),
+                MeshMaterial3d(assets.material.clone()),
+                Transform::from_translation(center + Vec3::Y * height).with_scale(Vec3::new(
+                    length,
+                    AIR_DASH_STREAK_THICKNESS,
+                    AIR_DASH_STREAK_THICKNESS,
+                )),
+                NotShadowCaster,
+                NotShadowReceiver,
+                InGameStateRoot,
+                DashStreak {
+                    scroll: forward_move.get(),
+                    remaining: AIR_DASH_STREAK_DURATION,
+                },
+            ));
+        }
+    }
+}
+
+/// Carries the streaks back with the ground and thins them away.
+fn update_air_dash_streaks(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Transform, &mut DashStreak)>,
+    time_scale: Res<TimeScale>,
+    time: Res<Time>,
+) {
+    let elapsed = time.delta_secs() * time_scale.get();
+    for (entity, mut transform, mut streak) in query.iter_mut() {
+        streak.remaining -= time.delta_secs();
+        if streak.remaining <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        transform.translation.z -= streak.scroll * elapsed;
+        let thickness = AIR_DASH_STREAK_THICKNESS * streak.remaining / AIR_DASH_STREAK_DURATION;
+        transform.scale.y = thickness;
+        transform.scale.z = thickness;
+    }
+}
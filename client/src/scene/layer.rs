@@ -0,0 +1,73 @@
+use bevy::{prelude::*, ui::UiSystem};
+
+// --- PLUGIN ---
+
+/// Stacks the UI nodes by the [`UiLayer`] they belong to,
+/// so overlays from different scenes never fight over the same z-index.
+pub struct UiLayerPlugin;
+
+impl Plugin for UiLayerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PostUpdate, assign_ui_layer.before(UiSystem::Stack));
+    }
+}
+
+// --- COMPONENTS ---
+
+/// The layer a UI node is drawn on. Later layers are drawn on top of earlier ones.
+///
+/// A root node is stacked against every other root node with a [`GlobalZIndex`],
+/// while a child node is only stacked against its siblings with a [`ZIndex`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Component)]
+pub enum UiLayer {
+    /// The world rendered offscreen by the in-game camera, below every other node.
+    Backdrop,
+    /// Screen-space effects drawn over the 3D world, below everything else.
+    World,
+    /// Scores, gauges, buttons and menus that make up a scene.
+    Hud,
+    /// Short-lived labels and captions shown over the HUD.
+    Overlay,
+    /// Dialogs that block the scene behind them.
+    Modal,
+    /// Notifications shown over every scene and dialog.
+    Toast,
+    /// The screen cover played while the game state changes.
+    Transition,
+    /// Developer tools, drawn above everything.
+    #[cfg_attr(feature = "no-debuging-budget", allow(dead_code))]
+    Debug,
+}
+
+impl UiLayer {
+    /// Returns the [`GlobalZIndex`] or [`ZIndex`] of the layer.
+    /// The values are spaced apart so a layer can be split later without renumbering the others.
+    pub const fn z_index(self) -> i32 {
+        match self {
+            Self::Backdrop => -10,
+            Self::World => 0,
+            Self::Hud => 10,
+            Self::Overlay => 20,
+            Self::Modal => 30,
+            Self::Toast => 35,
+            Self::Transition => 40,
+            Self::Debug => 50,
+        }
+    }
+}
+
+// --- POSTUPDATE SYSTEMS ---
+
+fn assign_ui_layer(
+    mut commands: Commands,
+    query: Query<(Entity, &UiLayer, Has<ChildOf>), Changed<UiLayer>>,
+) {
+    for (entity, layer, is_child) in query.iter() {
+        match is_child {
+            true => commands.entity(entity).insert(ZIndex(layer.z_index())),
+            false => commands
+                .entity(entity)
+                .insert(GlobalZIndex(layer.z_index())),
+        };
+    }
+}
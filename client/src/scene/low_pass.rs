@@ -0,0 +1,160 @@
+use std::{
+    f32::consts::TAU,
+    sync::{
+        Arc,
+        atomic::{AtomicU32, Ordering},
+    },
+    time::Duration,
+};
+
+use bevy::{
+    audio::{AddAudioSource, AudioSource, Decodable, Sample, Source},
+    prelude::*,
+};
+
+// --- PLUGIN ---
+
+/// Muffles sounds with a low-pass filter applied while decoding, as the native audio backend has no filters.
+/// The filter is a one-pole filter, gentler than the biquad filter node that muffles sounds on the web.
+pub struct LowPassPlugin;
+
+impl Plugin for LowPassPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_audio_source::<LowPassAudio>().add_systems(
+            PostUpdate,
+            (start_low_pass_audio, sync_low_pass_control).chain(),
+        );
+    }
+}
+
+// --- COMPONENTS ---
+
+/// The cutoff frequency of the low-pass filter on a sound, in hertz, or `None` to play it unfiltered.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Component)]
+pub struct LowPass(pub Option<f32>);
+
+/// Plays a sound that can be muffled with [`LowPass`].
+/// It turns into an [`AudioPlayer`] once the sound is loaded.
+#[derive(Component)]
+#[require(LowPass, PlaybackSettings)]
+pub struct LowPassAudioPlayer(pub Handle<AudioSource>);
+
+impl LowPassAudioPlayer {
+    pub fn new(source: Handle<AudioSource>) -> Self {
+        Self(source)
+    }
+}
+
+/// Shares the cutoff frequency of a playing sound with its decoder. Zero lets the sound through unfiltered.
+#[derive(Component)]
+pub struct LowPassControl(Arc<AtomicU32>);
+
+// --- ASSETS ---
+
+/// A sound decoded through an adjustable low-pass filter.
+#[derive(Asset, TypePath)]
+pub struct LowPassAudio {
+    source: AudioSource,
+    cutoff: Arc<AtomicU32>,
+}
+
+impl Decodable for LowPassAudio {
+    type DecoderItem = f32;
+    type Decoder = LowPassDecoder;
+
+    fn decoder(&self) -> Self::Decoder {
+        LowPassDecoder {
+            inner: self.source.decoder(),
+            cutoff: self.cutoff.clone(),
+            outputs: Vec::new(),
+            channel: 0,
+        }
+    }
+}
+
+pub struct LowPassDecoder {
+    inner: <AudioSource as Decodable>::Decoder,
+    cutoff: Arc<AtomicU32>,
+    /// The last output of the filter on each channel.
+    outputs: Vec<f32>,
+    channel: usize,
+}
+
+impl Iterator for LowPassDecoder {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let sample = self.inner.next()?.to_f32();
+        let channels = self.inner.channels().max(1) as usize;
+        if self.outputs.len() != channels {
+            self.outputs = vec![0.0; channels];
+            self.channel = 0;
+        }
+        let channel = self.channel;
+        self.channel = (channel + 1) % channels;
+
+        let cutoff = f32::from_bits(self.cutoff.load(Ordering::Relaxed));
+        let output = &mut self.outputs[channel];
+        *output = match cutoff > 0.0 {
+            true => {
+                let alpha = 1.0 - (-TAU * cutoff / self.inner.sample_rate() as f32).exp();
+                *output + alpha * (sample - *output)
+            }
+            false => sample,
+        };
+        Some(*output)
+    }
+}
+
+impl Source for LowPassDecoder {
+    fn current_frame_len(&self) -> Option<usize> {
+        self.inner.current_frame_len()
+    }
+
+    fn channels(&self) -> u16 {
+        self.inner.channels()
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.inner.sample_rate()
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        self.inner.total_duration()
+    }
+}
+
+// --- POSTUPDATE SYSTEMS ---
+
+fn start_low_pass_audio(
+    mut commands: Commands,
+    mut low_pass_assets: ResMut<Assets<LowPassAudio>>,
+    sources: Res<Assets<AudioSource>>,
+    query: Query<(Entity, &LowPassAudioPlayer, &LowPass)>,
+) {
+    for (entity, player, low_pass) in query.iter() {
+        let Some(source) = sources.get(&player.0) else {
+            continue;
+        };
+
+        let control = Arc::new(AtomicU32::new(cutoff_bits(low_pass)));
+        let audio = low_pass_assets.add(LowPassAudio {
+            source: source.clone(),
+            cutoff: control.clone(),
+        });
+        commands
+            .entity(entity)
+            .remove::<LowPassAudioPlayer>()
+            .insert((AudioPlayer(audio), LowPassControl(control)));
+    }
+}
+
+fn sync_low_pass_control(query: Query<(&LowPass, &LowPassControl), Changed<LowPass>>) {
+    for (low_pass, control) in query.iter() {
+        control.0.store(cutoff_bits(low_pass), Ordering::Relaxed);
+    }
+}
+
+fn cutoff_bits(low_pass: &LowPass) -> u32 {
+    low_pass.0.unwrap_or(0.0).to_bits()
+}
@@ -0,0 +1,285 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use crate::asset::material::EyeMouthMaterial;
+
+use super::*;
+
+// --- PLUGIN ---
+
+/// Tints the materials of the models under an entity with a [`MaterialEffect`].
+///
+/// The materials are copied for the entity the first time an effect is added to it,
+/// so that an effect never shows on the other models sharing them.
+/// An entity under another with an effect keeps its own effect, if it has one.
+pub struct MaterialEffectPlugin;
+
+impl Plugin for MaterialEffectPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            (
+                cache_effect_materials,
+                reset_material_effects,
+                apply_material_effects,
+            )
+                .chain(),
+        );
+    }
+}
+
+// --- COMPONENTS ---
+
+/// How an effect tints the materials.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MaterialEffectKind {
+    /// Flashes between black and white, `cycle` radians a second.
+    Flash { cycle: f32 },
+    /// Cycles through the colors, faster and faster until `duration` seconds have passed.
+    Rainbow { duration: f32 },
+    /// Fades the models out over `duration` seconds.
+    Fade { duration: f32 },
+    /// Draws the models in a single color.
+    Tint(Color),
+}
+
+/// An effect on the materials of the models under the entity, and the time since it was added.
+/// Removing the effect gives the materials back their own colors.
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+pub struct MaterialEffect {
+    kind: MaterialEffectKind,
+    elapsed: f32,
+}
+
+impl MaterialEffect {
+    pub fn new(kind: MaterialEffectKind) -> Self {
+        Self { kind, elapsed: 0.0 }
+    }
+
+    pub fn kind(&self) -> MaterialEffectKind {
+        self.kind
+    }
+
+    /// The color of a material whose own color is `base`.
+    fn color(&self, base: Color) -> Color {
+        match self.kind {
+            MaterialEffectKind::Flash { cycle } => {
+                let fill = 0.5 * (self.elapsed * cycle).cos() + 0.5;
+                Color::srgba(fill, fill, fill, base.alpha())
+            }
+            MaterialEffectKind::Rainbow { duration } => {
+                let t = (self.elapsed / duration).min(1.0);
+                let cycle =
+                    MIN_INVINCIBLE_EFFECT_CYCLE * (1.0 - t) + MAX_INVINCIBLE_EFFECT_CYCLE * t;
+                let red = 0.5 * (t * cycle).sin() + 0.5;
+                let green = 0.5 * (TAU / 3.0 * t * cycle).sin() + 0.5;
+                let blue = 0.5 * (2.0 * TAU / 3.0 * t * cycle).sin() + 0.5;
+                Color::srgba(red, green, blue, base.alpha())
+            }
+            MaterialEffectKind::Fade { duration } => {
+                let t = (self.elapsed / duration).min(1.0);
+                base.with_alpha(base.alpha() * (1.0 - t))
+            }
+            MaterialEffectKind::Tint(color) => color,
+        }
+    }
+
+    /// Checks if the effect changes with time, rather than only when it is set.
+    fn is_animated(&self) -> bool {
+        !matches!(self.kind, MaterialEffectKind::Tint(_))
+    }
+}
+
+/// A material copied for an entity with an effect, and how it was drawn before any effect.
+struct EffectMaterial<M: Asset> {
+    handle: Handle<M>,
+    base_color: Color,
+    alpha_mode: AlphaMode,
+}
+
+/// The materials of the models under an entity with an effect, gathered once.
+/// The list is gathered again when the hierarchy or the materials under the entity change.
+#[derive(Component)]
+struct EffectMaterials {
+    standard: Vec<EffectMaterial<StandardMaterial>>,
+    extended: Vec<EffectMaterial<EyeMouthMaterial>>,
+}
+
+impl EffectMaterials {
+    /// Tints the materials for the effect, or gives them back their own colors.
+    fn apply(
+        &self,
+        effect: Option<&MaterialEffect>,
+        standard_materials: &mut Assets<StandardMaterial>,
+        extended_materials: &mut Assets<EyeMouthMaterial>,
+    ) {
+        let fades = effect.is_some_and(|e| matches!(e.kind, MaterialEffectKind::Fade { .. }));
+        for cached in self.standard.iter() {
+            if let Some(material) = standard_materials.get_mut(cached.handle.id()) {
+                material.base_color =
+                    effect.map_or(cached.base_color, |e| e.color(cached.base_color));
+                material.alpha_mode = match fades {
+                    true => AlphaMode::Blend,
+                    false => cached.alpha_mode,
+                };
+            }
+        }
+        for cached in self.extended.iter() {
+            if let Some(material) = extended_materials.get_mut(cached.handle.id()) {
+                material.base.base_color =
+                    effect.map_or(cached.base_color, |e| e.color(cached.base_color));
+                material.base.alpha_mode = match fades {
+                    true => AlphaMode::Blend,
+                    false => cached.alpha_mode,
+                };
+            }
+        }
+    }
+}
+
+// --- POSTUPDATE SYSTEMS ---
+
+/// Gathers the materials under the entities given an effect, and under those whose models changed this frame.
+#[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments)]
+fn cache_effect_materials(
+    mut commands: Commands,
+    root_query: Query<Option<&EffectMaterials>, With<MaterialEffect>>,
+    added_query: Query<Entity, Added<MaterialEffect>>,
+    changed_query: Query<
+        Entity,
+        Or<(
+            Changed<Children>,
+            Changed<MeshMaterial3d<StandardMaterial>>,
+            Changed<MeshMaterial3d<EyeMouthMaterial>>,
+        )>,
+    >,
+    parent_query: Query<&ChildOf>,
+    children_query: Query<&Children>,
+    base_color_query: Query<&BaseColor>,
+    mesh_query: Query<(
+        Option<&MeshMaterial3d<StandardMaterial>>,
+        Option<&MeshMaterial3d<EyeMouthMaterial>>,
+    )>,
+    mut standard_materials: ResMut<Assets<StandardMaterial>>,
+    mut extended_materials: ResMut<Assets<EyeMouthMaterial>>,
+) {
+    let mut dirty: Vec<Entity> = added_query.iter().collect();
+    for entity in changed_query.iter() {
+        if let Some(root) = std::iter::once(entity)
+            .chain(parent_query.iter_ancestors(entity))
+            .find(|&ancestor| root_query.contains(ancestor))
+            && !dirty.contains(&root)
+        {
+            dirty.push(root);
+        }
+    }
+
+    for root in dirty {
+        let Ok(previous) = root_query.get(root) else {
+            continue;
+        };
+
+        let mut materials = EffectMaterials {
+            standard: Vec::new(),
+            extended: Vec::new(),
+        };
+        let mut stack = vec![root];
+        while let Some(entity) = stack.pop() {
+            if entity != root && root_query.contains(entity) {
+                continue;
+            }
+            if let Ok(children) = children_query.get(entity) {
+                stack.extend(children.iter());
+            }
+
+            let Ok((standard, extended)) = mesh_query.get(entity) else {
+                continue;
+            };
+            let base_color = base_color_query.get(entity).map(|c| c.0).ok();
+            if let Some(handle) = standard {
+                let reused = previous.and_then(|p| {
+                    p.standard
+                        .iter()
+                        .find(|cached| cached.handle.id() == handle.id())
+                });
+                if let Some(cached) = reused {
+                    materials.standard.push(EffectMaterial {
+                        handle: cached.handle.clone(),
+                        ..*cached
+                    });
+                } else if let Some(material) = standard_materials.get(handle.id()).cloned() {
+                    let cached = EffectMaterial {
+                        base_color: base_color.unwrap_or(material.base_color),
+                        alpha_mode: material.alpha_mode,
+                        handle: standard_materials.add(material),
+                    };
+                    commands
+                        .entity(entity)
+                        .insert(MeshMaterial3d(cached.handle.clone()));
+                    materials.standard.push(cached);
+                }
+            }
+            if let Some(handle) = extended {
+                let reused = previous.and_then(|p| {
+                    p.extended
+                        .iter()
+                        .find(|cached| cached.handle.id() == handle.id())
+                });
+                if let Some(cached) = reused {
+                    materials.extended.push(EffectMaterial {
+                        handle: cached.handle.clone(),
+                        ..*cached
+                    });
+                } else if let Some(material) = extended_materials.get(handle.id()).cloned() {
+                    let cached = EffectMaterial {
+                        base_color: base_color.unwrap_or(material.base.base_color),
+                        alpha_mode: material.base.alpha_mode,
+                        handle: extended_materials.add(material),
+                    };
+                    commands
+                        .entity(entity)
+                        .insert(MeshMaterial3d(cached.handle.clone()));
+                    materials.extended.push(cached);
+                }
+            }
+        }
+        commands.entity(root).insert(materials);
+    }
+}
+
+/// Gives the materials back their own colors when the effect is removed.
+fn reset_material_effects(
+    mut removed: RemovedComponents<MaterialEffect>,
+    query: Query<&EffectMaterials, Without<MaterialEffect>>,
+    mut standard_materials: ResMut<Assets<StandardMaterial>>,
+    mut extended_materials: ResMut<Assets<EyeMouthMaterial>>,
+) {
+    for entity in removed.read() {
+        if let Ok(materials) = query.get(entity) {
+            materials.apply(None, &mut standard_materials, &mut extended_materials);
+        }
+    }
+}
+
+/// Advances the effects and tints the materials, skipping the still effects that have not changed.
+fn apply_material_effects(
+    mut query: Query<(&mut MaterialEffect, Ref<EffectMaterials>)>,
+    mut standard_materials: ResMut<Assets<StandardMaterial>>,
+    mut extended_materials: ResMut<Assets<EyeMouthMaterial>>,
+    time: Res<Time>,
+) {
+    for (mut effect, materials) in query.iter_mut() {
+        if effect.is_animated() {
+            effect.elapsed += time.delta_secs();
+        } else if !effect.is_changed() && !materials.is_changed() {
+            continue;
+        }
+        materials.apply(
+            Some(&effect),
+            &mut standard_materials,
+            &mut extended_materials,
+        );
+    }
+}
@@ -0,0 +1,253 @@
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::{Map, Value};
+
+/// The key of the schema version in every stored object.
+const VERSION_KEY: &str = "version";
+
+/// The settings and save data stored by the game, each with the migrations from its older versions.
+/// A new version is made by adding its migration at the end of the list, and never by changing an old one.
+/// A schema that holds another, such as the save profile, gets a new version whenever the one it holds does.
+#[cfg(any(target_arch = "wasm32", test))]
+pub const SYSTEM_VOLUME_SCHEMA: SaveSchema = SaveSchema::new("system volume", &[unversioned]);
+#[cfg(any(target_arch = "wasm32", test))]
+pub const GRAPHICS_OPTIONS_SCHEMA: SaveSchema = SaveSchema::new("graphics options", &[unversioned]);
+#[cfg(any(target_arch = "wasm32", test))]
+pub const TRAIN_UPGRADES_SCHEMA: SaveSchema = SaveSchema::new("train upgrades", &[unversioned]);
+#[cfg(any(target_arch = "wasm32", test))]
+pub const BEST_PACE_SCHEMA: SaveSchema = SaveSchema::new("best pace", &[unversioned]);
+#[cfg(any(target_arch = "wasm32", test))]
+pub const RUN_HISTORY_SCHEMA: SaveSchema = SaveSchema::new("run history", &[unversioned]);
+pub const SAVE_PROFILE_SCHEMA: SaveSchema = SaveSchema::new("save profile", &[unversioned]);
+
+/// Upgrades a stored object from one version to the next, before it is read into its type.
+pub type Migration = fn(&mut Map<String, Value>);
+
+/// The versions a kind of stored data has gone through.
+/// Version 0 is the data stored before versions were recorded, and every migration adds one version.
+pub struct SaveSchema {
+    name: &'static str,
+    migrations: &'static [Migration],
+}
+
+impl SaveSchema {
+    pub const fn new(name: &'static str, migrations: &'static [Migration]) -> Self {
+        Self { name, migrations }
+    }
+
+    /// The version data is stored at now.
+    pub const fn version(&self) -> u32 {
+        self.migrations.len() as u32
+    }
+
+    /// Stores the data as a JSON object, tagged with the current version.
+    pub fn encode<T: Serialize>(&self, data: &T) -> Result<String, SaveSchemaError> {
+        let mut object = match serde_json::to_value(data)? {
+            Value::Object(object) => object,
+            _ => return Err(SaveSchemaError::NotAnObject(self.name)),
+        };
+        object.insert(VERSION_KEY.to_string(), Value::from(self.version()));
+        Ok(serde_json::to_string(&object)?)
+    }
+
+    /// Reads stored data of any version up to the current one, migrating it on the way.
+    pub fn decode<T: DeserializeOwned>(
+        &self,
+        json: impl AsRef<[u8]>,
+    ) -> Result<T, SaveSchemaError> {
+        let mut object = match serde_json::from_slice(json.as_ref())? {
+            Value::Object(object) => object,
+            _ => return Err(SaveSchemaError::NotAnObject(self.name)),
+        };
+
+        let version = match object.remove(VERSION_KEY) {
+            Some(value) => value
+                .as_u64()
+                .and_then(|version| u32::try_from(version).ok())
+                .ok_or(SaveSchemaError::InvalidVersion(self.name))?,
+            None => 0,
+        };
+        if version > self.version() {
+            return Err(SaveSchemaError::Newer {
+                name: self.name,
+                version,
+            });
+        }
+
+        for migrate in &self.migrations[version as usize..] {
+            migrate(&mut object);
+        }
+        Ok(serde_json::from_value(Value::Object(object))?)
+    }
+}
+
+/// The reason stored data could not be read.
+#[derive(Debug, thiserror::Error)]
+pub enum SaveSchemaError {
+    #[error("The stored {0} is not a versioned object")]
+    NotAnObject(&'static str),
+    #[error("The stored {0} has an invalid version")]
+    InvalidVersion(&'static str),
+    #[error("The stored {name} was made by a newer version of the game ({version})")]
+    Newer { name: &'static str, version: u32 },
+    #[error("The stored data is invalid: {0}")]
+    Invalid(#[from] serde_json::Error),
+}
+
+/// Reads the data stored before versions were recorded as the first version.
+/// The fields added until then have defaults, so the data needs no change.
+fn unversioned(_: &mut Map<String, Value>) {}
+
+#[cfg(test)]
+mod tests {
+    use crate::asset::sound::SystemVolume;
+
+    use super::super::*;
+    use super::*;
+
+    /// A schema that went through a renamed field and a field that changed its unit.
+    const TEST_SCHEMA: SaveSchema =
+        SaveSchema::new("test", &[unversioned, rename_speed, to_percent]);
+
+    #[derive(Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct TestData {
+        name: String,
+        speed_percent: u32,
+    }
+
+    fn rename_speed(object: &mut Map<String, Value>) {
+        if let Some(speed) = object.remove("velocity") {
+            object.insert("speed".to_string(), speed);
+        }
+    }
+
+    fn to_percent(object: &mut Map<String, Value>) {
+        if let Some(speed) = object.remove("speed").and_then(|speed| speed.as_f64()) {
+            object.insert(
+                "speed_percent".to_string(),
+                Value::from((speed * 100.0).round() as u32),
+            );
+        }
+    }
+
+    #[test]
+    fn migrations_apply_in_order_from_the_stored_version() {
+        let expected = TestData {
+            name: "shupogaki".to_string(),
+            speed_percent: 75,
+        };
+        for json in [
+            r#"{ "name": "shupogaki", "velocity": 0.75 }"#,
+            r#"{ "version": 1, "name": "shupogaki", "velocity": 0.75 }"#,
+            r#"{ "version": 2, "name": "shupogaki", "speed": 0.75 }"#,
+            r#"{ "version": 3, "name": "shupogaki", "speed_percent": 75 }"#,
+        ] {
+            assert_eq!(TEST_SCHEMA.decode::<TestData>(json).unwrap(), expected);
+        }
+
+        let encoded = TEST_SCHEMA.encode(&expected).unwrap();
+        assert!(encoded.contains(r#""version":3"#));
+        assert_eq!(TEST_SCHEMA.decode::<TestData>(encoded).unwrap(), expected);
+    }
+
+    #[test]
+    fn newer_and_malformed_data_is_refused() {
+        assert!(matches!(
+            TEST_SCHEMA.decode::<TestData>(r#"{ "version": 4, "name": "x", "speed_percent": 1 }"#),
+            Err(SaveSchemaError::Newer { version: 4, .. })
+        ));
+        assert!(matches!(
+            TEST_SCHEMA.decode::<TestData>("[1, 2, 3]"),
+            Err(SaveSchemaError::NotAnObject(_))
+        ));
+        assert!(matches!(
+            TEST_SCHEMA.decode::<TestData>(r#"{ "version": "3" }"#),
+            Err(SaveSchemaError::InvalidVersion(_))
+        ));
+        assert!(matches!(
+            TEST_SCHEMA.decode::<TestData>("{ not json"),
+            Err(SaveSchemaError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn system_volume_loads_from_every_version() {
+        let oldest: SystemVolume = SYSTEM_VOLUME_SCHEMA
+            .decode(include_str!("../../fixtures/saves/system_volume_v0.json"))
+            .unwrap();
+        assert_eq!(
+            (oldest.background, oldest.effect, oldest.voice),
+            (100, 150, 200)
+        );
+        assert_eq!(oldest.master, 255);
+        assert!(!oldest.background_muted && !oldest.effect_muted && !oldest.voice_muted);
+
+        let muted: SystemVolume = SYSTEM_VOLUME_SCHEMA
+            .decode(include_str!(
+                "../../fixtures/saves/system_volume_v0_muted.json"
+            ))
+            .unwrap();
+        assert_eq!(muted.master, 128);
+        assert!(muted.effect_muted);
+        assert_eq!(muted.rolloff.max_distance, 64.0);
+
+        let current: SystemVolume = SYSTEM_VOLUME_SCHEMA
+            .decode(include_str!("../../fixtures/saves/system_volume_v1.json"))
+            .unwrap();
+        assert_eq!(current.master, 204);
+        assert!(current.voice_muted);
+    }
+
+    #[test]
+    fn graphics_options_load_from_every_version() {
+        let oldest: GraphicsOptions = GRAPHICS_OPTIONS_SCHEMA
+            .decode(include_str!(
+                "../../fixtures/saves/graphics_options_v0.json"
+            ))
+            .unwrap();
+        assert!(!oldest.speed_lines && oldest.reduce_motion);
+        assert!(!oldest.captions && !oldest.pace_clock);
+        assert_eq!(oldest.camera_preset, CameraPreset::Classic);
+        assert_eq!(oldest.fog_mode, FogMode::Linear);
+
+        let current: GraphicsOptions = GRAPHICS_OPTIONS_SCHEMA
+            .decode(include_str!(
+                "../../fixtures/saves/graphics_options_v1.json"
+            ))
+            .unwrap();
+        assert!(current.captions && current.atmospheric_scattering);
+        assert_eq!(current.camera_preset, CameraPreset::LowChase);
+        assert_eq!(current.render_scale_limit, RenderScaleLimit::Half);
+        assert_eq!(current.device_tier, DeviceTier::Mobile);
+        assert_eq!(current.fog_mode, FogMode::Exponential);
+    }
+
+    #[test]
+    fn progress_loads_from_every_version() {
+        let upgrades: TrainUpgrades = TRAIN_UPGRADES_SCHEMA
+            .decode(include_str!("../../fixtures/saves/train_upgrades_v0.json"))
+            .unwrap();
+        assert_eq!((upgrades.bells, upgrades.extra_cars), (340, 2));
+
+        let pace: BestPace = BEST_PACE_SCHEMA
+            .decode(include_str!("../../fixtures/saves/best_pace_v0.json"))
+            .unwrap();
+        assert_eq!(pace.splits, vec![9_800, 19_100, 28_050]);
+
+        let history: RunHistory = RUN_HISTORY_SCHEMA
+            .decode(include_str!("../../fixtures/saves/run_history_v0.json"))
+            .unwrap();
+        assert_eq!(history.previous.map(|run| run.score), Some(4_210));
+        assert_eq!(history.best.map(|run| run.distance), Some(2_875));
+
+        for schema in [
+            &TRAIN_UPGRADES_SCHEMA,
+            &BEST_PACE_SCHEMA,
+            &RUN_HISTORY_SCHEMA,
+        ] {
+            assert_eq!(schema.version(), 1);
+        }
+        let stored = RUN_HISTORY_SCHEMA.encode(&history).unwrap();
+        let reloaded: RunHistory = RUN_HISTORY_SCHEMA.decode(stored).unwrap();
+        assert_eq!(reloaded.best, history.best);
+    }
+}
@@ -0,0 +1,59 @@
+use bevy::prelude::*;
+
+use super::*;
+
+// --- PLUGIN ---
+
+/// Shows the world mirrored left to right while the mirror lanes mutator is active.
+///
+/// The in-game camera is rendered offscreen, and the image is drawn flipped behind the UI,
+/// so the models keep their winding order and the UI stays readable.
+pub struct MirrorViewPlugin;
+
+impl Plugin for MirrorViewPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            (
+                mirror_in_game_camera.before(OffscreenViewSystems),
+                flip_mirrored_backdrop.after(OffscreenViewSystems),
+            ),
+        );
+    }
+}
+
+// --- COMPONENTS ---
+
+/// Marks a camera whose view is shown mirrored.
+#[derive(Component)]
+pub struct MirroredView;
+
+// --- POSTUPDATE SYSTEMS ---
+
+fn mirror_in_game_camera(
+    mut commands: Commands,
+    config: Res<GameplayConfig>,
+    query: Query<Entity, Added<CameraRig>>,
+) {
+    if !config.mutators.is_active(Mutator::MirrorLanes) {
+        return;
+    }
+
+    for entity in query.iter() {
+        info!("Mirror the in-game camera");
+        commands
+            .entity(entity)
+            .insert((MirroredView, RenderOffscreen));
+    }
+}
+
+fn flip_mirrored_backdrop(
+    query: Query<&OffscreenView, (With<MirroredView>, Added<OffscreenView>)>,
+    mut backdrop_query: Query<&mut ImageNode>,
+) {
+    for view in query.iter() {
+        if let Ok(mut node) = backdrop_query.get_mut(view.backdrop()) {
+            node.flip_x = true;
+        }
+    }
+}
@@ -1,9 +1,42 @@
 mod assets;
+mod attenuation;
+#[cfg(not(target_arch = "wasm32"))]
+mod audio_device;
+mod banner;
+mod chain;
 mod constants;
+mod cutscene;
+mod fast_restart;
+mod focus;
+mod fog;
+mod format;
+mod icon;
+mod jump_feedback;
+mod layer;
+#[cfg(not(target_arch = "wasm32"))]
+mod low_pass;
+mod material_effect;
+mod migration;
+mod mirror;
+mod mod_loader;
+mod offscreen;
+mod profile;
+mod render_scale;
 mod resources;
+mod rhythm;
+#[cfg(not(feature = "no-debuging-scrubber"))]
+mod scrubber;
+mod slider;
+mod sound_pan;
+mod sound_variation;
 mod system;
+mod toast;
+mod transition;
 mod types;
+mod ui_sound;
 mod utils;
+mod voice;
+mod world_view;
 
 mod in_game;
 mod initialize;
@@ -15,8 +48,21 @@ mod title;
 
 use bevy::prelude::*;
 
+#[cfg(not(target_arch = "wasm32"))]
+pub use self::audio_device::*;
+#[cfg(not(target_arch = "wasm32"))]
+pub use self::low_pass::*;
+#[cfg(not(feature = "no-debuging-scrubber"))]
+pub use self::scrubber::*;
 #[allow(unused_imports)]
-pub use self::{assets::*, constants::*, resources::*, system::*, types::*, utils::*};
+pub use self::{
+    assets::*, attenuation::*, banner::*, chain::*, constants::*, cutscene::*, fast_restart::*,
+    focus::*, fog::*, format::*, icon::*, jump_feedback::*, layer::*, material_effect::*,
+    migration::*, mirror::*, mod_loader::*, offscreen::*, profile::*, render_scale::*,
+    resources::*, rhythm::*, slider::*, sound_pan::*, sound_variation::*, system::*, toast::*,
+    transition::*, types::*, ui_sound::*, utils::*, voice::*, world_view::*,
+};
+pub use shupogaki_core::*;
 
 // --- PLUGIN ---
 
@@ -31,7 +77,51 @@ impl Plugin for StatePlugin {
             .add_plugins(title::StatePlugin)
             .add_plugins(in_game::StatePlugin)
             .add_plugins(result::StatePlugin)
-            .add_systems(Update, (initialize_font_size, update_font_size));
+            .add_plugins(SliderPlugin)
+            .add_plugins(FocusPlugin)
+            .add_plugins(UiSoundPlugin)
+            .add_plugins(VoicePlugin)
+            .add_plugins(RhythmPlugin)
+            .add_plugins(TransitionPlugin)
+            .add_plugins(UiLayerPlugin)
+            .add_plugins(SoundPanPlugin)
+            .add_plugins(AttenuationPlugin)
+            .add_plugins(FastRestartPlugin)
+            .add_plugins(OffscreenViewPlugin)
+            .add_plugins(MirrorViewPlugin)
+            .add_plugins(RenderScalePlugin)
+            .add_plugins(WorldViewPlugin)
+            .add_plugins(FogPlugin)
+            .add_plugins(JumpFeedbackPlugin)
+            .add_plugins(ModLoaderPlugin)
+            .add_plugins(BannerPlugin)
+            .add_plugins(IconPlugin)
+            .add_plugins(ToastPlugin)
+            .add_plugins(MaterialEffectPlugin)
+            .init_resource::<GameplayConfig>()
+            .init_resource::<Mutators>()
+            .add_systems(Update, (initialize_font_size, update_font_size))
+            .add_systems(
+                Update,
+                reset_loading_progress.run_if(state_changed::<GameState>),
+            )
+            .add_systems(PostUpdate, update_loading_bar_width);
+
+        #[cfg(not(target_arch = "wasm32"))]
+        app.add_plugins(AudioDevicePlugin)
+            .add_plugins(LowPassPlugin);
+
+        #[cfg(not(feature = "no-debuging-scrubber"))]
+        app.add_plugins(CutsceneScrubberPlugin);
+
+        #[cfg(target_arch = "wasm32")]
+        app.add_systems(
+            Update,
+            (
+                save_train_upgrades.run_if(resource_exists_and_changed::<TrainUpgrades>),
+                save_assist_mode.run_if(resource_exists_and_changed::<AssistMode>),
+            ),
+        );
     }
 }
 
@@ -53,7 +143,6 @@ pub enum GameState {
     LoadInGame,
     InitInGame,
     ExitInGame,
-    InitResult,
     PrepareInGame,
     StartInGame,
     InGame,
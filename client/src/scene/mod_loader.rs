@@ -0,0 +1,542 @@
+#[cfg(not(target_arch = "wasm32"))]
+use std::{fs, path::Path};
+
+#[cfg(not(target_arch = "wasm32"))]
+use bevy::asset::io::file::FileAssetReader;
+use bevy::{
+    asset::{AssetPath, LoadState},
+    platform::collections::HashMap,
+    prelude::*,
+};
+use shupogaki_core::collider::Collider;
+
+use crate::asset::{
+    content_pack::{
+        CONTENT_PACK_SOURCE, ContentPack, ContentPackIndex, ContentPackIndexLoader,
+        ContentPackLoader,
+    },
+    locale::{Locale, LocalizationAssets, LocalizationData},
+    model::ModelAsset,
+};
+
+use super::*;
+
+/// The list of pack files the web build loads from the content pack source.
+#[cfg(target_arch = "wasm32")]
+const CONTENT_PACK_INDEX_PATH: &str = "index.packs";
+
+// --- PLUGIN ---
+
+/// Loads the content packs found at startup and registers what they add, in load order.
+/// Packs that fail to load and entries that conflict with an earlier pack are reported in the log.
+pub struct ModLoaderPlugin;
+
+impl Plugin for ModLoaderPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<ContentPack>()
+            .init_asset::<ContentPackIndex>()
+            .register_asset_loader(ContentPackLoader)
+            .register_asset_loader(ContentPackIndexLoader)
+            .init_resource::<ContentRegistry>()
+            .init_resource::<ContentPackQueue>()
+            .add_systems(Startup, discover_content_packs)
+            .add_systems(
+                Update,
+                register_content_packs.run_if(resource_exists::<ContentPackQueue>),
+            );
+    }
+}
+
+// --- RESOURCES ---
+
+/// The content packs still being loaded. Removed once they have been registered.
+#[derive(Default, Resource)]
+pub struct ContentPackQueue {
+    index: Option<Handle<ContentPackIndex>>,
+    packs: Vec<Handle<ContentPack>>,
+}
+
+/// Everything the registered content packs add to the game.
+#[derive(Default, Resource)]
+pub struct ContentRegistry {
+    /// The ids of the registered packs, in load order.
+    pub packs: Vec<String>,
+    pub obstacles: Vec<RegisteredObstacle>,
+    /// The model that replaces the model of a built-in object.
+    pub skins: HashMap<Object, String>,
+    pub strings: HashMap<Locale, HashMap<String, String>>,
+    /// Keeps the models of the packs loaded, so they are ready when a run starts.
+    models: Vec<Handle<ModelAsset>>,
+}
+
+/// An obstacle added by a content pack, with the spawn patterns that place it.
+#[derive(Debug, Clone)]
+pub struct RegisteredObstacle {
+    pub id: String,
+    pub base: Object,
+    /// The asset path of the model, resolved against the pack file.
+    pub model: String,
+    pub collider: Collider,
+    pub weight: u32,
+    /// The lanes the obstacle is placed in together, and how often each set is picked.
+    pub patterns: Vec<(Vec<usize>, u32)>,
+}
+
+/// A problem found while registering the content packs.
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum ContentPackIssue {
+    #[error("Content pack {pack} was skipped, as a pack with the same id was registered first")]
+    DuplicatePack { pack: String },
+    #[error("Obstacle {id} of {pack} replaces the one of {previous}")]
+    Obstacle {
+        id: String,
+        pack: String,
+        previous: String,
+    },
+    #[error("Pattern {id} of {pack} replaces the one of {previous}")]
+    Pattern {
+        id: String,
+        pack: String,
+        previous: String,
+    },
+    #[error("Skin of {object:?} in {pack} replaces the one of {previous}")]
+    Skin {
+        object: Object,
+        pack: String,
+        previous: String,
+    },
+    #[error("String {key} ({locale}) of {pack} replaces the one of {previous}")]
+    String {
+        locale: Locale,
+        key: String,
+        pack: String,
+        previous: String,
+    },
+    #[error("Content pack {pack} has an invalid entry: {reason}")]
+    Invalid { pack: String, reason: String },
+}
+
+impl ContentRegistry {
+    /// Registers the given packs, sorted by their load order and then by their id.
+    /// When two packs add the same entry, the later one wins and the conflict is returned with any invalid entries.
+    pub fn register(packs: &[(AssetPath<'static>, &ContentPack)]) -> (Self, Vec<ContentPackIssue>) {
+        let mut packs: Vec<_> = packs.iter().collect();
+        packs.sort_by(|(_, a), (_, b)| (a.load_order, &a.id).cmp(&(b.load_order, &b.id)));
+
+        let mut registry = Self::default();
+        let mut issues = Vec::new();
+        let mut obstacles: Vec<(String, RegisteredObstacle)> = Vec::new();
+        let mut patterns: Vec<(String, &str, &str, Vec<usize>, u32)> = Vec::new();
+        let mut skins: HashMap<Object, String> = HashMap::default();
+        let mut strings: HashMap<(Locale, &str), String> = HashMap::default();
+
+        for (path, pack) in packs {
+            if registry.packs.contains(&pack.id) {
+                issues.push(ContentPackIssue::DuplicatePack {
+                    pack: pack.id.clone(),
+                });
+                continue;
+            }
+            info!("Registering content pack: {} ({})", pack.name, pack.id);
+            registry.packs.push(pack.id.clone());
+            let invalid = |reason: String| ContentPackIssue::Invalid {
+                pack: pack.id.clone(),
+                reason,
+            };
+
+            for obstacle in pack.obstacles.iter() {
+                if obstacle.base.is_pickup() {
+                    issues.push(invalid(format!(
+                        "obstacle {} is based on the pickup {:?}",
+                        obstacle.id, obstacle.base
+                    )));
+                    continue;
+                }
+                let Ok(model) = path.resolve_embed(&obstacle.model) else {
+                    issues.push(invalid(format!("model {} is not a path", obstacle.model)));
+                    continue;
+                };
+
+                let registered = RegisteredObstacle {
+                    id: obstacle.id.clone(),
+                    base: obstacle.base,
+                    model: model.to_string(),
+                    collider: obstacle.collider,
+                    weight: obstacle.weight,
+                    patterns: Vec::new(),
+                };
+                match obstacles.iter_mut().find(|(_, o)| o.id == obstacle.id) {
+                    Some((previous, slot)) => {
+                        issues.push(ContentPackIssue::Obstacle {
+                            id: obstacle.id.clone(),
+                            pack: pack.id.clone(),
+                            previous: previous.clone(),
+                        });
+                        *previous = pack.id.clone();
+                        *slot = registered;
+                    }
+                    None => obstacles.push((pack.id.clone(), registered)),
+                }
+            }
+
+            for pattern in pack.patterns.iter() {
+                let mut lanes = pattern.lanes.clone();
+                lanes.sort_unstable();
+                lanes.dedup();
+                if lanes.is_empty() || lanes.iter().any(|&lane| lane >= NUM_LANES) {
+                    issues.push(invalid(format!(
+                        "pattern {} has lanes {:?}",
+                        pattern.id, pattern.lanes
+                    )));
+                    continue;
+                }
+
+                let entry = (
+                    pack.id.clone(),
+                    pattern.id.as_str(),
+                    pattern.obstacle.as_str(),
+                    lanes,
+                    pattern.weight,
+                );
+                match patterns.iter_mut().find(|p| p.1 == pattern.id) {
+                    Some(slot) => {
+                        issues.push(ContentPackIssue::Pattern {
+                            id: pattern.id.clone(),
+                            pack: pack.id.clone(),
+                            previous: slot.0.clone(),
+                        });
+                        *slot = entry;
+                    }
+                    None => patterns.push(entry),
+                }
+            }
+
+            for skin in pack.skins.iter() {
+                let Ok(model) = path.resolve_embed(&skin.model) else {
+                    issues.push(invalid(format!("model {} is not a path", skin.model)));
+                    continue;
+                };
+                if let Some(previous) = skins.insert(skin.object, pack.id.clone()) {
+                    issues.push(ContentPackIssue::Skin {
+                        object: skin.object,
+                        pack: pack.id.clone(),
+                        previous,
+                    });
+                }
+                registry.skins.insert(skin.object, model.to_string());
+            }
+
+            for (&locale, table) in pack.strings.iter() {
+                for (key, value) in table.iter() {
+                    if let Some(previous) = strings.insert((locale, key), pack.id.clone()) {
+                        issues.push(ContentPackIssue::String {
+                            locale,
+                            key: key.clone(),
+                            pack: pack.id.clone(),
+                            previous,
+                        });
+                    }
+                    registry
+                        .strings
+                        .entry(locale)
+                        .or_default()
+                        .insert(key.clone(), value.clone());
+                }
+            }
+        }
+
+        // Patterns may place the obstacles of an earlier pack, so they are attached once every pack is in.
+        // A pattern the player could not get past even on an empty track at the lowest speed,
+        // where jumps clear the least, would never be placed.
+        let path = PathCheck::new(
+            &GameplayConfig::default(),
+            &TrainUpgrades::default().collider(),
+        );
+        for (pack, id, obstacle, lanes, weight) in patterns {
+            match obstacles.iter_mut().find(|(_, o)| o.id == obstacle) {
+                Some((_, registered))
+                    if !path
+                        .after_row(&lanes, &registered.collider, 0.0)
+                        .is_passable() =>
+                {
+                    issues.push(ContentPackIssue::Invalid {
+                        pack,
+                        reason: format!(
+                            "pattern {} places {} where it cannot be got past",
+                            id, obstacle
+                        ),
+                    })
+                }
+                Some((_, registered)) => registered.patterns.push((lanes, weight)),
+                None => issues.push(ContentPackIssue::Invalid {
+                    pack,
+                    reason: format!("pattern {} places the unknown obstacle {}", id, obstacle),
+                }),
+            }
+        }
+
+        // An obstacle without patterns takes a single lane, and a weight of zero disables it.
+        registry.obstacles = obstacles
+            .into_iter()
+            .map(|(_, mut obstacle)| {
+                obstacle.patterns.retain(|&(_, weight)| weight > 0);
+                if obstacle.patterns.is_empty() {
+                    obstacle.patterns = (0..NUM_LANES).map(|lane| (vec![lane], 1)).collect();
+                }
+                obstacle
+            })
+            .filter(|obstacle| obstacle.weight > 0)
+            .collect();
+
+        (registry, issues)
+    }
+}
+
+// --- STARTUP SYSTEMS ---
+
+/// Queues every pack file in the `mods` directory and in its immediate subdirectories.
+#[cfg(not(target_arch = "wasm32"))]
+fn discover_content_packs(mut queue: ResMut<ContentPackQueue>, asset_server: Res<AssetServer>) {
+    let root = FileAssetReader::get_base_path().join(CONTENT_PACK_SOURCE);
+    let Ok(entries) = fs::read_dir(&root) else {
+        info!("No content packs found in {}", root.display());
+        return;
+    };
+
+    let is_pack = |path: &Path| path.extension().is_some_and(|ext| ext == "pack");
+    let mut paths = Vec::new();
+    for path in entries.flatten().map(|entry| entry.path()) {
+        if path.is_dir()
+            && let Ok(files) = fs::read_dir(&path)
+        {
+            paths.extend(
+                files
+                    .flatten()
+                    .map(|file| file.path())
+                    .filter(|p| is_pack(p)),
+            );
+        } else if is_pack(&path) {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    for path in paths {
+        if let Ok(relative) = path.strip_prefix(&root) {
+            let path = AssetPath::from_path(relative)
+                .with_source(CONTENT_PACK_SOURCE)
+                .into_owned();
+            queue.packs.push(asset_server.load(path));
+        }
+    }
+}
+
+/// Requests the list of pack files, since the web build cannot read a directory.
+#[cfg(target_arch = "wasm32")]
+fn discover_content_packs(mut queue: ResMut<ContentPackQueue>, asset_server: Res<AssetServer>) {
+    let path = AssetPath::from(CONTENT_PACK_INDEX_PATH).with_source(CONTENT_PACK_SOURCE);
+    queue.index = Some(asset_server.load(path));
+}
+
+// --- UPDATE SYSTEMS ---
+
+/// Registers the packs once all of them have loaded or failed, and merges their strings into the localization data.
+#[allow(clippy::too_many_arguments)]
+fn register_content_packs(
+    mut commands: Commands,
+    mut queue: ResMut<ContentPackQueue>,
+    mut registry: ResMut<ContentRegistry>,
+    mut localization_data: ResMut<Assets<LocalizationData>>,
+    localizations: Option<Res<LocalizationAssets>>,
+    indices: Res<Assets<ContentPackIndex>>,
+    packs: Res<Assets<ContentPack>>,
+    asset_server: Res<AssetServer>,
+) {
+    if let Some(handle) = queue.index.clone() {
+        match asset_server.load_state(handle.id()) {
+            LoadState::Loaded => {
+                for path in indices
+                    .get(handle.id())
+                    .iter()
+                    .flat_map(|index| index.0.iter())
+                {
+                    let path = AssetPath::from(path.clone()).with_source(CONTENT_PACK_SOURCE);
+                    queue.packs.push(asset_server.load(path));
+                }
+                queue.index = None;
+            }
+            LoadState::Failed(_) => {
+                info!("No content pack index found.");
+                queue.index = None;
+            }
+            _ => return,
+        }
+    }
+
+    let settled = queue.packs.iter().all(|handle| {
+        matches!(
+            asset_server.load_state(handle.id()),
+            LoadState::Loaded | LoadState::Failed(_)
+        )
+    });
+    let Some(localizations) = localizations.filter(|localizations| {
+        localizations
+            .locale
+            .values()
+            .all(|handle| localization_data.contains(handle.id()))
+    }) else {
+        return;
+    };
+    if !settled {
+        return;
+    }
+
+    let mut loaded = Vec::new();
+    for handle in queue.packs.iter() {
+        match (handle.path(), packs.get(handle.id())) {
+            (Some(path), Some(pack)) => loaded.push((path.clone(), pack)),
+            (path, _) => error!("Failed to load content pack: {:?}", path),
+        }
+    }
+
+    let (mut content, issues) = ContentRegistry::register(&loaded);
+    for issue in issues.iter() {
+        warn!("{}", issue);
+    }
+    for (locale, strings) in content.strings.iter() {
+        if let Some(handle) = localizations.locale.get(locale)
+            && let Some(data) = localization_data.get_mut(handle.id())
+        {
+            data.0
+                .extend(strings.iter().map(|(k, v)| (k.clone(), v.clone())));
+        }
+    }
+    if !content.packs.is_empty() {
+        info!(
+            "Registered content packs: {} ({} obstacles, {} skins)",
+            content.packs.join(", "),
+            content.obstacles.len(),
+            content.skins.len()
+        );
+    }
+
+    content.models = content
+        .obstacles
+        .iter()
+        .map(|obstacle| &obstacle.model)
+        .chain(content.skins.values())
+        .map(|path| asset_server.load(path.clone()))
+        .collect();
+    *registry = content;
+    commands.remove_resource::<ContentPackQueue>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pack(json: &str) -> ContentPack {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn later_packs_win_conflicts() {
+        let base = pack(
+            r#"{
+                "id": "base",
+                "obstacles": [{
+                    "id": "crate", "base": "Barricade", "model": "Crate.model", "weight": 10,
+                    "collider": { "Aabb": { "offset": [0.0, 0.5, 0.0], "size": [1.0, 1.0, 1.0] } }
+                }],
+                "skins": [{ "object": "Stone", "model": "Rock.model" }],
+                "strings": { "En": { "Title": "Base" } }
+            }"#,
+        );
+        let patch = pack(
+            r#"{
+                "id": "patch",
+                "load_order": 1,
+                "patterns": [
+                    { "id": "wall", "obstacle": "crate", "lanes": [2, 0, 2] },
+                    { "id": "ghost", "obstacle": "nothing", "lanes": [1] },
+                    { "id": "wide", "obstacle": "crate", "lanes": [3] }
+                ],
+                "skins": [{ "object": "Stone", "model": "Snowball.model" }],
+                "strings": { "En": { "Title": "Patch" } }
+            }"#,
+        );
+
+        // The packs are given out of order, and are registered by their load order.
+        let (registry, issues) = ContentRegistry::register(&[
+            (
+                AssetPath::parse("mods://patch/patch.pack").into_owned(),
+                &patch,
+            ),
+            (
+                AssetPath::parse("mods://base/base.pack").into_owned(),
+                &base,
+            ),
+            (
+                AssetPath::parse("mods://copy/base.pack").into_owned(),
+                &base,
+            ),
+        ]);
+
+        assert_eq!(registry.packs, ["base", "patch"]);
+        assert_eq!(registry.obstacles.len(), 1);
+        assert_eq!(registry.obstacles[0].model, "mods://base/Crate.model");
+        assert_eq!(registry.obstacles[0].patterns, [(vec![0, 2], 1)]);
+        assert_eq!(
+            registry.skins.get(&Object::Stone).map(String::as_str),
+            Some("mods://patch/Snowball.model")
+        );
+        assert_eq!(registry.strings[&Locale::En]["Title"], "Patch");
+
+        assert_eq!(issues.len(), 5);
+        assert!(issues.contains(&ContentPackIssue::DuplicatePack {
+            pack: "base".to_string()
+        }));
+        assert!(issues.contains(&ContentPackIssue::Skin {
+            object: Object::Stone,
+            pack: "patch".to_string(),
+            previous: "base".to_string(),
+        }));
+        assert!(issues.contains(&ContentPackIssue::String {
+            locale: Locale::En,
+            key: "Title".to_string(),
+            pack: "patch".to_string(),
+            previous: "base".to_string(),
+        }));
+    }
+
+    #[test]
+    fn rejects_patterns_that_cannot_be_got_past() {
+        let tower = pack(
+            r#"{
+                "id": "tower",
+                "obstacles": [{
+                    "id": "tower", "base": "Barricade", "model": "Tower.model", "weight": 10,
+                    "collider": { "Aabb": { "offset": [0.0, 5.0, 0.0], "size": [1.0, 10.0, 1.0] } }
+                }],
+                "patterns": [
+                    { "id": "wall", "obstacle": "tower", "lanes": [0, 1, 2] },
+                    { "id": "gate", "obstacle": "tower", "lanes": [0, 2] }
+                ]
+            }"#,
+        );
+
+        let (registry, issues) = ContentRegistry::register(&[(
+            AssetPath::parse("mods://tower/tower.pack").into_owned(),
+            &tower,
+        )]);
+
+        assert_eq!(registry.obstacles[0].patterns, [(vec![0, 2], 1)]);
+        assert_eq!(
+            issues,
+            [ContentPackIssue::Invalid {
+                pack: "tower".to_string(),
+                reason: "pattern wall places tower where it cannot be got past".to_string(),
+            }]
+        );
+    }
+}
@@ -0,0 +1,193 @@
+use bevy::{
+    math::FloatOrd,
+    prelude::*,
+    render::{
+        camera::{CameraUpdateSystem, ImageRenderTarget, RenderTarget},
+        render_asset::RenderAssetUsages,
+        render_resource::{Extent3d, TextureDimension, TextureFormat, TextureUsages},
+    },
+    ui::UiSystem,
+    window::PrimaryWindow,
+};
+
+use super::*;
+
+// --- PLUGIN ---
+
+/// Renders the cameras asked for with [`RenderOffscreen`] to an image at the current [`RenderScale`],
+/// which is drawn behind the UI by a second camera. The UI stays at the resolution of the window.
+/// Once the component is removed, the camera renders to the window again.
+pub struct OffscreenViewPlugin;
+
+impl Plugin for OffscreenViewPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(
+            PostUpdate,
+            OffscreenViewSystems
+                .before(CameraUpdateSystem)
+                .before(UiSystem::Prepare),
+        )
+        .add_systems(
+            PostUpdate,
+            (render_offscreen, render_to_window, resize_offscreen_view)
+                .chain()
+                .in_set(OffscreenViewSystems),
+        );
+    }
+}
+
+/// The systems that set up the offscreen views. Systems asking for one run before them.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, SystemSet)]
+pub struct OffscreenViewSystems;
+
+// --- COMPONENTS ---
+
+/// Asks for a camera to be rendered offscreen instead of to the window.
+#[derive(Component)]
+pub struct RenderOffscreen;
+
+/// Holds the image a camera renders to instead of the window.
+#[derive(Component)]
+pub struct OffscreenView {
+    image: Handle<Image>,
+    ui_camera: Entity,
+    backdrop: Entity,
+}
+
+impl OffscreenView {
+    pub fn image(&self) -> &Handle<Image> {
+        &self.image
+    }
+
+    /// Returns the node that draws the image behind the UI.
+    pub fn backdrop(&self) -> Entity {
+        self.backdrop
+    }
+}
+
+// --- POSTUPDATE SYSTEMS ---
+
+fn render_offscreen(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    render_scale: Res<RenderScale>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut query: Query<(Entity, &mut Camera), Added<RenderOffscreen>>,
+) {
+    let Ok(window) = windows.single() else {
+        return;
+    };
+
+    for (entity, mut camera) in query.iter_mut() {
+        let mut image = Image::new_fill(
+            offscreen_size(window, render_scale.get()),
+            TextureDimension::D2,
+            &[0, 0, 0, 0],
+            TextureFormat::Bgra8UnormSrgb,
+            RenderAssetUsages::default(),
+        );
+        image.texture_descriptor.usage = TextureUsages::TEXTURE_BINDING
+            | TextureUsages::COPY_DST
+            | TextureUsages::RENDER_ATTACHMENT;
+        let handle = images.add(image);
+
+        info!("Render the camera {} offscreen", entity);
+        camera.target = RenderTarget::Image(ImageRenderTarget {
+            handle: handle.clone(),
+            scale_factor: FloatOrd(offscreen_scale_factor(window, render_scale.get())),
+        });
+
+        // Draws the UI, including the world below it, to the window.
+        let ui_camera = commands
+            .spawn((
+                Camera2d,
+                Camera {
+                    order: 1,
+                    ..Default::default()
+                },
+                InGameStateRoot,
+            ))
+            .id();
+        let backdrop = commands
+            .spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    ..Default::default()
+                },
+                ImageNode::new(handle.clone()),
+                UiLayer::Backdrop,
+                Pickable::IGNORE,
+                InGameStateRoot,
+            ))
+            .id();
+
+        commands.entity(entity).insert(OffscreenView {
+            image: handle,
+            ui_camera,
+            backdrop,
+        });
+    }
+}
+
+fn render_to_window(
+    mut commands: Commands,
+    mut removed: RemovedComponents<RenderOffscreen>,
+    mut query: Query<(&OffscreenView, &mut Camera)>,
+) {
+    for entity in removed.read() {
+        let Ok((view, mut camera)) = query.get_mut(entity) else {
+            continue;
+        };
+
+        info!("Render the camera {} to the window", entity);
+        camera.target = RenderTarget::default();
+        commands.entity(view.ui_camera).despawn();
+        commands.entity(view.backdrop).despawn();
+        commands.entity(entity).remove::<OffscreenView>();
+    }
+}
+
+/// Keeps the offscreen image the size of the window at the current render scale.
+fn resize_offscreen_view(
+    mut images: ResMut<Assets<Image>>,
+    render_scale: Res<RenderScale>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut query: Query<(&OffscreenView, &mut Camera)>,
+) {
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let size = offscreen_size(window, render_scale.get());
+    let scale_factor = FloatOrd(offscreen_scale_factor(window, render_scale.get()));
+
+    for (view, mut camera) in query.iter_mut() {
+        if images
+            .get(&view.image)
+            .is_some_and(|image| image.texture_descriptor.size != size)
+            && let Some(image) = images.get_mut(&view.image)
+        {
+            image.resize(size);
+        }
+
+        // Keeps the logical size of the view that of the window, so that points projected to the view line up with the UI.
+        if let RenderTarget::Image(target) = &mut camera.target
+            && target.scale_factor != scale_factor
+        {
+            target.scale_factor = scale_factor;
+        }
+    }
+}
+
+fn offscreen_size(window: &Window, scale: f32) -> Extent3d {
+    Extent3d {
+        width: ((window.physical_width() as f32 * scale) as u32).max(1),
+        height: ((window.physical_height() as f32 * scale) as u32).max(1),
+        ..Default::default()
+    }
+}
+
+fn offscreen_scale_factor(window: &Window, scale: f32) -> f32 {
+    window.scale_factor() * scale
+}
@@ -8,6 +8,7 @@ use crate::web::{WebAudioPlayer, WebPlaybackSettings};
 use crate::asset::{
     locale::{CurrentLocale, Locale},
     sound::SystemVolume,
+    spawner::TranslatableText,
 };
 
 use super::*;
@@ -18,42 +19,42 @@ pub struct StatePlugin;
 
 impl Plugin for StatePlugin {
     fn build(&self, app: &mut App) {
-        app
+        app.init_resource::<ProfileImport>()
             // Register systems to run when entering the `GameState::Option` state.
-            .add_systems(
-                OnEnter(GameState::Option),
-                (debug_label, show_interface, init_slider_cursor_flag),
-            )
+            .add_systems(OnEnter(GameState::Option), (debug_label, show_interface))
             // Register a cleanup system to run when exiting the `GameState::Option` state.
             .add_systems(
                 OnExit(GameState::Option),
                 (
                     hide_state_ui,
-                    clear_slider_cursor_flag,
+                    reset_profile_import,
                     #[cfg(target_arch = "wasm32")]
                     save_volume_options,
+                    #[cfg(target_arch = "wasm32")]
+                    save_graphics_options,
                 ),
             )
             .add_systems(
                 PreUpdate,
-                (
-                    handle_player_input,
-                    slider_interaction_system,
-                    slider_interaction_system_for_moblie,
-                )
-                    .run_if(in_state(GameState::Option)),
+                handle_player_input.run_if(in_state(GameState::Option)),
             )
             // Register systems that run every frame while in the `GameState::Option` state.
             .add_systems(
                 Update,
                 (
                     update_slider_visual,
-                    update_current_volume,
-                    update_slider_cursor,
-                    update_slider_cursor_for_moblie,
-                    slider_feedback_system,
-                    slider_feedback_system_for_moblie,
+                    (apply_volume_slider, update_current_volume).chain(),
+                    preview_volume_slider,
                     update_loacle_button,
+                    update_graphics_button,
+                    update_camera_button,
+                    update_render_scale_button,
+                    update_device_tier_button,
+                    update_fog_mode_button,
+                    #[cfg(not(target_arch = "wasm32"))]
+                    update_audio_device_button,
+                    update_profile_buttons,
+                    update_mute_button,
                     update_back_button, // Note: This function handles the "Back" button.
                     control_background_volume,
                     control_effect_volume,
@@ -64,27 +65,6 @@ impl Plugin for StatePlugin {
     }
 }
 
-// --- RESOURCES ---
-
-#[derive(Default, Resource)]
-pub struct SelectedSliderCursor(Option<(UI, Entity, u64)>);
-
-impl SelectedSliderCursor {
-    pub fn take(&mut self) -> Option<(UI, Entity, u64)> {
-        self.0.take()
-    }
-
-    pub fn get(&self) -> Option<(UI, Entity, u64)> {
-        self.0
-    }
-
-    pub fn set(&mut self, ui: UI, entity: Entity, id: u64) {
-        if self.0.is_none() {
-            self.0 = Some((ui, entity, id))
-        }
-    }
-}
-
 // --- SETUP SYSTEMS ---
 
 /// Prints a debug message to the console indicating the current game state.
@@ -99,28 +79,44 @@ fn show_interface(mut query: Query<(&UI, &mut Visibility)>) {
         match ui {
             UI::OptionModal
             | UI::SliderRail
+            | UI::MasterLabel
+            | UI::MasterVolume
+            | UI::MasterVolumeCursor
             | UI::BgmLabel
             | UI::BgmVolume
             | UI::BgmVolumeCursor
+            | UI::BgmMuteButton
             | UI::SfxLabel
             | UI::SfxVolume
             | UI::SfxVolumeCursor
+            | UI::SfxMuteButton
             | UI::VoiceLabel
             | UI::VoiceVolume
             | UI::VoiceVolumeCursor
+            | UI::VoiceMuteButton
             | UI::BackButton
+            | UI::ExportButton
+            | UI::ImportButton
             | UI::LanguageEn
             | UI::LanguageJa
-            | UI::LanguageKo => *visibility = Visibility::Visible,
+            | UI::LanguageKo
+            | UI::SpeedLineButton
+            | UI::ReduceMotionButton
+            | UI::CaptionButton
+            | UI::LandingMarkerButton
+            | UI::PaceClockButton
+            | UI::CameraPresetButton
+            | UI::RenderScaleButton
+            | UI::DeviceTierButton
+            | UI::FogModeButton
+            | UI::ScatteringButton => *visibility = Visibility::Visible,
+            #[cfg(not(target_arch = "wasm32"))]
+            UI::AudioDeviceLabel | UI::AudioDeviceButton => *visibility = Visibility::Visible,
             _ => { /* Do nothing for other UI elements. */ }
         }
     }
 }
 
-fn init_slider_cursor_flag(mut commands: Commands) {
-    commands.insert_resource(SelectedSliderCursor::default());
-}
-
 // --- CLEANUP SYSTEMS ---
 
 /// Hides all UI elements of the option screen.
@@ -130,46 +126,79 @@ fn hide_state_ui(mut query: Query<(&UI, &mut Visibility)>) {
         match ui {
             UI::OptionModal
             | UI::SliderRail
+            | UI::MasterLabel
+            | UI::MasterVolume
+            | UI::MasterVolumeCursor
             | UI::BgmLabel
             | UI::BgmVolume
             | UI::BgmVolumeCursor
+            | UI::BgmMuteButton
             | UI::SfxLabel
             | UI::SfxVolume
             | UI::SfxVolumeCursor
+            | UI::SfxMuteButton
             | UI::VoiceLabel
             | UI::VoiceVolume
             | UI::VoiceVolumeCursor
+            | UI::VoiceMuteButton
             | UI::BackButton
+            | UI::ExportButton
+            | UI::ImportButton
             | UI::LanguageEn
             | UI::LanguageJa
-            | UI::LanguageKo => *visibility = Visibility::Hidden,
+            | UI::LanguageKo
+            | UI::SpeedLineButton
+            | UI::ReduceMotionButton
+            | UI::CaptionButton
+            | UI::LandingMarkerButton
+            | UI::PaceClockButton
+            | UI::CameraPresetButton
+            | UI::RenderScaleButton
+            | UI::DeviceTierButton
+            | UI::FogModeButton
+            | UI::ScatteringButton => *visibility = Visibility::Hidden,
+            #[cfg(not(target_arch = "wasm32"))]
+            UI::AudioDeviceLabel | UI::AudioDeviceButton => *visibility = Visibility::Hidden,
             _ => { /* Do nothing for other UI elements. */ }
         }
     }
 }
 
-fn clear_slider_cursor_flag(mut commands: Commands) {
-    commands.remove_resource::<SelectedSliderCursor>();
+/// Forgets an import that was not confirmed before leaving the options.
+fn reset_profile_import(mut import: ResMut<ProfileImport>) {
+    *import = ProfileImport::Idle;
 }
 
 #[cfg(target_arch = "wasm32")]
 fn save_volume_options(system_volume: Res<SystemVolume>) {
     if let Some(storage) = get_local_storage()
-        && let Ok(value) = serde_json::ser::to_string(&*system_volume)
+        && let Ok(value) = SYSTEM_VOLUME_SCHEMA.encode(&*system_volume)
     {
         info!("Store system volume: {:?}", &value);
         let _ = storage.set_item(SYSTEM_VOLUME_KEY, &value);
     }
 }
 
+#[cfg(target_arch = "wasm32")]
+fn save_graphics_options(options: Res<GraphicsOptions>) {
+    if let Some(storage) = get_local_storage()
+        && let Ok(value) = GRAPHICS_OPTIONS_SCHEMA.encode(&*options)
+    {
+        info!("Store graphics options: {:?}", &value);
+        let _ = storage.set_item(GRAPHICS_OPTIONS_KEY, &value);
+    }
+}
+
 // --- PREUPDATE SYSTEMS ---
 
+/// Leaves the options with Escape, unless it reverts the slider being edited.
 fn handle_player_input(
     in_game_query: Query<(), With<InGameStateRoot>>,
     keyboard_input: Res<ButtonInput<KeyCode>>,
+    focused_slider: Res<FocusedSlider>,
     mut next_state: ResMut<NextState<GameState>>,
 ) {
-    if keyboard_input.just_pressed(KeyCode::Escape) {
+    if keyboard_input.just_pressed(KeyCode::Escape) && !focused_slider.is_editing() {
         if in_game_query.is_empty() {
             next_state.set(GameState::Title);
         } else {
@@ -178,49 +207,6 @@ fn handle_player_input(
     }
 }
 
-fn slider_interaction_system(
-    interaction_query: Query<(&UI, &Interaction, &ChildOf), Changed<Interaction>>,
-    mut selected: ResMut<SelectedSliderCursor>,
-) {
-    for (&ui, &interaction, child_of) in interaction_query.iter() {
-        match (ui, interaction) {
-            (UI::BgmVolumeCursor, Interaction::Pressed) => {
-                selected.set(UI::BgmVolumeCursor, child_of.parent(), 0);
-            }
-            (UI::SfxVolumeCursor, Interaction::Pressed) => {
-                selected.set(UI::SfxVolumeCursor, child_of.parent(), 0);
-            }
-            (UI::VoiceVolumeCursor, Interaction::Pressed) => {
-                selected.set(UI::VoiceVolumeCursor, child_of.parent(), 0);
-            }
-            _ => { /* empty */ }
-        }
-    }
-}
-
-fn slider_interaction_system_for_moblie(
-    touches: Res<Touches>,
-    interaction_query: Query<(&UI, &Interaction, &ChildOf), Changed<Interaction>>,
-    mut selected: ResMut<SelectedSliderCursor>,
-) {
-    if let Some(touch) = touches.iter_just_pressed().last() {
-        for (&ui, &interaction, child_of) in interaction_query.iter() {
-            match (ui, interaction) {
-                (UI::BgmVolumeCursor, Interaction::Pressed) => {
-                    selected.set(UI::BgmVolumeCursor, child_of.parent(), touch.id());
-                }
-                (UI::SfxVolumeCursor, Interaction::Pressed) => {
-                    selected.set(UI::SfxVolumeCursor, child_of.parent(), touch.id());
-                }
-                (UI::VoiceVolumeCursor, Interaction::Pressed) => {
-                    selected.set(UI::VoiceVolumeCursor, child_of.parent(), touch.id());
-                }
-                _ => { /* empty */ }
-            }
-        }
-    }
-}
-
 // --- UPDATE SYSTEMS ---
 
 /// Provides visual feedback for volume slider handles based on their interaction state (hovered, pressed).
@@ -230,17 +216,20 @@ fn update_slider_visual(
     for (&ui, &interaction, mut color) in interaction_query.iter_mut() {
         // Darken the color of the slider handle based on interaction.
         match (ui, interaction) {
-            (UI::BgmVolumeCursor, Interaction::Pressed)
+            (UI::MasterVolumeCursor, Interaction::Pressed)
+            | (UI::BgmVolumeCursor, Interaction::Pressed)
             | (UI::SfxVolumeCursor, Interaction::Pressed)
             | (UI::VoiceVolumeCursor, Interaction::Pressed) => {
                 *color = BackgroundColor(SLIDER_HANDLE_COLOR.darker(0.5));
             }
-            (UI::BgmVolumeCursor, Interaction::Hovered)
+            (UI::MasterVolumeCursor, Interaction::Hovered)
+            | (UI::BgmVolumeCursor, Interaction::Hovered)
             | (UI::SfxVolumeCursor, Interaction::Hovered)
             | (UI::VoiceVolumeCursor, Interaction::Hovered) => {
                 *color = BackgroundColor(SLIDER_HANDLE_COLOR.darker(0.3));
             }
-            (UI::BgmVolumeCursor, Interaction::None)
+            (UI::MasterVolumeCursor, Interaction::None)
+            | (UI::BgmVolumeCursor, Interaction::None)
             | (UI::SfxVolumeCursor, Interaction::None)
             | (UI::VoiceVolumeCursor, Interaction::None) => {
                 *color = BackgroundColor(SLIDER_HANDLE_COLOR);
@@ -250,153 +239,89 @@ fn update_slider_visual(
     }
 }
 
-/// Updates the volume percentage text displays (0-100) to match the values in the `SystemVolume` resource.
-fn update_current_volume(system_volume: Res<SystemVolume>, mut query: Query<(&UI, &mut Text)>) {
-    for (&ui, mut text) in query.iter_mut() {
-        match ui {
-            UI::BgmVolume => {
-                *text = Text::new(format!(
-                    "{}",
-                    (100.0 * system_volume.background_percentage()).floor()
-                ));
-            }
-            UI::SfxVolume => {
-                *text = Text::new(format!(
-                    "{}",
-                    (100.0 * system_volume.effect_percentage()).floor()
-                ));
-            }
-            UI::VoiceVolume => {
-                *text = Text::new(format!(
-                    "{}",
-                    (100.0 * system_volume.voice_percentage()).floor()
-                ));
-            }
-            _ => { /* empty */ }
-        }
-    }
-}
-
-fn update_slider_cursor(
-    windows: Query<&Window>,
-    mut node_query: Query<&mut Node>,
+/// Applies the value changes of the volume sliders to the `SystemVolume` resource.
+fn apply_volume_slider(
+    mut events: EventReader<SliderChanged>,
+    channel_query: Query<&VolumeChannel>,
     mut system_volume: ResMut<SystemVolume>,
-    selected: Res<SelectedSliderCursor>,
 ) {
-    let Some((ui, entity, _id)) = selected.get() else {
-        return;
-    };
-    let Ok(window) = windows.single() else { return };
-    let Some(cursor_position) = window.cursor_position() else {
-        return;
-    };
-
-    let slider_width = window.width() * 0.5 * 0.4;
-    let slider_begin = window.width() * 0.5 - slider_width / 2.0;
-    let slider_end = window.width() * 0.5 + slider_width / 2.0;
-
-    let slider_pos = cursor_position.x.clamp(slider_begin, slider_end);
-    let percentage = (slider_pos - slider_begin) / slider_width;
-
-    if let Ok(mut node) = node_query.get_mut(entity) {
-        match ui {
-            UI::BgmVolumeCursor => {
-                node.left = Val::Percent(percentage * 100.0);
-                system_volume.background = (percentage * 255.0).floor() as u8;
-            }
-            UI::SfxVolumeCursor => {
-                node.left = Val::Percent(percentage * 100.0);
-                system_volume.effect = (percentage * 255.0).floor() as u8;
-            }
-            UI::VoiceVolumeCursor => {
-                node.left = Val::Percent(percentage * 100.0);
-                system_volume.voice = (percentage * 255.0).floor() as u8;
-            }
-            _ => { /* empty */ }
+    for event in events.read() {
+        let value = (event.value * 255.0).round() as u8;
+        match channel_query.get(event.slider) {
+            Ok(VolumeChannel::Master) => system_volume.master = value,
+            Ok(VolumeChannel::Background) => system_volume.background = value,
+            Ok(VolumeChannel::Effect) => system_volume.effect = value,
+            Ok(VolumeChannel::Voice) => system_volume.voice = value,
+            Err(_) => { /* empty */ }
         }
     }
 }
 
-fn update_slider_cursor_for_moblie(
-    windows: Query<&Window>,
-    touches: Res<Touches>,
-    mut node_query: Query<&mut Node>,
-    mut system_volume: ResMut<SystemVolume>,
-    selected: Res<SelectedSliderCursor>,
+/// Plays a preview sound at the new volume while a volume slider is being changed.
+/// Previews are rate-limited, and a voice preview waits until the previous one finishes.
+#[allow(clippy::too_many_arguments)]
+fn preview_volume_slider(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    system_volume: Res<SystemVolume>,
+    mut rng: ResMut<RngService>,
+    mut events: EventReader<SliderChanged>,
+    channel_query: Query<&VolumeChannel>,
+    voice_query: Query<(), With<VoiceSound>>,
+    time: Res<Time>,
+    mut last_preview: Local<Option<f32>>,
 ) {
-    let Some((ui, entity, id)) = selected.get() else {
-        return;
-    };
-    let Ok(window) = windows.single() else { return };
-    let Some(touch) = touches.get_pressed(id) else {
+    let Some(channel) = events
+        .read()
+        .filter_map(|event| channel_query.get(event.slider).ok())
+        .last()
+        .copied()
+    else {
         return;
     };
 
-    let slider_width = window.width() * 0.5 * 0.4;
-    let slider_begin = window.width() * 0.5 - slider_width / 2.0;
-    let slider_end = window.width() * 0.5 + slider_width / 2.0;
-
-    let slider_pos = touch.position().x.clamp(slider_begin, slider_end);
-    let percentage = (slider_pos - slider_begin) / slider_width;
+    let now = time.elapsed_secs();
+    if last_preview.is_some_and(|last| now - last < VOLUME_PREVIEW_INTERVAL) {
+        return;
+    }
 
-    if let Ok(mut node) = node_query.get_mut(entity) {
-        match ui {
-            UI::BgmVolumeCursor => {
-                node.left = Val::Percent(percentage * 100.0);
-                system_volume.background = (percentage * 255.0).floor() as u8;
-            }
-            UI::SfxVolumeCursor => {
-                node.left = Val::Percent(percentage * 100.0);
-                system_volume.effect = (percentage * 255.0).floor() as u8;
-            }
-            UI::VoiceVolumeCursor => {
-                node.left = Val::Percent(percentage * 100.0);
-                system_volume.voice = (percentage * 255.0).floor() as u8;
-            }
-            _ => { /* empty */ }
+    match channel {
+        VolumeChannel::Master | VolumeChannel::Effect => {
+            play_sfx_preview(&mut commands, &asset_server, &system_volume);
+        }
+        VolumeChannel::Voice if voice_query.is_empty() => {
+            play_voice_preview(&mut commands, &asset_server, &system_volume, &mut rng);
         }
+        // The background music itself previews its volume.
+        VolumeChannel::Voice | VolumeChannel::Background => return,
     }
+    *last_preview = Some(now);
 }
 
-fn slider_feedback_system(
-    mut commands: Commands,
-    asset_server: Res<AssetServer>,
-    system_volume: Res<SystemVolume>,
-    mouse_button: Res<ButtonInput<MouseButton>>,
-    mut selected: ResMut<SelectedSliderCursor>,
-) {
-    if mouse_button.just_released(MouseButton::Left)
-        && let Some((select, _, _)) = selected.take()
-    {
-        match select {
-            UI::SfxVolumeCursor => {
-                play_sfx_feedback_when_released(&mut commands, &asset_server, &system_volume);
+/// Updates the volume percentage text displays (0-100) to match the values in the `SystemVolume` resource.
+fn update_current_volume(system_volume: Res<SystemVolume>, mut query: Query<(&UI, &mut Text)>) {
+    for (&ui, mut text) in query.iter_mut() {
+        match ui {
+            UI::MasterVolume => {
+                *text = Text::new(format!(
+                    "{}",
+                    (100.0 * system_volume.master_level()).round()
+                ));
             }
-            UI::VoiceVolumeCursor => {
-                play_voice_feedback_when_released(&mut commands, &asset_server, &system_volume);
+            UI::BgmVolume => {
+                *text = Text::new(format!(
+                    "{}",
+                    (100.0 * system_volume.background_level()).round()
+                ));
             }
-            _ => { /* empty */ }
-        }
-    }
-}
-
-fn slider_feedback_system_for_moblie(
-    mut commands: Commands,
-    asset_server: Res<AssetServer>,
-    system_volume: Res<SystemVolume>,
-    touches: Res<Touches>,
-    mut selected: ResMut<SelectedSliderCursor>,
-) {
-    if let Some((_, _, id)) = selected.get()
-        && touches.just_released(id)
-        && let Some((select, _, _)) = selected.take()
-    {
-        match select {
-            UI::SfxVolumeCursor => {
-                play_sfx_feedback_when_released(&mut commands, &asset_server, &system_volume);
+            UI::SfxVolume => {
+                *text = Text::new(format!(
+                    "{}",
+                    (100.0 * system_volume.effect_level()).round()
+                ));
             }
-            UI::VoiceVolumeCursor => {
-                play_voice_feedback_when_released(&mut commands, &asset_server, &system_volume);
+            UI::VoiceVolume => {
+                *text = Text::new(format!("{}", (100.0 * system_volume.voice_level()).round()));
             }
             _ => { /* empty */ }
         }
@@ -405,9 +330,6 @@ fn slider_feedback_system_for_moblie(
 
 #[allow(clippy::type_complexity)]
 fn update_loacle_button(
-    mut commands: Commands,
-    asset_server: Res<AssetServer>,
-    system_volume: Res<SystemVolume>,
     mut current_locale: ResMut<CurrentLocale>,
     mut set: ParamSet<(
         Query<(&UI, &mut BackgroundColor), With<Button>>,
@@ -422,11 +344,9 @@ fn update_loacle_button(
             } // Active state
             (UI::LanguageEn, Interaction::Hovered, _) => {
                 *color = BackgroundColor(LANGUAGE_BTN_COLOR.darker(0.3));
-                play_button_sound_when_hovered(&mut commands, &asset_server, &system_volume);
             }
             (UI::LanguageEn, Interaction::Pressed, _) => {
                 *color = BackgroundColor(LANGUAGE_BTN_COLOR.darker(0.5));
-                play_button_sound_when_pressed(&mut commands, &asset_server, &system_volume);
                 current_locale.0 = Locale::En;
             }
             (UI::LanguageEn, Interaction::None, _) => *color = BackgroundColor(LANGUAGE_BTN_COLOR),
@@ -437,11 +357,9 @@ fn update_loacle_button(
             } // Active state
             (UI::LanguageJa, Interaction::Hovered, _) => {
                 *color = BackgroundColor(LANGUAGE_BTN_COLOR.darker(0.3));
-                play_button_sound_when_hovered(&mut commands, &asset_server, &system_volume);
             }
             (UI::LanguageJa, Interaction::Pressed, _) => {
                 *color = BackgroundColor(LANGUAGE_BTN_COLOR.darker(0.5));
-                play_button_sound_when_pressed(&mut commands, &asset_server, &system_volume);
                 current_locale.0 = Locale::Ja;
             }
             (UI::LanguageJa, Interaction::None, _) => *color = BackgroundColor(LANGUAGE_BTN_COLOR),
@@ -452,11 +370,9 @@ fn update_loacle_button(
             } // Active state
             (UI::LanguageKo, Interaction::Hovered, _) => {
                 *color = BackgroundColor(LANGUAGE_BTN_COLOR.darker(0.3));
-                play_button_sound_when_hovered(&mut commands, &asset_server, &system_volume);
             }
             (UI::LanguageKo, Interaction::Pressed, _) => {
                 *color = BackgroundColor(LANGUAGE_BTN_COLOR.darker(0.5));
-                play_button_sound_when_pressed(&mut commands, &asset_server, &system_volume);
                 current_locale.0 = Locale::Ko;
             }
             (UI::LanguageKo, Interaction::None, _) => *color = BackgroundColor(LANGUAGE_BTN_COLOR),
@@ -478,13 +394,297 @@ fn update_loacle_button(
     }
 }
 
+/// Handles interactions with the graphics option toggle buttons.
+/// An enabled option is shown with a darker background.
+#[allow(clippy::type_complexity)]
+fn update_graphics_button(
+    mut options: ResMut<GraphicsOptions>,
+    mut set: ParamSet<(
+        Query<(&UI, &mut BackgroundColor), With<Button>>,
+        Query<(&UI, &Interaction, &mut BackgroundColor), (Changed<Interaction>, With<Button>)>,
+    )>,
+) {
+    for (&ui, &interaction, _) in set.p1().iter_mut() {
+        match (ui, interaction) {
+            (UI::SpeedLineButton, Interaction::Hovered)
+            | (UI::ReduceMotionButton, Interaction::Hovered)
+            | (UI::CaptionButton, Interaction::Hovered)
+            | (UI::LandingMarkerButton, Interaction::Hovered)
+            | (UI::PaceClockButton, Interaction::Hovered)
+            | (UI::ScatteringButton, Interaction::Hovered) => {}
+            (UI::SpeedLineButton, Interaction::Pressed) => {
+                options.speed_lines ^= true;
+            }
+            (UI::ReduceMotionButton, Interaction::Pressed) => {
+                options.reduce_motion ^= true;
+            }
+            (UI::CaptionButton, Interaction::Pressed) => {
+                options.captions ^= true;
+            }
+            (UI::LandingMarkerButton, Interaction::Pressed) => {
+                options.landing_marker ^= true;
+            }
+            (UI::PaceClockButton, Interaction::Pressed) => {
+                options.pace_clock ^= true;
+            }
+            (UI::ScatteringButton, Interaction::Pressed) => {
+                options.atmospheric_scattering ^= true;
+            }
+            _ => { /* empty */ }
+        }
+    }
+
+    for (&ui, mut color) in set.p0().iter_mut() {
+        let enabled = match ui {
+            UI::SpeedLineButton => options.speed_lines,
+            UI::ReduceMotionButton => options.reduce_motion,
+            UI::CaptionButton => options.captions,
+            UI::LandingMarkerButton => options.landing_marker,
+            UI::PaceClockButton => options.pace_clock,
+            UI::ScatteringButton => options.atmospheric_scattering,
+            _ => continue,
+        };
+
+        *color = if enabled {
+            BackgroundColor(GRAPHICS_BTN_COLOR.darker(0.5))
+        } else {
+            BackgroundColor(GRAPHICS_BTN_COLOR)
+        };
+    }
+}
+
+/// Handles interactions with the camera preset buttons.
+/// The selected preset is shown with a darker background.
+fn update_camera_button(
+    mut options: ResMut<GraphicsOptions>,
+    interaction_query: Query<(&Interaction, &CameraPresetChoice), Changed<Interaction>>,
+    mut query: Query<(&CameraPresetChoice, &mut BackgroundColor)>,
+) {
+    for (&interaction, choice) in interaction_query.iter() {
+        if interaction == Interaction::Pressed {
+            options.camera_preset = choice.0;
+        }
+    }
+
+    for (choice, mut color) in query.iter_mut() {
+        *color = if choice.0 == options.camera_preset {
+            BackgroundColor(GRAPHICS_BTN_COLOR.darker(0.5))
+        } else {
+            BackgroundColor(GRAPHICS_BTN_COLOR)
+        };
+    }
+}
+
+/// Handles interactions with the render scale buttons.
+/// The selected limit is shown with a darker background.
+fn update_render_scale_button(
+    mut options: ResMut<GraphicsOptions>,
+    interaction_query: Query<(&Interaction, &RenderScaleChoice), Changed<Interaction>>,
+    mut query: Query<(&RenderScaleChoice, &mut BackgroundColor)>,
+) {
+    for (&interaction, choice) in interaction_query.iter() {
+        if interaction == Interaction::Pressed {
+            options.render_scale_limit = choice.0;
+        }
+    }
+
+    for (choice, mut color) in query.iter_mut() {
+        *color = if choice.0 == options.render_scale_limit {
+            BackgroundColor(GRAPHICS_BTN_COLOR.darker(0.5))
+        } else {
+            BackgroundColor(GRAPHICS_BTN_COLOR)
+        };
+    }
+}
+
+/// Handles interactions with the device tier buttons.
+/// The selected tier is shown with a darker background.
+fn update_device_tier_button(
+    mut options: ResMut<GraphicsOptions>,
+    interaction_query: Query<(&Interaction, &DeviceTierChoice), Changed<Interaction>>,
+    mut query: Query<(&DeviceTierChoice, &mut BackgroundColor)>,
+) {
+    for (&interaction, choice) in interaction_query.iter() {
+        if interaction == Interaction::Pressed {
+            options.device_tier = choice.0;
+        }
+    }
+
+    for (choice, mut color) in query.iter_mut() {
+        *color = if choice.0 == options.device_tier {
+            BackgroundColor(GRAPHICS_BTN_COLOR.darker(0.5))
+        } else {
+            BackgroundColor(GRAPHICS_BTN_COLOR)
+        };
+    }
+}
+
+/// Handles interactions with the fog mode buttons.
+/// The selected mode is shown with a darker background.
+fn update_fog_mode_button(
+    mut options: ResMut<GraphicsOptions>,
+    interaction_query: Query<(&Interaction, &FogModeChoice), Changed<Interaction>>,
+    mut query: Query<(&FogModeChoice, &mut BackgroundColor)>,
+) {
+    for (&interaction, choice) in interaction_query.iter() {
+        if interaction == Interaction::Pressed {
+            options.fog_mode = choice.0;
+        }
+    }
+
+    for (choice, mut color) in query.iter_mut() {
+        *color = if choice.0 == options.fog_mode {
+            BackgroundColor(GRAPHICS_BTN_COLOR.darker(0.5))
+        } else {
+            BackgroundColor(GRAPHICS_BTN_COLOR)
+        };
+    }
+}
+
+/// Handles interactions with the output device button, which switches to the next output device.
+/// The button shows the name of the picked device, or the translated name of the default device of the system.
+#[cfg(not(target_arch = "wasm32"))]
+fn update_audio_device_button(
+    mut commands: Commands,
+    mut devices: ResMut<OutputDevices>,
+    interaction_query: Query<(&UI, &Interaction), Changed<Interaction>>,
+    mut query: Query<(Entity, &mut Text, Has<TranslatableText>), With<AudioDeviceName>>,
+) {
+    for (&ui, &interaction) in interaction_query.iter() {
+        if ui == UI::AudioDeviceButton && interaction == Interaction::Pressed {
+            devices.select_next();
+        }
+    }
+
+    for (entity, mut text, translated) in query.iter_mut() {
+        match devices.selected() {
+            Some(name) => {
+                if translated {
+                    commands.entity(entity).remove::<TranslatableText>();
+                }
+                if text.0 != name {
+                    text.0 = name.to_string();
+                }
+            }
+            None if !translated => {
+                commands
+                    .entity(entity)
+                    .insert(TranslatableText("system_default".into()));
+            }
+            None => { /* empty */ }
+        }
+    }
+}
+
+/// Handles interactions with the export and import buttons of the save profile.
+/// An imported profile replaces the current one only when the import button is pressed again to confirm it.
+fn update_profile_buttons(
+    mut commands: Commands,
+    mut save_data: SaveData,
+    mut import: ResMut<ProfileImport>,
+    interaction_query: Query<(&UI, &Interaction), Changed<Interaction>>,
+    mut slider_query: Query<(&mut Slider, &VolumeChannel)>,
+    mut label_query: Query<&mut TranslatableText, With<ImportLabel>>,
+) {
+    #[cfg(target_arch = "wasm32")]
+    if matches!(*import, ProfileImport::Waiting) {
+        import.poll();
+    }
+
+    for (&ui, &interaction) in interaction_query.iter() {
+        match (ui, interaction) {
+            (UI::ExportButton, Interaction::Pressed) => {
+                export_save_code(&save_data.profile().encode());
+                commands.trigger(Toast::info("Save code exported").with_icon(Icon::Share));
+            }
+            (UI::ImportButton, Interaction::Pressed) => match std::mem::take(&mut *import) {
+                ProfileImport::Pending(profile) => {
+                    save_data.apply(*profile);
+                    *import = ProfileImport::Done;
+                    info!("Imported the save profile");
+
+                    // The sliders keep their own value, so they are moved to the imported volume.
+                    let volume = save_data.volume();
+                    for (mut slider, channel) in slider_query.iter_mut() {
+                        slider.set(match channel {
+                            VolumeChannel::Master => volume.master_level(),
+                            VolumeChannel::Background => volume.background_level(),
+                            VolumeChannel::Effect => volume.effect_level(),
+                            VolumeChannel::Voice => volume.voice_level(),
+                        });
+                    }
+                }
+                #[cfg(target_arch = "wasm32")]
+                ProfileImport::Waiting => *import = ProfileImport::Waiting,
+                _ => import.start(),
+            },
+            _ => { /* empty */ }
+        }
+    }
+
+    if import.is_changed() {
+        match *import {
+            ProfileImport::Rejected => commands.trigger(Toast::warning("Invalid save code")),
+            ProfileImport::Done => commands.trigger(Toast::success("Save profile imported")),
+            _ => { /* empty */ }
+        }
+    }
+
+    for mut key in label_query.iter_mut() {
+        if key.0 != import.label() {
+            key.0 = import.label().to_string();
+        }
+    }
+}
+
+/// Handles interactions with the per-category mute toggle buttons.
+/// A muted category is shown with a darker background.
+#[allow(clippy::type_complexity)]
+fn update_mute_button(
+    mut system_volume: ResMut<SystemVolume>,
+    mut set: ParamSet<(
+        Query<(&UI, &mut BackgroundColor), With<Button>>,
+        Query<(&UI, &Interaction, &mut BackgroundColor), (Changed<Interaction>, With<Button>)>,
+    )>,
+) {
+    for (&ui, &interaction, _) in set.p1().iter_mut() {
+        match (ui, interaction) {
+            (UI::BgmMuteButton, Interaction::Hovered)
+            | (UI::SfxMuteButton, Interaction::Hovered)
+            | (UI::VoiceMuteButton, Interaction::Hovered) => {}
+            (UI::BgmMuteButton, Interaction::Pressed) => {
+                system_volume.background_muted ^= true;
+            }
+            (UI::SfxMuteButton, Interaction::Pressed) => {
+                system_volume.effect_muted ^= true;
+            }
+            (UI::VoiceMuteButton, Interaction::Pressed) => {
+                system_volume.voice_muted ^= true;
+            }
+            _ => { /* empty */ }
+        }
+    }
+
+    for (&ui, mut color) in set.p0().iter_mut() {
+        let muted = match ui {
+            UI::BgmMuteButton => system_volume.background_muted,
+            UI::SfxMuteButton => system_volume.effect_muted,
+            UI::VoiceMuteButton => system_volume.voice_muted,
+            _ => continue,
+        };
+
+        *color = if muted {
+            BackgroundColor(MUTE_BTN_COLOR.darker(0.5))
+        } else {
+            BackgroundColor(MUTE_BTN_COLOR)
+        };
+    }
+}
+
 /// Handles interactions with the 'Back' button.
 /// It provides visual feedback and transitions back to the `Title` state when pressed.
 #[allow(clippy::type_complexity)]
 fn update_back_button(
-    mut commands: Commands,
-    asset_server: Res<AssetServer>,
-    system_volume: Res<SystemVolume>,
     in_game_query: Query<(), With<InGameStateRoot>>,
     mut next_state: ResMut<NextState<GameState>>,
     mut interaction_query: Query<
@@ -496,11 +696,9 @@ fn update_back_button(
         match (ui, interaction) {
             (UI::BackButton, Interaction::Hovered) => {
                 *color = BackgroundColor(BACK_BTN_COLOR.darker(0.1));
-                play_button_sound_when_hovered(&mut commands, &asset_server, &system_volume);
             }
             (UI::BackButton, Interaction::Pressed) => {
                 *color = BackgroundColor(BACK_BTN_COLOR.darker(0.2));
-                play_button_sound_when_returned(&mut commands, &asset_server, &system_volume);
                 // Return to the previous screen.
                 if in_game_query.is_empty() {
                     next_state.set(GameState::Title);
@@ -577,85 +775,7 @@ fn control_voice_volume(
 }
 
 #[cfg(not(target_arch = "wasm32"))]
-fn play_button_sound_when_hovered(
-    commands: &mut Commands,
-    asset_server: &AssetServer,
-    system_volume: &SystemVolume,
-) {
-    commands.spawn((
-        AudioPlayer::new(asset_server.load(SOUND_PATH_UI_LOADING)),
-        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.effect_percentage())),
-        EffectSound,
-    ));
-}
-
-#[cfg(target_arch = "wasm32")]
-fn play_button_sound_when_hovered(
-    commands: &mut Commands,
-    asset_server: &AssetServer,
-    system_volume: &SystemVolume,
-) {
-    commands.spawn((
-        WebAudioPlayer::new(asset_server.load(SOUND_PATH_UI_LOADING)),
-        WebPlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.effect_percentage())),
-        EffectSound,
-    ));
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-fn play_button_sound_when_pressed(
-    commands: &mut Commands,
-    asset_server: &AssetServer,
-    system_volume: &SystemVolume,
-) {
-    commands.spawn((
-        AudioPlayer::new(asset_server.load(SOUND_PATH_UI_BUTTON_TOUCH)),
-        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.effect_percentage())),
-        EffectSound,
-    ));
-}
-
-#[cfg(target_arch = "wasm32")]
-fn play_button_sound_when_pressed(
-    commands: &mut Commands,
-    asset_server: &AssetServer,
-    system_volume: &SystemVolume,
-) {
-    commands.spawn((
-        WebAudioPlayer::new(asset_server.load(SOUND_PATH_UI_BUTTON_TOUCH)),
-        WebPlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.effect_percentage())),
-        EffectSound,
-    ));
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-fn play_button_sound_when_returned(
-    commands: &mut Commands,
-    asset_server: &AssetServer,
-    system_volume: &SystemVolume,
-) {
-    commands.spawn((
-        AudioPlayer::new(asset_server.load(SOUND_PATH_UI_BUTTON_BACK)),
-        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.effect_percentage())),
-        EffectSound,
-    ));
-}
-
-#[cfg(target_arch = "wasm32")]
-fn play_button_sound_when_returned(
-    commands: &mut Commands,
-    asset_server: &AssetServer,
-    system_volume: &SystemVolume,
-) {
-    commands.spawn((
-        WebAudioPlayer::new(asset_server.load(SOUND_PATH_UI_BUTTON_BACK)),
-        WebPlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.effect_percentage())),
-        EffectSound,
-    ));
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-fn play_sfx_feedback_when_released(
+fn play_sfx_preview(
     commands: &mut Commands,
     asset_server: &AssetServer,
     system_volume: &SystemVolume,
@@ -668,7 +788,7 @@ fn play_sfx_feedback_when_released(
 }
 
 #[cfg(target_arch = "wasm32")]
-fn play_sfx_feedback_when_released(
+fn play_sfx_preview(
     commands: &mut Commands,
     asset_server: &AssetServer,
     system_volume: &SystemVolume,
@@ -680,36 +800,15 @@ fn play_sfx_feedback_when_released(
     ));
 }
 
-#[cfg(not(target_arch = "wasm32"))]
-fn play_voice_feedback_when_released(
-    commands: &mut Commands,
-    asset_server: &AssetServer,
-    system_volume: &SystemVolume,
-) {
-    let path = SOUND_PATH_VO_TITLES
-        .choose(&mut rand::rng())
-        .copied()
-        .unwrap();
-    commands.spawn((
-        AudioPlayer::new(asset_server.load(path)),
-        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.voice_percentage())),
-        VoiceSound,
-    ));
-}
-
-#[cfg(target_arch = "wasm32")]
-fn play_voice_feedback_when_released(
+fn play_voice_preview(
     commands: &mut Commands,
     asset_server: &AssetServer,
     system_volume: &SystemVolume,
+    rng: &mut RngService,
 ) {
     let path = SOUND_PATH_VO_TITLES
-        .choose(&mut rand::rng())
+        .choose(rng.cosmetic())
         .copied()
         .unwrap();
-    commands.spawn((
-        WebAudioPlayer::new(asset_server.load(path)),
-        WebPlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.voice_percentage())),
-        VoiceSound,
-    ));
+    play_voice(commands, asset_server, system_volume, path);
 }
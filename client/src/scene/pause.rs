@@ -1,10 +1,9 @@
 // Import necessary Bevy modules.
-use bevy::{audio::Volume, prelude::*};
-
-use crate::asset::sound::SystemVolume;
+use bevy::prelude::*;
 
+use crate::shader::pause_blur::PauseBlurMaterial;
 #[cfg(target_arch = "wasm32")]
-use crate::web::{WebAudioPlayer, WebPlaybackSettings};
+use crate::web::WebPlaybackSettings;
 
 use super::*;
 
@@ -24,14 +23,17 @@ impl Plugin for StatePlugin {
                 pause_voice_sounds,
             ),
         )
-        .add_systems(OnExit(GameState::Pause), (hide_title, hide_interface))
+        .add_systems(
+            OnExit(GameState::Pause),
+            (hide_title, hide_interface, hide_backdrop),
+        )
         .add_systems(
             PreUpdate,
             (handle_player_input, handle_button_system).run_if(in_state(GameState::Pause)),
         )
         .add_systems(
             Update,
-            update_pause_title.run_if(in_state(GameState::Pause)),
+            (update_pause_title, show_backdrop).run_if(in_state(GameState::Pause)),
         );
     }
 }
@@ -99,6 +101,15 @@ fn hide_title(mut query: Query<&mut Visibility, With<PauseTitle>>) {
     }
 }
 
+/// Lets go of the captured frame, which is taken again the next time the game is paused.
+fn hide_backdrop(mut commands: Commands, query: Query<Entity, With<PauseBackdrop>>) {
+    for entity in query.iter() {
+        commands
+            .entity(entity)
+            .remove::<MaterialNode<PauseBlurMaterial>>();
+    }
+}
+
 fn hide_interface(mut query: Query<(&UI, &mut Visibility)>) {
     for (&ui, mut visibility) in query.iter_mut() {
         match ui {
@@ -132,26 +143,48 @@ fn update_pause_title(mut query: Query<&mut Visibility, With<PauseTitle>>, time:
     }
 }
 
+/// Shows the last frame of the world, blurred, behind the pause menu once it has been captured.
 #[allow(clippy::type_complexity)]
-fn handle_button_system(
+fn show_backdrop(
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
-    system_volume: Res<SystemVolume>,
+    mut materials: ResMut<Assets<PauseBlurMaterial>>,
+    camera_query: Query<(&OffscreenView, Has<MirroredView>), With<CameraRig>>,
+    query: Query<
+        Entity,
+        (
+            With<PauseBackdrop>,
+            Without<MaterialNode<PauseBlurMaterial>>,
+        ),
+    >,
+) {
+    let Ok((view, mirrored)) = camera_query.single() else {
+        return;
+    };
+
+    for entity in query.iter() {
+        let material = PauseBlurMaterial::new(view.image().clone(), mirrored);
+        commands
+            .entity(entity)
+            .insert(MaterialNode(materials.add(material)));
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn handle_button_system(
     mut query: Query<
         (&UI, &Interaction, &mut BackgroundColor),
         (Changed<Interaction>, With<Button>),
     >,
     mut next_state: ResMut<NextState<GameState>>,
+    mut transition: EventWriter<RequestTransition>,
 ) {
     for (ui, interaction, mut color) in query.iter_mut() {
         match (*ui, *interaction) {
             (UI::ResumeButton, Interaction::Hovered) => {
                 color.0 = RESUME_BTN_COLOR.darker(0.15);
-                play_button_sound_when_hovered(&mut commands, &asset_server, &system_volume);
             }
             (UI::ResumeButton, Interaction::Pressed) => {
                 color.0 = RESUME_BTN_COLOR.darker(0.3);
-                play_button_sound_when_pressed(&mut commands, &asset_server, &system_volume);
                 next_state.set(GameState::Resume);
             }
             (UI::ResumeButton, Interaction::None) => {
@@ -159,11 +192,9 @@ fn handle_button_system(
             }
             (UI::OptionButton, Interaction::Hovered) => {
                 color.0 = OPTION_BTN_COLOR.darker(0.15);
-                play_button_sound_when_hovered(&mut commands, &asset_server, &system_volume);
             }
             (UI::OptionButton, Interaction::Pressed) => {
                 color.0 = OPTION_BTN_COLOR.darker(0.3);
-                play_button_sound_when_pressed(&mut commands, &asset_server, &system_volume);
                 next_state.set(GameState::Option);
             }
             (UI::OptionButton, Interaction::None) => {
@@ -171,12 +202,10 @@ fn handle_button_system(
             }
             (UI::InGameExitButton, Interaction::Hovered) => {
                 color.0 = EXIT_BTN_COLOR.darker(0.15);
-                play_button_sound_when_hovered(&mut commands, &asset_server, &system_volume);
             }
             (UI::InGameExitButton, Interaction::Pressed) => {
                 color.0 = EXIT_BTN_COLOR.darker(0.3);
-                play_button_sound_when_returned(&mut commands, &asset_server, &system_volume);
-                next_state.set(GameState::ExitInGame);
+                transition.write(RequestTransition::fade(GameState::ExitInGame));
             }
             (UI::InGameExitButton, Interaction::None) => {
                 color.0 = EXIT_BTN_COLOR;
@@ -185,81 +214,3 @@ fn handle_button_system(
         }
     }
 }
-
-#[cfg(not(target_arch = "wasm32"))]
-fn play_button_sound_when_hovered(
-    commands: &mut Commands,
-    asset_server: &AssetServer,
-    system_volume: &SystemVolume,
-) {
-    commands.spawn((
-        AudioPlayer::new(asset_server.load(SOUND_PATH_UI_LOADING)),
-        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.effect_percentage())),
-        EffectSound,
-    ));
-}
-
-#[cfg(target_arch = "wasm32")]
-fn play_button_sound_when_hovered(
-    commands: &mut Commands,
-    asset_server: &AssetServer,
-    system_volume: &SystemVolume,
-) {
-    commands.spawn((
-        WebAudioPlayer::new(asset_server.load(SOUND_PATH_UI_LOADING)),
-        WebPlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.effect_percentage())),
-        EffectSound,
-    ));
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-fn play_button_sound_when_pressed(
-    commands: &mut Commands,
-    asset_server: &AssetServer,
-    system_volume: &SystemVolume,
-) {
-    commands.spawn((
-        AudioPlayer::new(asset_server.load(SOUND_PATH_UI_BUTTON_TOUCH)),
-        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.effect_percentage())),
-        EffectSound,
-    ));
-}
-
-#[cfg(target_arch = "wasm32")]
-fn play_button_sound_when_pressed(
-    commands: &mut Commands,
-    asset_server: &AssetServer,
-    system_volume: &SystemVolume,
-) {
-    commands.spawn((
-        WebAudioPlayer::new(asset_server.load(SOUND_PATH_UI_BUTTON_TOUCH)),
-        WebPlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.effect_percentage())),
-        EffectSound,
-    ));
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-fn play_button_sound_when_returned(
-    commands: &mut Commands,
-    asset_server: &AssetServer,
-    system_volume: &SystemVolume,
-) {
-    commands.spawn((
-        AudioPlayer::new(asset_server.load(SOUND_PATH_UI_BUTTON_BACK)),
-        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.effect_percentage())),
-        EffectSound,
-    ));
-}
-
-#[cfg(target_arch = "wasm32")]
-fn play_button_sound_when_returned(
-    commands: &mut Commands,
-    asset_server: &AssetServer,
-    system_volume: &SystemVolume,
-) {
-    commands.spawn((
-        WebAudioPlayer::new(asset_server.load(SOUND_PATH_UI_BUTTON_BACK)),
-        WebPlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.effect_percentage())),
-        EffectSound,
-    ));
-}
@@ -0,0 +1,314 @@
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use bevy::{ecs::system::SystemParam, prelude::*};
+use serde::{Deserialize, Serialize};
+
+use crate::asset::sound::SystemVolume;
+
+use super::*;
+
+/// The start of every save code, so that other text is told apart from a damaged code.
+const SAVE_CODE_PREFIX: &str = "shupogaki:";
+/// The name of the file a save profile is exported to.
+pub const SAVE_PROFILE_FILE_NAME: &str = "shupogaki_save.txt";
+
+/// Everything the game saves, gathered so that the progress can be moved to another browser or machine.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SaveProfile {
+    high_score: u32,
+    assist_high_score: u32,
+    hardcore_high_score: u32,
+    assist_mode: bool,
+    train_upgrades: TrainUpgrades,
+    best_pace: BestPace,
+    run_history: RunHistory,
+    system_volume: SystemVolume,
+    graphics_options: GraphicsOptions,
+}
+
+impl SaveProfile {
+    /// Encodes the profile as a code of plain characters, which can be saved to a file or pasted.
+    pub fn encode(&self) -> String {
+        let json = SAVE_PROFILE_SCHEMA.encode(self).unwrap_or_default();
+        format!("{}{}", SAVE_CODE_PREFIX, URL_SAFE_NO_PAD.encode(json))
+    }
+
+    /// Decodes a save code, checking that it holds a complete profile this version can read.
+    /// Profiles of older versions are migrated.
+    pub fn decode(code: &str) -> Result<Self, SaveProfileError> {
+        let encoded = code
+            .trim()
+            .strip_prefix(SAVE_CODE_PREFIX)
+            .ok_or(SaveProfileError::NotASaveCode)?;
+        let json = URL_SAFE_NO_PAD.decode(encoded)?;
+        Ok(SAVE_PROFILE_SCHEMA.decode(json)?)
+    }
+}
+
+/// The reason a save code was refused.
+#[derive(Debug, thiserror::Error)]
+pub enum SaveProfileError {
+    #[error("The text is not a save code")]
+    NotASaveCode,
+    #[error("The save code is damaged: {0}")]
+    Damaged(#[from] base64::DecodeError),
+    #[error("The save code does not hold a valid profile: {0}")]
+    InvalidProfile(#[from] SaveSchemaError),
+}
+
+// --- RESOURCES ---
+
+/// The progress of importing a save profile from the options.
+/// A valid profile waits for the player to confirm it before it replaces the current one.
+#[derive(Default, Resource)]
+pub enum ProfileImport {
+    #[default]
+    Idle,
+    /// The browser is waiting for the player to pick a file.
+    #[cfg(target_arch = "wasm32")]
+    Waiting,
+    Pending(Box<SaveProfile>),
+    Rejected,
+    Done,
+}
+
+impl ProfileImport {
+    /// The translation key of the label of the import button for the current step.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Idle => "import",
+            #[cfg(target_arch = "wasm32")]
+            Self::Waiting => "import_waiting",
+            Self::Pending(_) => "import_confirm",
+            Self::Rejected => "import_invalid",
+            Self::Done => "imported",
+        }
+    }
+
+    /// Starts reading a save code from the file the profile is exported to.
+    /// Natively there is no file dialog to pick another file, so the file is always
+    /// read from the working directory, where [`export_save_code`] writes it.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn start(&mut self) {
+        match std::fs::read_to_string(SAVE_PROFILE_FILE_NAME) {
+            Ok(code) => self.receive(&code),
+            Err(e) => {
+                warn!("Failed to read {}: {}", SAVE_PROFILE_FILE_NAME, e);
+                *self = Self::Rejected;
+            }
+        }
+    }
+
+    /// Starts reading a save code from a file the player picks in the browser.
+    #[cfg(target_arch = "wasm32")]
+    pub fn start(&mut self) {
+        crate::web::upload_file(".txt,text/plain");
+        *self = Self::Waiting;
+    }
+
+    /// Takes the file picked in the browser once it has been read.
+    #[cfg(target_arch = "wasm32")]
+    pub fn poll(&mut self) {
+        if let Some(code) = crate::web::take_uploaded_file() {
+            self.receive(&code);
+        }
+    }
+
+    /// Takes a save code read from a file, keeping it for confirmation if it is valid.
+    pub fn receive(&mut self, code: &str) {
+        *self = match SaveProfile::decode(code) {
+            Ok(profile) => Self::Pending(Box::new(profile)),
+            Err(e) => {
+                warn!("Failed to import the save profile: {}", e);
+                Self::Rejected
+            }
+        };
+    }
+}
+
+/// Writes the save code to a file in the working directory, replacing the previous export.
+/// There is no file dialog natively, so the file name and place are fixed.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn export_save_code(code: &str) {
+    match std::fs::write(SAVE_PROFILE_FILE_NAME, code) {
+        Ok(_) => info!("Save profile exported to {}", SAVE_PROFILE_FILE_NAME),
+        Err(e) => error!("Failed to export the save profile: {:?}", e),
+    }
+}
+
+/// Lets the browser save the save code as a file, and copies it to the clipboard.
+#[cfg(target_arch = "wasm32")]
+pub fn export_save_code(code: &str) {
+    crate::web::download_file(SAVE_PROFILE_FILE_NAME, code.as_bytes(), "text/plain");
+    crate::web::copy_to_clipboard(code);
+}
+
+// --- SYSTEM PARAMS ---
+
+/// The resources that make up the save profile.
+#[derive(SystemParam)]
+pub struct SaveData<'w> {
+    high_score: ResMut<'w, HighScore>,
+    assist_high_score: ResMut<'w, AssistHighScore>,
+    hardcore_high_score: ResMut<'w, HardcoreHighScore>,
+    assist_mode: ResMut<'w, AssistMode>,
+    train_upgrades: ResMut<'w, TrainUpgrades>,
+    best_pace: ResMut<'w, BestPace>,
+    run_history: ResMut<'w, RunHistory>,
+    system_volume: ResMut<'w, SystemVolume>,
+    graphics_options: ResMut<'w, GraphicsOptions>,
+}
+
+impl SaveData<'_> {
+    /// Gathers the current progress and options into a profile.
+    pub fn profile(&self) -> SaveProfile {
+        SaveProfile {
+            high_score: self.high_score.0,
+            assist_high_score: self.assist_high_score.0,
+            hardcore_high_score: self.hardcore_high_score.0,
+            assist_mode: self.assist_mode.0,
+            train_upgrades: *self.train_upgrades,
+            best_pace: self.best_pace.clone(),
+            run_history: *self.run_history,
+            system_volume: self.system_volume.clone(),
+            graphics_options: *self.graphics_options,
+        }
+    }
+
+    /// The current system volume.
+    pub fn volume(&self) -> &SystemVolume {
+        &self.system_volume
+    }
+
+    /// Replaces the current progress and options with those of the profile, and stores them.
+    pub fn apply(&mut self, profile: SaveProfile) {
+        self.high_score.0 = profile.high_score;
+        self.assist_high_score.0 = profile.assist_high_score;
+        self.hardcore_high_score.0 = profile.hardcore_high_score;
+        self.assist_mode.0 = profile.assist_mode;
+        *self.train_upgrades = profile.train_upgrades;
+        *self.best_pace = profile.best_pace;
+        *self.run_history = profile.run_history;
+        *self.system_volume = profile.system_volume;
+        *self.graphics_options = profile.graphics_options;
+
+        #[cfg(target_arch = "wasm32")]
+        self.store();
+    }
+
+    /// Writes every part of the profile to the local storage of the browser.
+    #[cfg(target_arch = "wasm32")]
+    fn store(&self) {
+        let Some(storage) = get_local_storage() else {
+            return;
+        };
+
+        let _ = storage.set_item(HIGH_SCORE_KEY, &self.high_score.0.to_string());
+        let _ = storage.set_item(ASSIST_HIGH_SCORE_KEY, &self.assist_high_score.0.to_string());
+        let _ = storage.set_item(
+            HARDCORE_HIGH_SCORE_KEY,
+            &self.hardcore_high_score.0.to_string(),
+        );
+        let _ = storage.set_item(ASSIST_MODE_KEY, &self.assist_mode.0.to_string());
+        let items = [
+            (
+                TRAIN_UPGRADES_KEY,
+                TRAIN_UPGRADES_SCHEMA.encode(&*self.train_upgrades),
+            ),
+            (BEST_PACE_KEY, BEST_PACE_SCHEMA.encode(&*self.best_pace)),
+            (
+                RUN_HISTORY_KEY,
+                RUN_HISTORY_SCHEMA.encode(&*self.run_history),
+            ),
+            (
+                SYSTEM_VOLUME_KEY,
+                SYSTEM_VOLUME_SCHEMA.encode(&*self.system_volume),
+            ),
+            (
+                GRAPHICS_OPTIONS_KEY,
+                GRAPHICS_OPTIONS_SCHEMA.encode(&*self.graphics_options),
+            ),
+        ];
+        for (key, value) in items {
+            if let Ok(value) = value {
+                let _ = storage.set_item(key, &value);
+            }
+        }
+        info!("Stored the imported save profile");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile() -> SaveProfile {
+        SaveProfile {
+            high_score: 12_345,
+            assist_high_score: 678,
+            hardcore_high_score: 90,
+            assist_mode: true,
+            train_upgrades: TrainUpgrades::default(),
+            best_pace: BestPace::default(),
+            run_history: RunHistory::default(),
+            system_volume: SystemVolume::default(),
+            graphics_options: GraphicsOptions::default(),
+        }
+    }
+
+    fn code(json: &str) -> String {
+        format!("{}{}", SAVE_CODE_PREFIX, URL_SAFE_NO_PAD.encode(json))
+    }
+
+    #[test]
+    fn codes_round_trip() {
+        let code = profile().encode();
+        assert!(code.starts_with(SAVE_CODE_PREFIX));
+
+        let decoded = SaveProfile::decode(&format!("  {}\n", code)).unwrap();
+        assert_eq!(decoded.high_score, 12_345);
+        assert_eq!(decoded.assist_high_score, 678);
+        assert_eq!(decoded.hardcore_high_score, 90);
+        assert!(decoded.assist_mode);
+        assert_eq!(decoded.encode(), code);
+    }
+
+    #[test]
+    fn codes_load_from_every_version() {
+        let profile =
+            SaveProfile::decode(include_str!("../../fixtures/saves/save_profile_v1.txt")).unwrap();
+        assert_eq!(profile.high_score, 8_800);
+        assert_eq!(profile.train_upgrades.extra_cars, 1);
+        assert_eq!(profile.best_pace.splits, vec![9_800, 19_100]);
+        assert_eq!(profile.run_history.best.map(|run| run.score), Some(8_800));
+        assert!(profile.graphics_options.pace_clock);
+    }
+
+    #[test]
+    fn invalid_codes_are_refused() {
+        assert!(matches!(
+            SaveProfile::decode("hello"),
+            Err(SaveProfileError::NotASaveCode)
+        ));
+        assert!(matches!(
+            SaveProfile::decode("shupogaki:!!!"),
+            Err(SaveProfileError::Damaged(_))
+        ));
+        assert!(matches!(
+            SaveProfile::decode(&code(r#"{"version":1,"high_score":10}"#)),
+            Err(SaveProfileError::InvalidProfile(SaveSchemaError::Invalid(
+                _
+            )))
+        ));
+
+        let newer = profile().encode().replace(SAVE_CODE_PREFIX, "");
+        let json = String::from_utf8(URL_SAFE_NO_PAD.decode(newer).unwrap()).unwrap();
+        let json = json.replace(r#""version":1"#, r#""version":2"#);
+        assert!(matches!(
+            SaveProfile::decode(&code(&json)),
+            Err(SaveProfileError::InvalidProfile(SaveSchemaError::Newer {
+                version: 2,
+                ..
+            }))
+        ));
+    }
+}
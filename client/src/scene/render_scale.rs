@@ -0,0 +1,124 @@
+use bevy::prelude::*;
+
+use super::*;
+
+/// The longest a frame may take, in seconds, before it counts as over budget.
+const FRAME_TIME_BUDGET: f32 = 1.0 / 50.0;
+/// How much of the latest frame time goes into the smoothed frame time.
+const FRAME_TIME_SMOOTHING: f32 = 0.1;
+/// How much the render scale changes in one step.
+const RENDER_SCALE_STEP: f32 = 0.125;
+/// The time, in seconds, frames must stay over budget before the render scale is lowered.
+const SCALE_DOWN_DELAY: f32 = 1.0;
+/// The time, in seconds, frames must stay within budget before the render scale is raised.
+const SCALE_UP_DELAY: f32 = 4.0;
+/// The longest the governor waits before raising the render scale again after it had to lower it.
+const MAX_SCALE_UP_DELAY: f32 = 60.0;
+
+// --- PLUGIN ---
+
+/// Lowers the resolution the in-game camera renders at while frames take too long,
+/// and raises it again once they have headroom. The UI is always drawn at the resolution of the window.
+///
+/// The camera is only rendered offscreen, at the [`RenderScale`], if the render scale limit allows it to go below native.
+pub struct RenderScalePlugin;
+
+impl Plugin for RenderScalePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RenderScale>()
+            .add_systems(
+                Update,
+                govern_render_scale.run_if(in_state(GameState::InGame)),
+            )
+            .add_systems(
+                PostUpdate,
+                scale_in_game_camera.before(OffscreenViewSystems),
+            );
+    }
+}
+
+// --- RESOURCES ---
+
+/// The scale of the window resolution the in-game camera renders at.
+#[derive(Debug, Resource)]
+pub struct RenderScale {
+    scale: f32,
+    frame_time: f32,
+    over_budget: f32,
+    within_budget: f32,
+    /// Grows each time the scale is lowered, so that it does not keep flickering between two steps.
+    scale_up_delay: f32,
+}
+
+impl RenderScale {
+    pub fn get(&self) -> f32 {
+        self.scale
+    }
+
+    fn on_frame(&mut self, delta: f32, min_scale: f32) {
+        self.frame_time = self.frame_time.lerp(delta, FRAME_TIME_SMOOTHING);
+        if self.frame_time > FRAME_TIME_BUDGET {
+            self.over_budget += delta;
+            self.within_budget = 0.0;
+        } else {
+            self.within_budget += delta;
+            self.over_budget = 0.0;
+        }
+
+        if self.over_budget >= SCALE_DOWN_DELAY && self.scale > min_scale {
+            self.scale = (self.scale - RENDER_SCALE_STEP).max(min_scale);
+            self.scale_up_delay = (self.scale_up_delay * 2.0).min(MAX_SCALE_UP_DELAY);
+            self.over_budget = 0.0;
+            info!("Lower the render scale to {:.3}", self.scale);
+        } else if self.within_budget >= self.scale_up_delay && self.scale < 1.0 {
+            self.scale = (self.scale + RENDER_SCALE_STEP).min(1.0);
+            if self.scale >= 1.0 {
+                self.scale_up_delay = SCALE_UP_DELAY;
+            }
+            self.within_budget = 0.0;
+            info!("Raise the render scale to {:.3}", self.scale);
+        }
+
+        // The limit may have been raised in the options modal.
+        self.scale = self.scale.max(min_scale);
+    }
+}
+
+impl Default for RenderScale {
+    fn default() -> Self {
+        Self {
+            scale: 1.0,
+            frame_time: 0.0,
+            over_budget: 0.0,
+            within_budget: 0.0,
+            scale_up_delay: SCALE_UP_DELAY,
+        }
+    }
+}
+
+// --- UPDATE SYSTEMS ---
+
+fn govern_render_scale(
+    mut render_scale: ResMut<RenderScale>,
+    options: Res<GraphicsOptions>,
+    time: Res<Time<Real>>,
+) {
+    let min_scale = options.render_scale_limit.min_scale();
+    render_scale.on_frame(time.delta_secs(), min_scale);
+}
+
+// --- POSTUPDATE SYSTEMS ---
+
+fn scale_in_game_camera(
+    mut commands: Commands,
+    options: Res<GraphicsOptions>,
+    query: Query<Entity, Added<CameraRig>>,
+) {
+    if options.render_scale_limit == RenderScaleLimit::Native {
+        return;
+    }
+
+    for entity in query.iter() {
+        commands.entity(entity).insert(RenderOffscreen);
+    }
+}
@@ -1,220 +1,407 @@
 use std::collections::VecDeque;
 
 use bevy::audio::Volume;
-use bevy::{platform::collections::HashMap, prelude::*};
-use rand::{Rng, distr::Distribution, seq::IndexedRandom};
+use bevy::{
+    platform::collections::{HashMap, HashSet},
+    prelude::*,
+};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
 
-use crate::asset::{animation::AnimationClipHandle, sound::SystemVolume, spawner::SpawnModel};
+use crate::asset::{
+    animation::AnimationClipHandle, model::ModelAsset, sound::SystemVolume, spawner::SpawnModel,
+};
 
 #[cfg(target_arch = "wasm32")]
 use crate::web::{WebAudioPlayer, WebPlaybackSettings};
 
 use super::*;
 
-#[derive(Default, Resource)]
-pub struct HighScore(pub u32);
-
-#[derive(Default, Resource, Deref, DerefMut)]
-pub struct RetryCounter(pub u32);
-
-#[derive(Resource)]
-pub struct SceneTimer {
-    elapsed_time: f32,
+/// The user's graphics preferences, toggled in the options modal.
+#[derive(Debug, Clone, Copy, Resource, Deserialize, Serialize)]
+pub struct GraphicsOptions {
+    pub speed_lines: bool,
+    pub reduce_motion: bool,
+    /// Shows the subtitles of voice lines.
+    #[serde(default)]
+    pub captions: bool,
+    /// Shows where the player will land while jumping.
+    #[serde(default)]
+    pub landing_marker: bool,
+    /// Shows the play time and the pace against the best run while playing.
+    #[serde(default)]
+    pub pace_clock: bool,
+    /// Where the in-game camera sits.
+    #[serde(default)]
+    pub camera_preset: CameraPreset,
+    /// Compares a finished run with the best run instead of the previous one on the result screen.
+    #[serde(default)]
+    pub compare_with_best: bool,
+    /// How far the in-game camera may lower its resolution when frames take too long.
+    #[serde(default)]
+    pub render_scale_limit: RenderScaleLimit,
+    /// How far down the track objects are placed. Applies from the next time a run is loaded.
+    #[serde(default)]
+    pub device_tier: DeviceTier,
+    /// How the end of the track fades into the sky.
+    #[serde(default)]
+    pub fog_mode: FogMode,
+    /// Lights the fog with the sun when looking toward it.
+    #[serde(default)]
+    pub atmospheric_scattering: bool,
 }
 
-impl SceneTimer {
-    pub fn elapsed_sec(&self) -> f32 {
-        self.elapsed_time
-    }
+/// The framings of the in-game camera the player can choose from in the options modal.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum CameraPreset {
+    /// The original view from high up beside the track.
+    #[default]
+    Classic,
+    /// A lower view that makes the height of a jump easier to judge.
+    LowChase,
+    /// A view from further up that shows more of the track ahead.
+    High,
+}
 
-    pub fn tick(&mut self, elapsed: f32) {
-        self.elapsed_time += elapsed;
-    }
+impl CameraPreset {
+    pub const ALL: [CameraPreset; 3] = [
+        CameraPreset::Classic,
+        CameraPreset::LowChase,
+        CameraPreset::High,
+    ];
 
-    pub fn reset(&mut self) {
-        self.elapsed_time = 0.0;
+    /// The translation key of the label of the option button.
+    pub fn label(self) -> &'static str {
+        match self {
+            CameraPreset::Classic => "camera_classic",
+            CameraPreset::LowChase => "camera_low_chase",
+            CameraPreset::High => "camera_high",
+        }
     }
 }
 
-impl Default for SceneTimer {
-    fn default() -> Self {
-        Self { elapsed_time: 0.0 }
-    }
+/// The lowest resolution the in-game camera may be rendered at, chosen in the options modal.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum RenderScaleLimit {
+    /// Always renders at the resolution of the window.
+    #[default]
+    Native,
+    ThreeQuarters,
+    Half,
 }
 
-#[derive(Default, Resource)]
-pub struct PlayTime {
-    play_time_ms: u128,
-}
+impl RenderScaleLimit {
+    pub const ALL: [RenderScaleLimit; 3] = [
+        RenderScaleLimit::Native,
+        RenderScaleLimit::ThreeQuarters,
+        RenderScaleLimit::Half,
+    ];
 
-impl PlayTime {
-    pub fn tick(&mut self, time: &Time) {
-        self.play_time_ms = self.play_time_ms.saturating_add(time.delta().as_millis());
+    /// The translation key of the label of the option button.
+    pub fn label(self) -> &'static str {
+        match self {
+            RenderScaleLimit::Native => "render_scale_native",
+            RenderScaleLimit::ThreeQuarters => "render_scale_75",
+            RenderScaleLimit::Half => "render_scale_50",
+        }
     }
 
-    pub fn millis(&self) -> u128 {
-        self.play_time_ms
+    /// Returns the lowest scale of the window resolution the camera may be rendered at.
+    pub fn min_scale(self) -> f32 {
+        match self {
+            RenderScaleLimit::Native => 1.0,
+            RenderScaleLimit::ThreeQuarters => 0.75,
+            RenderScaleLimit::Half => 0.5,
+        }
     }
 }
 
-#[derive(Default, Resource)]
-pub struct Attacked {
-    count: u32,
+/// The class of device the game is tuned for, chosen in the options modal.
+/// Touch devices default to the mobile tier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum DeviceTier {
+    /// Keeps fewer objects on a shorter track, hidden in the fog.
+    Mobile,
+    Desktop,
 }
 
-impl Attacked {
-    pub fn add(&mut self) {
-        self.count = self.count.saturating_add(1);
+impl DeviceTier {
+    pub const ALL: [DeviceTier; 2] = [DeviceTier::Mobile, DeviceTier::Desktop];
+
+    /// The translation key of the label of the option button.
+    pub fn label(self) -> &'static str {
+        match self {
+            DeviceTier::Mobile => "tier_mobile",
+            DeviceTier::Desktop => "tier_desktop",
+        }
     }
-}
 
-#[derive(Resource)]
-pub struct InputDelay {
-    remaining: f32,
+    pub fn horizon(self) -> TrackHorizon {
+        match self {
+            DeviceTier::Mobile => TrackHorizon {
+                spawn: MOBILE_SPAWN_POSITION,
+                despawn: MOBILE_DESPAWN_POSITION,
+                fog_start: MOBILE_FOG_START,
+            },
+            DeviceTier::Desktop => TrackHorizon::default(),
+        }
+    }
 }
 
-impl InputDelay {
-    pub fn on_advanced(&mut self, elapsed: f32) {
-        self.remaining = (self.remaining - elapsed).max(0.0);
+impl Default for DeviceTier {
+    #[cfg(not(target_arch = "wasm32"))]
+    fn default() -> Self {
+        DeviceTier::Desktop
     }
 
-    pub fn is_expired(&self) -> bool {
-        self.remaining <= 0.0
+    #[cfg(target_arch = "wasm32")]
+    fn default() -> Self {
+        match crate::web::is_touch_device() {
+            true => DeviceTier::Mobile,
+            false => DeviceTier::Desktop,
+        }
     }
+}
 
-    pub fn reset(&mut self) {
-        self.remaining = INPUT_DELAY_TIME;
-    }
+/// How the distance fog of the in-game camera falls off, chosen in the options modal.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum FogMode {
+    /// Objects pop in at the end of the track.
+    Off,
+    /// Fades the track out between the fog start and the spawn position.
+    #[default]
+    Linear,
+    /// Thickens the fog with distance, so that it is nearly opaque at the spawn position.
+    Exponential,
 }
 
-impl Default for InputDelay {
-    fn default() -> Self {
-        Self { remaining: 0.0 }
+impl FogMode {
+    pub const ALL: [FogMode; 3] = [FogMode::Off, FogMode::Linear, FogMode::Exponential];
+
+    /// The translation key of the label of the option button.
+    pub fn label(self) -> &'static str {
+        match self {
+            FogMode::Off => "fog_off",
+            FogMode::Linear => "fog_linear",
+            FogMode::Exponential => "fog_exponential",
+        }
+    }
+
+    /// Returns the falloff of the fog on the given track, or `None` when the fog is off.
+    pub fn falloff(self, horizon: &TrackHorizon) -> Option<FogFalloff> {
+        match self {
+            FogMode::Off => None,
+            FogMode::Linear => Some(FogFalloff::Linear {
+                start: horizon.fog_start,
+                end: horizon.spawn,
+            }),
+            FogMode::Exponential => Some(FogFalloff::from_visibility(horizon.spawn)),
+        }
     }
 }
 
-#[derive(Resource)]
-pub struct CurrentScore {
-    point: u32,
-    distance: f32,
+/// Where objects appear and disappear along the track, fixed when the in-game scene is loaded.
+#[derive(Debug, Clone, Copy, Resource)]
+pub struct TrackHorizon {
+    pub spawn: f32,
+    pub despawn: f32,
+    /// The distance from the camera at which the track starts to fade into the fog.
+    /// Objects as far from the camera as the spawn position is down the track are hidden in it.
+    pub fog_start: f32,
 }
 
-impl CurrentScore {
-    pub fn get(&self) -> u32 {
-        self.point
+impl TrackHorizon {
+    /// Returns the ground planes laid from the despawn position to the spawn position.
+    pub fn grounds(&self) -> GroundLayout {
+        GroundLayout {
+            spawn: self.spawn,
+            despawn: self.despawn,
+        }
     }
+}
 
-    pub fn inc(&mut self, amount: u32) {
-        self.point = (self.point + amount).min(SCORE_LIMITS);
+impl Default for TrackHorizon {
+    fn default() -> Self {
+        Self {
+            spawn: SPAWN_POSITION,
+            despawn: DESPAWN_POSITION,
+            fog_start: FOG_START,
+        }
     }
+}
 
-    pub fn on_advanced(&mut self, forward_move: &ForwardMovement, elapsed: f32) {
-        self.distance += forward_move.get() * elapsed;
-        let amount = (self.distance / POINT_PER_DIST).floor() as u32;
-        self.point = (self.point + amount).min(SCORE_LIMITS);
-        self.distance %= POINT_PER_DIST;
+impl GraphicsOptions {
+    /// Speed lines are never shown in reduce-motion mode.
+    pub fn speed_lines_enabled(&self) -> bool {
+        self.speed_lines && !self.reduce_motion
     }
 }
 
-impl Default for CurrentScore {
+impl Default for GraphicsOptions {
     fn default() -> Self {
         Self {
-            point: 0,
-            distance: 0.0,
+            speed_lines: true,
+            reduce_motion: false,
+            captions: false,
+            landing_marker: false,
+            pace_clock: false,
+            camera_preset: CameraPreset::Classic,
+            compare_with_best: false,
+            render_scale_limit: RenderScaleLimit::Native,
+            device_tier: DeviceTier::default(),
+            fog_mode: FogMode::Linear,
+            atmospheric_scattering: false,
         }
     }
 }
 
+#[derive(Default, Resource, Deref, DerefMut)]
+pub struct RetryCounter(pub u32);
+
+/// Marks that the score and bells of the finished run were recorded by the result screen.
+#[derive(Resource)]
+pub struct RunRecorded;
+
+/// Marks that the finished run set a new high score, so the result screen plays its celebration.
+#[derive(Resource)]
+pub struct NewRecordRun;
+
+/// The finished run, and the previous and best runs as they were before it was recorded.
 #[derive(Resource)]
-pub struct TrainFuel {
-    remaining: f32,
+pub struct RunComparison {
+    pub run: RunRecord,
+    pub previous: Option<RunRecord>,
+    pub best: Option<RunRecord>,
 }
 
-impl TrainFuel {
-    pub fn get(&self) -> f32 {
-        self.remaining
-    }
+#[derive(Resource)]
+pub struct SceneTimer {
+    elapsed_time: f32,
+}
 
-    pub fn set(&mut self, amount: f32) {
-        self.remaining = amount.min(FUEL_LIMITS);
+impl SceneTimer {
+    pub fn elapsed_sec(&self) -> f32 {
+        self.elapsed_time
     }
 
-    pub fn inc(&mut self, amount: f32) {
-        self.remaining = (self.remaining + amount).min(FUEL_LIMITS);
+    pub fn tick(&mut self, elapsed: f32) {
+        self.elapsed_time += elapsed;
     }
 
-    pub fn dec(&mut self, amount: f32) {
-        self.remaining = (self.remaining - amount).max(0.0);
+    pub fn reset(&mut self) {
+        self.elapsed_time = 0.0;
     }
+}
 
-    pub fn is_empty(&self) -> bool {
-        self.remaining <= 0.0
+impl Default for SceneTimer {
+    fn default() -> Self {
+        Self { elapsed_time: 0.0 }
     }
 }
 
-impl Default for TrainFuel {
-    fn default() -> Self {
-        Self {
-            remaining: FUEL_LIMITS,
+/// Tracks whether the fuel alarm is raised. The alarm is raised once when the fuel falls
+/// below the warning threshold, and re-armed only after a refill above a higher threshold,
+/// so the fuel hovering around the threshold does not repeat the alarm.
+#[derive(Debug, Default, Clone, Copy, Resource)]
+pub struct FuelAlarmState {
+    raised: bool,
+}
+
+impl FuelAlarmState {
+    pub fn is_raised(&self) -> bool {
+        self.raised
+    }
+
+    /// Returns the alarm event to send, if the fuel ratio (0.0 to 1.0) crossed a threshold.
+    pub fn update(&mut self, ratio: f32) -> Option<FuelAlarm> {
+        match self.raised {
+            false if ratio < FUEL_ALARM_RATIO => {
+                self.raised = true;
+                Some(FuelAlarm::Raised)
+            }
+            true if ratio > FUEL_ALARM_REARM_RATIO => {
+                self.raised = false;
+                Some(FuelAlarm::Cleared)
+            }
+            _ => None,
         }
     }
 }
 
+/// How strongly the in-game camera is graded, eased toward the player's state and fuel.
 #[derive(Debug, Default, Clone, Copy, Resource)]
-pub struct IsPlayerJumping {
-    prev: bool,
-    curr: bool,
+pub struct GradingState {
+    /// The saturation and bloom boost while invincible (0.0 to 1.0).
+    pub boost: f32,
+    /// The red vignette while the fuel is low (0.0 to 1.0).
+    pub warning: f32,
 }
 
-impl IsPlayerJumping {
-    pub fn jump(&mut self) {
-        self.prev = self.curr;
-        self.curr = true;
+impl GradingState {
+    /// Moves both weights toward their targets by at most `step`.
+    pub fn ease(&mut self, boost: f32, warning: f32, step: f32) {
+        self.boost += (boost - self.boost).clamp(-step, step);
+        self.warning += (warning - self.warning).clamp(-step, step);
     }
+}
 
-    pub fn reset(&mut self) {
-        self.prev = self.curr;
-        self.curr = false;
+/// The train's steam whistle, which the player blows to startle the Aobas by the track.
+#[derive(Debug, Default, Clone, Copy, Resource)]
+pub struct TrainHorn {
+    cooldown: f32,
+}
+
+impl TrainHorn {
+    pub fn is_ready(&self) -> bool {
+        self.cooldown <= 0.0
     }
 
-    pub fn get(&self) -> bool {
-        self.curr
+    pub fn blow(&mut self) {
+        self.cooldown = HORN_COOLDOWN;
     }
 
-    pub fn changed(&self) -> bool {
-        self.prev != self.curr
+    pub fn on_advanced(&mut self, elapsed: f32) {
+        self.cooldown = (self.cooldown - elapsed).max(0.0);
+    }
+
+    /// How far the whistle has recharged (0.0 to 1.0).
+    pub fn charge(&self) -> f32 {
+        1.0 - self.cooldown / HORN_COOLDOWN
     }
 }
 
-#[derive(Debug, Default, Clone, Copy, Resource)]
-pub enum CurrentState {
-    #[cfg(not(feature = "no-debuging-player"))]
-    Debug,
-    #[default]
-    Idle,
-    Attacked {
-        remaining: f32,
-    },
-    Invincible {
-        remaining: f32,
-    },
+/// The chain of bells collected in quick succession, each chiming a little higher than the last.
+#[derive(Debug, Clone, Copy, Resource)]
+pub struct BellChime {
+    /// The number of bells collected after the first of the chain.
+    streak: u32,
+    /// The time since the last bell was collected.
+    since_last: f32,
 }
 
-impl CurrentState {
-    /// Checks if the player is in the `Debug` state (invincible).
-    #[cfg(not(feature = "no-debuging-player"))]
-    pub fn is_debug(&self) -> bool {
-        matches!(self, CurrentState::Debug)
+impl BellChime {
+    pub fn on_advanced(&mut self, elapsed: f32) {
+        self.since_last += elapsed;
     }
 
-    /// Checks if the player is in any state that grants invincibility.
-    #[allow(clippy::match_like_matches_macro)]
-    pub fn is_invincible(&self) -> bool {
-        match self {
-            #[cfg(not(feature = "no-debuging-player"))]
-            CurrentState::Debug => true,
-            CurrentState::Invincible { .. } => true,
-            _ => false,
+    /// Counts a collected bell and returns the playback speed of its chime.
+    /// The chain starts over when the bell comes too long after the last one.
+    pub fn chime(&mut self) -> f32 {
+        self.streak = match self.since_last <= BELL_CHIME_GAP {
+            true => self.streak + 1,
+            false => 0,
+        };
+        self.since_last = 0.0;
+        2f32.powf(self.streak as f32 * BELL_CHIME_STEP / 12.0)
+            .min(BELL_CHIME_MAX_SPEED)
+    }
+}
+
+impl Default for BellChime {
+    fn default() -> Self {
+        Self {
+            streak: 0,
+            since_last: f32::INFINITY,
         }
     }
 }
@@ -239,6 +426,36 @@ pub struct InGameAssets {
     pub handles: Vec<UntypedHandle>,
 }
 
+/// The mesh and material shared by every thrown toy whistle.
+#[derive(Resource)]
+pub struct WhistleAssets {
+    pub mesh: Handle<Mesh>,
+    pub material: Handle<StandardMaterial>,
+}
+
+/// The progress of loading the result scene in the background while the run is played.
+#[derive(Resource)]
+pub enum ResultPreload {
+    /// The run has just started, and the given play time has passed.
+    Waiting { elapsed: f32 },
+    /// The assets of the result scene are loading.
+    Loading(Vec<UntypedHandle>),
+    /// The entities of the result scene are spawned, and the assets are kept with the in-game assets.
+    Spawned,
+}
+
+impl ResultPreload {
+    pub fn is_spawned(&self) -> bool {
+        matches!(self, Self::Spawned)
+    }
+}
+
+impl Default for ResultPreload {
+    fn default() -> Self {
+        Self::Waiting { elapsed: 0.0 }
+    }
+}
+
 #[derive(Resource)]
 pub struct RetiredGrounds {
     entities: VecDeque<Entity>,
@@ -262,258 +479,289 @@ impl Default for RetiredGrounds {
     }
 }
 
-#[derive(Resource)]
+/// Spawns the objects the [`ObjectSchedule`] places on the track, with their models,
+/// and keeps the entities of the objects that have left the track to place them again.
+#[derive(Default, Resource)]
 pub struct ObjectSpawner {
-    distance: f32,
-    next_obj: Object,
+    schedule: ObjectSchedule,
     retired: HashMap<Object, VecDeque<Entity>>,
+    /// The obstacles added by content packs, in the order the schedule knows them by.
+    modded: Vec<RegisteredObstacle>,
+    /// The entities of modded obstacles, which are despawned rather than recycled.
+    modded_entities: HashSet<Entity>,
+    /// The models that replace the models of built-in objects.
+    skins: HashMap<Object, String>,
 }
 
 impl ObjectSpawner {
+    /// Creates a spawner with the registered content whose models have finished loading,
+    /// so that a broken pack never leaves an invisible obstacle on the track.
+    pub fn new(
+        config: &GameplayConfig,
+        upgrades: &TrainUpgrades,
+        content: &ContentRegistry,
+        asset_server: &AssetServer,
+        horizon: &TrackHorizon,
+    ) -> Self {
+        let is_loaded = |path: &str| {
+            asset_server
+                .get_handle::<ModelAsset>(path)
+                .is_some_and(|handle| asset_server.is_loaded_with_dependencies(handle.id()))
+        };
+
+        let (modded, patterns): (Vec<_>, Vec<_>) = content
+            .obstacles
+            .iter()
+            .filter(|obstacle| is_loaded(&obstacle.model))
+            .filter_map(|obstacle| {
+                let patterns = ModdedPatterns::new(
+                    obstacle.id.clone(),
+                    obstacle.collider,
+                    obstacle.weight,
+                    &obstacle.patterns,
+                )?;
+                Some((obstacle.clone(), patterns))
+            })
+            .unzip();
+        let skins = content
+            .skins
+            .iter()
+            .filter(|(_, path)| is_loaded(path))
+            .map(|(&obj, path)| (obj, path.clone()))
+            .collect();
+
+        Self {
+            schedule: ObjectSchedule::new(config, &upgrades.collider(), horizon.spawn, patterns),
+            modded,
+            skins,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the object spawned next and where along the track it will appear.
+    /// Its lanes are rolled only when it spawns.
+    #[cfg(not(feature = "no-debuging-gizmo"))]
+    pub fn next_spawn(&self) -> (Object, f32) {
+        self.schedule.next_spawn()
+    }
+
+    /// Keeps the patterns with the tag off the track for the given number of seconds from now,
+    /// unless they are already kept off for longer.
+    pub fn exclude(&mut self, tag: PatternTag, duration: f32) {
+        self.schedule.exclude(tag, duration);
+    }
+
     pub fn on_advanced(
         &mut self,
         commands: &mut Commands,
         asset_server: &AssetServer,
         forward_move: &ForwardMovement,
+        rng: &mut impl Rng,
         elapsed: f32,
     ) {
-        let mut rng = rand::rng();
-        self.distance += forward_move.get() * elapsed;
-        while self.distance >= OBJECT_SPAWN_INTERVAL {
-            let path = OBJECT_MODELS.get(&self.next_obj).cloned().unwrap();
-            let collider = OBJECT_COLLIDER.get(&self.next_obj).cloned().unwrap();
-            let model = asset_server.load(path);
-            let delta = OBJECT_SPAWN_INTERVAL - self.distance;
-
-            match self.next_obj {
-                Object::Barricade => {
-                    let index = BARRICADE_WEIGHTS.sample(&mut rng);
-                    let indices = &BARRICADE_POSITION_INDICES[index];
-                    for &lane_index in indices {
-                        let recycle = self
-                            .retired
-                            .get_mut(&self.next_obj)
-                            .and_then(|entities| entities.pop_front());
-
-                        match recycle {
-                            Some(entity) => {
-                                info!("Recycle Barricade entity");
-                                commands.entity(entity).insert((
-                                    Lane::new(lane_index),
-                                    Transform::from_xyz(
-                                        LANE_POSITIONS[lane_index],
-                                        0.0,
-                                        SPAWN_POSITION + delta,
-                                    ),
-                                    self.next_obj,
-                                ));
-                            }
-                            None => {
-                                info!("Spawn Barricade entity");
-                                commands.spawn((
-                                    SpawnModel(model.clone()),
-                                    Lane::new(lane_index),
-                                    Transform::from_xyz(
-                                        LANE_POSITIONS[lane_index],
-                                        0.0,
-                                        SPAWN_POSITION + delta,
-                                    ),
-                                    InGameStateRoot,
-                                    self.next_obj,
-                                    collider,
-                                ));
-                            }
-                        }
-                    }
-                }
-                Object::Stone => {
-                    let index = STONE_WEIGHTS.sample(&mut rng);
-                    let indices = &STONE_POSITION_INDICES[index];
-                    for &lane_index in indices {
-                        let recycle = self
-                            .retired
-                            .get_mut(&self.next_obj)
-                            .and_then(|entities| entities.pop_front());
-
-                        match recycle {
-                            Some(entity) => {
-                                info!("Recycle Stone entity");
-                                commands.entity(entity).insert((
-                                    Lane::new(lane_index),
-                                    Transform::from_xyz(
-                                        LANE_POSITIONS[lane_index],
-                                        0.0,
-                                        SPAWN_POSITION + delta,
-                                    ),
-                                    self.next_obj,
-                                ));
-                            }
-                            None => {
-                                info!("Spawn Stone entity");
-                                commands.spawn((
-                                    SpawnModel(model.clone()),
-                                    Lane::new(lane_index),
-                                    Transform::from_xyz(
-                                        LANE_POSITIONS[lane_index],
-                                        0.0,
-                                        SPAWN_POSITION + delta,
-                                    ),
-                                    InGameStateRoot,
-                                    self.next_obj,
-                                    collider,
-                                ));
-                            }
-                        }
-                    }
+        for placement in self.schedule.on_advanced(forward_move, rng, elapsed) {
+            match placement.object {
+                PlacedObject::Builtin(obj) => self.spawn(commands, asset_server, obj, placement),
+                PlacedObject::Modded(index) => {
+                    self.spawn_modded(commands, asset_server, index, placement)
                 }
-                Object::Fuel => {
-                    let lane_index = FUEL_POSITION_INDICES.choose(&mut rng).copied().unwrap();
-                    let recycle = self
-                        .retired
-                        .get_mut(&self.next_obj)
-                        .and_then(|entities| entities.pop_front());
-
-                    match recycle {
-                        Some(entity) => {
-                            info!("Recycle Fuel entity");
-                            commands.entity(entity).insert((
-                                Lane::new(lane_index),
-                                Transform::from_xyz(
-                                    LANE_POSITIONS[lane_index],
-                                    0.5,
-                                    SPAWN_POSITION + delta,
-                                ),
-                                RotateAnimation {
-                                    axis: Vec3::Y,
-                                    radian_per_sec: 120f32.to_radians(),
-                                },
-                                Visibility::Visible,
-                                self.next_obj,
-                            ));
-                        }
-                        None => {
-                            info!("Spawn Fuel entity");
-                            commands.spawn((
-                                SpawnModel(model.clone()),
-                                Lane::new(lane_index),
-                                Transform::from_xyz(
-                                    LANE_POSITIONS[lane_index],
-                                    0.5,
-                                    SPAWN_POSITION + delta,
-                                ),
-                                RotateAnimation {
-                                    axis: Vec3::Y,
-                                    radian_per_sec: 120f32.to_radians(),
-                                },
-                                Visibility::Visible,
-                                InGameStateRoot,
-                                self.next_obj,
-                                collider,
-                            ));
-                        }
-                    }
-                }
-                Object::Bell => {
-                    let lane_index = BELL_POSITION_INDICES.choose(&mut rng).copied().unwrap();
-                    let recycle = self
-                        .retired
-                        .get_mut(&self.next_obj)
-                        .and_then(|entities| entities.pop_front());
-
-                    match recycle {
-                        Some(entity) => {
-                            info!("Recycle Bell entity");
-                            commands.entity(entity).insert((
-                                Lane::new(lane_index),
-                                Transform::from_xyz(
-                                    LANE_POSITIONS[lane_index],
-                                    0.5,
-                                    SPAWN_POSITION + delta,
-                                ),
-                                RotateAnimation {
-                                    axis: Vec3::Y,
-                                    radian_per_sec: 120f32.to_radians(),
-                                },
-                                Visibility::Visible,
-                                self.next_obj,
-                            ));
-                        }
-                        None => {
-                            info!("Spawn Bell entity");
-                            commands.spawn((
-                                SpawnModel(model.clone()),
-                                Lane::new(lane_index),
-                                Transform::from_xyz(
-                                    LANE_POSITIONS[lane_index],
-                                    0.5,
-                                    SPAWN_POSITION + delta,
-                                ),
-                                RotateAnimation {
-                                    axis: Vec3::Y,
-                                    radian_per_sec: 120f32.to_radians(),
-                                },
-                                Visibility::Visible,
-                                InGameStateRoot,
-                                self.next_obj,
-                                collider,
-                            ));
-                        }
-                    }
+            }
+        }
+    }
+
+    fn spawn(
+        &mut self,
+        commands: &mut Commands,
+        asset_server: &AssetServer,
+        obj: Object,
+        placement: Placement,
+    ) {
+        let lane = Lane::new(placement.lane);
+        let mut transform = Transform::from_translation(placement.translation);
+        if matches!(obj, Object::Shield | Object::Clock) {
+            // The glow faces the camera like the other billboards, so it is not spun.
+            let scale = match obj {
+                Object::Shield => SHIELD_PICKUP_SCALE,
+                _ => CLOCK_PICKUP_SCALE,
+            };
+            transform = transform
+                .looking_to(*in_game::IN_GAME_AOBA_DIR, Vec3::Y)
+                .with_scale(scale);
+        }
+
+        if obj == Object::Aoba {
+            info!("Spawn Aoba entity");
+            commands
+                .spawn((lane, transform, InGameStateRoot, obj, OBJECT_COLLIDER[&obj]))
+                .with_children(|parent| {
+                    parent.spawn((
+                        SpawnModel(self.model(asset_server, obj)),
+                        AnimationClipHandle(asset_server.load(ANIM_PATH_AOBA)),
+                        Transform::IDENTITY.looking_to(*in_game::IN_GAME_AOBA_DIR, Vec3::Y),
+                        InGameStateEntity,
+                    ));
+
+                    let direction = *in_game::IN_GAME_AOBA_DIR;
+                    let translation = direction * 2.0 + Vec3::Y * 10.0;
+                    parent.spawn((
+                        SpawnModel(asset_server.load(MODEL_PATH_GLOW)),
+                        Transform::from_translation(translation)
+                            .with_scale((3.0, 20.0, 3.0).into())
+                            .looking_to(direction, Vec3::Y),
+                        GlowRoot,
+                        BillBoard,
+                    ));
+                });
+            return;
+        }
+
+        let recycle = self
+            .retired
+            .get_mut(&obj)
+            .and_then(|entities| entities.pop_front());
+        let entity = match recycle {
+            Some(entity) => {
+                info!("Recycle {:?} entity", obj);
+                commands.entity(entity).insert((lane, transform, obj)).id()
+            }
+            None => {
+                info!("Spawn {:?} entity", obj);
+                commands
+                    .spawn((
+                        SpawnModel(self.model(asset_server, obj)),
+                        lane,
+                        transform,
+                        InGameStateRoot,
+                        obj,
+                        OBJECT_COLLIDER[&obj],
+                    ))
+                    .id()
+            }
+        };
+
+        let mut entity_commands = commands.entity(entity);
+        match obj {
+            Object::Barricade | Object::Stone => {
+                entity_commands.insert(SpawnPattern(placement.pattern.unwrap()));
+                if let Some((from_lane, to_lane)) = placement.drift {
+                    entity_commands.insert(LateralDrift::new(from_lane, to_lane));
                 }
-                Object::Aoba => {
-                    info!("Spawn Aoba entity");
-                    let lane_index = AOBA_POSITION_INDICES.choose(&mut rng).copied().unwrap();
-                    commands
-                        .spawn((
-                            Lane::new(lane_index),
-                            Transform::from_xyz(
-                                LANE_POSITIONS[lane_index],
-                                0.0,
-                                SPAWN_POSITION + delta,
-                            ),
-                            InGameStateRoot,
-                            self.next_obj,
-                            collider,
-                        ))
-                        .with_children(|parent| {
-                            parent.spawn((
-                                SpawnModel(model.clone()),
-                                AnimationClipHandle(asset_server.load(ANIM_PATH_AOBA)),
-                                Transform::IDENTITY.looking_to(*in_game::IN_GAME_AOBA_DIR, Vec3::Y),
-                                InGameStateEntity,
-                            ));
-
-                            let direction = *in_game::IN_GAME_AOBA_DIR;
-                            let translation = direction * 2.0 + Vec3::Y * 10.0;
-                            parent.spawn((
-                                SpawnModel(asset_server.load(MODEL_PATH_GLOW)),
-                                Transform::from_translation(translation)
-                                    .with_scale((3.0, 20.0, 3.0).into())
-                                    .looking_to(direction, Vec3::Y),
-                                GlowRoot,
-                                BillBoard,
-                            ));
-                        });
+            }
+            Object::Fuel | Object::Bell => {
+                entity_commands.insert((
+                    RotateAnimation {
+                        axis: Vec3::Y,
+                        radian_per_sec: 120f32.to_radians(),
+                    },
+                    Visibility::Visible,
+                ));
+            }
+            Object::Shield | Object::Clock => {
+                entity_commands.insert(Visibility::Visible);
+                if recycle.is_none() {
+                    entity_commands.insert(BillBoard);
                 }
             }
+            Object::Aoba => unreachable!(),
+        }
+    }
+
+    /// Places a bell in the given lane and depth, outside the spawn slots,
+    /// so that it reaches the player on a beat of the background track.
+    pub fn spawn_beat_bell(
+        &mut self,
+        commands: &mut Commands,
+        asset_server: &AssetServer,
+        lane_index: usize,
+        depth: f32,
+        beat: BeatBell,
+    ) {
+        let obj = Object::Bell;
+        let transform = Transform::from_xyz(LANE_POSITIONS[lane_index], 0.5, depth);
+        let recycle = self
+            .retired
+            .get_mut(&obj)
+            .and_then(|entities| entities.pop_front());
+
+        match recycle {
+            Some(entity) => {
+                info!("Recycle Beat Bell entity");
+                commands.entity(entity).insert((
+                    Lane::new(lane_index),
+                    transform,
+                    RotateAnimation {
+                        axis: Vec3::Y,
+                        radian_per_sec: 120f32.to_radians(),
+                    },
+                    Visibility::Visible,
+                    beat,
+                    obj,
+                ));
+            }
+            None => {
+                info!("Spawn Beat Bell entity");
+                commands.spawn((
+                    SpawnModel(self.model(asset_server, obj)),
+                    Lane::new(lane_index),
+                    transform,
+                    RotateAnimation {
+                        axis: Vec3::Y,
+                        radian_per_sec: 120f32.to_radians(),
+                    },
+                    Visibility::Visible,
+                    InGameStateRoot,
+                    beat,
+                    obj,
+                    OBJECT_COLLIDER[&obj],
+                ));
+            }
+        }
+    }
 
-            let offset = rng.random_range(OBJECT_SPAWN_OFFSET);
-            let index = SPAWN_WEIGHTS.sample(&mut rng);
-            let next_obj = OBJECT_LIST[index];
+    /// Spawns a modded obstacle in one of the lanes of its pattern.
+    fn spawn_modded(
+        &mut self,
+        commands: &mut Commands,
+        asset_server: &AssetServer,
+        index: usize,
+        placement: Placement,
+    ) {
+        let modded = &self.modded[index];
+        info!("Spawn {} entity", modded.id);
+        let entity = commands
+            .spawn((
+                SpawnModel(asset_server.load(modded.model.clone())),
+                Lane::new(placement.lane),
+                Transform::from_translation(placement.translation),
+                InGameStateRoot,
+                modded.base,
+                modded.collider,
+            ))
+            .id();
+        self.modded_entities.insert(entity);
+    }
 
-            self.distance -= OBJECT_SPAWN_INTERVAL + offset;
-            self.next_obj = next_obj;
+    /// Returns the model of a built-in object, or the model of the skin that replaces it.
+    fn model(&self, asset_server: &AssetServer, obj: Object) -> Handle<ModelAsset> {
+        match self.skins.get(&obj) {
+            Some(skin) => asset_server.load(skin.clone()),
+            None => asset_server.load(OBJECT_MODELS[&obj]),
         }
     }
 
     pub fn drain(&mut self, commands: &mut Commands, entity: Entity, obj: Object) {
-        if matches!(obj, Object::Aoba) {
+        if matches!(obj, Object::Aoba) || self.modded_entities.remove(&entity) {
             commands.entity(entity).despawn();
         } else {
             commands
                 .entity(entity)
                 .insert(Visibility::Hidden)
                 .remove::<RotateAnimation>()
+                .remove::<LateralDrift>()
+                .remove::<BeatBell>()
+                .remove::<Activated>()
+                .remove::<Appearing>()
+                .remove::<MaterialEffect>()
                 .remove::<Object>();
 
             self.retired
@@ -526,19 +774,11 @@ impl ObjectSpawner {
     }
 }
 
-impl Default for ObjectSpawner {
-    fn default() -> Self {
-        Self {
-            distance: 0.0,
-            next_obj: Object::default(),
-            retired: HashMap::default(),
-        }
-    }
-}
-
+/// Places the Tok9 trains at the spawn position of the desktop tier on every tier,
+/// since the time they take to reach the player after their warning is part of the game.
 #[derive(Resource)]
 pub struct Tok9TrainSpawner {
-    remaining_sec: f32,
+    schedule: Tok9Schedule,
     retired: HashMap<Tok9Train, VecDeque<Entity>>,
     retired_billboard: VecDeque<Entity>,
 }
@@ -549,15 +789,12 @@ impl Tok9TrainSpawner {
         commands: &mut Commands,
         asset_server: &AssetServer,
         system_volume: &SystemVolume,
+        rng: &mut impl Rng,
         elapsed: f32,
     ) {
-        let mut rng = rand::rng();
-        self.remaining_sec -= elapsed;
-        if self.remaining_sec <= 0.0 {
-            let index = TOK9_TRAIN_WEIGHTS.sample(&mut rng);
-            let indices = &TOK9_TRAIN_POSITION_INDICES[index];
-            for &lane_index in indices {
-                let train = rng.random::<Tok9Train>();
+        let trains = self.schedule.on_advanced(rng, elapsed);
+        if !trains.is_empty() {
+            for (lane_index, train) in trains {
                 let path = TOK9_TRAIN_MODELS.get(&train).cloned().unwrap();
                 let collider = TOK9_TRAIN_COLLIDER.get(&train).cloned().unwrap();
 
@@ -667,9 +904,6 @@ impl Tok9TrainSpawner {
                 InGameStateRoot,
                 EffectSound,
             ));
-
-            let offset = rng.random_range(TOK9_TRAIN_OFFSET);
-            self.remaining_sec = TOK9_TRAIN_CYCLE + offset;
         }
     }
 
@@ -694,10 +928,116 @@ impl Tok9TrainSpawner {
     }
 }
 
+/// Sends a train along the far track every now and then. The trains are purely cosmetic,
+/// so they are drawn from the cosmetic random stream and never change the obstacles of a run.
+#[derive(Resource)]
+pub struct TrafficSpawner {
+    remaining_sec: f32,
+    retired: VecDeque<Entity>,
+    /// Where the passing trains appear along the track.
+    spawn_position: f32,
+}
+
+impl TrafficSpawner {
+    pub fn new(horizon: &TrackHorizon) -> Self {
+        Self {
+            remaining_sec: TRAFFIC_INIT_CYCLE,
+            retired: VecDeque::with_capacity(2),
+            spawn_position: horizon.spawn,
+        }
+    }
+
+    pub fn on_advanced(
+        &mut self,
+        commands: &mut Commands,
+        asset_server: &AssetServer,
+        rng: &mut impl Rng,
+        elapsed: f32,
+    ) {
+        self.remaining_sec -= elapsed;
+        if self.remaining_sec > 0.0 {
+            return;
+        }
+
+        let offset = rng.random_range(TRAFFIC_OFFSET);
+        self.remaining_sec = TRAFFIC_CYCLE + offset;
+
+        // Starts silent; the volume follows the distance to the player.
+        #[cfg(not(target_arch = "wasm32"))]
+        let sound = (
+            PannedAudioPlayer::new(asset_server.load(SOUND_PATH_SFX_TRAIN)),
+            PlaybackSettings::LOOP.with_volume(Volume::Linear(0.0)),
+            DistanceAttenuation {
+                volume: TRAFFIC_SOUND_VOLUME,
+            },
+        );
+        #[cfg(target_arch = "wasm32")]
+        let sound = (
+            WebAudioPlayer::new(asset_server.load(SOUND_PATH_SFX_TRAIN)),
+            WebPlaybackSettings::LOOP.with_volume(Volume::Linear(0.0)),
+            StereoPan::default(),
+            DistanceAttenuation {
+                volume: TRAFFIC_SOUND_VOLUME,
+            },
+        );
+
+        let transform = Transform::from_xyz(TRAFFIC_TRACK_X, 0.0, self.spawn_position);
+        match self.retired.pop_front() {
+            Some(entity) => {
+                info!("Recycle PassingTrain entity");
+                commands.entity(entity).insert((
+                    transform,
+                    Visibility::Visible,
+                    PassingTrain,
+                    sound,
+                ));
+            }
+            None => {
+                info!("Spawn PassingTrain entity");
+                let train = rng.random::<Tok9Train>();
+                let path = TOK9_TRAIN_MODELS.get(&train).cloned().unwrap();
+                commands.spawn((
+                    SpawnModel(asset_server.load(path)),
+                    transform,
+                    Visibility::Visible,
+                    ForwardMovement::new(TRAFFIC_SPEED),
+                    InGameStateRoot,
+                    EffectSound,
+                    PassingTrain,
+                    sound,
+                ));
+            }
+        }
+    }
+
+    /// Hides a train that has left the screen and stops its sound, keeping it for the next pass.
+    pub fn drain(&mut self, commands: &mut Commands, entity: Entity) {
+        let mut entity_commands = commands.entity(entity);
+        entity_commands
+            .insert(Visibility::Hidden)
+            .remove::<PassingTrain>();
+
+        #[cfg(not(target_arch = "wasm32"))]
+        entity_commands.remove::<(
+            PannedAudioPlayer,
+            AudioPlayer<PannedAudio>,
+            QueuedAudio<PannedAudio>,
+            PanControl,
+            PlaybackSettings,
+            AudioSink,
+            DistanceAttenuation,
+        )>();
+        #[cfg(target_arch = "wasm32")]
+        entity_commands.remove::<(WebAudioPlayer, WebPlaybackSettings, DistanceAttenuation)>();
+
+        self.retired.push_back(entity);
+    }
+}
+
 impl Default for Tok9TrainSpawner {
     fn default() -> Self {
         Self {
-            remaining_sec: TOK9_TRAIN_INIT_CYCLE,
+            schedule: Tok9Schedule::default(),
             retired: HashMap::default(),
             retired_billboard: VecDeque::with_capacity(8),
         }
@@ -26,15 +26,29 @@ fn debug_label(mut next_state: ResMut<NextState<GameState>>) {
 fn remove_resource(mut commands: Commands) {
     commands.remove_resource::<Attacked>();
     commands.remove_resource::<PlayTime>();
+    commands.remove_resource::<RunPace>();
     commands.remove_resource::<TrainFuel>();
     commands.remove_resource::<InputDelay>();
     commands.remove_resource::<CurrentScore>();
     commands.remove_resource::<IsPlayerJumping>();
     commands.remove_resource::<CurrentState>();
+    commands.remove_resource::<PlayerShield>();
+    commands.remove_resource::<BellChime>();
+    commands.remove_resource::<SoundRotation>();
+    commands.remove_resource::<TrainHorn>();
+    commands.remove_resource::<TimeScale>();
+    commands.remove_resource::<GradingState>();
+    commands.remove_resource::<FuelAlarmState>();
     commands.remove_resource::<RetiredGrounds>();
     commands.remove_resource::<ObjectSpawner>();
     commands.remove_resource::<Tok9TrainSpawner>();
+    commands.remove_resource::<TrafficSpawner>();
     commands.remove_resource::<InGameAssets>();
+    commands.remove_resource::<ResultPreload>();
+    commands.remove_resource::<Cutscene>();
+    commands.remove_resource::<RunRecorded>();
+    commands.remove_resource::<NewRecordRun>();
+    commands.remove_resource::<RunComparison>();
 }
 
 fn remove_entities(
@@ -1,6 +1,11 @@
 // Import necessary Bevy modules.
 use bevy::prelude::*;
 
+use crate::asset::locale::CurrentLocale;
+
+#[cfg(target_arch = "wasm32")]
+use crate::web::copy_to_clipboard;
+
 use super::*;
 
 // --- PLUGIN ---
@@ -83,21 +88,29 @@ fn fade_in_animation(
 }
 
 #[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments)]
 fn handle_button_system(
+    mut commands: Commands,
     mut query: Query<
-        (&UI, &Interaction, &mut BackgroundColor),
+        (Entity, &UI, &Interaction, &mut BackgroundColor),
         (Changed<Interaction>, With<Button>),
     >,
-    mut next_state: ResMut<NextState<GameState>>,
+    asset_server: Res<AssetServer>,
+    rng: Res<RngService>,
+    config: Res<GameplayConfig>,
+    comparison: Res<RunComparison>,
+    mut options: ResMut<GraphicsOptions>,
+    locale: Res<CurrentLocale>,
+    mut transition: EventWriter<RequestTransition>,
 ) {
-    for (ui, interaction, mut color) in query.iter_mut() {
+    for (entity, ui, interaction, mut color) in query.iter_mut() {
         match (*ui, *interaction) {
             (UI::RestartButton, Interaction::Hovered) => {
                 color.0 = RESTART_BTN_COLOR.darker(0.15);
             }
             (UI::RestartButton, Interaction::Pressed) => {
                 color.0 = RESTART_BTN_COLOR.darker(0.3);
-                next_state.set(GameState::RestartResult);
+                transition.write(RequestTransition::iris(GameState::RestartResult));
             }
             (UI::RestartButton, Interaction::None) => {
                 color.0 = RESUME_BTN_COLOR;
@@ -107,12 +120,66 @@ fn handle_button_system(
             }
             (UI::ResultExitButton, Interaction::Pressed) => {
                 color.0 = EXIT_BTN_COLOR.darker(0.3);
-                next_state.set(GameState::ExitResult);
+                transition.write(RequestTransition::fade(GameState::ExitResult));
             }
             (UI::ResultExitButton, Interaction::None) => {
                 color.0 = EXIT_BTN_COLOR;
             }
+            (UI::SeedButton, Interaction::Hovered) => {
+                color.0 = SEED_BTN_COLOR.darker(0.15);
+            }
+            (UI::SeedButton, Interaction::Pressed) => {
+                color.0 = SEED_BTN_COLOR.darker(0.3);
+                copy_seed(&rng, &config);
+            }
+            (UI::SeedButton, Interaction::None) => {
+                color.0 = SEED_BTN_COLOR;
+            }
+            (UI::RunComparison, Interaction::Hovered) => {
+                color.0 = COMPARE_BTN_COLOR.darker(0.15);
+            }
+            (UI::RunComparison, Interaction::Pressed) => {
+                color.0 = COMPARE_BTN_COLOR.darker(0.3);
+                options.compare_with_best = !options.compare_with_best;
+                save_graphics_options(&options);
+                spawn_run_comparison(
+                    &mut commands,
+                    entity,
+                    &asset_server.load(FONT_PATH_NOTOSANS_BOLD),
+                    &comparison,
+                    &options,
+                    locale.0,
+                    false,
+                );
+            }
+            (UI::RunComparison, Interaction::None) => {
+                color.0 = COMPARE_BTN_COLOR;
+            }
             _ => { /* empty */ }
         }
     }
 }
+
+#[cfg(target_arch = "wasm32")]
+fn save_graphics_options(options: &GraphicsOptions) {
+    if let Some(storage) = get_local_storage()
+        && let Ok(value) = GRAPHICS_OPTIONS_SCHEMA.encode(options)
+    {
+        let _ = storage.set_item(GRAPHICS_OPTIONS_KEY, &value);
+    }
+}
+
+/// Native builds do not keep the options between launches.
+#[cfg(not(target_arch = "wasm32"))]
+fn save_graphics_options(_options: &GraphicsOptions) {}
+
+#[cfg(target_arch = "wasm32")]
+fn copy_seed(rng: &RngService, config: &GameplayConfig) {
+    copy_to_clipboard(&run_code(rng, config));
+}
+
+/// Native builds have no clipboard access, so the seed is written to the log instead.
+#[cfg(not(target_arch = "wasm32"))]
+fn copy_seed(rng: &RngService, config: &GameplayConfig) {
+    info!("Run seed: {}", run_code(rng, config));
+}
@@ -4,9 +4,13 @@ mod restart;
 mod start;
 mod start_to_end;
 
+use std::{f32::consts::FRAC_PI_4, ops::RangeInclusive};
+
 // Import necessary Bevy modules.
 use bevy::prelude::*;
 
+use crate::asset::locale::Locale;
+
 use super::*;
 
 // --- CONSTANTS ---
@@ -15,6 +19,31 @@ pub const NOZOMI_POSITION: Vec3 = vec3(-5.0, 0.0, -0.383545);
 pub const STUDENT_DIRECTION: Vec3 = vec3(1.0, 0.0, 0.0);
 pub const CAMERA_POSITION: Vec3 = vec3(-2.0, 1.0, 0.0);
 pub const CAMERA_DIRECTION: Vec3 = vec3(-0.995037, -0.0995037, 0.0);
+pub const CAMERA_FOV: f32 = FRAC_PI_4;
+
+pub const NUM_CONFETTI: usize = 120;
+pub const CONFETTI_SIZE: Vec2 = vec2(0.06, 0.1);
+/// The box above the students that the confetti starts falling from.
+pub const CONFETTI_AREA_MIN: Vec3 = vec3(-5.8, 2.2, -1.6);
+pub const CONFETTI_AREA_MAX: Vec3 = vec3(-3.6, 5.5, 1.6);
+pub const CONFETTI_FALL_SPEED: RangeInclusive<f32> = 0.6..=1.1;
+pub const CONFETTI_SPIN_SPEED: RangeInclusive<f32> = 2.0..=6.0;
+/// How far the confetti flutters from side to side, and how often.
+pub const CONFETTI_FLUTTER: f32 = 0.4;
+pub const CONFETTI_FLUTTER_RATE: f32 = 3.0;
+pub const CONFETTI_COLORS: [Color; 5] = [
+    Color::srgb(1.0, 0.35, 0.45),
+    Color::srgb(1.0, 0.8, 0.2),
+    Color::srgb(0.3, 0.8, 0.45),
+    Color::srgb(0.3, 0.6, 1.0),
+    Color::srgb(0.75, 0.45, 1.0),
+];
+/// The height of the "NEW BEST" banner shown over a celebrated result, as a share of the screen height.
+pub const NEW_RECORD_BANNER_HEIGHT: f32 = 12.0;
+/// The colors of a total that beat the compared run, fell short of it, or matched it.
+pub const COMPARISON_BETTER_COLOR: Color = Color::srgb(0.15, 0.6, 0.25);
+pub const COMPARISON_WORSE_COLOR: Color = Color::srgb(0.8, 0.2, 0.15);
+pub const COMPARISON_EVEN_COLOR: Color = Color::srgb(0.4, 0.4, 0.4);
 
 // --- PLUGIN ---
 
@@ -26,6 +55,205 @@ impl Plugin for StatePlugin {
             .add_plugins(start_to_end::StatePlugin)
             .add_plugins(end::StatePlugin)
             .add_plugins(restart::StatePlugin)
-            .add_plugins(cleanup::StatePlugin);
+            .add_plugins(cleanup::StatePlugin)
+            .add_systems(
+                Update,
+                (
+                    play_cutscene.run_if(resource_exists::<Cutscene>),
+                    update_confetti,
+                )
+                    .run_if(in_state(GameState::StartResult).or(in_state(GameState::EndResult))),
+            );
+    }
+}
+
+// --- UPDATE SYSTEMS ---
+
+/// Plays the result choreography: the camera moves along its keyframed track
+/// (orbit, close-up, then the final framing) while the cues change the characters.
+/// In reduce-motion mode the camera stays at the final framing.
+fn play_cutscene(
+    mut cutscene: ResMut<Cutscene>,
+    mut stage: CutsceneStage<ResultStateEntity>,
+    mut query: Query<(&mut Projection, &mut Transform), With<ResultCamera>>,
+    options: Res<GraphicsOptions>,
+    time: Res<Time>,
+) {
+    for cue in cutscene.tick(time.delta_secs()) {
+        stage.fire(&cue, ResultStateRoot);
+    }
+
+    let key = if options.reduce_motion {
+        cutscene.final_camera()
+    } else {
+        cutscene.camera()
+    };
+
+    if let Some(key) = key
+        && let Ok((mut projection, mut transform)) = query.single_mut()
+    {
+        if let Projection::Perspective(perspective) = &mut *projection {
+            perspective.fov = CAMERA_FOV / key.zoom;
+        }
+        *transform = Transform::from_translation(key.position).looking_at(key.look_at, Vec3::Y);
+    }
+}
+
+/// Lets the confetti flutter down, spinning as it goes, and clears it once it reaches the floor.
+fn update_confetti(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Transform, &mut Confetti)>,
+    time: Res<Time>,
+) {
+    for (entity, mut transform, mut confetti) in query.iter_mut() {
+        if transform.translation.y <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        confetti.phase += CONFETTI_FLUTTER_RATE * time.delta_secs();
+        let flutter = Vec3::Z * confetti.phase.sin() * CONFETTI_FLUTTER;
+        transform.translation += (confetti.velocity + flutter) * time.delta_secs();
+        transform.rotate(Quat::from_scaled_axis(confetti.spin * time.delta_secs()));
+    }
+}
+
+// --- HELPERS ---
+
+/// The seed of the run, followed by the code of its mutators if any were active,
+/// so a shared score is only compared with runs played under the same rules.
+fn run_code(rng: &RngService, config: &GameplayConfig) -> String {
+    match config.mutators.is_empty() {
+        true => rng.seed_text(),
+        false => format!("{}-{}", rng.seed_text(), config.mutators.code()),
+    }
+}
+
+/// Returns the totals of the finished run.
+fn run_record(score: &CurrentScore, pace: &RunPace, bells: &CollectedBells) -> RunRecord {
+    RunRecord {
+        score: score.get(),
+        distance: pace.distance() as u32,
+        bells: bells.0,
+    }
+}
+
+/// Records the finished run as the previous run, and as the best run if it beat it.
+fn save_run_history(history: &mut RunHistory, run: RunRecord, config: &GameplayConfig) {
+    let unranked = matches!(
+        config.score_category(),
+        ScoreCategory::Assist | ScoreCategory::Mutated
+    );
+    history.record(run, unranked);
+
+    #[cfg(target_arch = "wasm32")]
+    if let Some(storage) = get_local_storage()
+        && let Ok(value) = RUN_HISTORY_SCHEMA.encode(&*history)
+    {
+        let _ = storage.set_item(RUN_HISTORY_KEY, &value);
+    }
+}
+
+/// Fills the comparison row with how each total of the run differs from the compared run.
+/// The entries fade in when the row is first shown, and appear at once when the row is switched.
+fn spawn_run_comparison(
+    commands: &mut Commands,
+    entity: Entity,
+    font: &Handle<Font>,
+    comparison: &RunComparison,
+    options: &GraphicsOptions,
+    locale: Locale,
+    fade_in: bool,
+) {
+    let (label, other) = match options.compare_with_best {
+        true => ("vs BEST", comparison.best),
+        false => ("vs LAST", comparison.previous),
+    };
+
+    let mut entries = vec![(label.to_string(), Color::BLACK)];
+    match other {
+        Some(other) => {
+            let delta = comparison.run.delta(&other);
+            for (name, value) in [
+                ("Score", delta.score),
+                ("Distance", delta.distance),
+                ("Bells", delta.bells),
+            ] {
+                let color = match value.signum() {
+                    1 => COMPARISON_BETTER_COLOR,
+                    -1 => COMPARISON_WORSE_COLOR,
+                    _ => COMPARISON_EVEN_COLOR,
+                };
+                entries.push((format!("{} {}", name, format_delta(value, locale)), color));
+            }
+        }
+        None => entries.push(("-".to_string(), COMPARISON_EVEN_COLOR)),
+    }
+
+    commands
+        .entity(entity)
+        .despawn_related::<Children>()
+        .with_children(|parent| {
+            for (text, color) in entries {
+                let mut entry = parent.spawn((
+                    Text::new(text),
+                    TextFont::from_font(font.clone()),
+                    TextLayout::new_with_justify(JustifyText::Center),
+                    ResizableFont::vertical(1280.0, 30.0),
+                    TextColor(color),
+                    Node::default(),
+                    Visibility::Inherited,
+                ));
+                if fade_in {
+                    entry.insert(FadeInAnimation::new(PREPARE_ANIM_DURATION));
+                }
+            }
+        });
+}
+
+/// Keeps the score if it beats the best score of its ranking, and returns whether it did.
+/// Runs played in assist mode or hardcore mode are ranked only against runs of the same mode,
+/// runs played with other mutators are not ranked, and only a high score of the standard ranking
+/// becomes the pace to beat.
+fn save_high_score(
+    high_score: &mut HighScore,
+    assist_high_score: &mut AssistHighScore,
+    hardcore_high_score: &mut HardcoreHighScore,
+    best_pace: &mut BestPace,
+    config: &GameplayConfig,
+    score: &CurrentScore,
+    pace: &RunPace,
+) -> bool {
+    let category = config.score_category();
+    let best = match category {
+        ScoreCategory::Standard => &mut high_score.0,
+        ScoreCategory::Assist => &mut assist_high_score.0,
+        ScoreCategory::Hardcore => &mut hardcore_high_score.0,
+        ScoreCategory::Mutated => return false,
+    };
+
+    if *best >= score.get() {
+        return false;
+    }
+    *best = score.get();
+    if category == ScoreCategory::Standard {
+        best_pace.0 = pace.curve().clone();
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    if let Some(storage) = get_local_storage() {
+        let key = match category {
+            ScoreCategory::Standard => HIGH_SCORE_KEY,
+            ScoreCategory::Assist => ASSIST_HIGH_SCORE_KEY,
+            ScoreCategory::Hardcore => HARDCORE_HIGH_SCORE_KEY,
+            ScoreCategory::Mutated => unreachable!(),
+        };
+        let _ = storage.set_item(key, &best.to_string());
+        if category == ScoreCategory::Standard
+            && let Ok(value) = BEST_PACE_SCHEMA.encode(&*best_pace)
+        {
+            let _ = storage.set_item(BEST_PACE_KEY, &value);
+        }
     }
+    true
 }
@@ -11,7 +11,12 @@ impl Plugin for StatePlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
             OnEnter(GameState::RestartResult),
-            (debug_label, remove_resource, remove_entities),
+            (
+                debug_label,
+                record_skipped_run.run_if(not(resource_exists::<RunRecorded>)),
+                remove_resource,
+                remove_entities,
+            ),
         );
     }
 }
@@ -23,14 +28,54 @@ fn debug_label(mut next_state: ResMut<NextState<GameState>>) {
     next_state.set(GameState::LoadInGame);
 }
 
+/// Keeps the score and banks the bells of a run whose results were skipped before they were shown.
+#[allow(clippy::too_many_arguments)]
+fn record_skipped_run(
+    mut high_score: ResMut<HighScore>,
+    mut assist_high_score: ResMut<AssistHighScore>,
+    mut hardcore_high_score: ResMut<HardcoreHighScore>,
+    mut best_pace: ResMut<BestPace>,
+    mut history: ResMut<RunHistory>,
+    mut upgrades: ResMut<TrainUpgrades>,
+    config: Res<GameplayConfig>,
+    score: Res<CurrentScore>,
+    pace: Res<RunPace>,
+    bells: Res<CollectedBells>,
+) {
+    save_high_score(
+        &mut high_score,
+        &mut assist_high_score,
+        &mut hardcore_high_score,
+        &mut best_pace,
+        &config,
+        &score,
+        &pace,
+    );
+    save_run_history(&mut history, run_record(&score, &pace, &bells), &config);
+    upgrades.bells = upgrades.bells.saturating_add(bells.0);
+}
+
 fn remove_resource(mut commands: Commands) {
+    commands.remove_resource::<RunRecorded>();
+    commands.remove_resource::<NewRecordRun>();
+    commands.remove_resource::<RunComparison>();
     commands.remove_resource::<TrainFuel>();
     commands.remove_resource::<InputDelay>();
     commands.remove_resource::<CurrentScore>();
     commands.remove_resource::<CurrentState>();
+    commands.remove_resource::<PlayerShield>();
+    commands.remove_resource::<BellChime>();
+    commands.remove_resource::<SoundRotation>();
+    commands.remove_resource::<TrainHorn>();
+    commands.remove_resource::<TimeScale>();
+    commands.remove_resource::<GradingState>();
+    commands.remove_resource::<FuelAlarmState>();
     commands.remove_resource::<RetiredGrounds>();
     commands.remove_resource::<ObjectSpawner>();
     commands.remove_resource::<Tok9TrainSpawner>();
+    commands.remove_resource::<TrafficSpawner>();
+    commands.remove_resource::<ResultPreload>();
+    commands.remove_resource::<Cutscene>();
 }
 
 fn remove_entities(
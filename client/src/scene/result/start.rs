@@ -1,15 +1,21 @@
-// Import necessary Bevy modules.
-use bevy::{audio::Volume, prelude::*};
-use rand::seq::IndexedRandom;
+use std::f32::consts::TAU;
 
-use crate::{
-    asset::{animation::AnimationClipHandle, material::EyeMouthMaterial, sound::SystemVolume},
-    shader::face_mouth::EyeMouth,
+// Import necessary Bevy modules.
+use bevy::{
+    audio::Volume,
+    pbr::{NotShadowCaster, NotShadowReceiver},
+    prelude::*,
 };
+use rand::{Rng, seq::IndexedRandom};
 
 #[cfg(target_arch = "wasm32")]
 use crate::web::{WebAudioPlayer, WebPlaybackSettings};
 
+use crate::asset::{
+    animation::AnimationClipHandle, cutscene_track::CutsceneTrack, locale::CurrentLocale,
+    sound::SystemVolume,
+};
+
 use super::*;
 
 // --- CONSTANTS ---
@@ -27,18 +33,24 @@ impl Plugin for StatePlugin {
             (
                 debug_label,
                 start_timer,
+                start_cutscene,
                 show_entities,
                 spawn_camera_and_light,
                 play_animation,
                 play_result_sound,
                 setup_result_text,
                 check_and_save_high_score.after(setup_result_text),
+                record_run_comparison,
+                (spawn_confetti, play_new_record_jingle)
+                    .after(check_and_save_high_score)
+                    .run_if(resource_exists::<NewRecordRun>),
+                bank_collected_bells,
             ),
         )
         .add_systems(OnExit(GameState::StartResult), end_timer)
         .add_systems(
             Update,
-            (update_scene_timer, set_mouth_expression).run_if(in_state(GameState::StartResult)),
+            update_scene_timer.run_if(in_state(GameState::StartResult)),
         );
     }
 }
@@ -53,6 +65,20 @@ fn start_timer(mut commands: Commands) {
     commands.insert_resource(SceneTimer::default());
 }
 
+/// Starts the result choreography: the camera orbits the podium, closes in on the characters,
+/// then settles on the final framing.
+fn start_cutscene(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    tracks: Res<Assets<CutsceneTrack>>,
+) {
+    let Some(track) = tracks.get(&asset_server.load(CUTSCENE_PATH_RESULT)) else {
+        warn!("The result cutscene is not loaded");
+        return;
+    };
+    commands.insert_resource(Cutscene::from_track(track));
+}
+
 fn show_entities(mut query: Query<&mut Visibility, (With<ResultStateRoot>, Without<UI>)>) {
     for mut visibility in query.iter_mut() {
         *visibility = Visibility::Visible;
@@ -64,7 +90,7 @@ fn spawn_camera_and_light(
     light_query: Query<(), With<DirectionalLight>>,
     camera_query: Query<(), With<Camera3d>>,
 ) {
-    if light_query.single().is_err() {
+    if light_query.is_empty() {
         commands.spawn((
             DirectionalLight {
                 illuminance: 10_000.0,
@@ -76,16 +102,17 @@ fn spawn_camera_and_light(
         ));
     }
 
-    if camera_query.single().is_err() {
+    if camera_query.is_empty() {
         commands.spawn((
             Camera3d::default(),
             Projection::from(PerspectiveProjection {
-                fov: 45f32.to_radians(),
+                fov: CAMERA_FOV,
                 aspect_ratio: 16.0 / 9.0,
                 near: 0.1,
                 far: 100.0,
             }),
             Transform::from_translation(CAMERA_POSITION).looking_to(CAMERA_DIRECTION, Vec3::Y),
+            ResultCamera,
             ResultStateRoot,
         ));
     }
@@ -110,81 +137,198 @@ fn play_animation(
     }
 }
 
-#[cfg(not(target_arch = "wasm32"))]
 fn play_result_sound(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     system_volume: Res<SystemVolume>,
+    mut rng: ResMut<RngService>,
+) {
+    let path = SOUND_PATH_VO_RESULTS.choose(rng.audio()).copied().unwrap();
+    play_voice(&mut commands, &asset_server, &system_volume, path);
+}
+
+#[allow(clippy::too_many_arguments)]
+fn check_and_save_high_score(
+    mut commands: Commands,
+    mut high_score: ResMut<HighScore>,
+    mut assist_high_score: ResMut<AssistHighScore>,
+    mut hardcore_high_score: ResMut<HardcoreHighScore>,
+    mut best_pace: ResMut<BestPace>,
+    config: Res<GameplayConfig>,
+    score: Res<CurrentScore>,
+    pace: Res<RunPace>,
+    new_record_query: Query<Entity, With<NewRecord>>,
+) {
+    if !save_high_score(
+        &mut high_score,
+        &mut assist_high_score,
+        &mut hardcore_high_score,
+        &mut best_pace,
+        &config,
+        &score,
+        &pace,
+    ) {
+        return;
+    }
+
+    commands.insert_resource(NewRecordRun);
+    if let Ok(entity) = new_record_query.single() {
+        commands.entity(entity).insert(UI::NewRecord);
+    }
+}
+
+/// Keeps the previous and best runs to compare the finished run with, then records it.
+fn record_run_comparison(
+    mut commands: Commands,
+    mut history: ResMut<RunHistory>,
+    config: Res<GameplayConfig>,
+    score: Res<CurrentScore>,
+    pace: Res<RunPace>,
+    bells: Res<CollectedBells>,
+) {
+    let run = run_record(&score, &pace, &bells);
+    commands.insert_resource(RunComparison {
+        run,
+        previous: history.previous,
+        best: history.best,
+    });
+    save_run_history(&mut history, run, &config);
+}
+
+/// Scatters confetti above the students, which keeps falling until the result screen is closed.
+fn spawn_confetti(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut rng: ResMut<RngService>,
+    options: Res<GraphicsOptions>,
+) {
+    if options.reduce_motion {
+        return;
+    }
+
+    let mesh = meshes.add(Rectangle::from_size(CONFETTI_SIZE));
+    let colors = CONFETTI_COLORS.map(|color| {
+        materials.add(StandardMaterial {
+            base_color: color,
+            unlit: true,
+            double_sided: true,
+            cull_mode: None,
+            ..Default::default()
+        })
+    });
+
+    let rng = rng.cosmetic();
+    for i in 0..NUM_CONFETTI {
+        let translation = Vec3::new(
+            rng.random_range(CONFETTI_AREA_MIN.x..=CONFETTI_AREA_MAX.x),
+            rng.random_range(CONFETTI_AREA_MIN.y..=CONFETTI_AREA_MAX.y),
+            rng.random_range(CONFETTI_AREA_MIN.z..=CONFETTI_AREA_MAX.z),
+        );
+        let axis = Vec3::new(
+            rng.random_range(-1.0..=1.0),
+            rng.random_range(-1.0..=1.0),
+            rng.random_range(-1.0..=1.0),
+        )
+        .normalize_or(Vec3::X);
+        commands.spawn((
+            Mesh3d(mesh.clone()),
+            MeshMaterial3d(colors[i % colors.len()].clone()),
+            Transform::from_translation(translation)
+                .with_rotation(Quat::from_scaled_axis(axis * rng.random_range(0.0..TAU))),
+            NotShadowCaster,
+            NotShadowReceiver,
+            ResultStateRoot,
+            Confetti {
+                velocity: Vec3::NEG_Y * rng.random_range(CONFETTI_FALL_SPEED),
+                spin: axis * rng.random_range(CONFETTI_SPIN_SPEED),
+                phase: rng.random_range(0.0..TAU),
+            },
+        ));
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn play_new_record_jingle(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    system_volume: Res<SystemVolume>,
 ) {
-    let path = SOUND_PATH_VO_RESULTS
-        .choose(&mut rand::rng())
-        .copied()
-        .unwrap();
     commands.spawn((
-        AudioPlayer::new(asset_server.load(path)),
-        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.voice_percentage())),
-        VoiceSound,
+        AudioPlayer::new(asset_server.load(SOUND_PATH_SFX_TRAIN_STEAM_WHISTLE)),
+        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.effect_percentage())),
+        EffectSound,
     ));
 }
 
 #[cfg(target_arch = "wasm32")]
-fn play_result_sound(
+fn play_new_record_jingle(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     system_volume: Res<SystemVolume>,
 ) {
-    let path = SOUND_PATH_VO_RESULTS
-        .choose(&mut rand::rng())
-        .copied()
-        .unwrap();
     commands.spawn((
-        WebAudioPlayer::new(asset_server.load(path)),
-        WebPlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.voice_percentage())),
-        VoiceSound,
+        WebAudioPlayer::new(asset_server.load(SOUND_PATH_SFX_TRAIN_STEAM_WHISTLE)),
+        WebPlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.effect_percentage())),
+        EffectSound,
     ));
 }
 
-fn check_and_save_high_score(
+/// Adds the bells collected during the run to the bells that can be spent on train upgrades.
+fn bank_collected_bells(
     mut commands: Commands,
-    mut high_score: ResMut<HighScore>,
-    score: Res<CurrentScore>,
-    new_record_query: Query<Entity, With<NewRecord>>,
+    mut upgrades: ResMut<TrainUpgrades>,
+    bells: Res<CollectedBells>,
 ) {
-    if high_score.0 < score.get() {
-        high_score.0 = score.get();
-
-        if let Ok(entity) = new_record_query.single() {
-            commands.entity(entity).insert(UI::NewRecord);
-        }
-
-        #[cfg(target_arch = "wasm32")]
-        if let Some(storage) = get_local_storage() {
-            let _ = storage.set_item(HIGH_SCORE_KEY, &high_score.0.to_string());
-        }
-    }
+    upgrades.bells = upgrades.bells.saturating_add(bells.0);
+    commands.insert_resource(RunRecorded);
 }
 
+#[allow(clippy::too_many_arguments)]
 fn setup_result_text(
     score: Res<CurrentScore>,
     play_time: Res<PlayTime>,
     high_score: Res<HighScore>,
+    assist_high_score: Res<AssistHighScore>,
+    hardcore_high_score: Res<HardcoreHighScore>,
+    config: Res<GameplayConfig>,
+    stats: Res<RunStats>,
+    locale: Res<CurrentLocale>,
     mut text_entities_query: Query<(&UI, &mut Text)>,
 ) {
+    let best = match config.score_category() {
+        ScoreCategory::Standard => high_score.0,
+        ScoreCategory::Assist => assist_high_score.0,
+        ScoreCategory::Hardcore => hardcore_high_score.0,
+        // Unranked runs have no best to show but their own score.
+        ScoreCategory::Mutated => 0,
+    };
+
     for (&ui, mut text) in text_entities_query.iter_mut() {
         match ui {
             UI::PlayTime => {
-                let total_millis = play_time.millis();
-                let minutes = (total_millis / (1000 * 60)) % 60;
-                let seconds = (total_millis / 1000) % 60;
-                let milliseconds = total_millis % 1000;
-                *text = Text::new(format!("{:02}:{:02}:{:03}", minutes, seconds, milliseconds));
+                *text = Text::new(format_play_time(play_time.millis()));
             }
             UI::GameScore => {
-                *text = Text::new(score.get().to_string());
+                *text = Text::new(format_number(score.get(), locale.0));
             }
             UI::BestScore => {
-                let high_score = score.get().max(high_score.0);
-                *text = Text::new(high_score.to_string());
+                *text = Text::new(format_number(score.get().max(best), locale.0));
+            }
+            UI::CollectionStats => {
+                let format = |percentage: Option<u32>| match percentage {
+                    Some(percentage) => format!("{}%", percentage),
+                    None => "-".to_string(),
+                };
+                *text = Text::new(format!(
+                    "Bells {} ({}/{})  Fuel {} ({}/{})",
+                    format(stats.bell_percentage()),
+                    stats.bells_collected,
+                    stats.bells_collected + stats.bells_missed,
+                    format(stats.fuel_percentage()),
+                    stats.fuel_collected,
+                    stats.fuel_collected + stats.fuel_missed,
+                ));
             }
             _ => { /* empty */ }
         }
@@ -209,19 +353,3 @@ fn update_scene_timer(
         next_state.set(GameState::Start2End);
     }
 }
-
-fn set_mouth_expression(
-    mut materials: ResMut<Assets<EyeMouthMaterial>>,
-    query: Query<&EyeMouth>,
-    timer: ResMut<SceneTimer>,
-) {
-    for mouth in query.iter() {
-        if let Some(material) = materials.get_mut(&mouth.0) {
-            if timer.elapsed_sec() < SCENE_DURATION * 0.5 {
-                material.extension.uniform.index.x = 2;
-            } else {
-                material.extension.uniform.index.x = 3;
-            }
-        }
-    }
-}
@@ -1,5 +1,13 @@
 // Import necessary Bevy modules.
-use bevy::prelude::*;
+use bevy::{audio::Volume, prelude::*};
+
+use crate::asset::{locale::CurrentLocale, sound::SystemVolume, spawner::TranslatableText};
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::cli::{GameMode, LaunchOptions};
+
+#[cfg(target_arch = "wasm32")]
+use crate::web::{WebAudioPlayer, WebPlaybackSettings};
 
 use super::*;
 
@@ -14,10 +22,18 @@ impl Plugin for StatePlugin {
             (
                 debug_label,
                 show_interface,
+                spawn_run_chips,
+                fill_run_comparison,
                 play_ui_animation,
                 play_hikari_animation,
                 play_nozomi_animation,
+                start_score_tally,
+                spawn_new_record_banner.run_if(resource_exists::<NewRecordRun>),
             ),
+        )
+        .add_systems(
+            Update,
+            update_score_tally.run_if(in_state(GameState::EndResult)),
         );
     }
 }
@@ -39,13 +55,127 @@ fn show_interface(mut query: Query<(&UI, &mut Visibility)>) {
             | UI::ResultModal
             | UI::RestartButton
             | UI::ResultExitButton
+            | UI::SeedButton
+            | UI::RunChips
             | UI::BestScore
+            | UI::CollectionStats
+            | UI::RunComparison
             | UI::NewRecord => *visibility = Visibility::Visible,
             _ => { /* empty */ }
         }
     }
 }
 
+/// Adds a chip for the seed of the run and one for the mode it was played in, followed by one for each of its modifiers.
+fn spawn_run_chips(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    rng: Res<RngService>,
+    config: Res<GameplayConfig>,
+    #[cfg(not(target_arch = "wasm32"))] options: Res<LaunchOptions>,
+    query: Query<(Entity, &UI)>,
+) {
+    #[cfg(not(target_arch = "wasm32"))]
+    let mode = match options.mode {
+        GameMode::Endless => "chip_endless",
+        GameMode::Daily => "chip_daily",
+        GameMode::Practice => "chip_practice",
+    };
+    // The web build has no mode selection, so every run is an endless run.
+    #[cfg(target_arch = "wasm32")]
+    let mode = "chip_endless";
+
+    // Each chip is the translation key of its label, its color and the value shown after the label.
+    let mut chips = vec![
+        ("chip_seed", SEED_CHIP_COLOR, Some(run_code(&rng, &config))),
+        (mode, MODE_CHIP_COLOR, None),
+    ];
+    if config.assist {
+        chips.push(("chip_assist", ASSIST_BADGE_COLOR, None));
+    }
+    chips.extend(
+        config
+            .mutators
+            .iter()
+            .map(|mutator| (mutator.label(), MUTATOR_CHIP_COLOR, None)),
+    );
+
+    let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
+    for (entity, &ui) in query.iter() {
+        if ui != UI::RunChips {
+            continue;
+        }
+
+        commands.entity(entity).with_children(|parent| {
+            for (label, color, value) in chips.iter() {
+                parent
+                    .spawn((
+                        Node {
+                            padding: UiRect::axes(Val::Vw(0.8), Val::Vh(0.4)),
+                            ..Default::default()
+                        },
+                        BorderRadius::all(Val::Vh(1.5)),
+                        BackgroundColor(*color),
+                        Visibility::Inherited,
+                        FadeInAnimation::new(PREPARE_ANIM_DURATION),
+                    ))
+                    .with_children(|parent| {
+                        let mut text = parent.spawn((
+                            Text::default(),
+                            TranslatableText(label.to_string()),
+                            TextFont::from_font(font.clone()),
+                            TextLayout::new_with_justify(JustifyText::Center),
+                            ResizableFont::Vertical {
+                                base: 1280.0,
+                                size: 28.0,
+                            },
+                            TextColor::WHITE,
+                            Node::default(),
+                            Visibility::Inherited,
+                            FadeInAnimation::new(PREPARE_ANIM_DURATION),
+                        ));
+                        // The value is a span of its own, so that translating the label keeps it.
+                        if let Some(value) = value {
+                            text.with_child((
+                                TextSpan::new(format!(" {}", value)),
+                                TextFont::from_font(font.clone()),
+                                ResizableFont::Vertical {
+                                    base: 1280.0,
+                                    size: 28.0,
+                                },
+                                TextColor::WHITE,
+                            ));
+                        }
+                    });
+            }
+        });
+    }
+}
+
+fn fill_run_comparison(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    comparison: Res<RunComparison>,
+    options: Res<GraphicsOptions>,
+    locale: Res<CurrentLocale>,
+    query: Query<(Entity, &UI)>,
+) {
+    let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
+    for (entity, &ui) in query.iter() {
+        if ui == UI::RunComparison {
+            spawn_run_comparison(
+                &mut commands,
+                entity,
+                &font,
+                &comparison,
+                &options,
+                locale.0,
+                true,
+            );
+        }
+    }
+}
+
 fn play_ui_animation(mut commands: Commands, query: Query<(Entity, &UI)>) {
     for (entity, &ui) in query.iter() {
         match ui {
@@ -56,7 +186,10 @@ fn play_ui_animation(mut commands: Commands, query: Query<(Entity, &UI)>) {
             | UI::ResultModal
             | UI::RestartButton
             | UI::ResultExitButton
+            | UI::SeedButton
             | UI::BestScore
+            | UI::CollectionStats
+            | UI::RunComparison
             | UI::NewRecord => {
                 commands
                     .entity(entity)
@@ -67,13 +200,18 @@ fn play_ui_animation(mut commands: Commands, query: Query<(Entity, &UI)>) {
     }
 }
 
+/// Keeps the victory pose going, or keeps cheering from the start of it when the run set a new record.
 fn play_hikari_animation(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     mut graphs: ResMut<Assets<AnimationGraph>>,
+    new_record: Option<Res<NewRecordRun>>,
     query: Query<Entity, (With<ResultStateEntity>, With<Hikari>)>,
 ) {
-    let clip = asset_server.load(ANIM_PATH_HIKARI_VICTORY_END);
+    let clip = match new_record {
+        Some(_) => asset_server.load(ANIM_PATH_HIKARI_VICTORY_START),
+        None => asset_server.load(ANIM_PATH_HIKARI_VICTORY_END),
+    };
     for entity in query.iter() {
         let (graph, animation_index) = AnimationGraph::from_clip(clip.clone());
         let mut player = AnimationPlayer::default();
@@ -85,13 +223,18 @@ fn play_hikari_animation(
     }
 }
 
+/// Keeps the victory pose going, or keeps cheering from the start of it when the run set a new record.
 fn play_nozomi_animation(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     mut graphs: ResMut<Assets<AnimationGraph>>,
+    new_record: Option<Res<NewRecordRun>>,
     query: Query<Entity, (With<ResultStateEntity>, With<Nozomi>)>,
 ) {
-    let clip = asset_server.load(ANIM_PATH_NOZOMI_VICTORY_END);
+    let clip = match new_record {
+        Some(_) => asset_server.load(ANIM_PATH_NOZOMI_VICTORY_START),
+        None => asset_server.load(ANIM_PATH_NOZOMI_VICTORY_END),
+    };
     for entity in query.iter() {
         let (graph, animation_index) = AnimationGraph::from_clip(clip.clone());
         let mut player = AnimationPlayer::default();
@@ -102,3 +245,111 @@ fn play_nozomi_animation(
             .insert((AnimationGraphHandle(graphs.add(graph)), player));
     }
 }
+
+/// Spells out "NEW BEST" across the top of the screen with the image font.
+fn spawn_new_record_banner(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Percent(4.0),
+                width: Val::Percent(100.0),
+                height: Val::Percent(NEW_RECORD_BANNER_HEIGHT),
+                justify_content: JustifyContent::Center,
+                column_gap: Val::Vw(1.0),
+                ..Default::default()
+            },
+            UiLayer::Overlay,
+            ResultStateRoot,
+        ))
+        .with_children(|parent| {
+            for (path, aspect_ratio) in [
+                (FONT_PATH_NEW, 110.0 / 48.0),
+                (FONT_PATH_BEST, 256.0 / 108.0),
+            ] {
+                parent.spawn((
+                    ImageNode::new(asset_server.load(path))
+                        .with_color(Color::WHITE.with_alpha(0.0)),
+                    Node {
+                        height: Val::Percent(100.0),
+                        aspect_ratio: Some(aspect_ratio),
+                        ..Default::default()
+                    },
+                    FadeInAnimation::new(PREPARE_ANIM_DURATION),
+                ));
+            }
+        });
+}
+
+fn start_score_tally(
+    mut commands: Commands,
+    score: Res<CurrentScore>,
+    mut query: Query<(Entity, &UI, &mut Text)>,
+) {
+    for (entity, &ui, mut text) in query.iter_mut() {
+        if ui == UI::GameScore {
+            *text = Text::new("0");
+            commands.entity(entity).insert(ScoreTally::new(score.get()));
+        }
+    }
+}
+
+// --- UPDATE SYSTEMS ---
+
+fn update_score_tally(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    system_volume: Res<SystemVolume>,
+    locale: Res<CurrentLocale>,
+    mut query: Query<(Entity, &mut Text, &mut TextColor, &mut ScoreTally)>,
+    time: Res<Time>,
+) {
+    for (entity, mut text, mut color, mut tally) in query.iter_mut() {
+        tally.tick(time.delta_secs());
+
+        if tally.take_tick() {
+            play_tally_tick_sound(&mut commands, &asset_server, &system_volume);
+        }
+
+        let value = format_number(tally.value(), locale.0);
+        if text.0 != value {
+            *text = Text::new(value);
+        }
+
+        // Keep the alpha, which is driven by the fade-in animation.
+        let alpha = color.0.alpha();
+        color.0 = Color::BLACK
+            .mix(&TALLY_FLASH_COLOR, tally.flash())
+            .with_alpha(alpha);
+
+        if tally.is_expired() {
+            commands.entity(entity).remove::<ScoreTally>();
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn play_tally_tick_sound(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    system_volume: &SystemVolume,
+) {
+    commands.spawn((
+        AudioPlayer::new(asset_server.load(SOUND_PATH_UI_BUTTON_TOUCH)),
+        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.effect_percentage())),
+        EffectSound,
+    ));
+}
+
+#[cfg(target_arch = "wasm32")]
+fn play_tally_tick_sound(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    system_volume: &SystemVolume,
+) {
+    commands.spawn((
+        WebAudioPlayer::new(asset_server.load(SOUND_PATH_UI_BUTTON_TOUCH)),
+        WebPlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.effect_percentage())),
+        EffectSound,
+    ));
+}
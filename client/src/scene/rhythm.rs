@@ -0,0 +1,243 @@
+use bevy::prelude::*;
+
+use crate::{asset::beat_map::BeatMap, diagnostics::FrameBudgetGroup};
+
+#[cfg(target_arch = "wasm32")]
+use crate::web::PlaybackPosition;
+
+use super::*;
+
+// --- PLUGIN ---
+
+/// Places bells on the beat of the background track during its rhythm sections,
+/// and builds a bonus multiplier while they are collected in rhythm.
+pub struct RhythmPlugin;
+
+impl Plugin for RhythmPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MusicClock>()
+            .init_resource::<BeatCursor>()
+            .add_systems(Startup, (load_beat_map, spawn_rhythm_label))
+            .add_systems(OnEnter(GameState::PrepareInGame), reset_beat_cursor)
+            .add_systems(Update, (update_music_clock, update_rhythm_label))
+            .add_systems(
+                Update,
+                (spawn_beat_bells.after(update_music_clock), miss_beat_bells)
+                    .run_if(in_state(GameState::InGame)),
+            )
+            .add_systems(
+                PostUpdate,
+                judge_beat_bells
+                    .after(FrameBudgetGroup::Collision)
+                    .run_if(in_state(GameState::InGame)),
+            );
+    }
+}
+
+// --- COMPONENTS ---
+
+/// Marks the label that shows the rhythm multiplier during a streak.
+#[derive(Component)]
+struct RhythmLabel;
+
+// --- RESOURCES ---
+
+#[derive(Resource)]
+struct BeatMapHandle(Handle<BeatMap>);
+
+/// The time, in seconds, the background track has played, counting every loop.
+#[derive(Default, Resource)]
+struct MusicClock(f32);
+
+/// The latest beat on the music clock whose bell has been considered for spawning.
+#[derive(Default, Resource)]
+struct BeatCursor(Option<f32>);
+
+// --- STARTUP SYSTEMS ---
+
+fn load_beat_map(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(BeatMapHandle(asset_server.load(BEAT_MAP_PATH_BACKGROUND)));
+}
+
+fn spawn_rhythm_label(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Vh(18.0),
+                width: Val::Vw(100.0),
+                justify_content: JustifyContent::Center,
+                ..Default::default()
+            },
+            UiLayer::Overlay,
+            Pickable::IGNORE,
+            Persistent,
+        ))
+        .with_children(|parent| {
+            let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
+            parent.spawn((
+                Text::default(),
+                TextFont::from_font(font),
+                TextLayout::new_with_justify(JustifyText::Center),
+                TextColor(RHYTHM_LABEL_COLOR),
+                TextShadow::default(),
+                ResizableFont::vertical(1280.0, 44.0),
+                Node::default(),
+                Visibility::Hidden,
+                RhythmLabel,
+            ));
+        });
+}
+
+// --- ENTER SYSTEMS ---
+
+fn reset_beat_cursor(mut cursor: ResMut<BeatCursor>) {
+    cursor.0 = None;
+}
+
+// --- UPDATE SYSTEMS ---
+
+/// Advances the music clock while the background track plays.
+/// The native audio backend does not report its position, so the clock follows the speed of the sink.
+#[cfg(not(target_arch = "wasm32"))]
+fn update_music_clock(
+    mut clock: ResMut<MusicClock>,
+    query: Query<&AudioSink, With<BackgroundSound>>,
+    time: Res<Time<Real>>,
+) {
+    if let Ok(sink) = query.single()
+        && !sink.is_paused()
+        && !sink.empty()
+    {
+        clock.0 += time.delta_secs() * sink.speed();
+    }
+}
+
+/// Reads the music clock from the audio context that plays the background track.
+#[cfg(target_arch = "wasm32")]
+fn update_music_clock(
+    mut clock: ResMut<MusicClock>,
+    query: Query<&PlaybackPosition, With<BackgroundSound>>,
+) {
+    if let Ok(position) = query.single() {
+        clock.0 = position.0 as f32;
+    }
+}
+
+/// Places the bells of the beats that come within reach of the spawn position,
+/// at the depth from which they reach the player on their beat.
+#[allow(clippy::too_many_arguments)]
+fn spawn_beat_bells(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut spawner: ResMut<ObjectSpawner>,
+    mut cursor: ResMut<BeatCursor>,
+    clock: Res<MusicClock>,
+    handle: Res<BeatMapHandle>,
+    beat_maps: Res<Assets<BeatMap>>,
+    player_query: Query<(&ForwardMovement, &Transform), With<Player>>,
+    time_scale: Res<TimeScale>,
+    horizon: Res<TrackHorizon>,
+) {
+    let Some(beat_map) = beat_maps.get(handle.0.id()) else {
+        return;
+    };
+    let Ok((forward_move, p_trans)) = player_query.single() else {
+        return;
+    };
+
+    // The music plays in real time, while the world scrolls in the scaled game time.
+    let speed = forward_move.get() * time_scale.get();
+    if speed <= 0.0 || beat_map.length <= 0.0 {
+        return;
+    }
+
+    let now = clock.0;
+    let horizon = now + (horizon.spawn - p_trans.translation.z) / speed;
+    // Beats already within reach when the run starts would appear in front of the player.
+    let since = *cursor.0.get_or_insert(horizon);
+    if horizon <= since {
+        return;
+    }
+
+    let first_loop = (since / beat_map.length).floor() as u32;
+    let last_loop = (horizon / beat_map.length).floor() as u32;
+    for track_loop in first_loop..=last_loop {
+        let loop_start = track_loop as f32 * beat_map.length;
+        for (time, lane) in beat_map.bells() {
+            let beat = loop_start + time;
+            if beat <= since || beat > horizon || lane > MAX_LANE_INDEX {
+                continue;
+            }
+
+            let depth = p_trans.translation.z + (beat - now) * speed;
+            spawner.spawn_beat_bell(
+                &mut commands,
+                &asset_server,
+                lane,
+                depth,
+                BeatBell { time: beat },
+            );
+        }
+    }
+    cursor.0 = Some(horizon);
+}
+
+/// Breaks the streak when a beat bell passes the player.
+fn miss_beat_bells(
+    mut commands: Commands,
+    mut rhythm: ResMut<RhythmBonus>,
+    bell_query: Query<(Entity, &Transform), With<BeatBell>>,
+    player_query: Query<&Transform, With<Player>>,
+) {
+    let Ok(p_trans) = player_query.single() else {
+        return;
+    };
+
+    let miss_line = p_trans.translation.z - MISS_DISTANCE;
+    for (entity, transform) in bell_query.iter() {
+        if transform.translation.z < miss_line {
+            rhythm.miss();
+            commands.entity(entity).remove::<BeatBell>();
+        }
+    }
+}
+
+/// Judges how close to its beat each beat bell was collected, and adds the bonus of the streak.
+fn judge_beat_bells(
+    mut events: EventReader<BeatBellCollected>,
+    mut rhythm: ResMut<RhythmBonus>,
+    mut score: ResMut<CurrentScore>,
+    clock: Res<MusicClock>,
+) {
+    for &BeatBellCollected(beat) in events.read() {
+        if rhythm.hit(clock.0 - beat) {
+            score.inc(rhythm.bonus_point());
+        }
+    }
+}
+
+/// Shows the rhythm multiplier while a streak lasts during a run.
+fn update_rhythm_label(
+    state: Res<State<GameState>>,
+    rhythm: Option<Res<RhythmBonus>>,
+    mut query: Query<(&mut Text, &mut Visibility), With<RhythmLabel>>,
+) {
+    let multiplier = rhythm
+        .filter(|_| *state.get() == GameState::InGame)
+        .map(|rhythm| rhythm.multiplier())
+        .filter(|&multiplier| multiplier > 1.0);
+
+    for (mut text, mut visibility) in query.iter_mut() {
+        match multiplier {
+            Some(multiplier) => {
+                let label = format!("Rhythm x{:.2}", multiplier);
+                if text.0 != label {
+                    text.0 = label;
+                }
+                *visibility = Visibility::Visible;
+            }
+            None => *visibility = Visibility::Hidden,
+        }
+    }
+}
@@ -0,0 +1,240 @@
+//! Lets the cutscenes be paused, stepped and scrubbed while they are authored.
+
+use bevy::prelude::*;
+
+use super::*;
+
+const TOGGLE_KEY: KeyCode = KeyCode::F7;
+const PAUSE_KEY: KeyCode = KeyCode::Backslash;
+const STEP_BACK_KEY: KeyCode = KeyCode::BracketLeft;
+const STEP_FORWARD_KEY: KeyCode = KeyCode::BracketRight;
+const SCRUB_BACK_KEY: KeyCode = KeyCode::Comma;
+const SCRUB_FORWARD_KEY: KeyCode = KeyCode::Period;
+
+/// The time, in seconds, a single step moves the clock by.
+const STEP_DURATION: f32 = 1.0 / 60.0;
+/// The cutscene seconds scrubbed per real second while a scrub key is held.
+const SCRUB_SPEED: f32 = 1.0;
+/// The number of characters in the progress bar of the overlay.
+const TIMELINE_WIDTH: usize = 40;
+/// The mouth expression the characters wear before the first cue of a cutscene.
+const DEFAULT_MOUTH: u32 = 1;
+
+// --- PLUGIN ---
+
+/// Shows a timeline of the playing cutscene in an overlay toggled with the F7 key.
+///
+/// While the overlay is shown, `\` pauses the game clock, `[` and `]` step it by a frame,
+/// and `,` and `.` scrub it while held. The camera tracks, animations and mouth expressions
+/// are evaluated again at the scrubbed time; the sound cues passed over are not played.
+pub struct CutsceneScrubberPlugin;
+
+impl Plugin for CutsceneScrubberPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CutsceneScrubber>()
+            .add_systems(Startup, spawn_scrubber_overlay)
+            .add_systems(
+                Update,
+                (
+                    toggle_scrubber,
+                    control_cutscene_clock,
+                    update_scrubber_overlay,
+                )
+                    .chain(),
+            );
+    }
+}
+
+// --- COMPONENTS ---
+
+#[derive(Component)]
+struct ScrubberOverlay;
+
+// --- RESOURCES ---
+
+/// Whether the scrubber is shown, and whether it has paused the game clock.
+#[derive(Default, Resource)]
+pub struct CutsceneScrubber {
+    shown: bool,
+    paused: bool,
+}
+
+impl CutsceneScrubber {
+    /// Checks if the keys pressed this frame belong to the scrubber,
+    /// so that they are not taken as the input of the scene.
+    pub fn captures(&self, keyboard_input: &ButtonInput<KeyCode>) -> bool {
+        self.shown || keyboard_input.just_pressed(TOGGLE_KEY)
+    }
+}
+
+// --- STARTUP SYSTEMS ---
+
+fn spawn_scrubber_overlay(mut commands: Commands) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(4.0),
+            left: Val::Px(4.0),
+            padding: UiRect::all(Val::Px(4.0)),
+            ..Default::default()
+        },
+        Text::default(),
+        TextFont::from_font_size(14.0),
+        TextColor(Color::WHITE),
+        BackgroundColor(Color::BLACK.with_alpha(0.6)),
+        UiLayer::Debug,
+        Visibility::Hidden,
+        ScrubberOverlay,
+        Persistent,
+    ));
+}
+
+// --- UPDATE SYSTEMS ---
+
+fn toggle_scrubber(
+    mut scrubber: ResMut<CutsceneScrubber>,
+    mut query: Query<&mut Visibility, With<ScrubberOverlay>>,
+    mut time: ResMut<Time<Virtual>>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+) {
+    if !keyboard_input.just_pressed(TOGGLE_KEY) {
+        return;
+    }
+
+    scrubber.shown = !scrubber.shown;
+    if !scrubber.shown && scrubber.paused {
+        scrubber.paused = false;
+        time.unpause();
+    }
+    for mut visibility in query.iter_mut() {
+        *visibility = match scrubber.shown {
+            true => Visibility::Visible,
+            false => Visibility::Hidden,
+        };
+    }
+}
+
+/// Pauses, steps and scrubs the clock of the playing cutscene, and gives the clock back
+/// once no cutscene is playing.
+#[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments)]
+fn control_cutscene_clock(
+    mut scrubber: ResMut<CutsceneScrubber>,
+    mut time: ResMut<Time<Virtual>>,
+    mut cutscene: Option<ResMut<Cutscene>>,
+    mut timer: Option<ResMut<SceneTimer>>,
+    mut params: ParamSet<(
+        CutsceneStage<InGameStateEntity>,
+        CutsceneStage<ResultStateEntity>,
+        Query<&mut AnimationPlayer>,
+    )>,
+    state: Res<State<GameState>>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    real_time: Res<Time<Real>>,
+) {
+    let Some(cutscene) = cutscene.as_mut().filter(|_| scrubber.shown) else {
+        if scrubber.paused && cutscene.is_none() {
+            scrubber.paused = false;
+            time.unpause();
+        }
+        return;
+    };
+
+    if keyboard_input.just_pressed(PAUSE_KEY) {
+        scrubber.paused = !scrubber.paused;
+    }
+
+    let mut offset = 0.0;
+    if keyboard_input.just_pressed(STEP_BACK_KEY) {
+        offset -= STEP_DURATION;
+    }
+    if keyboard_input.just_pressed(STEP_FORWARD_KEY) {
+        offset += STEP_DURATION;
+    }
+    if keyboard_input.pressed(SCRUB_BACK_KEY) {
+        offset -= SCRUB_SPEED * real_time.delta_secs();
+    }
+    if keyboard_input.pressed(SCRUB_FORWARD_KEY) {
+        offset += SCRUB_SPEED * real_time.delta_secs();
+    }
+    // Stepping or scrubbing holds the clock where it was left.
+    scrubber.paused |= offset != 0.0;
+
+    match scrubber.paused {
+        true => time.pause(),
+        false => time.unpause(),
+    }
+    if offset == 0.0 {
+        return;
+    }
+
+    // The clock of the cutscene leads, and the other clocks follow it by the same amount.
+    let current = cutscene.elapsed_sec();
+    let target = (current + offset).max(0.0);
+    let offset = target - current;
+
+    // The cues that set the state of the scene are fired again, so that it matches the new time.
+    let cues: Vec<_> = std::iter::once(CutsceneCue::Mouth(DEFAULT_MOUTH))
+        .chain(cutscene.seek(target))
+        .filter(CutsceneCue::sets_state)
+        .collect();
+    match state.get() {
+        GameState::PrepareInGame => {
+            let mut stage = params.p0();
+            for cue in cues.iter() {
+                stage.fire(cue, ());
+            }
+        }
+        _ => {
+            let mut stage = params.p1();
+            for cue in cues.iter() {
+                stage.fire(cue, ());
+            }
+        }
+    }
+
+    if let Some(timer) = timer.as_mut() {
+        let target = (timer.elapsed_sec() + offset).max(0.0);
+        timer.reset();
+        timer.tick(target);
+    }
+    for mut player in params.p2().iter_mut() {
+        for (_, animation) in player.playing_animations_mut() {
+            let target = (animation.seek_time() + offset).max(0.0);
+            animation.seek_to(target);
+        }
+    }
+}
+
+fn update_scrubber_overlay(
+    mut query: Query<&mut Text, With<ScrubberOverlay>>,
+    scrubber: Res<CutsceneScrubber>,
+    cutscene: Option<Res<Cutscene>>,
+) {
+    let Ok(mut text) = query.single_mut() else {
+        return;
+    };
+    if !scrubber.shown {
+        return;
+    }
+
+    let mut content = match cutscene {
+        Some(cutscene) => {
+            let (elapsed, duration) = (cutscene.elapsed_sec(), cutscene.duration());
+            let progress = match duration > 0.0 {
+                true => (elapsed / duration).clamp(0.0, 1.0),
+                false => 1.0,
+            };
+            let filled = (progress * TIMELINE_WIDTH as f32).round() as usize;
+            format!(
+                "cutscene {elapsed:>6.2} / {duration:.2} s{}\n[{}{}]",
+                if scrubber.paused { "  PAUSED" } else { "" },
+                "=".repeat(filled),
+                "-".repeat(TIMELINE_WIDTH - filled),
+            )
+        }
+        None => "no cutscene playing".to_string(),
+    };
+    content.push_str("\n\\ pause   [ ] step   , . scrub");
+    text.0 = content;
+}
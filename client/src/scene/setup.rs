@@ -3,15 +3,24 @@ use bevy::prelude::*;
 
 use crate::asset::{
     locale::{CurrentLocale, Locale, LocalizationAssets, LocalizationData},
+    size::loaded_fraction,
     sound::SystemVolume,
 };
 
+#[cfg(not(target_arch = "wasm32"))]
+use crate::cli::{GameMode, LaunchOptions};
+#[cfg(target_arch = "wasm32")]
+use crate::web::get_seed_param;
+
 use super::*;
 
 // --- CONSTANTS ---
 
 const TIMEOUT: f32 = 5.0;
 const MAX_RETRY_COUNT: u32 = 5;
+/// The environment variable that fixes the seed of every run, in hexadecimal.
+#[cfg(not(target_arch = "wasm32"))]
+const SEED_ENV: &str = "SHUPOGAKI_SEED";
 
 // --- PLUGIN ---
 
@@ -27,7 +36,13 @@ impl Plugin for StatePlugin {
                     debug_label,
                     setup_locale,
                     setup_high_score,
+                    setup_best_pace,
+                    setup_run_history,
+                    setup_assist_mode,
+                    setup_train_upgrades,
                     setup_system_volume,
+                    setup_graphics_options,
+                    setup_rng_service,
                     load_necessary_assets,
                     setup_loading_screen,
                     init_asset_load_timeout_retry,
@@ -99,11 +114,114 @@ fn setup_high_score(mut commands: Commands) {
     } else {
         commands.insert_resource(HighScore::default());
     }
+
+    if let Some(storage) = get_local_storage()
+        && let Ok(storage_item) = storage.get_item(HARDCORE_HIGH_SCORE_KEY)
+        && let Some(score_str) = storage_item
+        && let Ok(score) = score_str.parse::<u32>()
+    {
+        info!("Loaded hardcore high score: {}", score);
+        commands.insert_resource(HardcoreHighScore(score));
+    } else {
+        commands.insert_resource(HardcoreHighScore::default());
+    }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
 fn setup_high_score(mut commands: Commands) {
     commands.insert_resource(HighScore::default());
+    commands.insert_resource(HardcoreHighScore::default());
+}
+
+#[cfg(target_arch = "wasm32")]
+fn setup_best_pace(mut commands: Commands) {
+    if let Some(storage) = get_local_storage()
+        && let Ok(storage_item) = storage.get_item(BEST_PACE_KEY)
+        && let Some(pace_str) = storage_item
+        && let Ok(pace) = BEST_PACE_SCHEMA.decode::<BestPace>(&pace_str)
+    {
+        info!("Loaded best pace: {} splits", pace.splits.len());
+        commands.insert_resource(pace);
+    } else {
+        commands.insert_resource(BestPace::default());
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn setup_best_pace(mut commands: Commands) {
+    commands.insert_resource(BestPace::default());
+}
+
+#[cfg(target_arch = "wasm32")]
+fn setup_run_history(mut commands: Commands) {
+    if let Some(storage) = get_local_storage()
+        && let Ok(storage_item) = storage.get_item(RUN_HISTORY_KEY)
+        && let Some(history_str) = storage_item
+        && let Ok(history) = RUN_HISTORY_SCHEMA.decode::<RunHistory>(&history_str)
+    {
+        info!("Loaded run history: {:?}", history);
+        commands.insert_resource(history);
+    } else {
+        commands.insert_resource(RunHistory::default());
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn setup_run_history(mut commands: Commands) {
+    commands.insert_resource(RunHistory::default());
+}
+
+#[cfg(target_arch = "wasm32")]
+fn setup_assist_mode(mut commands: Commands) {
+    let storage = get_local_storage();
+
+    if let Some(storage) = storage.as_ref()
+        && let Ok(storage_item) = storage.get_item(ASSIST_MODE_KEY)
+        && let Some(enabled_str) = storage_item
+        && let Ok(enabled) = enabled_str.parse::<bool>()
+    {
+        info!("Loaded assist mode: {}", enabled);
+        commands.insert_resource(AssistMode(enabled));
+    } else {
+        commands.insert_resource(AssistMode::default());
+    }
+
+    if let Some(storage) = storage.as_ref()
+        && let Ok(storage_item) = storage.get_item(ASSIST_HIGH_SCORE_KEY)
+        && let Some(score_str) = storage_item
+        && let Ok(score) = score_str.parse::<u32>()
+    {
+        info!("Loaded assist high score: {}", score);
+        commands.insert_resource(AssistHighScore(score));
+    } else {
+        commands.insert_resource(AssistHighScore::default());
+    }
+}
+
+/// Practice mode is played with assist mode enabled.
+#[cfg(not(target_arch = "wasm32"))]
+fn setup_assist_mode(mut commands: Commands, options: Res<LaunchOptions>) {
+    commands.insert_resource(AssistMode(options.mode == GameMode::Practice));
+    commands.insert_resource(AssistHighScore::default());
+}
+
+#[cfg(target_arch = "wasm32")]
+fn setup_train_upgrades(mut commands: Commands) {
+    if let Some(storage) = get_local_storage()
+        && let Ok(storage_item) = storage.get_item(TRAIN_UPGRADES_KEY)
+        && let Some(upgrades_str) = storage_item
+        && let Ok(upgrades) = TRAIN_UPGRADES_SCHEMA.decode::<TrainUpgrades>(&upgrades_str)
+    {
+        info!("Loaded train upgrades: {:?}", &upgrades);
+        commands.insert_resource(upgrades);
+    } else {
+        commands.insert_resource(TrainUpgrades::default());
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn setup_train_upgrades(mut commands: Commands) {
+    commands.insert_resource(TrainUpgrades::default());
 }
 
 /// Initializes and inserts the default system volume as a resource.
@@ -112,7 +230,7 @@ fn setup_system_volume(mut commands: Commands) {
     if let Some(storage) = get_local_storage()
         && let Ok(storage_item) = storage.get_item(SYSTEM_VOLUME_KEY)
         && let Some(volume_str) = storage_item
-        && let Ok(system_volume) = serde_json::from_str::<SystemVolume>(&volume_str)
+        && let Ok(system_volume) = SYSTEM_VOLUME_SCHEMA.decode::<SystemVolume>(&volume_str)
     {
         info!("Loaded system volume: {:?}", &system_volume);
         commands.insert_resource(system_volume);
@@ -121,10 +239,62 @@ fn setup_system_volume(mut commands: Commands) {
     }
 }
 
-/// Initializes and inserts the default system volume as a resource.
+/// Initializes and inserts the default system volume as a resource, muted if requested on the command line.
 #[cfg(not(target_arch = "wasm32"))]
-fn setup_system_volume(mut commands: Commands) {
-    commands.insert_resource(SystemVolume::default());
+fn setup_system_volume(mut commands: Commands, options: Res<LaunchOptions>) {
+    let mut system_volume = SystemVolume::default();
+    if options.mute {
+        system_volume.background_muted = true;
+        system_volume.effect_muted = true;
+        system_volume.voice_muted = true;
+    }
+    commands.insert_resource(system_volume);
+}
+
+/// Initializes and inserts the graphics options as a resource.
+#[cfg(target_arch = "wasm32")]
+fn setup_graphics_options(mut commands: Commands) {
+    if let Some(storage) = get_local_storage()
+        && let Ok(storage_item) = storage.get_item(GRAPHICS_OPTIONS_KEY)
+        && let Some(options_str) = storage_item
+        && let Ok(options) = GRAPHICS_OPTIONS_SCHEMA.decode::<GraphicsOptions>(&options_str)
+    {
+        info!("Loaded graphics options: {:?}", &options);
+        commands.insert_resource(options);
+    } else {
+        commands.insert_resource(GraphicsOptions::default());
+    }
+}
+
+/// Initializes and inserts the default graphics options as a resource.
+#[cfg(not(target_arch = "wasm32"))]
+fn setup_graphics_options(mut commands: Commands) {
+    commands.insert_resource(GraphicsOptions::default());
+}
+
+/// Sets up the random number streams, seeded from the `seed` query parameter of the page if present.
+#[cfg(target_arch = "wasm32")]
+fn setup_rng_service(mut commands: Commands) {
+    let seed = get_seed_param().and_then(|text| RngService::parse_seed(&text));
+    if let Some(seed) = seed {
+        info!("Using fixed seed: {:016X}", seed);
+    }
+    commands.insert_resource(RngService::new(seed));
+}
+
+/// Sets up the random number streams, seeded from the command line or
+/// the `SHUPOGAKI_SEED` environment variable if present.
+#[cfg(not(target_arch = "wasm32"))]
+fn setup_rng_service(mut commands: Commands, options: Res<LaunchOptions>) {
+    let seed = options.fixed_seed().or_else(|| {
+        std::env::var(SEED_ENV)
+            .ok()
+            .and_then(|text| RngService::parse_seed(&text))
+    });
+    if let Some(seed) = seed {
+        info!("Using fixed seed: {:016X}", seed);
+    }
+    commands.insert_resource(RngService::new(seed));
 }
 
 /// Begins loading essential assets required for the game to start,
@@ -197,6 +367,7 @@ fn setup_loading_screen(mut commands: Commands, asset_server: Res<AssetServer>)
                 ..Default::default()
             },
             LoadingStateRoot,
+            UiLayer::Overlay,
         ))
         .with_children(|parent| {
             // Container for the loading text.
@@ -222,7 +393,7 @@ fn setup_loading_screen(mut commands: Commands, asset_server: Res<AssetServer>)
                             ..Default::default()
                         },
                         LoadingText,
-                        ZIndex(2),
+                        UiLayer::Overlay,
                     ));
                 });
 
@@ -249,7 +420,7 @@ fn setup_loading_screen(mut commands: Commands, asset_server: Res<AssetServer>)
                         BorderRadius::all(Val::Percent(50.0)),
                         BackgroundColor(LOADING_BAR_COLOR),
                         LoadingBar, // Tag component for querying.
-                        ZIndex(1),
+                        UiLayer::Hud,
                     ));
                 });
         });
@@ -278,12 +449,15 @@ fn check_loading_progress(
     asset_server: Res<AssetServer>,
     loading_assets: ResMut<SystemAssets>,
     mut next_state: ResMut<NextState<GameState>>,
+    pack_queue: Option<Res<ContentPackQueue>>,
 ) {
     // Check if all handles in the loading list have finished loading, including their dependencies.
+    // The content packs are registered first, since they add to the localization data.
     let all_loaded = loading_assets
         .handles
         .iter()
-        .all(|handle| asset_server.is_loaded_with_dependencies(handle.id()));
+        .all(|handle| asset_server.is_loaded_with_dependencies(handle.id()))
+        && pack_queue.is_none();
 
     if all_loaded {
         // Transition to the next state once loading is complete.
@@ -291,41 +465,35 @@ fn check_loading_progress(
     }
 }
 
-/// Updates the width of the loading bar UI element based on the current asset loading progress.
+/// Updates the loading bar with the current asset loading progress, weighted by asset size.
 fn update_loading_progress(
     asset_server: Res<AssetServer>,
     loading_assets: Res<SystemAssets>,
-    mut query: Query<&mut Node, With<LoadingBar>>,
+    mut query: Query<&mut LoadingProgress, With<LoadingBar>>,
 ) {
-    if let Ok(mut node) = query.single_mut() {
-        // Count how many assets have successfully loaded.
-        let loaded_count = loading_assets
-            .handles
-            .iter()
-            .filter(|handle| asset_server.is_loaded_with_dependencies(handle.id()))
-            .count();
-
-        let total_count = loading_assets.handles.len();
-        // Calculate the progress percentage.
-        let progress = if total_count > 0 {
-            loaded_count as f32 / total_count as f32
-        } else {
-            1.0 // Avoid division by zero if there are no assets to load.
-        };
-
-        // Update the width of the loading bar node.
-        node.width = Val::Percent(progress * 100.0);
+    if let Ok(mut progress) = query.single_mut() {
+        progress.set(loaded_fraction(&asset_server, &loading_assets.handles));
     }
 }
 
 fn check_and_retry_asset_load_timeout(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
+    loading_assets: Res<SystemAssets>,
     mut next_state: ResMut<NextState<GameState>>,
     mut counter: ResMut<RetryCounter>,
     mut scene_timer: ResMut<SceneTimer>,
     time: Res<Time>,
 ) {
+    // Waiting for the content packs to be registered is not a load failure.
+    let all_loaded = loading_assets
+        .handles
+        .iter()
+        .all(|handle| asset_server.is_loaded_with_dependencies(handle.id()));
+    if all_loaded {
+        return;
+    }
+
     scene_timer.tick(time.delta_secs());
     if scene_timer.elapsed_sec() >= TIMEOUT {
         scene_timer.reset();
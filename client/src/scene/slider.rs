@@ -0,0 +1,311 @@
+use bevy::{prelude::*, ui::UiSystem};
+
+use super::constants::STICK_THRESHOLD;
+
+// --- PLUGIN ---
+
+/// Drives every [`Slider`] widget: pointer and touch dragging, keyboard and gamepad
+/// editing of the focused slider, and the placement of the slider thumbs.
+pub struct SliderPlugin;
+
+impl Plugin for SliderPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<SliderChanged>()
+            .init_resource::<SliderDrag>()
+            .init_resource::<FocusedSlider>()
+            .add_systems(PreUpdate, begin_slider_drag.after(UiSystem::Focus))
+            .add_systems(
+                Update,
+                (drag_slider, adjust_focused_slider, update_slider_thumb).chain(),
+            );
+    }
+}
+
+// --- COMPONENTS ---
+
+/// A horizontal slider whose track is the node this component is attached to.
+/// The value is kept in the range of 0.0 to 1.0 and snapped to multiples of the step.
+#[derive(Debug, Component)]
+#[require(Interaction)]
+pub struct Slider {
+    value: f32,
+    step: f32,
+}
+
+impl Slider {
+    pub fn new(value: f32, step: f32) -> Self {
+        debug_assert!(step > 0.0 && step <= 1.0);
+        Self {
+            value: value.clamp(0.0, 1.0),
+            step,
+        }
+    }
+
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// Sets the value snapped to the nearest step. Returns `true` if the value changed.
+    pub fn set(&mut self, value: f32) -> bool {
+        let snapped = ((value / self.step).round() * self.step).clamp(0.0, 1.0);
+        let changed = (snapped - self.value).abs() > f32::EPSILON;
+        self.value = snapped;
+        changed
+    }
+
+    /// Moves the value by the given number of steps. Returns `true` if the value changed.
+    pub fn nudge(&mut self, steps: f32) -> bool {
+        self.set(self.value + steps * self.step)
+    }
+}
+
+/// Marks the node that is positioned along the track of its ancestor [`Slider`].
+#[derive(Component)]
+pub struct SliderThumb;
+
+// --- EVENTS ---
+
+/// Sent whenever the user changes the value of a slider.
+#[derive(Debug, Event)]
+pub struct SliderChanged {
+    pub slider: Entity,
+    pub value: f32,
+}
+
+// --- RESOURCES ---
+
+/// The slider being dragged and the pointer dragging it (`None` for the mouse, or a touch id).
+#[derive(Default, Resource)]
+pub struct SliderDrag(Option<(Entity, Option<u64>)>);
+
+impl SliderDrag {
+    pub fn is_dragging(&self) -> bool {
+        self.0.is_some()
+    }
+}
+
+/// The slider that receives keyboard and gamepad adjustments.
+/// Once adjusted, the slider is being edited until the edit is confirmed, cancelled,
+/// or the focus moves away.
+#[derive(Default, Resource)]
+pub struct FocusedSlider {
+    entity: Option<Entity>,
+    /// The value the edited slider had before it was first adjusted.
+    original: Option<f32>,
+}
+
+impl FocusedSlider {
+    pub fn get(&self) -> Option<Entity> {
+        self.entity
+    }
+
+    pub fn set(&mut self, entity: Entity) {
+        if self.entity != Some(entity) {
+            self.entity = Some(entity);
+            self.original = None;
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.entity = None;
+        self.original = None;
+    }
+
+    /// Returns `true` while the focused slider has unconfirmed adjustments.
+    /// The cancel action reverts them instead of leaving the screen.
+    pub fn is_editing(&self) -> bool {
+        self.original.is_some()
+    }
+}
+
+// --- PREUPDATE SYSTEMS ---
+
+/// Starts dragging a slider when its track or any node inside it is pressed.
+fn begin_slider_drag(
+    touches: Res<Touches>,
+    interaction_query: Query<(Entity, &Interaction), Changed<Interaction>>,
+    slider_query: Query<(), With<Slider>>,
+    parent_query: Query<&ChildOf>,
+    mut drag: ResMut<SliderDrag>,
+    mut focused: ResMut<FocusedSlider>,
+) {
+    if drag.is_dragging() {
+        return;
+    }
+
+    for (entity, &interaction) in interaction_query.iter() {
+        if interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let slider = std::iter::once(entity)
+            .chain(parent_query.iter_ancestors(entity))
+            .find(|&e| slider_query.contains(e));
+
+        if let Some(slider) = slider {
+            let pointer = touches.iter_just_pressed().last().map(|touch| touch.id());
+            drag.0 = Some((slider, pointer));
+            focused.set(slider);
+            return;
+        }
+    }
+}
+
+// --- UPDATE SYSTEMS ---
+
+/// Moves the dragged slider to the pointer and ends the drag once the pointer is released.
+fn drag_slider(
+    windows: Query<&Window>,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
+    mut drag: ResMut<SliderDrag>,
+    mut slider_query: Query<(&mut Slider, &ComputedNode, &GlobalTransform)>,
+    mut events: EventWriter<SliderChanged>,
+) {
+    let Some((entity, pointer)) = drag.0 else {
+        return;
+    };
+    let Ok(window) = windows.single() else { return };
+
+    // Both the window cursor and the node layout are measured in physical pixels here.
+    let position = match pointer {
+        None => window.physical_cursor_position(),
+        Some(id) => touches
+            .get_pressed(id)
+            .map(|touch| touch.position() * window.scale_factor()),
+    };
+
+    if let Some(position) = position
+        && let Ok((mut slider, node, transform)) = slider_query.get_mut(entity)
+    {
+        let track = Rect::from_center_size(transform.translation().truncate(), node.size());
+        if track.width() > 0.0 {
+            let value = (position.x - track.min.x) / track.width();
+            if slider.set(value) {
+                events.write(SliderChanged {
+                    slider: entity,
+                    value: slider.value(),
+                });
+            }
+        }
+    }
+
+    let released = match pointer {
+        None => !mouse_button.pressed(MouseButton::Left),
+        Some(id) => touches.get_pressed(id).is_none(),
+    };
+    if released || !slider_query.contains(entity) {
+        drag.0 = None;
+    }
+}
+
+/// Adjusts the focused slider by one step with the left and right arrow keys, d-pad or left stick.
+/// Enter or the south button confirms the edit, and Escape or the east button reverts the slider
+/// to the value it had before it was adjusted.
+fn adjust_focused_slider(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    mut stick_latched: Local<bool>,
+    mut focused: ResMut<FocusedSlider>,
+    mut slider_query: Query<(&mut Slider, &InheritedVisibility)>,
+    mut events: EventWriter<SliderChanged>,
+) {
+    let Some(entity) = focused.entity else {
+        return;
+    };
+    let Ok((mut slider, visibility)) = slider_query.get_mut(entity) else {
+        focused.clear();
+        return;
+    };
+
+    // A slider loses the focus once it is hidden.
+    if !visibility.get() {
+        focused.clear();
+        return;
+    }
+
+    let cancelled = keyboard_input.just_pressed(KeyCode::Escape)
+        || gamepads
+            .iter()
+            .any(|gamepad| gamepad.just_pressed(GamepadButton::East));
+    if cancelled && let Some(original) = focused.original.take() {
+        if slider.set(original) {
+            events.write(SliderChanged {
+                slider: entity,
+                value: slider.value(),
+            });
+        }
+        return;
+    }
+
+    let confirmed =
+        keyboard_input.any_just_pressed([KeyCode::Enter, KeyCode::NumpadEnter, KeyCode::Space])
+            || gamepads
+                .iter()
+                .any(|gamepad| gamepad.just_pressed(GamepadButton::South));
+    if confirmed {
+        focused.original = None;
+        return;
+    }
+
+    let mut steps = 0.0;
+    if keyboard_input.just_pressed(KeyCode::ArrowLeft)
+        || gamepads
+            .iter()
+            .any(|gamepad| gamepad.just_pressed(GamepadButton::DPadLeft))
+    {
+        steps -= 1.0;
+    }
+    if keyboard_input.just_pressed(KeyCode::ArrowRight)
+        || gamepads
+            .iter()
+            .any(|gamepad| gamepad.just_pressed(GamepadButton::DPadRight))
+    {
+        steps += 1.0;
+    }
+
+    // The stick moves one step per horizontal push, and has to return to neutral before the next.
+    let stick = gamepads
+        .iter()
+        .map(|gamepad| gamepad.left_stick())
+        .find(|stick| stick.length() > STICK_THRESHOLD);
+    match stick {
+        Some(stick) if !*stick_latched => {
+            *stick_latched = true;
+            if stick.x.abs() > stick.y.abs() {
+                steps += stick.x.signum();
+            }
+        }
+        Some(_) => { /* empty */ }
+        None => *stick_latched = false,
+    }
+
+    let value = slider.value();
+    if steps != 0.0 && slider.nudge(steps) {
+        focused.original.get_or_insert(value);
+        events.write(SliderChanged {
+            slider: entity,
+            value: slider.value(),
+        });
+    }
+}
+
+/// Places each thumb along its track according to the value of its slider.
+fn update_slider_thumb(
+    slider_query: Query<&Slider>,
+    parent_query: Query<&ChildOf>,
+    mut thumb_query: Query<(Entity, &mut Node), With<SliderThumb>>,
+) {
+    for (entity, mut node) in thumb_query.iter_mut() {
+        let slider = parent_query
+            .iter_ancestors(entity)
+            .find_map(|e| slider_query.get(e).ok());
+
+        if let Some(slider) = slider {
+            let left = Val::Percent(slider.value() * 100.0);
+            if node.left != left {
+                node.left = left;
+            }
+        }
+    }
+}
@@ -0,0 +1,225 @@
+#[cfg(not(target_arch = "wasm32"))]
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicU32, Ordering},
+    },
+    time::Duration,
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use bevy::audio::{AddAudioSource, AudioSource, Decodable, Sample, Source};
+use bevy::{prelude::*, transform::TransformSystem};
+
+use super::MirroredView;
+
+#[cfg(target_arch = "wasm32")]
+use crate::web::WebPlaybackSettings;
+
+/// The distance from the center of the screen, in world units, at which a sound is panned fully to one side.
+const PAN_WIDTH: f32 = 12.0;
+
+// --- PLUGIN ---
+
+/// Pans world sounds by their horizontal position on the screen.
+/// Natively the pan is a constant-power pan applied while decoding,
+/// and on the web it is a stereo panner node in the web audio graph.
+pub struct SoundPanPlugin;
+
+impl Plugin for SoundPanPlugin {
+    fn build(&self, app: &mut App) {
+        #[cfg(not(target_arch = "wasm32"))]
+        app.add_audio_source::<PannedAudio>().add_systems(
+            PostUpdate,
+            (follow_world_position, start_panned_audio, sync_pan_control)
+                .chain()
+                .after(TransformSystem::TransformPropagate),
+        );
+
+        #[cfg(target_arch = "wasm32")]
+        app.add_systems(
+            PostUpdate,
+            (follow_world_position, sync_web_pan)
+                .chain()
+                .after(TransformSystem::TransformPropagate),
+        );
+    }
+}
+
+// --- COMPONENTS ---
+
+/// The stereo position of a sound, from -1.0 (left) to 1.0 (right).
+/// Sounds with a [`GlobalTransform`] are panned by where that position appears on the screen.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Component)]
+pub struct StereoPan(pub f32);
+
+/// Plays a sound that can be panned with [`StereoPan`].
+/// It turns into an [`AudioPlayer`] once the sound is loaded.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Component)]
+#[require(StereoPan, PlaybackSettings)]
+pub struct PannedAudioPlayer(pub Handle<AudioSource>);
+
+#[cfg(not(target_arch = "wasm32"))]
+impl PannedAudioPlayer {
+    pub fn new(source: Handle<AudioSource>) -> Self {
+        Self(source)
+    }
+}
+
+/// Shares the pan of a playing sound with its decoder.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Component)]
+pub struct PanControl(Arc<AtomicU32>);
+
+// --- ASSETS ---
+
+/// A sound decoded to stereo with an adjustable pan.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Asset, TypePath)]
+pub struct PannedAudio {
+    source: AudioSource,
+    pan: Arc<AtomicU32>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Decodable for PannedAudio {
+    type DecoderItem = f32;
+    type Decoder = PannedDecoder;
+
+    fn decoder(&self) -> Self::Decoder {
+        PannedDecoder {
+            inner: self.source.decoder(),
+            pan: self.pan.clone(),
+            channel: 0,
+            pending: None,
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub struct PannedDecoder {
+    inner: <AudioSource as Decodable>::Decoder,
+    pan: Arc<AtomicU32>,
+    channel: u16,
+    /// The right sample of a mono sample that was split into two channels.
+    pending: Option<f32>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Iterator for PannedDecoder {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(sample) = self.pending.take() {
+            return Some(sample);
+        }
+
+        let sample = self.inner.next()?.to_f32();
+        let (left, right) = pan_gains(f32::from_bits(self.pan.load(Ordering::Relaxed)));
+        match self.inner.channels() {
+            1 => {
+                self.pending = Some(sample * right);
+                Some(sample * left)
+            }
+            2 => {
+                let channel = self.channel;
+                self.channel = 1 - channel;
+                Some(sample * if channel == 0 { left } else { right })
+            }
+            _ => Some(sample),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Source for PannedDecoder {
+    fn current_frame_len(&self) -> Option<usize> {
+        match self.inner.channels() {
+            1 => self.inner.current_frame_len().map(|len| len * 2),
+            _ => self.inner.current_frame_len(),
+        }
+    }
+
+    fn channels(&self) -> u16 {
+        self.inner.channels().max(2)
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.inner.sample_rate()
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        self.inner.total_duration()
+    }
+}
+
+/// Returns the gains of the left and right channels, keeping the total power constant.
+#[cfg(not(target_arch = "wasm32"))]
+fn pan_gains(pan: f32) -> (f32, f32) {
+    let angle = (pan.clamp(-1.0, 1.0) + 1.0) * std::f32::consts::FRAC_PI_4;
+    (angle.cos(), angle.sin())
+}
+
+// --- POSTUPDATE SYSTEMS ---
+
+/// Pans the sounds placed in the world by where they appear on the screen.
+fn follow_world_position(
+    camera_query: Query<(&Camera, &GlobalTransform, Has<MirroredView>), With<Camera3d>>,
+    mut query: Query<(&GlobalTransform, &mut StereoPan)>,
+) {
+    let Some((_, camera, mirrored)) = camera_query
+        .iter()
+        .find(|(camera, ..)| camera.is_active && camera.order == 0)
+    else {
+        return;
+    };
+    // A mirrored view shows the right side of the world on the left of the screen.
+    let right = match mirrored {
+        true => camera.left(),
+        false => camera.right(),
+    };
+
+    for (transform, mut pan) in query.iter_mut() {
+        let offset = (transform.translation() - camera.translation()).dot(*right);
+        pan.set_if_neq(StereoPan((offset / PAN_WIDTH).clamp(-1.0, 1.0)));
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn start_panned_audio(
+    mut commands: Commands,
+    mut panned_assets: ResMut<Assets<PannedAudio>>,
+    sources: Res<Assets<AudioSource>>,
+    query: Query<(Entity, &PannedAudioPlayer, &StereoPan)>,
+) {
+    for (entity, player, pan) in query.iter() {
+        let Some(source) = sources.get(&player.0) else {
+            continue;
+        };
+
+        let control = Arc::new(AtomicU32::new(pan.0.to_bits()));
+        let audio = panned_assets.add(PannedAudio {
+            source: source.clone(),
+            pan: control.clone(),
+        });
+        commands
+            .entity(entity)
+            .remove::<PannedAudioPlayer>()
+            .insert((AudioPlayer(audio), PanControl(control)));
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn sync_pan_control(query: Query<(&StereoPan, &PanControl), Changed<StereoPan>>) {
+    for (pan, control) in query.iter() {
+        control.0.store(pan.0.to_bits(), Ordering::Relaxed);
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn sync_web_pan(mut query: Query<(&StereoPan, &mut WebPlaybackSettings), Changed<StereoPan>>) {
+    for (pan, mut settings) in query.iter_mut() {
+        settings.pan = pan.0;
+    }
+}
@@ -0,0 +1,173 @@
+use bevy::{audio::Volume, platform::collections::HashMap, prelude::*};
+use rand::{Rng, seq::SliceRandom};
+
+#[cfg(target_arch = "wasm32")]
+use crate::web::WebPlaybackSettings;
+
+use super::*;
+
+/// The most a varied sound is played quieter than its set volume, as a ratio.
+const VOLUME_JITTER: f32 = 0.15;
+/// The most a varied sound is played lower or higher, as a ratio of its speed.
+const PITCH_JITTER: f32 = 0.04;
+/// How much quieter a sound is on the lane furthest from the camera than on the nearest one.
+const LANE_OCCLUSION: f32 = 0.2;
+
+// --- TYPES ---
+
+/// The recordings of a sound, one of which is played each time it is heard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SoundSet {
+    Damaged,
+    Healing,
+    AobaHit,
+    Invincible,
+}
+
+impl SoundSet {
+    pub fn paths(self) -> &'static [&'static str] {
+        match self {
+            Self::Damaged => &SOUND_PATH_VO_DAMAGEDS,
+            Self::Healing => &SOUND_PATH_VO_HEALINGS,
+            Self::AobaHit => &SOUND_PATH_VO_AOBA_HITS,
+            Self::Invincible => &SOUND_PATH_VO_INVINCIBLES,
+        }
+    }
+}
+
+/// Deals out the indices of a set in a shuffled order, so that every one is dealt once
+/// before any repeats, and the last of a round is never the first of the next.
+#[derive(Debug, Clone, Default)]
+pub struct RoundRobin {
+    /// The indices left in the round, dealt from the back.
+    remaining: Vec<usize>,
+    last: Option<usize>,
+}
+
+impl RoundRobin {
+    /// Deals the next index of a set of `len` items. `len` must not be zero.
+    pub fn next(&mut self, len: usize, rng: &mut impl Rng) -> usize {
+        if self.remaining.is_empty() {
+            self.remaining = (0..len).collect();
+            self.remaining.shuffle(rng);
+            if len > 1 && self.remaining.last() == self.last.as_ref() {
+                self.remaining.swap(0, len - 1);
+            }
+        }
+
+        let index = self.remaining.pop().unwrap();
+        self.last = Some(index);
+        index
+    }
+}
+
+/// How one playback of a sound differs from the recording, so that a sound heard over and
+/// over in a long run does not sound the same every time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SoundVariation {
+    /// The ratio of the set volume the sound is played at.
+    pub volume: f32,
+    /// The playback speed, which also shifts the pitch.
+    pub speed: f32,
+}
+
+impl SoundVariation {
+    /// Rolls a slightly quieter, lower or higher playback.
+    pub fn roll(rng: &mut impl Rng) -> Self {
+        Self {
+            volume: 1.0 - rng.random_range(0.0..=VOLUME_JITTER),
+            speed: 1.0 + rng.random_range(-PITCH_JITTER..=PITCH_JITTER),
+        }
+    }
+
+    /// Makes the sound quieter the further its position is from the camera,
+    /// which looks at the track from beyond the last lane.
+    pub fn occluded_at(self, position: Vec3) -> Self {
+        let near = LANE_POSITIONS[MAX_LANE_INDEX];
+        let far = LANE_POSITIONS[0];
+        let t = ((near - position.x) / (near - far)).clamp(0.0, 1.0);
+        Self {
+            volume: self.volume * (1.0 - LANE_OCCLUSION * t),
+            ..self
+        }
+    }
+
+    /// The settings of a sound played once at the given volume, with the variation.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn settings(&self, volume: f32) -> PlaybackSettings {
+        PlaybackSettings::DESPAWN
+            .with_volume(Volume::Linear(volume * self.volume))
+            .with_speed(self.speed)
+    }
+
+    /// The settings of a sound played once at the given volume, with the variation.
+    #[cfg(target_arch = "wasm32")]
+    pub fn settings(&self, volume: f32) -> WebPlaybackSettings {
+        WebPlaybackSettings::DESPAWN
+            .with_volume(Volume::Linear(volume * self.volume))
+            .with_speed(self.speed)
+    }
+}
+
+// --- RESOURCES ---
+
+/// Picks the recordings of the hit and pickup sounds of a run in turn.
+#[derive(Default, Resource)]
+pub struct SoundRotation {
+    sets: HashMap<SoundSet, RoundRobin>,
+}
+
+impl SoundRotation {
+    /// Returns the path of the next recording of the set.
+    pub fn pick(&mut self, set: SoundSet, rng: &mut impl Rng) -> &'static str {
+        let paths = set.paths();
+        paths[self.sets.entry(set).or_default().next(paths.len(), rng)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use super::*;
+
+    #[test]
+    fn deals_every_index_before_repeating() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut round_robin = RoundRobin::default();
+
+        let mut previous = None;
+        for _ in 0..50 {
+            let mut round: Vec<_> = (0..4).map(|_| round_robin.next(4, &mut rng)).collect();
+            assert_ne!(Some(round[0]), previous);
+            previous = round.last().copied();
+
+            round.sort_unstable();
+            assert_eq!(round, [0, 1, 2, 3]);
+        }
+    }
+
+    #[test]
+    fn deals_the_only_index_of_a_single_set() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut round_robin = RoundRobin::default();
+        assert_eq!(round_robin.next(1, &mut rng), 0);
+        assert_eq!(round_robin.next(1, &mut rng), 0);
+    }
+
+    #[test]
+    fn quiets_sounds_on_lanes_away_from_the_camera() {
+        let variation = SoundVariation {
+            volume: 1.0,
+            speed: 1.0,
+        };
+        let at = |lane: usize| {
+            variation
+                .occluded_at(Vec3::new(LANE_POSITIONS[lane], 0.0, 0.0))
+                .volume
+        };
+        assert_eq!(at(MAX_LANE_INDEX), 1.0);
+        assert!(at(1) < at(MAX_LANE_INDEX));
+        assert!((at(0) - (1.0 - LANE_OCCLUSION)).abs() < 1e-6);
+    }
+}
@@ -34,3 +34,40 @@ pub fn update_font_size(
         }
     }
 }
+
+/// Empties the loading bar whenever another loading state begins.
+pub fn reset_loading_progress(mut query: Query<&mut LoadingProgress>) {
+    for mut progress in query.iter_mut() {
+        progress.reset();
+    }
+}
+
+pub fn update_loading_bar_width(
+    mut query: Query<(&mut Node, &mut LoadingProgress)>,
+    time: Res<Time<Real>>,
+) {
+    for (mut node, mut progress) in query.iter_mut() {
+        progress.on_advanced(time.delta_secs());
+        node.width = Val::Percent(progress.displayed() * 100.0);
+    }
+}
+
+/// Stores the train upgrades whenever bells are banked or spent.
+#[cfg(target_arch = "wasm32")]
+pub fn save_train_upgrades(upgrades: Res<TrainUpgrades>) {
+    if let Some(storage) = get_local_storage()
+        && let Ok(value) = TRAIN_UPGRADES_SCHEMA.encode(&*upgrades)
+    {
+        info!("Store train upgrades: {:?}", &value);
+        let _ = storage.set_item(TRAIN_UPGRADES_KEY, &value);
+    }
+}
+
+/// Stores whether assist mode is enabled whenever it is toggled.
+#[cfg(target_arch = "wasm32")]
+pub fn save_assist_mode(assist: Res<AssistMode>) {
+    if let Some(storage) = get_local_storage() {
+        info!("Store assist mode: {}", assist.0);
+        let _ = storage.set_item(ASSIST_MODE_KEY, &assist.0.to_string());
+    }
+}
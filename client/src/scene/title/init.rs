@@ -3,6 +3,7 @@ use bevy::{audio::Volume, prelude::*, render::view::NoFrustumCulling};
 
 use crate::asset::{
     animation::AnimationClipHandle,
+    locale::{CurrentLocale, Locale},
     sound::SystemVolume,
     spawner::{SpawnModel, TranslatableText},
 };
@@ -75,6 +76,7 @@ fn spawn_entities(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     high_score: Res<HighScore>,
+    locale: Res<CurrentLocale>,
 ) {
     let mut loading_entities = LoadingEntities::default();
     spawn_title_entities(&mut commands, &asset_server, &mut loading_entities);
@@ -83,6 +85,7 @@ fn spawn_entities(
         &asset_server,
         &mut loading_entities,
         &high_score,
+        locale.0,
     );
 
     // --- Resource Insertion ---
@@ -95,13 +98,18 @@ fn spawn_title_entities(
     loading_entities: &mut LoadingEntities,
 ) {
     let model = asset_server.load(MODEL_PATH_PLANE_0);
-    for i in 0..3 {
+    for i in 0..title::NUM_DIORAMA_GROUNDS {
         let entity = commands
             .spawn((
                 SpawnModel(model.clone()),
-                Transform::from_xyz(0.0, 0.0, -30.0 * (i + 1) as f32),
+                Transform::from_xyz(
+                    0.0,
+                    -title::DIORAMA_GROUND_DEPTH,
+                    -GROUND_SPAWN_INTERVAL * (i + 1) as f32,
+                ),
                 Visibility::Hidden,
                 SpawnRequest,
+                DioramaGround,
             ))
             .id();
         loading_entities.handles.push(entity);
@@ -158,6 +166,11 @@ fn spawn_title_entities(
             Transform::from_translation(title::HIKARI_POSITION)
                 .looking_at(title::CAMERA_POSITION.with_y(0.0), Vec3::Y),
             AnimationClipHandle(clip),
+            IdleVariation::new(asset_server.load(ANIM_PATH_HIKARI_VICTORY_START)),
+            PokeTarget {
+                voice: SOUND_PATH_HIKARI_TITLE,
+                cooldown: 0.0,
+            },
             Visibility::Hidden,
             SpawnRequest,
         ))
@@ -172,6 +185,11 @@ fn spawn_title_entities(
             Transform::from_translation(title::NOZOMI_POSITION)
                 .looking_at(title::CAMERA_POSITION.with_y(0.0), Vec3::Y),
             AnimationClipHandle(clip),
+            IdleVariation::new(asset_server.load(ANIM_PATH_NOZOMI_VICTORY_START)),
+            PokeTarget {
+                voice: SOUND_PATH_NOZOMI_TITLE,
+                cooldown: 0.0,
+            },
             Visibility::Hidden,
             SpawnRequest,
         ))
@@ -184,6 +202,7 @@ fn spawn_title_ui_entities(
     asset_server: &AssetServer,
     loading_entities: &mut LoadingEntities,
     high_score: &HighScore,
+    locale: Locale,
 ) {
     let entity = commands
         .spawn((
@@ -199,8 +218,21 @@ fn spawn_title_ui_entities(
                 ..Default::default()
             },
             SpawnRequest,
+            UiLayer::Hud,
         ))
         .with_children(|parent| {
+            parent.spawn((
+                IconNode(Icon::Trophy),
+                Node {
+                    height: Val::Vh(7.0),
+                    aspect_ratio: Some(1.0),
+                    margin: UiRect::right(Val::Vw(0.5)),
+                    ..Default::default()
+                },
+                Visibility::Hidden,
+                UI::HighScore,
+            ));
+
             let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
             parent.spawn((
                 Text::new("High Score:"),
@@ -215,7 +247,7 @@ fn spawn_title_ui_entities(
             ));
 
             parent.spawn((
-                Text::new(format!("{}", high_score.0)),
+                Text::new(format_number(high_score.0, locale)),
                 TextFont::from_font(font.clone()),
                 TextLayout::new_with_justify(JustifyText::Center),
                 TextShadow::default(),
@@ -243,6 +275,7 @@ fn spawn_title_ui_entities(
                 ..Default::default()
             },
             SpawnRequest,
+            UiLayer::Hud,
         ))
         .with_children(|parent| {
             let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
@@ -257,6 +290,7 @@ fn spawn_title_ui_entities(
                 Visibility::Hidden,
                 UI::StartButton,
                 Button,
+                Focusable,
             ));
 
             parent.spawn((Node {
@@ -276,6 +310,7 @@ fn spawn_title_ui_entities(
                 Visibility::Hidden,
                 UI::OptionButton,
                 Button,
+                Focusable,
             ));
 
             parent.spawn((Node {
@@ -295,10 +330,192 @@ fn spawn_title_ui_entities(
                 Visibility::Hidden,
                 UI::TutorialButton,
                 Button,
+                // The tutorial is a page of the web build.
+                #[cfg(not(target_arch = "wasm32"))]
+                Disabled,
+                Focusable,
+            ));
+
+            parent.spawn((Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(10.0),
+                ..Default::default()
+            },));
+
+            // The text shows the bells and the cost of the next car, and is kept up to date on the title screen.
+            parent.spawn((
+                Text::default(),
+                TextFont::from_font(font.clone()),
+                TextLayout::new_with_justify(JustifyText::Center),
+                TextShadow::default(),
+                ResizableFont::vertical(1280.0, 64.0),
+                Node::default(),
+                Visibility::Hidden,
+                UI::BuyCarButton,
+                Button,
+                Focusable,
+            ));
+
+            parent.spawn((Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(4.0),
+                ..Default::default()
+            },));
+
+            // The text shows whether assist mode is enabled, and is kept up to date on the title screen.
+            parent.spawn((
+                Text::default(),
+                TranslatableText("assist_off".to_string()),
+                TextFont::from_font(font.clone()),
+                TextLayout::new_with_justify(JustifyText::Center),
+                TextShadow::default(),
+                ResizableFont::vertical(1280.0, 64.0),
+                Node::default(),
+                Visibility::Hidden,
+                UI::AssistButton,
+                Button,
+                Focusable,
+            ));
+
+            parent.spawn((Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(4.0),
+                ..Default::default()
+            },));
+
+            parent.spawn((
+                Text::new("Mutators"),
+                TranslatableText("mutators".to_string()),
+                TextFont::from_font(font.clone()),
+                TextLayout::new_with_justify(JustifyText::Center),
+                TextShadow::default(),
+                ResizableFont::vertical(1280.0, 64.0),
+                Node::default(),
+                Visibility::Hidden,
+                UI::MutatorsButton,
+                Button,
+                Focusable,
             ));
         })
         .id();
     loading_entities.handles.push(entity);
+
+    spawn_mutator_panel(commands, asset_server, loading_entities);
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
+        let entity = commands
+            .spawn((
+                Text::new("Install"),
+                TranslatableText("install".to_string()),
+                TextFont::from_font(font),
+                TextLayout::new_with_justify(JustifyText::Center),
+                TextShadow::default(),
+                ResizableFont::vertical(1280.0, 64.0),
+                Node {
+                    position_type: PositionType::Absolute,
+                    right: Val::Vw(3.0),
+                    top: Val::Vh(5.0),
+                    ..Default::default()
+                },
+                Visibility::Hidden,
+                UI::InstallButton,
+                UiLayer::Hud,
+                Button,
+                Focusable,
+                SpawnRequest,
+            ))
+            .id();
+        loading_entities.handles.push(entity);
+    }
+}
+
+/// Spawns the hidden panel that toggles the mutators of the next run.
+fn spawn_mutator_panel(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    loading_entities: &mut LoadingEntities,
+) {
+    let entity = commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Vw(100.0),
+                height: Val::Vh(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            BackgroundColor(PAUSE_BG_COLOR),
+            Visibility::Hidden,
+            SpawnRequest,
+            UiLayer::Modal,
+            UI::MutatorPanel,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Vh(2.5),
+                    ..Default::default()
+                })
+                .with_children(|parent| {
+                    let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
+                    parent.spawn((
+                        Text::new("Mutators"),
+                        TranslatableText("mutators".to_string()),
+                        TextFont::from_font(font.clone()),
+                        TextLayout::new_with_justify(JustifyText::Center),
+                        TextShadow::default(),
+                        ResizableFont::vertical(1280.0, 78.0),
+                        Node::default(),
+                    ));
+
+                    // The text shows whether the mutator is enabled, and is kept up to date on the title screen.
+                    for mutator in Mutator::ALL {
+                        parent.spawn((
+                            Text::default(),
+                            TranslatableText(format!("{}_off", mutator.label())),
+                            TextFont::from_font(font.clone()),
+                            TextLayout::new_with_justify(JustifyText::Center),
+                            TextShadow::default(),
+                            ResizableFont::vertical(1280.0, 56.0),
+                            Node::default(),
+                            UI::MutatorToggle,
+                            MutatorToggle(mutator),
+                            Button,
+                            Focusable,
+                        ));
+                    }
+
+                    parent.spawn((
+                        Text::default(),
+                        TextFont::from_font(font.clone()),
+                        TextLayout::new_with_justify(JustifyText::Center),
+                        TextColor(SCORE_MULTIPLIER_COLOR),
+                        ResizableFont::vertical(1280.0, 48.0),
+                        Node::default(),
+                        UI::MutatorMultiplier,
+                    ));
+
+                    parent.spawn((
+                        Text::new("Back"),
+                        TextFont::from_font(font),
+                        TextLayout::new_with_justify(JustifyText::Center),
+                        TextShadow::default(),
+                        TranslatableText("back".into()),
+                        ResizableFont::vertical(1280.0, 64.0),
+                        Node::default(),
+                        UI::MutatorCloseButton,
+                        Button,
+                        Focusable,
+                    ));
+                });
+        })
+        .id();
+    loading_entities.handles.push(entity);
 }
 
 // --- CLEANUP SYSTEMS ---
@@ -348,9 +565,9 @@ fn check_loading_progress(
 fn update_loading_bar(
     loading_entitis: Res<LoadingEntities>,
     request_query: Query<(), With<SpawnRequest>>,
-    mut query: Query<&mut Node, With<LoadingBar>>,
+    mut query: Query<&mut LoadingProgress, With<LoadingBar>>,
 ) {
-    if let Ok(mut node) = query.single_mut() {
+    if let Ok(mut bar) = query.single_mut() {
         let loaded_count = loading_entitis
             .handles
             .iter()
@@ -364,6 +581,6 @@ fn update_loading_bar(
             1.0
         };
 
-        node.width = Val::Percent(progress * 100.0);
+        bar.set(progress);
     }
 }
@@ -1,7 +1,7 @@
 // Import necessary Bevy modules.
 use bevy::prelude::*;
 
-use crate::asset::model::ModelAsset;
+use crate::asset::{model::ModelAsset, size::loaded_fraction};
 
 use super::*;
 
@@ -85,6 +85,12 @@ fn load_assets(commands: &mut Commands, asset_server: &AssetServer) {
     let clip: Handle<AnimationClip> = asset_server.load(ANIM_PATH_NOZOMI_CAFE_IDLE);
     loading_assets.handles.push(clip.into());
 
+    let clip: Handle<AnimationClip> = asset_server.load(ANIM_PATH_HIKARI_VICTORY_START);
+    loading_assets.handles.push(clip.into());
+
+    let clip: Handle<AnimationClip> = asset_server.load(ANIM_PATH_NOZOMI_VICTORY_START);
+    loading_assets.handles.push(clip.into());
+
     // --- Sound Loading ----
     let sound: Handle<AudioSource> = asset_server.load(SOUND_PATH_HIKARI_TITLE);
     loading_assets.handles.push(sound.into());
@@ -103,7 +109,7 @@ fn setup_loading_screen(
     asset_server: Res<AssetServer>,
     camera_query: Query<(), With<Camera2d>>,
 ) {
-    if camera_query.single().is_err() {
+    if camera_query.is_empty() {
         // Spawn a 2D camera for the loading screen UI.
         commands.spawn((Camera2d, LoadingStateRoot));
 
@@ -123,6 +129,7 @@ fn setup_loading_screen(
                     ..Default::default()
                 },
                 LoadingStateRoot,
+                UiLayer::Overlay,
             ))
             .with_children(|parent| {
                 // Container for the loading text.
@@ -148,7 +155,7 @@ fn setup_loading_screen(
                                 ..Default::default()
                             },
                             LoadingText,
-                            ZIndex(2),
+                            UiLayer::Overlay,
                         ));
                     });
 
@@ -175,7 +182,7 @@ fn setup_loading_screen(
                             BorderRadius::all(Val::Percent(50.0)),
                             BackgroundColor(LOADING_BAR_COLOR),
                             LoadingBar, // Tag component for querying.
-                            ZIndex(1),
+                            UiLayer::Hud,
                         ));
                     });
             });
@@ -217,23 +224,10 @@ fn check_loading_progress(
 fn update_loading_progress(
     asset_server: Res<AssetServer>,
     loading_assets: Res<TitleAssets>,
-    mut query: Query<&mut Node, With<LoadingBar>>,
+    mut query: Query<&mut LoadingProgress, With<LoadingBar>>,
 ) {
-    if let Ok(mut node) = query.single_mut() {
-        let loaded_count = loading_assets
-            .handles
-            .iter()
-            .filter(|handle| asset_server.is_loaded_with_dependencies(handle.id()))
-            .count();
-
-        let total_count = loading_assets.handles.len();
-        let progress = if total_count > 0 {
-            loaded_count as f32 / total_count as f32
-        } else {
-            1.0
-        };
-
-        node.width = Val::Percent(progress * 100.0);
+    if let Ok(mut progress) = query.single_mut() {
+        progress.set(loaded_fraction(&asset_server, &loading_assets.handles));
     }
 }
 
@@ -1,13 +1,20 @@
 // Import necessary Bevy modules.
-use bevy::{audio::Volume, prelude::*};
+use std::time::Duration;
 
+use bevy::{audio::Volume, prelude::*, window::PrimaryWindow};
+use rand::Rng;
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::cli::LaunchOptions;
 #[cfg(target_arch = "wasm32")]
-use crate::web::{WebAudioPlayer, WebPlaybackSettings, start_game_tutorial};
+use crate::web::{
+    WebAudioPlayer, WebPlaybackSettings, install_available, prompt_install, start_game_tutorial,
+};
 
 use crate::{
     asset::{
         animation::AnimationClipHandle, locale::CurrentLocale, material::EyeMouthMaterial,
-        sound::SystemVolume,
+        sound::SystemVolume, spawner::TranslatableText,
     },
     shader::face_mouth::EyeMouth,
 };
@@ -35,7 +42,32 @@ impl Plugin for StatePlugin {
         .add_systems(OnExit(GameState::Title), hide_interfaces)
         .add_systems(
             PreUpdate,
-            title_button_systems.run_if(in_state(GameState::Title)),
+            (title_button_systems, mutator_panel_systems).run_if(in_state(GameState::Title)),
+        )
+        .add_systems(
+            Update,
+            (
+                update_buy_car_button,
+                update_assist_button,
+                update_mutator_panel,
+                poke_title_characters,
+                update_idle_variations.after(poke_title_characters),
+            )
+                .run_if(in_state(GameState::Title)),
+        )
+        .add_systems(
+            Update,
+            scroll_diorama_grounds
+                .run_if(in_state(GameState::Title).or(in_state(GameState::Option))),
+        );
+
+        #[cfg(not(target_arch = "wasm32"))]
+        app.add_systems(OnEnter(GameState::Title), skip_title);
+
+        #[cfg(target_arch = "wasm32")]
+        app.add_systems(
+            Update,
+            update_install_button.run_if(in_state(GameState::Title)),
         );
     }
 }
@@ -55,9 +87,13 @@ fn show_entities(mut query: Query<&mut Visibility, (With<TitleStateRoot>, Withou
 fn show_interfaces(mut query: Query<(&UI, &mut Visibility)>) {
     for (&ui, mut visibility) in query.iter_mut() {
         match ui {
-            UI::HighScore | UI::StartButton | UI::OptionButton | UI::TutorialButton => {
-                *visibility = Visibility::Visible
-            }
+            UI::HighScore
+            | UI::StartButton
+            | UI::OptionButton
+            | UI::TutorialButton
+            | UI::BuyCarButton
+            | UI::AssistButton
+            | UI::MutatorsButton => *visibility = Visibility::Visible,
             _ => { /* empty */ }
         }
     }
@@ -68,7 +104,7 @@ fn spawn_camera_and_light(
     light_query: Query<(), With<DirectionalLight>>,
     camera_query: Query<(), With<Camera3d>>,
 ) {
-    if light_query.single().is_err() {
+    if light_query.is_empty() {
         commands.spawn((
             DirectionalLight {
                 illuminance: 10_000.0,
@@ -80,7 +116,7 @@ fn spawn_camera_and_light(
         ));
     }
 
-    if camera_query.single().is_err() {
+    if camera_query.is_empty() {
         commands.spawn((
             Camera3d::default(),
             Projection::from(PerspectiveProjection {
@@ -90,6 +126,7 @@ fn spawn_camera_and_light(
                 far: 100.0,
             }),
             Transform::from_translation(CAMERA_POSITION).looking_to(CAMERA_DIRECTION, Vec3::Y),
+            TitleCamera,
             TitleStateRoot,
         ));
     }
@@ -100,16 +137,35 @@ fn spawn_camera_and_light(
 fn play_animation(
     mut commands: Commands,
     mut graphs: ResMut<Assets<AnimationGraph>>,
-    query: Query<(Entity, &AnimationClipHandle)>,
+    mut rng: ResMut<RngService>,
+    mut query: Query<(Entity, &AnimationClipHandle, Option<&mut IdleVariation>)>,
 ) {
-    for (entity, clip) in query.iter() {
-        let (graph, animation_index) = AnimationGraph::from_clip(clip.0.clone());
+    for (entity, clip, variation) in query.iter_mut() {
+        let Some(mut variation) = variation else {
+            let (graph, animation_index) = AnimationGraph::from_clip(clip.0.clone());
+            let mut player = AnimationPlayer::default();
+            player.play(animation_index).repeat();
+
+            commands
+                .entity(entity)
+                .insert((AnimationGraphHandle(graphs.add(graph)), player))
+                .remove::<AnimationClipHandle>();
+            continue;
+        };
+
+        // The idle loop and its secondary clip share a graph, so the transitions can blend between them.
+        let (graph, indices) = AnimationGraph::from_clips([clip.0.clone(), variation.clip.clone()]);
         let mut player = AnimationPlayer::default();
-        player.play(animation_index).repeat();
+        let mut transitions = AnimationTransitions::new();
+        transitions
+            .play(&mut player, indices[0], Duration::ZERO)
+            .repeat();
+        variation.nodes = Some((indices[0], indices[1]));
+        variation.remaining = rng.cosmetic().random_range(IDLE_VARIATION_INTERVAL);
 
         commands
             .entity(entity)
-            .insert((AnimationGraphHandle(graphs.add(graph)), player))
+            .insert((AnimationGraphHandle(graphs.add(graph)), player, transitions))
             .remove::<AnimationClipHandle>();
     }
 }
@@ -121,12 +177,13 @@ fn setup_background_sound(
     system_volume: Res<SystemVolume>,
     query: Query<(), With<BackgroundSound>>,
 ) {
-    if query.single().is_err() {
+    if query.is_empty() {
         commands.spawn((
-            AudioPlayer::new(asset_server.load(SOUND_PATH_BACKGROUND)),
+            LowPassAudioPlayer::new(asset_server.load(SOUND_PATH_BACKGROUND)),
             PlaybackSettings::LOOP
                 .with_volume(Volume::Linear(system_volume.background_percentage())),
             BackgroundSound,
+            Persistent,
         ));
     }
 }
@@ -138,12 +195,13 @@ fn setup_background_sound(
     system_volume: Res<SystemVolume>,
     query: Query<(), With<BackgroundSound>>,
 ) {
-    if query.single().is_err() {
+    if query.is_empty() {
         commands.spawn((
             WebAudioPlayer::new(asset_server.load(SOUND_PATH_BACKGROUND)),
             WebPlaybackSettings::LOOP
                 .with_volume(Volume::Linear(system_volume.background_percentage())),
             BackgroundSound,
+            Persistent,
         ));
     }
 }
@@ -159,14 +217,30 @@ fn setup_mouth_expression(
     }
 }
 
+/// Starts the first run right away when requested on the command line.
+#[cfg(not(target_arch = "wasm32"))]
+fn skip_title(mut options: ResMut<LaunchOptions>, mut next_state: ResMut<NextState<GameState>>) {
+    if options.skip_title {
+        options.skip_title = false;
+        next_state.set(GameState::Title2InGame);
+    }
+}
+
 // --- CLEANUP SYSTEM ---
 
 fn hide_interfaces(mut query: Query<(&UI, &mut Visibility)>) {
     for (&ui, mut visibility) in query.iter_mut() {
         match ui {
-            UI::HighScore | UI::StartButton | UI::OptionButton | UI::TutorialButton => {
-                *visibility = Visibility::Hidden
-            }
+            UI::HighScore
+            | UI::StartButton
+            | UI::OptionButton
+            | UI::TutorialButton
+            | UI::BuyCarButton
+            | UI::AssistButton
+            | UI::MutatorsButton
+            | UI::MutatorPanel => *visibility = Visibility::Hidden,
+            #[cfg(target_arch = "wasm32")]
+            UI::InstallButton => *visibility = Visibility::Hidden,
             _ => { /* empty */ }
         }
     }
@@ -174,13 +248,136 @@ fn hide_interfaces(mut query: Query<(&UI, &mut Visibility)>) {
 
 // --- UPDATE SYSTEM ---
 
+/// Casts a ray from every click or tap into the scene, and lets the nearest character it hits react
+/// with its voice line and secondary clip. Presses on the interface are left to the interface.
 #[allow(clippy::type_complexity)]
-fn title_button_systems(
+#[allow(clippy::too_many_arguments)]
+fn poke_title_characters(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     system_volume: Res<SystemVolume>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<TitleCamera>>,
+    interaction_query: Query<&Interaction>,
+    mut target_query: Query<(
+        &GlobalTransform,
+        &mut PokeTarget,
+        Option<(
+            &mut AnimationPlayer,
+            &mut AnimationTransitions,
+            &mut IdleVariation,
+        )>,
+    )>,
+    time: Res<Time>,
+) {
+    for (_, mut target, _) in target_query.iter_mut() {
+        target.cooldown = (target.cooldown - time.delta_secs()).max(0.0);
+    }
+
+    let Ok((camera, camera_transform)) = camera_query.single() else {
+        return;
+    };
+    if interaction_query
+        .iter()
+        .any(|interaction| *interaction != Interaction::None)
+    {
+        return;
+    }
+
+    let cursor = windows
+        .single()
+        .ok()
+        .and_then(Window::cursor_position)
+        .filter(|_| mouse_input.just_pressed(MouseButton::Left));
+    let presses = cursor
+        .into_iter()
+        .chain(touches.iter_just_pressed().map(|touch| touch.position()));
+
+    for position in presses {
+        let Some(ray) = screen_ray(camera, camera_transform, position) else {
+            continue;
+        };
+
+        let hit = target_query
+            .iter_mut()
+            .filter_map(|item| {
+                let center = item.0.translation() + Vec3::Y * POKE_CENTER_HEIGHT;
+                ray_hits_box(ray, center, POKE_HALF_SIZE).map(|distance| (distance, item))
+            })
+            .min_by(|a, b| a.0.total_cmp(&b.0));
+        let Some((_, (transform, mut target, animation))) = hit else {
+            continue;
+        };
+        if target.cooldown > 0.0 {
+            continue;
+        }
+
+        target.cooldown = POKE_COOLDOWN;
+        play_voice_at(
+            &mut commands,
+            &asset_server,
+            &system_volume,
+            target.voice,
+            transform.translation(),
+        );
+
+        if let Some((mut player, mut transitions, mut variation)) = animation
+            && let Some((_, secondary)) = variation.nodes
+            && !variation.playing
+        {
+            let blend = Duration::from_secs_f32(IDLE_VARIATION_BLEND);
+            transitions.play(&mut player, secondary, blend);
+            variation.playing = true;
+        }
+    }
+}
+
+/// Plays the secondary clip of each title character once its idle time runs out,
+/// and blends back into the idle loop when the clip ends.
+fn update_idle_variations(
+    mut rng: ResMut<RngService>,
+    mut query: Query<(
+        &mut AnimationPlayer,
+        &mut AnimationTransitions,
+        &mut IdleVariation,
+    )>,
+    time: Res<Time>,
+) {
+    let blend = Duration::from_secs_f32(IDLE_VARIATION_BLEND);
+    for (mut player, mut transitions, mut variation) in query.iter_mut() {
+        let Some((idle, secondary)) = variation.nodes else {
+            continue;
+        };
+
+        if variation.playing {
+            let finished = player
+                .animation(secondary)
+                .is_none_or(|animation| animation.is_finished());
+            if finished {
+                transitions.play(&mut player, idle, blend).repeat();
+                variation.playing = false;
+                variation.remaining = rng.cosmetic().random_range(IDLE_VARIATION_INTERVAL);
+            }
+            continue;
+        }
+
+        variation.remaining -= time.delta_secs();
+        if variation.remaining <= 0.0 {
+            transitions.play(&mut player, secondary, blend);
+            variation.playing = true;
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn title_button_systems(
     #[allow(unused_variables)] current_locale: Res<CurrentLocale>,
     mut next_state: ResMut<NextState<GameState>>,
+    mut transition: EventWriter<RequestTransition>,
+    mut upgrades: ResMut<TrainUpgrades>,
+    mut assist: ResMut<AssistMode>,
     mut interaction_query: Query<
         (&UI, &Interaction, &mut TextColor),
         (Changed<Interaction>, With<Button>),
@@ -190,23 +387,19 @@ fn title_button_systems(
         match (ui, interaction) {
             (UI::StartButton, Interaction::Hovered) => {
                 *text_color = TextColor(Color::WHITE.darker(0.3));
-                play_button_sound_when_hovered(&mut commands, &asset_server, &system_volume);
             }
             (UI::StartButton, Interaction::Pressed) => {
                 *text_color = TextColor(Color::WHITE.darker(0.5));
-                play_button_sound_when_pressed(&mut commands, &asset_server, &system_volume);
-                next_state.set(GameState::Title2InGame);
+                transition.write(RequestTransition::iris(GameState::Title2InGame));
             }
             (UI::StartButton, Interaction::None) => {
                 *text_color = TextColor(Color::WHITE);
             }
             (UI::OptionButton, Interaction::Hovered) => {
                 *text_color = TextColor(Color::WHITE.darker(0.3));
-                play_button_sound_when_hovered(&mut commands, &asset_server, &system_volume);
             }
             (UI::OptionButton, Interaction::Pressed) => {
                 *text_color = TextColor(Color::WHITE.darker(0.5));
-                play_button_sound_when_pressed(&mut commands, &asset_server, &system_volume);
                 next_state.set(GameState::Option);
             }
             (UI::OptionButton, Interaction::None) => {
@@ -214,70 +407,223 @@ fn title_button_systems(
             }
             (UI::TutorialButton, Interaction::Hovered) => {
                 *text_color = TextColor(Color::WHITE.darker(0.3));
-                play_button_sound_when_hovered(&mut commands, &asset_server, &system_volume);
             }
             (UI::TutorialButton, Interaction::Pressed) => {
                 *text_color = TextColor(Color::WHITE.darker(0.5));
-                play_button_sound_when_pressed(&mut commands, &asset_server, &system_volume);
                 #[cfg(target_arch = "wasm32")]
                 start_game_tutorial(&current_locale.0.to_string());
             }
             (UI::TutorialButton, Interaction::None) => {
                 *text_color = TextColor(Color::WHITE);
             }
+            (UI::BuyCarButton, Interaction::Hovered) => {
+                *text_color = TextColor(Color::WHITE.darker(0.3));
+            }
+            (UI::BuyCarButton, Interaction::Pressed) => {
+                *text_color = TextColor(Color::WHITE.darker(0.5));
+                if upgrades.buy_car() {
+                    info!("Bought train car {}", upgrades.extra_cars);
+                }
+            }
+            (UI::BuyCarButton, Interaction::None) => {
+                *text_color = TextColor(Color::WHITE);
+            }
+            (UI::AssistButton, Interaction::Hovered) => {
+                *text_color = TextColor(Color::WHITE.darker(0.3));
+            }
+            (UI::AssistButton, Interaction::Pressed) => {
+                *text_color = TextColor(Color::WHITE.darker(0.5));
+                **assist ^= true;
+            }
+            (UI::AssistButton, Interaction::None) => {
+                *text_color = TextColor(Color::WHITE);
+            }
+            #[cfg(target_arch = "wasm32")]
+            (UI::InstallButton, Interaction::Hovered) => {
+                *text_color = TextColor(Color::WHITE.darker(0.3));
+            }
+            #[cfg(target_arch = "wasm32")]
+            (UI::InstallButton, Interaction::Pressed) => {
+                *text_color = TextColor(Color::WHITE.darker(0.5));
+                prompt_install();
+            }
+            #[cfg(target_arch = "wasm32")]
+            (UI::InstallButton, Interaction::None) => {
+                *text_color = TextColor(Color::WHITE);
+            }
             _ => { /* empty */ }
         }
     }
 }
 
-#[cfg(not(target_arch = "wasm32"))]
-fn play_button_sound_when_hovered(
-    commands: &mut Commands,
-    asset_server: &AssetServer,
-    system_volume: &SystemVolume,
+/// Shows the collected bells and the cost of the next car on the buy button,
+/// and disables the button while the next car cannot be bought.
+fn update_buy_car_button(
+    mut commands: Commands,
+    upgrades: Res<TrainUpgrades>,
+    mut query: Query<(Entity, &UI, &mut Text, Has<Disabled>)>,
 ) {
-    commands.spawn((
-        AudioPlayer::new(asset_server.load(SOUND_PATH_UI_LOADING)),
-        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.effect_percentage())),
-        EffectSound,
-    ));
+    for (entity, &ui, mut text, disabled) in query.iter_mut() {
+        if ui != UI::BuyCarButton {
+            continue;
+        }
+
+        let label = match upgrades.next_car_cost() {
+            Some(cost) => format!("Add Car ({} / {} Bells)", upgrades.bells, cost),
+            None => format!("Full Train ({} Bells)", upgrades.bells),
+        };
+        if text.0 != label {
+            text.0 = label;
+        }
+
+        match (upgrades.can_buy_car(), disabled) {
+            (true, true) => {
+                commands.entity(entity).remove::<Disabled>();
+            }
+            (false, false) => {
+                commands.entity(entity).insert(Disabled);
+            }
+            _ => { /* empty */ }
+        }
+    }
 }
 
-#[cfg(target_arch = "wasm32")]
-fn play_button_sound_when_hovered(
-    commands: &mut Commands,
-    asset_server: &AssetServer,
-    system_volume: &SystemVolume,
+/// Shows whether the next run is played in assist mode on the assist button.
+fn update_assist_button(assist: Res<AssistMode>, mut query: Query<(&UI, &mut TranslatableText)>) {
+    for (&ui, mut key) in query.iter_mut() {
+        if ui != UI::AssistButton {
+            continue;
+        }
+
+        let label = match assist.0 {
+            true => "assist_on",
+            false => "assist_off",
+        };
+        if key.0 != label {
+            key.0 = label.to_string();
+        }
+    }
+}
+
+/// Opens and closes the mutators panel, and toggles the mutators chosen on it.
+#[allow(clippy::type_complexity)]
+fn mutator_panel_systems(
+    mut mutators: ResMut<Mutators>,
+    mut interaction_query: Query<
+        (&UI, &Interaction, &mut TextColor, Option<&MutatorToggle>),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut panel_query: Query<(&UI, &mut Visibility)>,
 ) {
-    commands.spawn((
-        WebAudioPlayer::new(asset_server.load(SOUND_PATH_UI_LOADING)),
-        WebPlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.effect_percentage())),
-        EffectSound,
-    ));
+    let mut panel = None;
+    for (&ui, interaction, mut text_color, toggle) in interaction_query.iter_mut() {
+        match (ui, interaction) {
+            (
+                UI::MutatorsButton | UI::MutatorToggle | UI::MutatorCloseButton,
+                Interaction::Hovered,
+            ) => {
+                *text_color = TextColor(Color::WHITE.darker(0.3));
+            }
+            (UI::MutatorsButton, Interaction::Pressed) => {
+                *text_color = TextColor(Color::WHITE.darker(0.5));
+                panel = Some(Visibility::Visible);
+            }
+            (UI::MutatorToggle, Interaction::Pressed) => {
+                *text_color = TextColor(Color::WHITE.darker(0.5));
+                if let Some(toggle) = toggle {
+                    mutators.toggle(toggle.0);
+                }
+            }
+            (UI::MutatorCloseButton, Interaction::Pressed) => {
+                *text_color = TextColor(Color::WHITE.darker(0.5));
+                panel = Some(Visibility::Hidden);
+            }
+            (
+                UI::MutatorsButton | UI::MutatorToggle | UI::MutatorCloseButton,
+                Interaction::None,
+            ) => {
+                *text_color = TextColor(Color::WHITE);
+            }
+            _ => { /* empty */ }
+        }
+    }
+
+    if let Some(target) = panel {
+        for (&ui, mut visibility) in panel_query.iter_mut() {
+            if ui == UI::MutatorPanel {
+                *visibility = target;
+            }
+        }
+    }
 }
 
-#[cfg(not(target_arch = "wasm32"))]
-fn play_button_sound_when_pressed(
-    commands: &mut Commands,
-    asset_server: &AssetServer,
-    system_volume: &SystemVolume,
+/// Shows whether each mutator is enabled, and the score multiplier of the chosen ones.
+fn update_mutator_panel(
+    mutators: Res<Mutators>,
+    mut toggle_query: Query<(&MutatorToggle, &mut TranslatableText)>,
+    mut query: Query<(&UI, &mut Text)>,
 ) {
-    commands.spawn((
-        AudioPlayer::new(asset_server.load(SOUND_PATH_UI_BUTTON_TOUCH)),
-        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.effect_percentage())),
-        EffectSound,
-    ));
+    for (toggle, mut key) in toggle_query.iter_mut() {
+        // Each mutator has a string for either state, e.g. `double_speed_on`.
+        let label = match mutators.is_active(toggle.0) {
+            true => format!("{}_on", toggle.0.label()),
+            false => format!("{}_off", toggle.0.label()),
+        };
+        if key.0 != label {
+            key.0 = label;
+        }
+    }
+
+    for (&ui, mut text) in query.iter_mut() {
+        if ui != UI::MutatorMultiplier {
+            continue;
+        }
+
+        let label = format!("Score x{:.2}", mutators.score_multiplier());
+        if text.0 != label {
+            text.0 = label;
+        }
+    }
 }
 
+/// Shows the install button only while the browser offers to install the game.
 #[cfg(target_arch = "wasm32")]
-fn play_button_sound_when_pressed(
-    commands: &mut Commands,
-    asset_server: &AssetServer,
-    system_volume: &SystemVolume,
+fn update_install_button(mut query: Query<(&UI, &mut Visibility)>) {
+    let available = install_available();
+    for (&ui, mut visibility) in query.iter_mut() {
+        if ui != UI::InstallButton {
+            continue;
+        }
+
+        let target = match available {
+            true => Visibility::Visible,
+            false => Visibility::Hidden,
+        };
+        if *visibility != target {
+            *visibility = target;
+        }
+    }
+}
+
+/// Slowly scrolls the grounds behind the station toward it, so the world keeps moving behind the menu.
+/// A ground that has slid fully under the station is moved back to the far end of the track.
+///
+/// Only the grounds move. The in-game spawners of the scenery and the passing trains are not run here,
+/// since they are tied to the player and the in-game entity root.
+fn scroll_diorama_grounds(
+    options: Res<GraphicsOptions>,
+    mut query: Query<&mut Transform, With<DioramaGround>>,
+    time: Res<Time>,
 ) {
-    commands.spawn((
-        WebAudioPlayer::new(asset_server.load(SOUND_PATH_UI_BUTTON_TOUCH)),
-        WebPlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.effect_percentage())),
-        EffectSound,
-    ));
+    if options.reduce_motion {
+        return;
+    }
+
+    for mut transform in query.iter_mut() {
+        transform.translation.z += DIORAMA_SCROLL_SPEED * time.delta_secs();
+
+        if transform.translation.z >= 0.0 {
+            transform.translation.z -= GROUND_SPAWN_INTERVAL * NUM_DIORAMA_GROUNDS as f32;
+        }
+    }
 }
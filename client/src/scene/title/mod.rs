@@ -4,6 +4,8 @@ mod main;
 mod to_in_game;
 
 // Import necessary Bevy modules.
+use std::ops::RangeInclusive;
+
 use bevy::prelude::*;
 
 use super::*;
@@ -14,6 +16,19 @@ pub const HIKARI_POSITION: Vec3 = vec3(-2.8438, 0.0, 2.8438);
 pub const NOZOMI_POSITION: Vec3 = vec3(-2.0, 0.0, 2.0);
 pub const CAMERA_POSITION: Vec3 = vec3(-1.0, 1.0, 4.5);
 pub const CAMERA_DIRECTION: Vec3 = vec3(-0.703163, -0.105474, -0.703163);
+pub const NUM_DIORAMA_GROUNDS: usize = 3;
+pub const DIORAMA_SCROLL_SPEED: f32 = 1.5;
+/// Keeps the scrolling grounds just below the station so they slide underneath it.
+pub const DIORAMA_GROUND_DEPTH: f32 = 0.01;
+/// The time, in seconds, the title characters idle between their secondary clips.
+pub const IDLE_VARIATION_INTERVAL: RangeInclusive<f32> = 8.0..=14.0;
+/// The time, in seconds, spent blending into and out of a secondary clip.
+pub const IDLE_VARIATION_BLEND: f32 = 0.4;
+/// The time, in seconds, before a poked title character reacts to another poke.
+pub const POKE_COOLDOWN: f32 = 3.0;
+/// The half size of the box around a title character that catches pokes, and the height of its center.
+pub const POKE_HALF_SIZE: Vec3 = vec3(0.3, 0.75, 0.3);
+pub const POKE_CENTER_HEIGHT: f32 = 0.75;
 
 // --- PLUGIN ---
 
@@ -0,0 +1,254 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use super::*;
+
+/// How many toasts are shown at once. Later toasts wait in the queue until one leaves.
+const MAX_VISIBLE_TOASTS: usize = 3;
+/// The time, in seconds, a toast stays on the screen, including its enter and exit tweens.
+const TOAST_DURATION: f32 = 3.0;
+const TOAST_ENTER_DURATION: f32 = 0.25;
+const TOAST_EXIT_DURATION: f32 = 0.3;
+/// How far, in viewport widths, a toast slides in from the edge of the screen.
+const TOAST_SLIDE_DISTANCE: f32 = 24.0;
+
+const TOAST_INFO_COLOR: Color = Color::srgba(0.12, 0.14, 0.2, 0.9);
+const TOAST_SUCCESS_COLOR: Color = Color::srgba(0.1, 0.42, 0.2, 0.9);
+const TOAST_WARNING_COLOR: Color = Color::srgba(0.6, 0.2, 0.16, 0.9);
+
+// --- PLUGIN ---
+
+/// Shows short notifications stacked in the top-right corner of the screen, over every scene.
+///
+/// Any system can show one with `commands.trigger(Toast::info("..."))`.
+/// Toasts are timed with the real clock, so they still leave while the game is paused.
+pub struct ToastPlugin;
+
+impl Plugin for ToastPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ToastQueue>()
+            .add_observer(queue_toast)
+            .add_systems(Startup, spawn_toast_stack)
+            .add_systems(Update, (show_queued_toasts, update_toasts).chain());
+    }
+}
+
+// --- COMPONENTS ---
+
+/// The column the toasts are stacked in, the newest at the bottom.
+#[derive(Component)]
+struct ToastStack;
+
+/// A toast on the screen, and the time since it appeared.
+#[derive(Component)]
+struct ToastCard {
+    kind: ToastKind,
+    elapsed_time: f32,
+}
+
+impl ToastCard {
+    /// Returns how far the toast has come in (0.0 to 1.0) and gone out (0.0 to 1.0), eased.
+    fn progress(&self) -> (f32, f32) {
+        let enter = (self.elapsed_time / TOAST_ENTER_DURATION).min(1.0);
+        let exit = ((self.elapsed_time - (TOAST_DURATION - TOAST_EXIT_DURATION))
+            / TOAST_EXIT_DURATION)
+            .clamp(0.0, 1.0);
+        (1.0 - (1.0 - enter).powi(3), exit.powi(3))
+    }
+}
+
+// --- EVENTS ---
+
+/// The kind of a toast, which sets its color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastKind {
+    Info,
+    Success,
+    Warning,
+}
+
+impl ToastKind {
+    fn color(self) -> Color {
+        match self {
+            Self::Info => TOAST_INFO_COLOR,
+            Self::Success => TOAST_SUCCESS_COLOR,
+            Self::Warning => TOAST_WARNING_COLOR,
+        }
+    }
+}
+
+/// Shows a notification for a few seconds.
+#[derive(Debug, Clone, Event)]
+pub struct Toast {
+    message: String,
+    kind: ToastKind,
+    icon: Option<Icon>,
+}
+
+impl Toast {
+    pub fn info(message: impl Into<String>) -> Self {
+        Self::new(message, ToastKind::Info)
+    }
+
+    pub fn success(message: impl Into<String>) -> Self {
+        Self::new(message, ToastKind::Success)
+    }
+
+    pub fn warning(message: impl Into<String>) -> Self {
+        Self::new(message, ToastKind::Warning)
+    }
+
+    fn new(message: impl Into<String>, kind: ToastKind) -> Self {
+        Self {
+            message: message.into(),
+            kind,
+            icon: None,
+        }
+    }
+
+    /// Shows an icon of the icon atlas before the message.
+    pub fn with_icon(mut self, icon: Icon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+}
+
+// --- RESOURCES ---
+
+/// The toasts waiting for a free place on the screen, oldest first.
+#[derive(Default, Resource)]
+struct ToastQueue(VecDeque<Toast>);
+
+// --- STARTUP SYSTEMS ---
+
+fn spawn_toast_stack(mut commands: Commands) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Vh(2.0),
+            right: Val::Vw(1.5),
+            width: Val::Vw(30.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::FlexEnd,
+            row_gap: Val::Vh(1.0),
+            ..Default::default()
+        },
+        UiLayer::Toast,
+        ToastStack,
+        Persistent,
+    ));
+}
+
+// --- OBSERVERS ---
+
+fn queue_toast(trigger: Trigger<Toast>, mut queue: ResMut<ToastQueue>) {
+    info!("Toast: {}", trigger.message);
+    queue.0.push_back(trigger.event().clone());
+}
+
+// --- UPDATE SYSTEMS ---
+
+/// Moves the queued toasts onto the screen while there is room for them.
+fn show_queued_toasts(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut queue: ResMut<ToastQueue>,
+    stack_query: Query<Entity, With<ToastStack>>,
+    card_query: Query<(), With<ToastCard>>,
+) {
+    let Ok(stack) = stack_query.single() else {
+        return;
+    };
+
+    let mut visible = card_query.iter().count();
+    while visible < MAX_VISIBLE_TOASTS
+        && let Some(toast) = queue.0.pop_front()
+    {
+        visible += 1;
+        commands.entity(stack).with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        padding: UiRect::axes(Val::Vw(1.0), Val::Vh(1.0)),
+                        column_gap: Val::Vw(0.6),
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    BackgroundColor(toast.kind.color().with_alpha(0.0)),
+                    BorderRadius::all(Val::Vh(1.5)),
+                    ToastCard {
+                        kind: toast.kind,
+                        elapsed_time: 0.0,
+                    },
+                ))
+                .with_children(|parent| {
+                    if let Some(icon) = toast.icon {
+                        parent.spawn((
+                            IconNode(icon),
+                            ImageNode::default().with_color(Color::WHITE.with_alpha(0.0)),
+                            Node {
+                                height: Val::Vh(3.5),
+                                aspect_ratio: Some(1.0),
+                                ..Default::default()
+                            },
+                        ));
+                    }
+
+                    let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
+                    parent.spawn((
+                        Text::new(toast.message),
+                        TextFont::from_font(font),
+                        TextColor(Color::WHITE.with_alpha(0.0)),
+                        ResizableFont::vertical(1280.0, 30.0),
+                        Node::default(),
+                    ));
+                });
+        });
+    }
+}
+
+/// Slides and fades the toasts in and out, and removes them once they have left.
+/// In reduce-motion mode the toasts only fade.
+#[allow(clippy::type_complexity)]
+fn update_toasts(
+    mut commands: Commands,
+    mut card_query: Query<(
+        Entity,
+        &mut ToastCard,
+        &mut Node,
+        &mut BackgroundColor,
+        &Children,
+    )>,
+    mut text_query: Query<&mut TextColor>,
+    mut image_query: Query<&mut ImageNode>,
+    options: Res<GraphicsOptions>,
+    time: Res<Time<Real>>,
+) {
+    for (entity, mut card, mut node, mut background, children) in card_query.iter_mut() {
+        card.elapsed_time += time.delta_secs();
+        if card.elapsed_time >= TOAST_DURATION {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let (enter, exit) = card.progress();
+        let alpha = enter * (1.0 - exit);
+        node.left = match options.reduce_motion {
+            true => Val::Px(0.0),
+            false => Val::Vw(TOAST_SLIDE_DISTANCE * (1.0 - enter + exit)),
+        };
+        background.0 = card
+            .kind
+            .color()
+            .with_alpha(card.kind.color().alpha() * alpha);
+        for &child in children {
+            if let Ok(mut color) = text_query.get_mut(child) {
+                color.0.set_alpha(alpha);
+            }
+            if let Ok(mut image) = image_query.get_mut(child) {
+                image.color.set_alpha(alpha);
+            }
+        }
+    }
+}
@@ -0,0 +1,253 @@
+use bevy::{prelude::*, ui::FocusPolicy};
+
+use super::*;
+
+/// The time, in seconds, the overlay takes to cover the screen.
+const COVER_DURATION: f32 = 0.35;
+/// The time, in seconds, the overlay takes to uncover the screen.
+const REVEAL_DURATION: f32 = 0.35;
+/// The diameter of the iris, relative to the longer side of the screen, while fully open.
+const IRIS_OPEN_DIAMETER: f32 = 150.0;
+
+// --- PLUGIN ---
+
+/// Plays a transition effect between game states.
+/// The state is changed only once the screen is fully covered.
+pub struct TransitionPlugin;
+
+impl Plugin for TransitionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<RequestTransition>()
+            .init_resource::<TransitionManager>()
+            .add_systems(Startup, spawn_transition_overlay)
+            .add_systems(
+                Update,
+                (handle_transition_request, update_transition, update_overlay).chain(),
+            );
+    }
+}
+
+// --- COMPONENTS ---
+
+/// The full-screen overlay used by the fade effect.
+#[derive(Component)]
+struct TransitionOverlay;
+
+/// The hole of the iris wipe effect. The outline around it covers the rest of the screen.
+#[derive(Component)]
+struct TransitionIris;
+
+// --- EVENTS ---
+
+/// How the screen is covered while the game state changes.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TransitionEffect {
+    /// Fades the screen to black.
+    #[default]
+    Fade,
+    /// Closes a circle to the center of the screen.
+    /// Played as a fade in reduce-motion mode.
+    IrisWipe,
+}
+
+/// Requests a change of the game state.
+/// Requests sent while another transition is playing are ignored.
+#[derive(Debug, Clone, Copy, Event)]
+pub struct RequestTransition {
+    pub to: GameState,
+    pub effect: TransitionEffect,
+}
+
+impl RequestTransition {
+    pub fn fade(to: GameState) -> Self {
+        Self {
+            to,
+            effect: TransitionEffect::Fade,
+        }
+    }
+
+    pub fn iris(to: GameState) -> Self {
+        Self {
+            to,
+            effect: TransitionEffect::IrisWipe,
+        }
+    }
+}
+
+// --- RESOURCES ---
+
+#[derive(Debug, Default, Clone, Copy)]
+enum TransitionPhase {
+    #[default]
+    Idle,
+    Covering {
+        to: GameState,
+        effect: TransitionEffect,
+        elapsed: f32,
+    },
+    Revealing {
+        effect: TransitionEffect,
+        elapsed: f32,
+    },
+}
+
+/// Tracks the transition that is currently playing.
+#[derive(Resource, Default)]
+pub struct TransitionManager {
+    phase: TransitionPhase,
+}
+
+impl TransitionManager {
+    pub fn is_busy(&self) -> bool {
+        !matches!(self.phase, TransitionPhase::Idle)
+    }
+
+    /// Returns the effect that is playing and how much of the screen it covers, from 0.0 to 1.0.
+    fn coverage(&self) -> Option<(TransitionEffect, f32)> {
+        match self.phase {
+            TransitionPhase::Idle => None,
+            TransitionPhase::Covering {
+                effect, elapsed, ..
+            } => Some((effect, (elapsed / COVER_DURATION).clamp(0.0, 1.0))),
+            TransitionPhase::Revealing { effect, elapsed } => {
+                Some((effect, 1.0 - (elapsed / REVEAL_DURATION).clamp(0.0, 1.0)))
+            }
+        }
+    }
+}
+
+// --- STARTUP SYSTEMS ---
+
+fn spawn_transition_overlay(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Vw(100.0),
+                height: Val::Vh(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                overflow: Overflow::clip(),
+                ..Default::default()
+            },
+            BackgroundColor(Color::BLACK.with_alpha(0.0)),
+            UiLayer::Transition,
+            FocusPolicy::Block,
+            Visibility::Hidden,
+            TransitionOverlay,
+            Persistent,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Node {
+                    width: Val::VMax(IRIS_OPEN_DIAMETER),
+                    height: Val::VMax(IRIS_OPEN_DIAMETER),
+                    flex_shrink: 0.0,
+                    ..Default::default()
+                },
+                BorderRadius::MAX,
+                Outline::new(Val::VMax(100.0), Val::ZERO, Color::BLACK),
+                Visibility::Hidden,
+                TransitionIris,
+            ));
+        });
+}
+
+// --- UPDATE SYSTEMS ---
+
+fn handle_transition_request(
+    mut events: EventReader<RequestTransition>,
+    mut manager: ResMut<TransitionManager>,
+    options: Res<GraphicsOptions>,
+) {
+    for event in events.read() {
+        if manager.is_busy() {
+            continue;
+        }
+
+        let effect = match event.effect {
+            TransitionEffect::IrisWipe if options.reduce_motion => TransitionEffect::Fade,
+            effect => effect,
+        };
+        manager.phase = TransitionPhase::Covering {
+            to: event.to,
+            effect,
+            elapsed: 0.0,
+        };
+    }
+}
+
+fn update_transition(
+    mut manager: ResMut<TransitionManager>,
+    mut next_state: ResMut<NextState<GameState>>,
+    time: Res<Time<Real>>,
+) {
+    let delta = time.delta_secs();
+    manager.phase = match manager.phase {
+        TransitionPhase::Idle => TransitionPhase::Idle,
+        TransitionPhase::Covering {
+            to,
+            effect,
+            elapsed,
+        } if elapsed >= COVER_DURATION => {
+            // The screen was fully covered on the previous frame, so the change can't be seen.
+            next_state.set(to);
+            TransitionPhase::Revealing {
+                effect,
+                elapsed: 0.0,
+            }
+        }
+        TransitionPhase::Covering {
+            to,
+            effect,
+            elapsed,
+        } => TransitionPhase::Covering {
+            to,
+            effect,
+            elapsed: (elapsed + delta).min(COVER_DURATION),
+        },
+        TransitionPhase::Revealing { elapsed, .. } if elapsed >= REVEAL_DURATION => {
+            TransitionPhase::Idle
+        }
+        TransitionPhase::Revealing { effect, elapsed } => TransitionPhase::Revealing {
+            effect,
+            elapsed: elapsed + delta,
+        },
+    };
+}
+
+#[allow(clippy::type_complexity)]
+fn update_overlay(
+    manager: Res<TransitionManager>,
+    mut overlay_query: Query<(&mut BackgroundColor, &mut Visibility), With<TransitionOverlay>>,
+    mut iris_query: Query<
+        (&mut Node, &mut Visibility),
+        (With<TransitionIris>, Without<TransitionOverlay>),
+    >,
+) {
+    let Ok((mut color, mut visibility)) = overlay_query.single_mut() else {
+        return;
+    };
+    let Ok((mut node, mut iris_visibility)) = iris_query.single_mut() else {
+        return;
+    };
+
+    match manager.coverage() {
+        Some((TransitionEffect::IrisWipe, coverage)) => {
+            let diameter = Val::VMax(IRIS_OPEN_DIAMETER * (1.0 - coverage));
+            node.width = diameter;
+            node.height = diameter;
+            color.0 = Color::BLACK.with_alpha(0.0);
+            *iris_visibility = Visibility::Inherited;
+            *visibility = Visibility::Visible;
+        }
+        Some((_, coverage)) => {
+            color.0 = Color::BLACK.with_alpha(coverage);
+            *iris_visibility = Visibility::Hidden;
+            *visibility = Visibility::Visible;
+        }
+        None => {
+            *visibility = Visibility::Hidden;
+        }
+    }
+}
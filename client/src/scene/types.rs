@@ -1,10 +1,6 @@
 use std::f32::consts::PI;
 
 use bevy::prelude::*;
-use rand::{
-    Rng,
-    distr::{Distribution, StandardUniform},
-};
 
 use super::*;
 
@@ -30,13 +26,136 @@ pub struct TrainSoundLoop2;
 pub struct Ground;
 
 #[derive(Component)]
-pub struct ToyTrain0;
+pub struct DioramaGround;
 
+/// Marks the glow ring around the engine shown while the shield is charged.
 #[derive(Component)]
-pub struct ToyTrain1;
+pub struct ShieldGlow;
 
+/// A toy whistle thrown by the player. It flies to the nearest barricade ahead in its lane.
 #[derive(Component)]
-pub struct ToyTrain2;
+pub struct Whistle {
+    pub lane: usize,
+    pub target: Option<Entity>,
+}
+
+/// Requests a toy whistle to be thrown, if the player has enough bells.
+#[derive(Debug, Event)]
+pub struct ThrowWhistle;
+
+/// Requests the train's steam whistle to be blown, if it has recharged.
+#[derive(Debug, Event)]
+pub struct BlowHorn;
+
+/// Marks an Aoba startled by the steam whistle, hopping in surprise for a moment.
+/// Each Aoba is startled only once.
+#[derive(Default, Component)]
+pub struct Startled {
+    pub elapsed: f32,
+}
+
+/// Sent when the fuel crosses one of the alarm thresholds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Event)]
+pub enum FuelAlarm {
+    /// The fuel fell below the warning threshold.
+    Raised,
+    /// The fuel was refilled above the re-arm threshold, so the alarm can be raised again.
+    Cleared,
+}
+
+/// Sent when a hit or a pickup changes the fuel, with the amount it changed by.
+#[derive(Debug, Clone, Copy, Event)]
+pub struct FuelChanged(pub f32);
+
+/// Sent when the player air-dashes from its position to the lane at the given x position.
+#[derive(Debug, Clone, Copy, Event)]
+pub struct AirDashed {
+    pub from: Vec3,
+    pub to_x: f32,
+}
+
+/// Sent when an obstacle hits the player and takes fuel. Only read by the hit heatmap.
+#[cfg_attr(feature = "no-debuging-heatmap", allow(dead_code))]
+#[derive(Debug, Clone, Copy, Event)]
+pub struct ObstacleHit {
+    pub lane: usize,
+    /// The spawn pattern of the obstacle. Trains and obstacles added by content packs have none.
+    pub pattern: Option<usize>,
+    /// The object that hit the player, or `None` for a passing train.
+    pub object: Option<Object>,
+    /// Whether the hit used up the last of the fuel and ended the run.
+    pub fatal: bool,
+}
+
+/// A bell placed on a beat of the background track during a rhythm section.
+#[derive(Debug, Clone, Copy, Component)]
+pub struct BeatBell {
+    /// The time of its beat on the music clock.
+    pub time: f32,
+}
+
+/// Sent when the player collects a beat bell, with the time of its beat.
+#[derive(Debug, Clone, Copy, Event)]
+pub struct BeatBellCollected(pub f32);
+
+/// One of the pooled labels that float above the train to show a fuel change.
+#[derive(Component)]
+pub struct FuelIndicator {
+    pub remaining: f32,
+}
+
+/// Sent on the frame the player's train leaves the ground or lands back on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Event)]
+pub enum JumpPhase {
+    TakeOff,
+    Landing,
+}
+
+/// A title character that answers with a voice line when clicked or tapped.
+#[derive(Component)]
+pub struct PokeTarget {
+    pub voice: &'static str,
+    /// The time left before the character can be poked again.
+    pub cooldown: f32,
+}
+
+/// Plays a secondary clip on a title character from time to time,
+/// then blends back into the idle loop it started from.
+#[derive(Component)]
+pub struct IdleVariation {
+    pub clip: Handle<AnimationClip>,
+    /// The idle loop and the secondary clip in the character's animation graph, once it is built.
+    pub nodes: Option<(AnimationNodeIndex, AnimationNodeIndex)>,
+    /// The time left until the secondary clip plays.
+    pub remaining: f32,
+    pub playing: bool,
+}
+
+impl IdleVariation {
+    pub fn new(clip: Handle<AnimationClip>) -> Self {
+        Self {
+            clip,
+            nodes: None,
+            remaining: 0.0,
+            playing: false,
+        }
+    }
+}
+
+/// A short-lived label shown when a pickup scrolls past the player uncollected.
+#[derive(Component)]
+pub struct MissIndicator {
+    pub remaining: f32,
+}
+
+/// Marks the ground marker that shows where the jumping player will land.
+#[derive(Component)]
+pub struct LandingMarker;
+
+/// Marks a car of the player's toy train. The cars follow the player as a [`ChainLink`] chain.
+#[derive(Component)]
+#[require(Bank)]
+pub struct TrainCar;
 /// A marker component for the fuel gauge's decorative background.
 #[derive(Component)]
 pub struct FuelDeco;
@@ -45,6 +164,77 @@ pub struct FuelDeco;
 #[derive(Component)]
 pub struct FuelGauge;
 
+/// A marker component for the frame around the fuel gauge. Its border blinks while the fuel alarm is raised.
+#[derive(Component)]
+pub struct FuelGaugeFrame;
+
+/// A marker component for the bar behind the fuel gauge that shows the fuel just lost, draining slowly.
+#[derive(Component)]
+pub struct FuelGaugeGhost;
+
+/// A marker component for the bright band that sweeps across the fuel gauge when it is refilled.
+#[derive(Component)]
+pub struct FuelGaugeSweep;
+
+/// The animations of the fuel gauge, started by each [`FuelChanged`] event.
+#[derive(Default, Component)]
+pub struct FuelGaugeTween {
+    flash_time: f32,
+    sweep_time: f32,
+    /// The fuel shown by the ghost bar, and how long it waits before it drains.
+    ghost: f32,
+    ghost_hold_time: f32,
+}
+
+impl FuelGaugeTween {
+    /// Starts the animations for a change of the fuel, given the fuel after the change.
+    pub fn on_changed(&mut self, amount: f32, fuel: f32) {
+        if amount <= -FUEL_GAUGE_SHARP_DROP {
+            self.flash_time = FUEL_GAUGE_FLASH_DURATION;
+        }
+        if amount < 0.0 {
+            // A second loss while the ghost is held extends it back to where the first one started.
+            self.ghost = self.ghost.max(fuel - amount);
+            self.ghost_hold_time = FUEL_GAUGE_GHOST_HOLD;
+        } else if amount > 0.0 {
+            self.sweep_time = FUEL_GAUGE_SWEEP_DURATION;
+        }
+    }
+
+    pub fn tick(&mut self, delta_time: f32, fuel: f32) {
+        self.flash_time = (self.flash_time - delta_time).max(0.0);
+        self.sweep_time = (self.sweep_time - delta_time).max(0.0);
+        if self.ghost_hold_time > 0.0 {
+            self.ghost_hold_time -= delta_time;
+        } else {
+            self.ghost -= delta_time * FUEL_GAUGE_GHOST_DRAIN_RATE;
+        }
+        self.ghost = self.ghost.max(fuel);
+    }
+
+    /// Returns the strength of the damage flash (1.0 right after the hit, fading to 0.0).
+    pub fn flash(&self) -> f32 {
+        self.flash_time / FUEL_GAUGE_FLASH_DURATION
+    }
+
+    /// Returns the horizontal offset of the shake that goes with the flash, in viewport widths.
+    pub fn shake(&self) -> f32 {
+        (self.flash_time * FUEL_GAUGE_SHAKE_FREQUENCY).sin()
+            * FUEL_GAUGE_SHAKE_AMPLITUDE
+            * self.flash()
+    }
+
+    /// Returns how far the refill sweep has crossed the gauge (0.0 to 1.0), or `None` when no sweep is playing.
+    pub fn sweep(&self) -> Option<f32> {
+        (self.sweep_time > 0.0).then(|| 1.0 - self.sweep_time / FUEL_GAUGE_SWEEP_DURATION)
+    }
+
+    /// Returns the fuel shown by the ghost bar.
+    pub fn ghost(&self) -> f32 {
+        self.ghost
+    }
+}
+
 /// A marker component for the 1s place digit of the score display.
 #[derive(Component)]
 pub struct ScoreSpace1s;
@@ -73,9 +263,22 @@ pub struct ScoreSpace100000s;
 #[derive(Component)]
 pub struct PauseTitle;
 
+/// Marks the node behind the pause menu that shows the last frame of the world, blurred.
+#[derive(Component)]
+pub struct PauseBackdrop;
+
 #[derive(Component)]
 pub struct NewRecord;
 
+/// A scrap of confetti falling over the result screen when a run sets a new high score.
+#[derive(Component)]
+pub struct Confetti {
+    pub velocity: Vec3,
+    pub spin: Vec3,
+    /// The phase of the side-to-side flutter, so the scraps do not sway in step.
+    pub phase: f32,
+}
+
 #[derive(Component)]
 pub struct SpawnRequest;
 
@@ -100,6 +303,10 @@ pub struct ResultStateEntity;
 #[derive(Component)]
 pub struct ResultStateRoot;
 
+/// Marks an entity that lives for the whole session instead of being removed with a game state.
+#[derive(Component)]
+pub struct Persistent;
+
 #[derive(Component)]
 pub struct Nozomi;
 
@@ -118,8 +325,39 @@ pub struct LoadingText;
 
 /// A marker component for the loading bar UI entity.
 #[derive(Component)]
+#[require(LoadingProgress)]
 pub struct LoadingBar;
 
+/// The progress shown by the loading bar, eased towards the progress of the current loading state.
+#[derive(Component, Default)]
+pub struct LoadingProgress {
+    target: f32,
+    displayed: f32,
+}
+
+impl LoadingProgress {
+    /// Sets the progress of the current loading state, from 0.0 to 1.0.
+    /// The bar never moves backwards while the state is loading.
+    pub fn set(&mut self, progress: f32) {
+        self.target = self.target.max(progress.clamp(0.0, 1.0));
+    }
+
+    /// Starts over from an empty bar for the next loading state.
+    pub fn reset(&mut self) {
+        self.target = 0.0;
+        self.displayed = 0.0;
+    }
+
+    pub fn on_advanced(&mut self, delta: f32) {
+        let t = 1.0 - (-LOADING_BAR_EASE_RATE * delta).exp();
+        self.displayed += (self.target - self.displayed) * t;
+    }
+
+    pub fn displayed(&self) -> f32 {
+        self.displayed
+    }
+}
+
 #[derive(Component)]
 pub struct DangerZoneBackground;
 
@@ -127,45 +365,124 @@ pub struct DangerZoneBackground;
 pub struct DangerZone;
 
 #[derive(Component)]
-pub struct Player;
+pub struct SpeedLines;
 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Component)]
-pub enum Object {
-    #[default]
-    Barricade,
-    Stone,
-    Fuel,
-    Bell,
-    Aoba,
+/// The red vignette shown at the edges of the screen while the fuel is low.
+#[derive(Component)]
+pub struct LowFuelVignette;
+
+/// The camera the title scene is viewed through.
+#[derive(Component)]
+pub struct TitleCamera;
+
+/// The camera the result cutscene is played on.
+#[derive(Component)]
+pub struct ResultCamera;
+
+/// Drives the in-game camera's zoom and pull-back from the player's speed.
+#[derive(Default, Component)]
+pub struct CameraRig {
+    /// The smoothed speed factor (0.0 to 1.0).
+    pub zoom: f32,
+    /// The remaining punch-in amount (0.0 to 1.0).
+    pub punch: f32,
+    /// How far the camera has pushed in on the train at the end of a run (0.0 to 1.0).
+    pub push_in: f32,
+    pub was_invincible: bool,
+    /// The preset the camera was placed at when the run started or resumed.
+    pub preset: CameraPreset,
+}
+
+/// The volume category controlled by a volume slider in the options modal.
+#[derive(Debug, Clone, Copy, Component, PartialEq, Eq)]
+pub enum VolumeChannel {
+    Master,
+    Background,
+    Effect,
+    Voice,
 }
 
 #[derive(Clone, Copy, Component, PartialEq, Eq, Hash)]
 pub enum UI {
     SliderRail,
     OptionModal,
+    MasterLabel,
+    MasterVolume,
+    MasterVolumeCursor,
     BgmLabel,
     BgmVolume,
     BgmVolumeCursor,
+    BgmMuteButton,
     SfxLabel,
     SfxVolume,
     SfxVolumeCursor,
+    SfxMuteButton,
     VoiceLabel,
     VoiceVolume,
     VoiceVolumeCursor,
+    VoiceMuteButton,
+    /// The label of the output device row, which is shown only natively.
+    #[cfg(not(target_arch = "wasm32"))]
+    AudioDeviceLabel,
+    /// Switches the sounds to the next output device.
+    #[cfg(not(target_arch = "wasm32"))]
+    AudioDeviceButton,
     LanguageEn,
     LanguageJa,
     LanguageKo,
+    SpeedLineButton,
+    ReduceMotionButton,
+    CaptionButton,
+    LandingMarkerButton,
+    PaceClockButton,
+    /// Selects the camera preset of its [`CameraPresetChoice`].
+    CameraPresetButton,
+    /// Selects the render scale limit of its [`RenderScaleChoice`].
+    RenderScaleButton,
+    /// Selects the device tier of its [`DeviceTierChoice`].
+    DeviceTierButton,
+    /// Selects the fog mode of its [`FogModeChoice`].
+    FogModeButton,
+    ScatteringButton,
     BackButton,
+    /// Exports the save profile as a save code.
+    ExportButton,
+    /// Imports a save profile from a save code, once confirmed.
+    ImportButton,
 
     HighScore,
     StartButton,
     OptionButton,
     TutorialButton,
+    BuyCarButton,
+    AssistButton,
+    /// Opens the mutators panel.
+    MutatorsButton,
+    MutatorPanel,
+    /// Toggles the mutator of its [`MutatorToggle`].
+    MutatorToggle,
+    MutatorMultiplier,
+    MutatorCloseButton,
+    /// Installs the web build as an app. Shown only while the browser offers to install it.
+    #[cfg(target_arch = "wasm32")]
+    InstallButton,
     StartLabel,
     FinishLabel,
     PauseButton,
+    ThrowButton,
+    ThrowText,
+    /// Blows the steam whistle.
+    HornButton,
+    /// The shade over the steam whistle button that shrinks as the whistle recharges.
+    HornCooldown,
     Score,
     Fuel,
+    /// The play time and pace shown while playing when the pace clock option is on.
+    PaceClock,
+    PaceTime,
+    PaceDelta,
+    /// The fill of the gauge that shows how far the air-dash has recharged.
+    AirDashGauge,
 
     Pause,
     ResumeButton,
@@ -180,84 +497,64 @@ pub enum UI {
     ResultModal,
     RestartButton,
     ResultExitButton,
+    SeedButton,
     PlayTime,
     GameScore,
     BestScore,
+    RunChips,
+    CollectionStats,
+    /// Compares the run with the previous or best run. Pressing it switches between the two.
+    RunComparison,
     NewRecord,
 }
 
+/// The camera preset a button of the options modal selects.
 #[derive(Component)]
-pub struct Lane {
-    index: usize,
-}
+pub struct CameraPresetChoice(pub CameraPreset);
 
-impl Lane {
-    pub fn new(index: usize) -> Self {
-        Self { index }
-    }
-
-    pub fn get(&self) -> usize {
-        self.index
-    }
-
-    pub fn inc(&mut self) {
-        self.index = (self.index + 1).min(MAX_LANE_INDEX);
-    }
-
-    pub fn dec(&mut self) {
-        self.index = self.index.saturating_sub(1);
-    }
-}
+/// The render scale limit a button of the options modal selects.
+#[derive(Component)]
+pub struct RenderScaleChoice(pub RenderScaleLimit);
 
-impl Default for Lane {
-    fn default() -> Self {
-        Self {
-            index: MAX_LANE_INDEX / 2,
-        }
-    }
-}
+/// The device tier a button of the options modal selects.
+#[derive(Component)]
+pub struct DeviceTierChoice(pub DeviceTier);
 
+/// The fog mode a button of the options modal selects.
 #[derive(Component)]
-pub struct ForwardMovement(f32);
+pub struct FogModeChoice(pub FogMode);
 
-impl ForwardMovement {
-    pub fn new(velocity: f32) -> Self {
-        Self(velocity)
-    }
+/// Marks the text that shows the name of the picked output device.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Component)]
+pub struct AudioDeviceName;
 
-    pub fn get(&self) -> f32 {
-        self.0
-    }
+/// Marks the text of the import button, which shows the step of the import.
+#[derive(Component)]
+pub struct ImportLabel;
 
-    pub fn set(&mut self, velocity: f32) {
-        self.0 = velocity;
-    }
-}
+/// The mutator a button of the mutators panel toggles.
+#[derive(Component)]
+pub struct MutatorToggle(pub Mutator);
 
+/// A cosmetic train running along the far track. It never collides with the player.
 #[derive(Component)]
-pub struct VerticalMovement(f32);
+pub struct PassingTrain;
 
-impl VerticalMovement {
-    pub fn new(velocity: f32) -> Self {
-        Self(velocity)
-    }
+#[derive(Component)]
+pub struct DelayTime(f32);
 
-    pub fn get(&self) -> f32 {
-        self.0
+impl DelayTime {
+    pub fn new(amount: f32) -> Self {
+        Self(amount)
     }
 
-    pub fn set(&mut self, velocity: f32) {
-        self.0 = velocity;
+    pub fn on_advanced(&mut self, elapsed: f32) {
+        self.0 = (self.0 - elapsed).max(0.0);
     }
-}
 
-#[derive(Component)]
-
-pub struct Acceleration(f32);
-
-impl Acceleration {
-    pub fn new(amount: f32) -> Self {
-        Self(amount)
+    pub fn is_expired(&self) -> bool {
+        self.0 <= 0.0
     }
 
     pub fn get(&self) -> f32 {
@@ -265,47 +562,46 @@ impl Acceleration {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Component)]
-pub enum Tok9Train {
-    Blue,
-    Orange,
-}
+#[derive(Component)]
+pub struct BaseColor(pub Color);
 
-impl Distribution<Tok9Train> for StandardUniform {
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Tok9Train {
-        let index = rng.random_range(0..=1);
-        match index {
-            0 => Tok9Train::Blue,
-            1 => Tok9Train::Orange,
-            _ => unreachable!(),
-        }
-    }
-}
+/// Marks an object that has come within reach of the view, with the models under it.
+/// The per-frame systems of the objects skip those still far down the track.
+#[derive(Component)]
+pub struct Activated;
 
+/// Scales a newly placed object up to its full size, so that it does not pop in at the end of the track.
+/// The object cannot be collided with until it has fully appeared.
 #[derive(Component)]
-pub struct DelayTime(f32);
+pub struct Appearing {
+    elapsed: f32,
+    scale: Vec3,
+}
 
-impl DelayTime {
-    pub fn new(amount: f32) -> Self {
-        Self(amount)
+impl Appearing {
+    pub fn new(scale: Vec3) -> Self {
+        Self {
+            elapsed: 0.0,
+            scale,
+        }
     }
 
     pub fn on_advanced(&mut self, elapsed: f32) {
-        self.0 = (self.0 - elapsed).max(0.0);
+        self.elapsed += elapsed;
     }
 
-    pub fn is_expired(&self) -> bool {
-        self.0 <= 0.0
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= APPEARANCE_DURATION
     }
 
-    pub fn get(&self) -> f32 {
-        self.0
+    /// Returns the scale of the object at this point of the animation.
+    pub fn scale(&self) -> Vec3 {
+        let t = (self.elapsed / APPEARANCE_DURATION).clamp(0.0, 1.0);
+        let t = t * t * (3.0 - 2.0 * t);
+        self.scale * APPEARANCE_START_SCALE.lerp(1.0, t)
     }
 }
 
-#[derive(Component)]
-pub struct BaseColor(pub Color);
-
 #[derive(Component)]
 pub struct RotateAnimation {
     pub axis: Vec3,
@@ -344,6 +640,66 @@ impl FadeInAnimation {
     }
 }
 
+/// Counts a score up from zero with an ease-out curve, then flashes.
+#[derive(Component)]
+pub struct ScoreTally {
+    target: u32,
+    elapsed_time: f32,
+    next_tick_time: f32,
+}
+
+impl ScoreTally {
+    pub fn new(target: u32) -> Self {
+        Self {
+            target,
+            elapsed_time: 0.0,
+            next_tick_time: 0.0,
+        }
+    }
+
+    pub fn tick(&mut self, delta_time: f32) {
+        self.elapsed_time += delta_time;
+    }
+
+    /// Returns the currently displayed value.
+    pub fn value(&self) -> u32 {
+        let t = (self.elapsed_time / TALLY_DURATION).min(1.0);
+        let eased = 1.0 - (1.0 - t).powi(3);
+        (self.target as f32 * eased).round() as u32
+    }
+
+    /// Returns `true` when a tick sound is due. Ticks accelerate as the tally progresses.
+    pub fn take_tick(&mut self) -> bool {
+        if self.is_counting() && self.elapsed_time >= self.next_tick_time {
+            let t = self.elapsed_time / TALLY_DURATION;
+            let interval =
+                TALLY_MAX_TICK_INTERVAL + (TALLY_MIN_TICK_INTERVAL - TALLY_MAX_TICK_INTERVAL) * t;
+            self.next_tick_time = self.elapsed_time + interval;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns the strength of the finishing flash (1.0 right after the count ends, fading to 0.0).
+    pub fn flash(&self) -> f32 {
+        if self.is_counting() {
+            0.0
+        } else {
+            let t = (self.elapsed_time - TALLY_DURATION) / TALLY_FLASH_DURATION;
+            1.0 - t.clamp(0.0, 1.0)
+        }
+    }
+
+    pub fn is_counting(&self) -> bool {
+        self.target > 0 && self.elapsed_time < TALLY_DURATION
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.elapsed_time >= TALLY_DURATION + TALLY_FLASH_DURATION
+    }
+}
+
 #[derive(Component)]
 pub struct FadeInOutAnimation {
     duration: f32,
@@ -0,0 +1,165 @@
+use bevy::{audio::Volume, platform::collections::HashMap, prelude::*};
+
+use crate::asset::sound::SystemVolume;
+
+#[cfg(target_arch = "wasm32")]
+use crate::web::{WebAudioPlayer, WebPlaybackSettings};
+
+use super::*;
+
+/// The minimum time, in seconds, between two hover sounds of the same button.
+const HOVER_DEBOUNCE: f32 = 0.25;
+/// The volume scale of the sound played when a disabled button is pressed.
+const DISABLED_VOLUME_SCALE: f32 = 0.5;
+
+// --- PLUGIN ---
+
+/// Plays the interaction sounds of every [`Button`], so button systems only need
+/// to handle what the button does.
+pub struct UiSoundPlugin;
+
+impl Plugin for UiSoundPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (play_interaction_sound, play_focus_sound));
+    }
+}
+
+// --- COMPONENTS ---
+
+/// Selects the sounds of a button. Buttons without this component use [`UiSound::Touch`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Component)]
+pub enum UiSound {
+    /// Plays the touch sound when pressed.
+    #[default]
+    Touch,
+    /// Plays the back sound when pressed, for buttons that leave or close a screen.
+    Back,
+    /// Plays no sound at all.
+    Silent,
+}
+
+/// Marks a button that cannot be used at the moment.
+/// Pressing it plays a muted back sound instead of its own sound.
+#[derive(Component)]
+pub struct Disabled;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum UiSoundKind {
+    Hover,
+    Touch,
+    Back,
+    Disabled,
+}
+
+impl UiSoundKind {
+    fn path(self) -> &'static str {
+        match self {
+            Self::Hover => SOUND_PATH_UI_LOADING,
+            Self::Touch => SOUND_PATH_UI_BUTTON_TOUCH,
+            Self::Back | Self::Disabled => SOUND_PATH_UI_BUTTON_BACK,
+        }
+    }
+
+    fn volume(self, system_volume: &SystemVolume) -> f32 {
+        match self {
+            Self::Disabled => system_volume.effect_percentage() * DISABLED_VOLUME_SCALE,
+            _ => system_volume.effect_percentage(),
+        }
+    }
+}
+
+// --- UPDATE SYSTEMS ---
+
+#[allow(clippy::type_complexity)]
+fn play_interaction_sound(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    system_volume: Res<SystemVolume>,
+    query: Query<
+        (Entity, &Interaction, Option<&UiSound>, Has<Disabled>),
+        (Changed<Interaction>, With<Button>),
+    >,
+    time: Res<Time>,
+    mut last_hovered: Local<HashMap<Entity, f32>>,
+) {
+    let now = time.elapsed_secs();
+    last_hovered.retain(|_, &mut hovered| now - hovered < HOVER_DEBOUNCE);
+
+    for (entity, &interaction, sound, disabled) in query.iter() {
+        let sound = sound.copied().unwrap_or_default();
+        if sound == UiSound::Silent {
+            continue;
+        }
+
+        let kind = match (interaction, disabled, sound) {
+            (Interaction::Hovered, _, _) => {
+                // A cursor wiggling on the edge of a button would replay the sound every frame.
+                if last_hovered.insert(entity, now).is_some() {
+                    continue;
+                }
+                UiSoundKind::Hover
+            }
+            (Interaction::Pressed, true, _) => UiSoundKind::Disabled,
+            (Interaction::Pressed, false, UiSound::Back) => UiSoundKind::Back,
+            (Interaction::Pressed, false, _) => UiSoundKind::Touch,
+            (Interaction::None, _, _) => continue,
+        };
+
+        play_ui_sound(&mut commands, &asset_server, &system_volume, kind);
+    }
+}
+
+/// Plays the hover sound when the keyboard or gamepad focus moves to another node.
+fn play_focus_sound(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    system_volume: Res<SystemVolume>,
+    focus: Res<UiFocus>,
+    query: Query<&UiSound>,
+    mut last_focused: Local<Option<Entity>>,
+) {
+    let focused = focus.get();
+    if focused == *last_focused {
+        return;
+    }
+    *last_focused = focused;
+
+    if let Some(entity) = focused
+        && query.get(entity).ok() != Some(&UiSound::Silent)
+    {
+        play_ui_sound(
+            &mut commands,
+            &asset_server,
+            &system_volume,
+            UiSoundKind::Hover,
+        );
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn play_ui_sound(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    system_volume: &SystemVolume,
+    kind: UiSoundKind,
+) {
+    commands.spawn((
+        AudioPlayer::new(asset_server.load(kind.path())),
+        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(kind.volume(system_volume))),
+        EffectSound,
+    ));
+}
+
+#[cfg(target_arch = "wasm32")]
+fn play_ui_sound(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    system_volume: &SystemVolume,
+    kind: UiSoundKind,
+) {
+    commands.spawn((
+        WebAudioPlayer::new(asset_server.load(kind.path())),
+        WebPlaybackSettings::DESPAWN.with_volume(Volume::Linear(kind.volume(system_volume))),
+        EffectSound,
+    ));
+}
@@ -1,3 +1,7 @@
+use bevy::{
+    math::bounding::{Aabb3d, RayCast3d},
+    prelude::*,
+};
 #[cfg(target_arch = "wasm32")]
 use web_sys::{Storage, window};
 
@@ -5,3 +9,17 @@ use web_sys::{Storage, window};
 pub fn get_local_storage() -> Option<Storage> {
     window()?.local_storage().ok()?
 }
+
+/// Returns the ray from the camera through a point on the screen, such as the cursor or a touch.
+pub fn screen_ray(
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+    position: Vec2,
+) -> Option<Ray3d> {
+    camera.viewport_to_world(camera_transform, position).ok()
+}
+
+/// Returns how far along the ray it hits the box with the given center and half size, if it does.
+pub fn ray_hits_box(ray: Ray3d, center: Vec3, half_size: Vec3) -> Option<f32> {
+    RayCast3d::from_ray(ray, f32::MAX).aabb_intersection_at(&Aabb3d::new(center, half_size))
+}
@@ -0,0 +1,222 @@
+use bevy::{audio::Volume, prelude::*};
+
+use crate::asset::{caption::CaptionTable, locale::CurrentLocale, sound::SystemVolume};
+
+#[cfg(target_arch = "wasm32")]
+use crate::web::{WebAudioPlayer, WebPlaybackSettings};
+
+use super::*;
+
+/// The time, in seconds, a caption stays on the screen.
+const CAPTION_DURATION: f32 = 3.0;
+
+// --- PLUGIN ---
+
+/// Plays voice lines and shows their captions, so that every line that is heard can also be read.
+pub struct VoicePlugin;
+
+impl Plugin for VoicePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<VoiceCaption>()
+            .add_systems(Startup, (load_caption_table, spawn_caption_box))
+            .add_systems(Update, (show_voice_caption, hide_voice_caption).chain());
+    }
+}
+
+// --- COMPONENTS ---
+
+/// The box at the bottom of the screen that shows the caption of the current voice line.
+#[derive(Component)]
+struct CaptionBox {
+    remaining: f32,
+}
+
+/// Marks the text of the caption box.
+#[derive(Component)]
+struct CaptionText;
+
+// --- EVENTS ---
+
+/// Sent with the asset path of every voice line that starts playing.
+#[derive(Debug, Event)]
+pub struct VoiceCaption(pub &'static str);
+
+// --- RESOURCES ---
+
+#[derive(Resource)]
+struct CaptionTableHandle(Handle<CaptionTable>);
+
+/// Plays a voice line and requests its caption.
+/// Returns the commands of the spawned sound so callers can attach their state markers.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn play_voice<'a>(
+    commands: &'a mut Commands,
+    asset_server: &AssetServer,
+    system_volume: &SystemVolume,
+    path: &'static str,
+) -> EntityCommands<'a> {
+    commands.send_event(VoiceCaption(path));
+    commands.spawn((
+        AudioPlayer::new(asset_server.load(path)),
+        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.voice_percentage())),
+        VoiceSound,
+    ))
+}
+
+/// Plays a voice line and requests its caption.
+/// Returns the commands of the spawned sound so callers can attach their state markers.
+#[cfg(target_arch = "wasm32")]
+pub fn play_voice<'a>(
+    commands: &'a mut Commands,
+    asset_server: &AssetServer,
+    system_volume: &SystemVolume,
+    path: &'static str,
+) -> EntityCommands<'a> {
+    commands.send_event(VoiceCaption(path));
+    commands.spawn((
+        WebAudioPlayer::new(asset_server.load(path)),
+        WebPlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.voice_percentage())),
+        VoiceSound,
+    ))
+}
+
+/// Plays a voice line panned by where `position` appears on the screen, and requests its caption.
+/// Returns the commands of the spawned sound so callers can attach their state markers.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn play_voice_at<'a>(
+    commands: &'a mut Commands,
+    asset_server: &AssetServer,
+    system_volume: &SystemVolume,
+    path: &'static str,
+    position: Vec3,
+) -> EntityCommands<'a> {
+    commands.send_event(VoiceCaption(path));
+    commands.spawn((
+        PannedAudioPlayer::new(asset_server.load(path)),
+        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.voice_percentage())),
+        Transform::from_translation(position),
+        VoiceSound,
+    ))
+}
+
+/// Plays a voice line panned by where `position` appears on the screen, and requests its caption.
+/// Returns the commands of the spawned sound so callers can attach their state markers.
+#[cfg(target_arch = "wasm32")]
+pub fn play_voice_at<'a>(
+    commands: &'a mut Commands,
+    asset_server: &AssetServer,
+    system_volume: &SystemVolume,
+    path: &'static str,
+    position: Vec3,
+) -> EntityCommands<'a> {
+    commands.send_event(VoiceCaption(path));
+    commands.spawn((
+        WebAudioPlayer::new(asset_server.load(path)),
+        WebPlaybackSettings::DESPAWN.with_volume(Volume::Linear(system_volume.voice_percentage())),
+        Transform::from_translation(position),
+        StereoPan::default(),
+        VoiceSound,
+    ))
+}
+
+// --- STARTUP SYSTEMS ---
+
+fn load_caption_table(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(CaptionTableHandle(asset_server.load(CAPTION_PATH_VOICE)));
+}
+
+fn spawn_caption_box(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Vh(4.0),
+                width: Val::Vw(100.0),
+                justify_content: JustifyContent::Center,
+                ..Default::default()
+            },
+            UiLayer::Overlay,
+            Pickable::IGNORE,
+            Persistent,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        max_width: Val::Vw(70.0),
+                        padding: UiRect::axes(Val::Vw(1.5), Val::Vh(1.0)),
+                        ..Default::default()
+                    },
+                    BorderRadius::all(Val::Vh(1.0)),
+                    BackgroundColor(CAPTION_BG_COLOR),
+                    Visibility::Hidden,
+                    CaptionBox { remaining: 0.0 },
+                ))
+                .with_children(|parent| {
+                    let font = asset_server.load(FONT_PATH_NOTOSANS_BOLD);
+                    parent.spawn((
+                        Text::default(),
+                        TextFont::from_font(font),
+                        TextLayout::new_with_justify(JustifyText::Center),
+                        TextColor::WHITE,
+                        ResizableFont::vertical(1280.0, 40.0),
+                        Node::default(),
+                        Visibility::Inherited,
+                        CaptionText,
+                    ));
+                });
+        });
+}
+
+// --- UPDATE SYSTEMS ---
+
+/// Shows the caption of the latest voice line while captions are enabled.
+fn show_voice_caption(
+    options: Res<GraphicsOptions>,
+    current_locale: Res<CurrentLocale>,
+    handle: Res<CaptionTableHandle>,
+    tables: Res<Assets<CaptionTable>>,
+    mut events: EventReader<VoiceCaption>,
+    mut box_query: Query<(&mut CaptionBox, &mut Visibility)>,
+    mut text_query: Query<&mut Text, With<CaptionText>>,
+) {
+    let Some(VoiceCaption(path)) = events.read().last() else {
+        return;
+    };
+    if !options.captions {
+        return;
+    }
+
+    let Some(caption) = tables
+        .get(&handle.0)
+        .and_then(|table| table.get(path, current_locale.0))
+    else {
+        return;
+    };
+
+    if let Ok((mut caption_box, mut visibility)) = box_query.single_mut()
+        && let Ok(mut text) = text_query.single_mut()
+    {
+        *text = Text::new(caption);
+        *visibility = Visibility::Visible;
+        caption_box.remaining = CAPTION_DURATION;
+    }
+}
+
+/// Hides the caption once it has been shown long enough, or as soon as captions are disabled.
+fn hide_voice_caption(
+    options: Res<GraphicsOptions>,
+    mut query: Query<(&mut CaptionBox, &mut Visibility)>,
+    time: Res<Time>,
+) {
+    for (mut caption_box, mut visibility) in query.iter_mut() {
+        if *visibility == Visibility::Hidden {
+            continue;
+        }
+
+        caption_box.remaining -= time.delta_secs();
+        if caption_box.remaining <= 0.0 || !options.captions {
+            *visibility = Visibility::Hidden;
+        }
+    }
+}
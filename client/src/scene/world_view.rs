@@ -0,0 +1,79 @@
+use std::time::Duration;
+
+use bevy::{
+    prelude::*,
+    winit::{UpdateMode, WinitSettings},
+};
+
+use super::*;
+
+/// The longest the app waits between two updates while the world is stopped behind a menu.
+/// Input still wakes it up at once.
+const MENU_UPDATE_INTERVAL: Duration = Duration::from_millis(33);
+
+// --- PLUGIN ---
+
+/// Stops rendering the world while a run is paused or the options are open over it,
+/// and updates the app only about thirty times a second, or on input, until the run resumes.
+///
+/// The last frame of the world is kept in the offscreen image of the in-game camera, to be shown behind the menu.
+/// A camera that renders to the window is rendered offscreen for one more frame to capture it,
+/// and goes back to the window once the run resumes.
+///
+/// The result screen keeps rendering, as its students and confetti never stop moving.
+pub struct WorldViewPlugin;
+
+impl Plugin for WorldViewPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            update_world_view
+                .run_if(state_changed::<GameState>.or(any_match_filter::<Added<OffscreenView>>)),
+        );
+    }
+}
+
+// --- COMPONENTS ---
+
+/// Marks a camera rendered offscreen only to capture the last frame of the world.
+#[derive(Component)]
+pub struct WorldCapture;
+
+// --- UPDATE SYSTEMS ---
+
+#[allow(clippy::type_complexity)]
+fn update_world_view(
+    mut commands: Commands,
+    state: Res<State<GameState>>,
+    mut winit_settings: ResMut<WinitSettings>,
+    mut query: Query<(Entity, &mut Camera, Has<OffscreenView>, Has<WorldCapture>), With<CameraRig>>,
+) {
+    let stopped = matches!(state.get(), GameState::Pause | GameState::Option) && !query.is_empty();
+    for (entity, mut camera, offscreen, captured) in query.iter_mut() {
+        if stopped && !offscreen {
+            info!("Capture the world view");
+            commands
+                .entity(entity)
+                .insert((RenderOffscreen, WorldCapture));
+            continue;
+        }
+        if !stopped && captured {
+            commands
+                .entity(entity)
+                .remove::<(RenderOffscreen, WorldCapture)>();
+        }
+
+        if camera.is_active == stopped {
+            info!("Set the world view active: {}", !stopped);
+            camera.is_active = !stopped;
+        }
+    }
+
+    *winit_settings = match stopped {
+        true => WinitSettings {
+            focused_mode: UpdateMode::reactive(MENU_UPDATE_INTERVAL),
+            unfocused_mode: UpdateMode::reactive_low_power(MENU_UPDATE_INTERVAL),
+        },
+        false => WinitSettings::game(),
+    };
+}
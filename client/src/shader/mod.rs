@@ -1 +1,4 @@
 pub mod face_mouth;
+pub mod pause_blur;
+pub mod speed_lines;
+pub mod vignette;
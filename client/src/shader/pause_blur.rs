@@ -0,0 +1,44 @@
+#![allow(dead_code)]
+use bevy::{
+    prelude::*,
+    render::render_resource::{AsBindGroup, ShaderRef, ShaderType},
+};
+
+#[derive(Debug, Clone, Copy, ShaderType)]
+pub struct PauseBlurUniform {
+    /// `x`: blur radius in texels, `y`: brightness (0.0 to 1.0),
+    /// `z`: 1.0 to flip the frame left to right, as a mirrored view is shown, or 0.0.
+    pub params: Vec4,
+}
+
+impl Default for PauseBlurUniform {
+    fn default() -> Self {
+        Self {
+            params: Vec4::new(6.0, 0.6, 0.0, 0.0),
+        }
+    }
+}
+
+/// Draws the last frame of the world blurred and darkened behind the pause menu.
+#[derive(AsBindGroup, Asset, TypePath, Debug, Default, Clone)]
+pub struct PauseBlurMaterial {
+    #[uniform(0)]
+    pub uniform: PauseBlurUniform,
+    #[texture(1)]
+    #[sampler(2)]
+    pub texture: Handle<Image>,
+}
+
+impl PauseBlurMaterial {
+    pub fn new(texture: Handle<Image>, flip_x: bool) -> Self {
+        let mut uniform = PauseBlurUniform::default();
+        uniform.params.z = if flip_x { 1.0 } else { 0.0 };
+        Self { uniform, texture }
+    }
+}
+
+impl UiMaterial for PauseBlurMaterial {
+    fn fragment_shader() -> ShaderRef {
+        "shaders/pause_blur.wgsl".into()
+    }
+}
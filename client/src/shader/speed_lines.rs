@@ -0,0 +1,30 @@
+#![allow(dead_code)]
+use bevy::{
+    prelude::*,
+    render::render_resource::{AsBindGroup, ShaderRef, ShaderType},
+};
+
+#[derive(Debug, Default, Clone, Copy, ShaderType)]
+pub struct SpeedLineUniform {
+    /// `x`: intensity (0.0 to 1.0), `y`: elapsed time in seconds.
+    pub params: Vec4,
+}
+
+#[derive(AsBindGroup, Asset, TypePath, Debug, Default, Clone)]
+pub struct SpeedLineMaterial {
+    #[uniform(0)]
+    pub uniform: SpeedLineUniform,
+}
+
+impl SpeedLineMaterial {
+    pub fn set(&mut self, intensity: f32, elapsed_sec: f32) {
+        self.uniform.params.x = intensity;
+        self.uniform.params.y = elapsed_sec;
+    }
+}
+
+impl UiMaterial for SpeedLineMaterial {
+    fn fragment_shader() -> ShaderRef {
+        "shaders/speed_lines.wgsl".into()
+    }
+}
@@ -0,0 +1,30 @@
+#![allow(dead_code)]
+use bevy::{
+    prelude::*,
+    render::render_resource::{AsBindGroup, ShaderRef, ShaderType},
+};
+
+#[derive(Debug, Default, Clone, Copy, ShaderType)]
+pub struct VignetteUniform {
+    /// `rgb`: color of the screen edges, `a`: intensity (0.0 to 1.0).
+    pub color: Vec4,
+}
+
+#[derive(AsBindGroup, Asset, TypePath, Debug, Default, Clone)]
+pub struct VignetteMaterial {
+    #[uniform(0)]
+    pub uniform: VignetteUniform,
+}
+
+impl VignetteMaterial {
+    pub fn set(&mut self, color: Color, intensity: f32) {
+        let color = color.to_linear();
+        self.uniform.color = Vec4::new(color.red, color.green, color.blue, intensity);
+    }
+}
+
+impl UiMaterial for VignetteMaterial {
+    fn fragment_shader() -> ShaderRef {
+        "shaders/vignette.wgsl".into()
+    }
+}
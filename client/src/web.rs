@@ -1,5 +1,7 @@
 #![cfg(target_arch = "wasm32")]
 
+mod asset_cache;
+
 use bevy::{
     audio::{AudioSource, PlaybackMode, Volume},
     platform::collections::HashMap,
@@ -8,30 +10,51 @@ use bevy::{
 use flume::{Receiver, Sender};
 use js_sys::ArrayBuffer;
 use wasm_bindgen::prelude::*;
-use web_sys::{AudioBuffer, AudioBufferSourceNode, AudioContext, GainNode};
+use web_sys::{
+    AudioBuffer, AudioBufferSourceNode, AudioContext, AudioContextState, BiquadFilterNode,
+    BiquadFilterType, GainNode, StereoPannerNode,
+};
+
+use crate::scene::{EffectSound, VoiceSound};
+
+pub use self::asset_cache::WebAssetCachePlugin;
+
+/// The time, in seconds, between attempts to resume an audio context that was suspended again.
+const RESUME_RETRY_INTERVAL: f32 = 1.0;
+/// The user gestures that allow a web page to start playing audio.
+const UNLOCK_EVENTS: [&str; 3] = ["pointerdown", "touchend", "keydown"];
+/// The time, in seconds, a one-shot sound may outlive its clip before the watchdog despawns it.
+const WATCHDOG_GRACE_PERIOD: f32 = 2.0;
+/// The time, in seconds, a one-shot sound whose clip was never decoded is kept before the watchdog despawns it.
+const WATCHDOG_UNDECODED_TIMEOUT: f32 = 10.0;
 
 pub struct WebAudioPlugin;
 
 impl Plugin for WebAudioPlugin {
     fn build(&self, app: &mut App) {
         let context = WebAudioContext::new().expect("Web browser does not support AudioContext");
+        context.resume_on_user_gesture();
         app.insert_non_send_resource(context)
+            .init_resource::<WebAudioStatus>()
             .init_non_send_resource::<WebAudioSources>()
             .init_non_send_resource::<WebAudioBufferCache>()
             .init_non_send_resource::<WebAudioDecodedChannel>()
             .add_systems(
                 Update,
                 (
+                    system_update_context_state.before(WebAudioSet::Spawn),
                     system_spawn_new_web_players.in_set(WebAudioSet::Spawn),
                     system_setup_decoded_audio
                         .in_set(WebAudioSet::Setup)
                         .after(WebAudioSet::Spawn),
-                    system_sync_playback_state
+                    (system_sync_playback_state, system_update_playback_position)
+                        .chain()
                         .in_set(WebAudioSet::Sync)
                         .after(WebAudioSet::Setup),
                     (
                         system_cleanup_finished_sounds,
                         system_despawn_finished_sounds,
+                        system_despawn_stale_sounds,
                     )
                         .in_set(WebAudioSet::Cleanup)
                         .after(WebAudioSet::Sync),
@@ -51,6 +74,7 @@ enum WebAudioSet {
 // --- COMPONENTS ---
 
 #[derive(Component)]
+#[require(SoundWatchdog)]
 pub struct WebAudioPlayer(Handle<AudioSource>);
 
 impl WebAudioPlayer {
@@ -64,6 +88,12 @@ pub struct WebPlaybackSettings {
     pub mode: PlaybackMode,
     pub volume: Volume,
     pub paused: bool,
+    /// The cutoff frequency, in hertz, of a low-pass filter applied to the sound.
+    pub low_pass: Option<f32>,
+    /// The playback rate of the sound, which shifts its pitch as well.
+    pub speed: f32,
+    /// The stereo position of the sound, from -1.0 (left) to 1.0 (right).
+    pub pan: f32,
 }
 
 impl Default for WebPlaybackSettings {
@@ -72,6 +102,9 @@ impl Default for WebPlaybackSettings {
             mode: PlaybackMode::Despawn,
             volume: Volume::default(),
             paused: false,
+            low_pass: None,
+            speed: 1.0,
+            pan: 0.0,
         }
     }
 }
@@ -82,30 +115,47 @@ impl WebPlaybackSettings {
         mode: PlaybackMode::Once,
         volume: Volume::Linear(1.0),
         paused: false,
+        low_pass: None,
+        speed: 1.0,
+        pan: 0.0,
     };
 
     pub const LOOP: WebPlaybackSettings = WebPlaybackSettings {
         mode: PlaybackMode::Loop,
         volume: Volume::Linear(1.0),
         paused: false,
+        low_pass: None,
+        speed: 1.0,
+        pan: 0.0,
     };
 
     pub const DESPAWN: WebPlaybackSettings = WebPlaybackSettings {
         mode: PlaybackMode::Despawn,
         volume: Volume::Linear(1.0),
         paused: false,
+        low_pass: None,
+        speed: 1.0,
+        pan: 0.0,
     };
 
     pub const REMOVE: WebPlaybackSettings = WebPlaybackSettings {
         mode: PlaybackMode::Remove,
         volume: Volume::Linear(1.0),
         paused: false,
+        low_pass: None,
+        speed: 1.0,
+        pan: 0.0,
     };
 
     pub const fn with_volume(mut self, volume: Volume) -> Self {
         self.volume = volume;
         self
     }
+
+    pub const fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
 }
 
 pub enum PlaybackState {
@@ -123,21 +173,76 @@ pub struct PlaybackTracker {
 #[derive(Component)]
 pub struct WebPlaybackDespawnMarker;
 
+/// The time, in seconds, a sound has played by the clock of the audio context, counting every loop.
+#[derive(Component, Default)]
+pub struct PlaybackPosition(pub f64);
+
+/// How long a sound has been waiting or playing while not paused.
+/// A sound that never starts, e.g. because the audio context stayed suspended,
+/// would otherwise never reach the end of its clip and be despawned.
+#[derive(Component, Default)]
+pub struct SoundWatchdog {
+    age: f32,
+}
+
 // --- RESOURCES ---
 
+/// Whether the browser lets the game play audio.
+/// Browsers keep audio suspended until the user interacts with the page.
+#[derive(Resource, Default)]
+struct WebAudioStatus {
+    /// Whether the audio context was running when last checked, or `None` before the first check.
+    running: Option<bool>,
+    unlocked: bool,
+    retry_elapsed: f32,
+}
+
 struct WebAudioContext(AudioContext);
 
 impl WebAudioContext {
     pub fn new() -> Result<Self, JsValue> {
         Ok(Self(AudioContext::new()?))
     }
+
+    fn is_running(&self) -> bool {
+        self.0.state() == AudioContextState::Running
+    }
+
+    /// Resumes the audio context from within every user gesture,
+    /// since browsers only allow audio to start from such an event.
+    fn resume_on_user_gesture(&self) {
+        let Some(window) = web_sys::window() else {
+            return;
+        };
+
+        let context = self.0.clone();
+        let callback = Closure::<dyn FnMut()>::new(move || {
+            if context.state() != AudioContextState::Running {
+                let _ = context.resume();
+            }
+        });
+
+        for event in UNLOCK_EVENTS {
+            window
+                .add_event_listener_with_callback(event, callback.as_ref().unchecked_ref())
+                .ok();
+        }
+
+        // The listeners live as long as the page.
+        callback.forget();
+    }
 }
 
 struct WebAudioEntry {
     gain_node: GainNode,
+    panner_node: StereoPannerNode,
+    filter_node: BiquadFilterNode,
     source_node: AudioBufferSourceNode,
 }
 
+/// The cutoff frequency used while no low-pass filter is requested, above the audible range.
+const LOW_PASS_BYPASS: f32 = 22_050.0;
+
 struct WebAudioDecodedChannel {
     sender: Sender<(Entity, AudioBuffer, AssetId<AudioSource>)>,
     receiver: Receiver<(Entity, AudioBuffer, AssetId<AudioSource>)>,
@@ -156,8 +261,41 @@ struct WebAudioSources(HashMap<Entity, WebAudioEntry>);
 #[derive(Default)]
 struct WebAudioBufferCache(HashMap<AssetId<AudioSource>, AudioBuffer>);
 
+impl WebAudioBufferCache {
+    /// Returns the length, in seconds, of a clip that has been decoded.
+    fn clip_duration(&self, asset_id: AssetId<AudioSource>) -> Option<f32> {
+        self.0.get(&asset_id).map(|buffer| buffer.duration() as f32)
+    }
+}
+
 // --- UPDATE SYSTEMS ---
 
+/// Tracks the state of the audio context and keeps trying to resume it
+/// when the browser suspends it again after it was unlocked.
+fn system_update_context_state(
+    context: NonSend<WebAudioContext>,
+    mut status: ResMut<WebAudioStatus>,
+    time: Res<Time<Real>>,
+) {
+    let running = context.is_running();
+    if status.running != Some(running) {
+        info!("Audio context running: {}", running);
+        // The start overlay of the page asks the user for the gesture that lets audio play.
+        set_start_overlay_visible(!running);
+        status.running = Some(running);
+        status.unlocked |= running;
+        status.retry_elapsed = 0.0;
+    }
+
+    if !running && status.unlocked {
+        status.retry_elapsed += time.delta_secs();
+        if status.retry_elapsed >= RESUME_RETRY_INTERVAL {
+            status.retry_elapsed = 0.0;
+            let _ = context.0.resume();
+        }
+    }
+}
+
 fn system_spawn_new_web_players(
     mut commands: Commands,
     query: Query<(Entity, &WebAudioPlayer, Option<&WebPlaybackSettings>), Added<WebAudioPlayer>>,
@@ -221,15 +359,34 @@ fn system_setup_decoded_audio(
         cache.0.insert(asset_id, audio_buffer.clone());
 
         if let Ok(settings) = query.get(entity) {
+            // One-shot sounds heard while the browser keeps audio suspended are dropped,
+            // rather than all played at once when it resumes.
+            if !context.is_running() && matches!(settings.mode, PlaybackMode::Despawn) {
+                commands.entity(entity).despawn();
+                continue;
+            }
+
             let gain_node = context.0.create_gain().unwrap();
             gain_node
                 .connect_with_audio_node(&context.0.destination())
                 .unwrap();
             gain_node.gain().set_value(settings.volume.to_linear());
 
+            let panner_node = context.0.create_stereo_panner().unwrap();
+            panner_node.pan().set_value(settings.pan);
+            panner_node.connect_with_audio_node(&gain_node).unwrap();
+
+            let filter_node = context.0.create_biquad_filter().unwrap();
+            filter_node.set_type(BiquadFilterType::Lowpass);
+            filter_node
+                .frequency()
+                .set_value(settings.low_pass.unwrap_or(LOW_PASS_BYPASS));
+            filter_node.connect_with_audio_node(&panner_node).unwrap();
+
             let source_node = context.0.create_buffer_source().unwrap();
-            source_node.connect_with_audio_node(&gain_node).unwrap();
+            source_node.connect_with_audio_node(&filter_node).unwrap();
             source_node.set_buffer(Some(&audio_buffer));
+            source_node.playback_rate().set_value(settings.speed);
 
             match settings.mode {
                 PlaybackMode::Loop => {
@@ -254,11 +411,15 @@ fn system_setup_decoded_audio(
                 info!("Play Sound: {}", asset_id);
             };
 
-            commands.entity(entity).insert(tracker);
+            commands
+                .entity(entity)
+                .insert((tracker, PlaybackPosition::default()));
             sources.0.insert(
                 entity,
                 WebAudioEntry {
                     gain_node,
+                    panner_node,
+                    filter_node,
                     source_node,
                 },
             );
@@ -286,6 +447,12 @@ fn system_sync_playback_state(
                 .gain_node
                 .gain()
                 .set_value(settings.volume.to_linear());
+            entry.panner_node.pan().set_value(settings.pan);
+            entry
+                .filter_node
+                .frequency()
+                .set_value(settings.low_pass.unwrap_or(LOW_PASS_BYPASS));
+            entry.source_node.playback_rate().set_value(settings.speed);
 
             let current_time = context.0.current_time();
             let is_playing = matches!(tracker.state, PlaybackState::Playing { .. });
@@ -309,9 +476,10 @@ fn system_sync_playback_state(
 
                 let new_source = context.0.create_buffer_source().unwrap();
                 new_source
-                    .connect_with_audio_node(&entry.gain_node)
+                    .connect_with_audio_node(&entry.filter_node)
                     .unwrap();
                 new_source.set_buffer(Some(audio_buffer));
+                new_source.playback_rate().set_value(settings.speed);
                 if matches!(settings.mode, PlaybackMode::Loop) {
                     new_source.set_loop(true);
                 }
@@ -339,11 +507,29 @@ fn system_cleanup_finished_sounds(
         if let Some(entry) = sources.0.remove(&entity) {
             entry.source_node.stop().ok();
             entry.source_node.disconnect().ok();
+            entry.filter_node.disconnect().ok();
+            entry.panner_node.disconnect().ok();
             entry.gain_node.disconnect().ok();
         }
     }
 }
 
+fn system_update_playback_position(
+    context: NonSend<WebAudioContext>,
+    mut query: Query<(&PlaybackTracker, &mut PlaybackPosition)>,
+) {
+    let current_time = context.0.current_time();
+    for (tracker, mut position) in query.iter_mut() {
+        position.0 = match tracker.state {
+            PlaybackState::Playing { start_time } => current_time - start_time,
+            PlaybackState::Paused {
+                elapsed_before_pause,
+            } => elapsed_before_pause,
+            PlaybackState::Stopped => continue,
+        };
+    }
+}
+
 fn system_despawn_finished_sounds(
     mut commands: Commands,
     context: NonSend<WebAudioContext>,
@@ -362,14 +548,110 @@ fn system_despawn_finished_sounds(
     }
 }
 
+/// Despawns one-shot effect and voice sounds that have lived longer than their clip,
+/// which happens when playback never started or the audio context stopped advancing.
+#[allow(clippy::type_complexity)]
+fn system_despawn_stale_sounds(
+    mut commands: Commands,
+    cache: NonSend<WebAudioBufferCache>,
+    mut query: Query<
+        (
+            Entity,
+            &WebAudioPlayer,
+            &WebPlaybackSettings,
+            &mut SoundWatchdog,
+        ),
+        Or<(With<EffectSound>, With<VoiceSound>)>,
+    >,
+    time: Res<Time<Real>>,
+) {
+    for (entity, player, settings, mut watchdog) in query.iter_mut() {
+        if !matches!(settings.mode, PlaybackMode::Despawn) || settings.paused {
+            continue;
+        }
+
+        watchdog.age += time.delta_secs();
+        let lifetime = match cache.clip_duration(player.0.id()) {
+            Some(duration) => duration / settings.speed.max(f32::EPSILON) + WATCHDOG_GRACE_PERIOD,
+            None => WATCHDOG_UNDECODED_TIMEOUT,
+        };
+        if watchdog.age >= lifetime {
+            commands.entity(entity).despawn();
+            warn!("Despawning stale sound for entity: {:?}", entity);
+        }
+    }
+}
+
 // --- JavaScript FFI ---
 
 #[wasm_bindgen]
 extern "C" {
     #[wasm_bindgen(js_name = start_tutorial)]
     fn start_tutorial(lang: &str);
+
+    #[wasm_bindgen(js_name = get_seed_param)]
+    fn get_seed_query_param() -> Option<String>;
+
+    #[wasm_bindgen(js_name = copy_to_clipboard)]
+    fn copy_text_to_clipboard(text: &str);
+
+    #[wasm_bindgen(js_name = is_install_available)]
+    fn is_install_prompt_available() -> bool;
+
+    #[wasm_bindgen(js_name = prompt_install)]
+    fn prompt_install_app();
+
+    #[wasm_bindgen(js_name = download_file)]
+    fn download_bytes(filename: &str, bytes: &[u8], mime_type: &str);
+
+    #[wasm_bindgen(js_name = upload_file)]
+    fn open_upload_dialog(accept: &str);
+
+    #[wasm_bindgen(js_name = take_uploaded_file)]
+    fn take_uploaded_file_text() -> Option<String>;
+
+    #[wasm_bindgen(js_name = set_start_overlay_visible)]
+    fn set_start_overlay_visible(visible: bool);
 }
 
 pub fn start_game_tutorial(lang: &str) {
     start_tutorial(lang);
 }
+
+pub fn get_seed_param() -> Option<String> {
+    get_seed_query_param()
+}
+
+pub fn copy_to_clipboard(text: &str) {
+    copy_text_to_clipboard(text);
+}
+
+/// Returns whether the browser reports a touch screen, which is taken as a sign of a mobile device.
+pub fn is_touch_device() -> bool {
+    web_sys::window().is_some_and(|window| window.navigator().max_touch_points() > 0)
+}
+
+/// Returns whether the browser offers to install the game as an app.
+pub fn install_available() -> bool {
+    is_install_prompt_available()
+}
+
+pub fn prompt_install() {
+    prompt_install_app();
+}
+
+/// Lets the browser save the given bytes as a file.
+pub fn download_file(filename: &str, bytes: &[u8], mime_type: &str) {
+    download_bytes(filename, bytes, mime_type);
+}
+
+/// Asks the browser to let the player pick a text file of the given types.
+/// The file is read in the background, and handed over by [`take_uploaded_file`].
+pub fn upload_file(accept: &str) {
+    open_upload_dialog(accept);
+}
+
+/// Returns the text of the file the player picked since the last call, if any.
+pub fn take_uploaded_file() -> Option<String> {
+    take_uploaded_file_text()
+}
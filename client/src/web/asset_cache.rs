@@ -0,0 +1,224 @@
+use std::{
+    cell::RefCell,
+    path::{Path, PathBuf},
+};
+
+use bevy::{
+    asset::io::{
+        AssetReader, AssetReaderError, AssetSource, AssetSourceId, PathStream, Reader, VecReader,
+        wasm::HttpWasmAssetReader,
+    },
+    prelude::*,
+};
+use js_sys::{Function, Promise, Uint8Array};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::{JsFuture, future_to_promise};
+use web_sys::{
+    Event, IdbDatabase, IdbObjectStore, IdbOpenDbRequest, IdbRequest, IdbTransactionMode,
+};
+
+use crate::asset::{
+    manifest::{self, ASSET_MANIFEST_VERSION, ManifestEntry},
+    texture::supports_compressed_variants,
+};
+
+/// The directory the assets are served from, relative to the page.
+const ASSET_ROOT: &str = "assets";
+const DATABASE_NAME: &str = "shupogaki-assets";
+const DATABASE_VERSION: u32 = 1;
+const STORE_NAME: &str = "assets";
+/// The key under which the manifest version of the cached assets is stored.
+const VERSION_KEY: &str = "manifest-version";
+
+thread_local! {
+    static DATABASE: RefCell<Option<Promise>> = const { RefCell::new(None) };
+}
+
+// --- PLUGIN ---
+
+/// Keeps downloaded asset files in IndexedDB, so that they are not downloaded again on the next visit.
+/// Must be added before the `AssetPlugin`.
+pub struct WebAssetCachePlugin;
+
+impl Plugin for WebAssetCachePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build().with_reader(|| Box::new(CachedAssetReader::new(ASSET_ROOT))),
+        );
+    }
+}
+
+// --- ASSET READER ---
+
+/// Serves assets listed in the asset manifest from IndexedDB, and fetches the rest over HTTP.
+/// Cached files are keyed by their content hash, so a changed file is never served from the cache.
+/// Textures are served from their block-compressed variant when the build ships one and the GPU can sample it.
+struct CachedAssetReader {
+    inner: HttpWasmAssetReader,
+}
+
+impl CachedAssetReader {
+    fn new(path: &str) -> Self {
+        Self {
+            inner: HttpWasmAssetReader::new(path),
+        }
+    }
+
+    async fn fetch(&self, path: &Path) -> Result<Vec<u8>, AssetReaderError> {
+        let mut reader = self.inner.read(path).await?;
+        let mut bytes = Vec::new();
+        reader
+            .read_to_end(&mut bytes)
+            .await
+            .map_err(|e| AssetReaderError::Io(e.into()))?;
+        Ok(bytes)
+    }
+}
+
+impl AssetReader for CachedAssetReader {
+    async fn read<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        let variant = compressed_variant(path);
+        let path = variant.as_deref().unwrap_or(path);
+
+        let Some(key) = path.to_str().and_then(manifest::find).map(cache_key) else {
+            return self.fetch(path).await.map(VecReader::new);
+        };
+
+        match load(&key).await {
+            Ok(Some(bytes)) => return Ok(VecReader::new(bytes)),
+            Ok(None) => { /* empty */ }
+            Err(e) => warn!("Failed to read the asset cache: {:?}", e),
+        }
+
+        let bytes = self.fetch(path).await?;
+        let cached = bytes.clone();
+        wasm_bindgen_futures::spawn_local(async move {
+            if let Err(e) = store(&key, cached).await {
+                warn!("Failed to write the asset cache: {:?}", e);
+            }
+        });
+
+        Ok(VecReader::new(bytes))
+    }
+
+    async fn read_meta<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        self.inner.read_meta(path).await
+    }
+
+    async fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Result<Box<PathStream>, AssetReaderError> {
+        self.inner.read_directory(path).await
+    }
+
+    async fn is_directory<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+        self.inner.is_directory(path).await
+    }
+}
+
+/// Returns the path of the KTX2 variant of a texture or sprite, if it is in the manifest and can be used.
+/// The query string of the path is kept.
+fn compressed_variant(path: &Path) -> Option<PathBuf> {
+    if !supports_compressed_variants() {
+        return None;
+    }
+
+    let path = path.to_str()?;
+    let (file, query) = match path.split_once('?') {
+        Some((file, query)) => (file, Some(query)),
+        None => (path, None),
+    };
+    if !file.ends_with(".texture") && !file.ends_with(".sprite") {
+        return None;
+    }
+
+    let variant = format!("{}.ktx2", file);
+    manifest::find(&variant)?;
+    Some(PathBuf::from(match query {
+        Some(query) => format!("{}?{}", variant, query),
+        None => variant,
+    }))
+}
+
+fn cache_key(entry: &ManifestEntry) -> String {
+    format!("{}#{:016x}", entry.path, entry.hash)
+}
+
+// --- INDEXED DB ---
+
+/// Resolves with the result of the request once it succeeds.
+async fn wait_for(request: &IdbRequest) -> Result<JsValue, JsValue> {
+    let promise = Promise::new(&mut |resolve: Function, reject: Function| {
+        request.set_onsuccess(Some(&resolve));
+        request.set_onerror(Some(&reject));
+    });
+    JsFuture::from(promise).await?;
+    request.result()
+}
+
+fn object_store(
+    database: &IdbDatabase,
+    mode: IdbTransactionMode,
+) -> Result<IdbObjectStore, JsValue> {
+    database
+        .transaction_with_str_and_mode(STORE_NAME, mode)?
+        .object_store(STORE_NAME)
+}
+
+/// Returns the cache database, opening it on first use.
+async fn database() -> Result<IdbDatabase, JsValue> {
+    let promise = DATABASE.with(|database| {
+        database
+            .borrow_mut()
+            .get_or_insert_with(|| future_to_promise(open_database()))
+            .clone()
+    });
+    Ok(JsFuture::from(promise).await?.unchecked_into())
+}
+
+async fn open_database() -> Result<JsValue, JsValue> {
+    let factory = web_sys::window()
+        .ok_or("No window")?
+        .indexed_db()?
+        .ok_or("IndexedDB is not supported")?;
+
+    let request = factory.open_with_u32(DATABASE_NAME, DATABASE_VERSION)?;
+    let on_upgrade_needed = Closure::once_into_js(|event: Event| {
+        let request: IdbOpenDbRequest = event.target().unwrap().unchecked_into();
+        let database: IdbDatabase = request.result().unwrap().unchecked_into();
+        database.create_object_store(STORE_NAME).ok();
+    });
+    request.set_onupgradeneeded(Some(on_upgrade_needed.unchecked_ref()));
+    let database: IdbDatabase = wait_for(&request).await?.unchecked_into();
+
+    // Drops every cached file once the assets of the build change, so old files don't pile up.
+    let version = format!("{:016x}", ASSET_MANIFEST_VERSION);
+    let stored =
+        wait_for(&object_store(&database, IdbTransactionMode::Readonly)?.get(&VERSION_KEY.into())?)
+            .await?;
+    if stored.as_string().as_deref() != Some(version.as_str()) {
+        info!("Asset manifest changed, clearing the asset cache.");
+        let store = object_store(&database, IdbTransactionMode::Readwrite)?;
+        store.clear()?;
+        wait_for(&store.put_with_key(&version.into(), &VERSION_KEY.into())?).await?;
+    }
+
+    Ok(database.into())
+}
+
+async fn load(key: &str) -> Result<Option<Vec<u8>>, JsValue> {
+    let database = database().await?;
+    let store = object_store(&database, IdbTransactionMode::Readonly)?;
+    let value = wait_for(&store.get(&key.into())?).await?;
+    Ok((!value.is_undefined()).then(|| Uint8Array::new(&value).to_vec()))
+}
+
+async fn store(key: &str, bytes: Vec<u8>) -> Result<(), JsValue> {
+    let database = database().await?;
+    let store = object_store(&database, IdbTransactionMode::Readwrite)?;
+    let value = Uint8Array::from(bytes.as_slice());
+    wait_for(&store.put_with_key(&value, &key.into())?).await?;
+    Ok(())
+}
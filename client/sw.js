@@ -0,0 +1,42 @@
+// Caches the game for offline play.
+// The file list, including the page and the generated script and module, is written by the build script,
+// and its version changes whenever an asset or the package version changes.
+const CACHE_PREFIX = 'shupogaki-';
+
+self.addEventListener('install', (event) => {
+    event.waitUntil((async () => {
+        const response = await fetch('assets/precache.json', { cache: 'no-store' });
+        const precache = await response.json();
+        const cache = await caches.open(CACHE_PREFIX + precache.version);
+        await cache.addAll(precache.files);
+        await self.skipWaiting();
+    })());
+});
+
+self.addEventListener('activate', (event) => {
+    event.waitUntil((async () => {
+        const response = await fetch('assets/precache.json', { cache: 'no-store' }).catch(() => null);
+        if (response) {
+            // Drops the caches of older builds.
+            const precache = await response.json();
+            const current = CACHE_PREFIX + precache.version;
+            const keys = await caches.keys();
+            await Promise.all(keys
+                .filter((key) => key.startsWith(CACHE_PREFIX) && key !== current)
+                .map((key) => caches.delete(key)));
+        }
+        await self.clients.claim();
+    })());
+});
+
+self.addEventListener('fetch', (event) => {
+    if (event.request.method !== 'GET') {
+        return;
+    }
+
+    // Asset requests carry a version query, which the cached files don't have.
+    event.respondWith((async () => {
+        const cached = await caches.match(event.request, { ignoreSearch: true });
+        return cached || fetch(event.request);
+    })());
+});
@@ -0,0 +1,247 @@
+//! Scrolls the ground of the track for hours of simulated play at varying frame rates,
+//! and checks that the planes never open a seam or overlap, and that no depth grows without bound.
+//!
+//! The world scrolls toward the player, who stays at the origin, so every depth on the track
+//! stays between the despawn and spawn positions, and no origin has to be shifted.
+//! Only the spacing of the planes and the counters of the distance travelled can drift.
+//!
+//! Usage: `cargo run --release -p shupogaki_core --example soak -- [hours] [seed]`
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use shupogaki_core::*;
+
+const DEFAULT_HOURS: f32 = 3.0;
+/// The frame rates a session switches between, as on a device that throttles or a monitor that changes.
+const FRAME_RATES: [f32; 7] = [30.0, 60.0, 75.0, 90.0, 120.0, 144.0, 240.0];
+/// The simulated time, in seconds, between two changes of the frame rate.
+const FRAME_RATE_PERIOD: f32 = 90.0;
+/// The chance that a frame hitches, and the longest a hitch lasts.
+const HITCH_CHANCE: f64 = 0.002;
+const MAX_HITCH: f32 = 0.25;
+/// The largest gap or overlap, in world units, allowed between two neighbouring planes.
+const MAX_SEAM: f32 = 1e-2;
+/// The largest error allowed in the distance of the run, relative to the distance travelled.
+const MAX_DISTANCE_ERROR: f64 = 1e-6;
+
+#[derive(Component)]
+struct Ground;
+
+#[derive(Default, Resource)]
+struct RetiredGrounds(Vec<Entity>);
+
+#[derive(Resource)]
+struct Layout(GroundLayout);
+
+/// The same scrolling as the in-game ground: every plane moves by the distance of the frame.
+fn update_ground_position(
+    player_query: Query<&ForwardMovement, With<Player>>,
+    mut ground_entities: Query<(Entity, &mut Transform), With<Ground>>,
+    mut retired: ResMut<RetiredGrounds>,
+    layout: Res<Layout>,
+    time_scale: Res<TimeScale>,
+    time: Res<Time>,
+) {
+    let elapsed = time.delta_secs() * time_scale.get();
+    let player_velocity = player_query
+        .single()
+        .map(|forward_move| forward_move.get())
+        .unwrap_or(0.0);
+
+    for (entity, mut transform) in ground_entities.iter_mut() {
+        transform.translation.z -= player_velocity * elapsed;
+
+        if layout.0.is_retired(transform.translation.z) {
+            retired.0.push(entity);
+        }
+    }
+}
+
+/// The same recycling as the in-game ground: retired planes are laid again ahead of the front plane.
+fn spawn_grounds(
+    mut retired: ResMut<RetiredGrounds>,
+    mut ground_query: Query<&mut Transform, With<Ground>>,
+    layout: Res<Layout>,
+) {
+    let mut front = ground_query
+        .iter()
+        .map(|transform| transform.translation.z)
+        .fold(layout.0.despawn, f32::max);
+    for entity in retired.0.drain(..) {
+        front = layout.0.recycled_depth(front);
+        if let Ok(mut transform) = ground_query.get_mut(entity) {
+            transform.translation.z = front;
+        }
+    }
+}
+
+fn update_time_scale(mut time_scale: ResMut<TimeScale>, time: Res<Time>) {
+    time_scale.on_advanced(time.delta_secs());
+}
+
+fn update_play_time(mut play_time: ResMut<PlayTime>, time: Res<Time>) {
+    play_time.tick(&time);
+}
+
+fn new_session(layout: GroundLayout) -> App {
+    let mut app = App::new();
+    app.init_resource::<Time>()
+        .init_resource::<TimeScale>()
+        .init_resource::<CurrentScore>()
+        .init_resource::<PlayTime>()
+        .init_resource::<RunPace>()
+        .init_resource::<RetiredGrounds>()
+        .insert_resource(Layout(layout))
+        .add_systems(
+            Update,
+            (
+                update_time_scale,
+                update_score,
+                (update_play_time, update_run_pace).chain(),
+                update_ground_position,
+            ),
+        )
+        .add_systems(PostUpdate, spawn_grounds);
+
+    app.world_mut()
+        .spawn((Player, ForwardMovement::new(MIN_PLAYER_SPEED)));
+    for depth in layout.depths() {
+        app.world_mut()
+            .spawn((Ground, Transform::from_xyz(0.0, 0.0, depth)));
+    }
+    app
+}
+
+/// Returns the depths of the planes from the back of the track to the front.
+fn ground_depths(app: &mut App) -> Vec<f32> {
+    let mut depths: Vec<f32> = app
+        .world_mut()
+        .query_filtered::<&Transform, With<Ground>>()
+        .iter(app.world())
+        .map(|transform| transform.translation.z)
+        .collect();
+    depths.sort_by(f32::total_cmp);
+    depths
+}
+
+/// Plays a session of the given length, and prints the largest seam seen between two planes.
+fn soak(name: &str, layout: GroundLayout, hours: f32, rng: &mut StdRng) {
+    let mut app = new_session(layout);
+    let duration = hours * 3600.0;
+    let mut elapsed = 0.0;
+    let mut frames = 0u64;
+    let mut frame_rate = FRAME_RATES[0];
+    let mut next_change = 0.0;
+    let mut max_seam = 0.0f32;
+    let mut min_depth = f32::MAX;
+    let mut max_depth = f32::MIN;
+    // The distance travelled, added up at double precision to check the run's own count against.
+    let mut traveled = 0.0f64;
+
+    while elapsed < duration {
+        if elapsed >= next_change {
+            frame_rate = FRAME_RATES[rng.random_range(0..FRAME_RATES.len())];
+            next_change += FRAME_RATE_PERIOD;
+        }
+        let delta = match rng.random_bool(HITCH_CHANCE) {
+            true => rng.random_range(1.0 / frame_rate..MAX_HITCH),
+            false => 1.0 / frame_rate,
+        };
+
+        // The speed runs through the whole range of a run, with the slow motion of a clock now and then.
+        let speed = rng.random_range(MIN_PLAYER_SPEED..=INVINCIBLE_SPEED);
+        let world = app.world_mut();
+        if let Ok(mut forward_move) = world
+            .query_filtered::<&mut ForwardMovement, With<Player>>()
+            .single_mut(world)
+        {
+            forward_move.set(speed);
+        }
+        if rng.random_bool(1e-4) {
+            world.resource_mut::<TimeScale>().slow_down(CLOCK_DURATION);
+        }
+        traveled += f64::from(speed) * f64::from(delta * world.resource::<TimeScale>().get());
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(delta));
+        app.update();
+        elapsed += delta;
+        frames += 1;
+
+        let depths = ground_depths(&mut app);
+        assert_eq!(depths.len(), layout.num_grounds(), "A plane was lost");
+        for pair in depths.windows(2) {
+            let seam = (pair[1] - pair[0] - GROUND_SPAWN_INTERVAL).abs();
+            max_seam = max_seam.max(seam);
+            assert!(
+                seam <= MAX_SEAM,
+                "{}: the planes at {} and {} are {} units out of place after {:.2} hours",
+                name,
+                pair[0],
+                pair[1],
+                seam,
+                elapsed / 3600.0
+            );
+        }
+
+        min_depth = min_depth.min(depths[0]);
+        max_depth = max_depth.max(depths[depths.len() - 1]);
+        // The back plane may overshoot the despawn position by one frame before it is laid ahead.
+        let max_step = INVINCIBLE_SPEED * MAX_HITCH;
+        let front_limit = layout.despawn + layout.num_grounds() as f32 * GROUND_SPAWN_INTERVAL;
+        assert!(
+            min_depth > layout.despawn - max_step && max_depth <= front_limit + MAX_SEAM,
+            "{}: the planes left the track, between {} and {}",
+            name,
+            min_depth,
+            max_depth
+        );
+    }
+
+    let distance = f64::from(app.world().resource::<RunPace>().distance());
+    let error = (distance - traveled).abs() / traveled;
+    assert!(
+        error <= MAX_DISTANCE_ERROR,
+        "{}: the run counted {} units, but {} were travelled",
+        name,
+        distance,
+        traveled
+    );
+
+    println!(
+        "{}: {} frames over {:.2} hours, largest seam {:e}, depths between {:.3} and {:.3}, distance {:.0} (error {:e}), score {}",
+        name,
+        frames,
+        elapsed / 3600.0,
+        max_seam,
+        min_depth,
+        max_depth,
+        distance,
+        error,
+        app.world().resource::<CurrentScore>().get()
+    );
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let hours = args
+        .next()
+        .and_then(|arg| arg.parse().ok())
+        .unwrap_or(DEFAULT_HOURS);
+    let seed = args.next().and_then(|arg| arg.parse().ok()).unwrap_or(0);
+    println!("Soak the track for {} hours with seed {}", hours, seed);
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let desktop = GroundLayout {
+        spawn: SPAWN_POSITION,
+        despawn: DESPAWN_POSITION,
+    };
+    let mobile = GroundLayout {
+        spawn: MOBILE_SPAWN_POSITION,
+        despawn: MOBILE_DESPAWN_POSITION,
+    };
+    soak("desktop", desktop, hours, &mut rng);
+    soak("mobile", mobile, hours, &mut rng);
+}
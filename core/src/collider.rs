@@ -0,0 +1,550 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+
+/// Represents a geometric shape that can be used for collision detection.
+#[derive(Debug, Component, Clone, Copy, Deserialize)]
+pub enum Collider {
+    /// An Axis-Aligned Bounding Box (AABB) collider.
+    Aabb { offset: Vec3, size: Vec3 },
+    /// A sphere collider.
+    Sphere { offset: Vec3, radius: f32 },
+    /// A capsule collider lying along the track: a sphere swept `half_length` forwards and backwards along the z axis.
+    Capsule {
+        offset: Vec3,
+        half_length: f32,
+        radius: f32,
+    },
+}
+
+impl Collider {
+    /// Checks if this collider intersects with another collider.
+    /// Checks if this collider intersects with another collider, taking their world transforms into account.
+    ///
+    /// # Arguments
+    ///
+    /// * `transform` - The `Transform` of this collider.
+    /// * `other` - The other collider to check against.
+    /// * `other_transform` - The `Transform` of the other collider.
+    ///
+    /// # Returns
+    ///
+    /// * `true` if the colliders intersect, `false` otherwise.
+    pub fn intersects(
+        &self,
+        transform: &Transform,
+        other: &Self,
+        other_transform: &Transform,
+    ) -> bool {
+        match (self, other) {
+            // Case 1: AABB vs AABB intersection.
+            (
+                Collider::Aabb {
+                    offset: a_offset,
+                    size: a_size,
+                },
+                Collider::Aabb {
+                    offset: b_offset,
+                    size: b_size,
+                },
+            ) => {
+                // Calculate the minimum and maximum coordinates for both AABBs.
+                let min_a = transform.translation + *a_offset - *a_size * 0.5;
+                let max_a = transform.translation + *a_offset + *a_size * 0.5;
+                let min_b = other_transform.translation + *b_offset - *b_size * 0.5;
+                let max_b = other_transform.translation + *b_offset + *b_size * 0.5;
+
+                // Check for overlap on each axis. If there is no gap between the boxes on any axis, they are intersecting.
+                max_a.x >= min_b.x
+                    && min_a.x <= max_b.x
+                    && max_a.y >= min_b.y
+                    && min_a.y <= max_b.y
+                    && max_a.z >= min_b.z
+                    && min_a.z <= max_b.z
+            }
+            // Case 2: AABB vs Sphere intersection.
+            (
+                Collider::Aabb {
+                    offset: a_offset,
+                    size: a_size,
+                },
+                Collider::Sphere {
+                    offset: b_offset,
+                    radius: b_radius,
+                },
+            ) => {
+                // Find the point on the AABB closest to the sphere's center.
+                // This is done by "clamping" the sphere's center coordinates to be within the AABB's bounds.
+                // If the sphere's center is inside the AABB, the closest point is the center itself.
+                // If it's outside, the closest point will be on the surface of the AABB.
+                let a_min = transform.translation + *a_offset - *a_size * 0.5;
+                let a_max = transform.translation + *a_offset + *a_size * 0.5;
+                let b_center = other_transform.translation + *b_offset;
+                let closest_point = b_center.clamp(a_min, a_max);
+
+                // Check if the squared distance from the closest point to the sphere's center
+                // is less than or equal to the sphere's radius squared.
+                // Using squared distances avoids a costly square root operation.
+                let distance_squared = (closest_point - b_center).length_squared();
+                distance_squared <= b_radius * b_radius
+            }
+            // Case 3: Sphere vs AABB intersection.
+            (Collider::Sphere { .. }, other @ Collider::Aabb { .. }) => {
+                other.intersects(other_transform, self, transform)
+            }
+            // Case 4: Sphere vs Sphere intersection.
+            (
+                Collider::Sphere {
+                    offset: a_offset,
+                    radius: a_radius,
+                },
+                Collider::Sphere {
+                    offset: b_offset,
+                    radius: b_radius,
+                },
+            ) => {
+                let a_center = transform.translation + *a_offset;
+                let b_center = other_transform.translation + *b_offset;
+
+                // Calculate the squared distance between the centers of the two spheres.
+                let distance_squared = (a_center - b_center).length_squared();
+                let radius_sum = a_radius + b_radius;
+
+                // Compare the squared distance with the squared sum of their radii.
+                // This is more efficient than comparing the actual distances as it avoids a square root.
+                distance_squared <= radius_sum * radius_sum
+            }
+            // Case 5: AABB vs Capsule intersection.
+            (
+                Collider::Aabb {
+                    offset: a_offset,
+                    size: a_size,
+                },
+                Collider::Capsule {
+                    offset: b_offset,
+                    half_length: b_half_length,
+                    radius: b_radius,
+                },
+            ) => {
+                let a_min = transform.translation + *a_offset - *a_size * 0.5;
+                let a_max = transform.translation + *a_offset + *a_size * 0.5;
+                let b_center = other_transform.translation + *b_offset;
+
+                // The capsule touches the box if its axis comes within its radius of the box.
+                let distance_squared = axis_to_box_squared(b_center, *b_half_length, a_min, a_max);
+                distance_squared <= b_radius * b_radius
+            }
+            // Case 6: Capsule vs AABB intersection.
+            (Collider::Capsule { .. }, other @ Collider::Aabb { .. }) => {
+                other.intersects(other_transform, self, transform)
+            }
+            // Case 7: Sphere vs Capsule intersection, as a capsule of no length.
+            (Collider::Sphere { offset, radius }, other @ Collider::Capsule { .. }) => {
+                let sphere = Collider::Capsule {
+                    offset: *offset,
+                    half_length: 0.0,
+                    radius: *radius,
+                };
+                sphere.intersects(transform, other, other_transform)
+            }
+            // Case 8: Capsule vs Sphere intersection.
+            (Collider::Capsule { .. }, other @ Collider::Sphere { .. }) => {
+                other.intersects(other_transform, self, transform)
+            }
+            // Case 9: Capsule vs Capsule intersection.
+            (
+                Collider::Capsule {
+                    offset: a_offset,
+                    half_length: a_half_length,
+                    radius: a_radius,
+                },
+                Collider::Capsule {
+                    offset: b_offset,
+                    half_length: b_half_length,
+                    radius: b_radius,
+                },
+            ) => {
+                let a_center = transform.translation + *a_offset;
+                let b_center = other_transform.translation + *b_offset;
+
+                // Both axes run along the z axis, so they are closest across the gap between their ends.
+                let across = (a_center.xy() - b_center.xy()).length_squared();
+                let gap =
+                    ((a_center.z - b_center.z).abs() - a_half_length - b_half_length).max(0.0);
+                let radius_sum = a_radius + b_radius;
+                across + gap * gap <= radius_sum * radius_sum
+            }
+        }
+    }
+}
+
+/// Returns the squared distance between an axis-aligned box and the axis of a capsule,
+/// which runs from `center` `half_length` forwards and backwards along the z axis.
+fn axis_to_box_squared(center: Vec3, half_length: f32, min: Vec3, max: Vec3) -> f32 {
+    // Across the track, the closest point of the box is the one closest to the axis.
+    let across = (center.xy().clamp(min.xy(), max.xy()) - center.xy()).length_squared();
+    // Along the track, the axis and the box are apart by the gap between their ends, if any.
+    let gap = (min.z - (center.z + half_length))
+        .max(center.z - half_length - max.z)
+        .max(0.0);
+    across + gap * gap
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{JUMP_APEX_HEIGHT, LANE_POSITIONS};
+
+    #[test]
+    fn test_aabb_vs_aabb_intersection() {
+        let aabb1 = Collider::Aabb {
+            offset: Vec3::ZERO,
+            size: Vec3::new(2.0, 2.0, 2.0),
+        };
+        let aabb2 = Collider::Aabb {
+            offset: Vec3::ZERO,
+            size: Vec3::new(2.0, 2.0, 2.0),
+        };
+
+        let transform1 = Transform::from_translation(Vec3::ZERO);
+
+        // Barely touching at the edge
+        let transform2 = Transform::from_translation(Vec3::new(2.0, 0.0, 0.0));
+        assert!(aabb1.intersects(&transform1, &aabb2, &transform2));
+
+        // Clearly overlapping
+        let transform3 = Transform::from_translation(Vec3::new(1.0, 0.0, 0.0));
+        assert!(aabb1.intersects(&transform1, &aabb2, &transform3));
+
+        // Not intersecting
+        let transform4 = Transform::from_translation(Vec3::new(2.1, 0.0, 0.0));
+        assert!(!aabb1.intersects(&transform1, &aabb2, &transform4));
+
+        // One contains another
+        let aabb_small = Collider::Aabb {
+            offset: Vec3::ZERO,
+            size: Vec3::new(1.0, 1.0, 1.0),
+        };
+        let transform_center = Transform::from_translation(Vec3::new(0.5, 0.5, 0.5));
+        assert!(aabb1.intersects(&transform1, &aabb_small, &transform_center));
+    }
+
+    #[test]
+    fn test_sphere_vs_sphere_intersection() {
+        let sphere1 = Collider::Sphere {
+            offset: Vec3::ZERO,
+            radius: 1.0,
+        };
+        let sphere2 = Collider::Sphere {
+            offset: Vec3::ZERO,
+            radius: 1.0,
+        };
+
+        let transform1 = Transform::from_translation(Vec3::ZERO);
+
+        // Barely touching
+        let transform2 = Transform::from_translation(Vec3::new(2.0, 0.0, 0.0));
+        assert!(sphere1.intersects(&transform1, &sphere2, &transform2));
+
+        // Clearly overlapping
+        let transform3 = Transform::from_translation(Vec3::new(1.0, 0.0, 0.0));
+        assert!(sphere1.intersects(&transform1, &sphere2, &transform3));
+
+        // Not intersecting
+        let transform4 = Transform::from_translation(Vec3::new(2.1, 0.0, 0.0));
+        assert!(!sphere1.intersects(&transform1, &sphere2, &transform4));
+
+        // One contains another
+        let sphere_small = Collider::Sphere {
+            offset: Vec3::ZERO,
+            radius: 0.5,
+        };
+        let transform_center = Transform::from_translation(Vec3::new(0.2, 0.0, 0.0));
+        assert!(sphere1.intersects(&transform1, &sphere_small, &transform_center));
+    }
+
+    #[test]
+    fn test_aabb_vs_sphere_intersection() {
+        let aabb = Collider::Aabb {
+            offset: Vec3::ZERO,
+            size: Vec3::new(2.0, 2.0, 2.0),
+        };
+        let sphere = Collider::Sphere {
+            offset: Vec3::ZERO,
+            radius: 1.0,
+        };
+
+        let aabb_transform = Transform::from_translation(Vec3::ZERO);
+
+        // Sphere center inside AABB
+        let sphere_transform_inside = Transform::from_translation(Vec3::new(0.5, 0.5, 0.5));
+        assert!(aabb.intersects(&aabb_transform, &sphere, &sphere_transform_inside));
+
+        // Barely touching at the face
+        let sphere_transform_touch_face = Transform::from_translation(Vec3::new(2.0, 0.0, 0.0));
+        assert!(aabb.intersects(&aabb_transform, &sphere, &sphere_transform_touch_face));
+
+        // Overlapping at the face
+        let sphere_transform_overlap_face = Transform::from_translation(Vec3::new(1.5, 0.0, 0.0));
+        assert!(aabb.intersects(&aabb_transform, &sphere, &sphere_transform_overlap_face));
+
+        // Not intersecting
+        let sphere_transform_no_intersect = Transform::from_translation(Vec3::new(2.1, 0.0, 0.0));
+        assert!(!aabb.intersects(&aabb_transform, &sphere, &sphere_transform_no_intersect));
+
+        // Intersecting at an edge
+        let sphere_transform_edge_intersect = Transform::from_translation(Vec3::new(1.5, 1.5, 0.0));
+        assert!(aabb.intersects(&aabb_transform, &sphere, &sphere_transform_edge_intersect));
+
+        // Intersecting at a corner
+        let sphere_transform_corner_intersect =
+            Transform::from_translation(Vec3::new(1.5, 1.5, 1.5));
+        assert!(aabb.intersects(&aabb_transform, &sphere, &sphere_transform_corner_intersect));
+
+        // Not intersecting at a corner
+        let sphere_transform_corner_no_intersect =
+            Transform::from_translation(Vec3::new(2.0, 2.0, 2.0));
+        assert!(!aabb.intersects(
+            &aabb_transform,
+            &sphere,
+            &sphere_transform_corner_no_intersect
+        ));
+    }
+
+    #[test]
+    fn test_sphere_vs_aabb_intersection() {
+        // This is just the reverse of aabb_vs_sphere, which is handled by the implementation.
+        // We add a simple test for completeness.
+        let aabb = Collider::Aabb {
+            offset: Vec3::ZERO,
+            size: Vec3::new(2.0, 2.0, 2.0),
+        };
+        let sphere = Collider::Sphere {
+            offset: Vec3::ZERO,
+            radius: 1.0,
+        };
+
+        let aabb_transform = Transform::from_translation(Vec3::ZERO);
+
+        // Intersecting
+        let sphere_transform_intersect = Transform::from_translation(Vec3::new(1.5, 0.0, 0.0));
+        assert!(sphere.intersects(&sphere_transform_intersect, &aabb, &aabb_transform));
+
+        // Not intersecting
+        let sphere_transform_no_intersect = Transform::from_translation(Vec3::new(2.1, 0.0, 0.0));
+        assert!(!sphere.intersects(&sphere_transform_no_intersect, &aabb, &aabb_transform));
+    }
+
+    #[test]
+    fn test_capsule_intersections() {
+        // A train car lying along the track, 4 long in all.
+        let capsule = Collider::Capsule {
+            offset: Vec3::ZERO,
+            half_length: 1.5,
+            radius: 0.5,
+        };
+        let transform = Transform::from_translation(Vec3::ZERO);
+        let sphere = Collider::Sphere {
+            offset: Vec3::ZERO,
+            radius: 0.5,
+        };
+
+        // Barely touching the rounded end, and just beyond it.
+        let at = |x, y, z| Transform::from_translation(Vec3::new(x, y, z));
+        assert!(capsule.intersects(&transform, &sphere, &at(0.0, 0.0, 2.5)));
+        assert!(!capsule.intersects(&transform, &sphere, &at(0.0, 0.0, 2.51)));
+
+        // Barely touching the side, anywhere along the axis.
+        assert!(capsule.intersects(&transform, &sphere, &at(1.0, 0.0, 1.5)));
+        assert!(!capsule.intersects(&transform, &sphere, &at(1.01, 0.0, 1.5)));
+
+        // Another capsule end to end, and side by side in the neighbouring lane.
+        assert!(capsule.intersects(&transform, &capsule, &at(0.0, 0.0, 4.0)));
+        assert!(!capsule.intersects(&transform, &capsule, &at(0.0, 0.0, 4.01)));
+        let lane_width = LANE_POSITIONS[1] - LANE_POSITIONS[0];
+        assert!(!capsule.intersects(&transform, &capsule, &at(lane_width, 0.0, 0.0)));
+
+        // A box just past the rounded end, which its corner would reach if the end were square.
+        let aabb = Collider::Aabb {
+            offset: Vec3::ZERO,
+            size: Vec3::splat(1.0),
+        };
+        assert!(capsule.intersects(&transform, &aabb, &at(0.0, 0.0, 2.5)));
+        assert!(!capsule.intersects(&transform, &aabb, &at(0.9, 0.9, 2.4)));
+        assert!(capsule.intersects(&transform, &aabb, &at(0.9, 0.0, 1.5)));
+    }
+
+    // The player's train and a stone, as they are spawned in game.
+    const PLAYER: Collider = Collider::Aabb {
+        offset: Vec3::new(0.0, 0.5, -1.5),
+        size: Vec3::new(0.9, 1.0, 3.6),
+    };
+    const STONE: Collider = Collider::Sphere {
+        offset: Vec3::ZERO,
+        radius: 1.0,
+    };
+
+    #[test]
+    fn test_stone_in_neighbouring_lane() {
+        let stone_transform = Transform::from_xyz(LANE_POSITIONS[2], 0.0, -1.5);
+
+        // In the middle lane.
+        let player_transform = Transform::from_xyz(LANE_POSITIONS[1], 0.0, 0.0);
+        assert!(!PLAYER.intersects(&player_transform, &STONE, &stone_transform));
+
+        // Halfway through a lane switch towards the stone.
+        let halfway = (LANE_POSITIONS[1] + LANE_POSITIONS[2]) * 0.5;
+        let player_transform = Transform::from_xyz(halfway, 0.0, 0.0);
+        assert!(!PLAYER.intersects(&player_transform, &STONE, &stone_transform));
+
+        // The side of the train exactly touches the stone.
+        let touching = LANE_POSITIONS[2] - 1.0 - 0.45;
+        let player_transform = Transform::from_xyz(touching, 0.0, 0.0);
+        assert!(PLAYER.intersects(&player_transform, &STONE, &stone_transform));
+        assert!(STONE.intersects(&stone_transform, &PLAYER, &player_transform));
+
+        // Just short of touching it.
+        let player_transform = Transform::from_xyz(touching - 0.01, 0.0, 0.0);
+        assert!(!PLAYER.intersects(&player_transform, &STONE, &stone_transform));
+        assert!(!STONE.intersects(&stone_transform, &PLAYER, &player_transform));
+    }
+
+    #[test]
+    fn test_jumping_over_stone() {
+        let stone_transform = Transform::from_xyz(LANE_POSITIONS[1], 0.0, -1.5);
+
+        // The bottom of the train grazes the top of the stone.
+        let player_transform = Transform::from_xyz(LANE_POSITIONS[1], 1.0, 0.0);
+        assert!(PLAYER.intersects(&player_transform, &STONE, &stone_transform));
+
+        // The train clears the stone.
+        let player_transform = Transform::from_xyz(LANE_POSITIONS[1], 1.01, 0.0);
+        assert!(!PLAYER.intersects(&player_transform, &STONE, &stone_transform));
+    }
+
+    #[test]
+    fn test_aerial_pickup_at_jump_apex() {
+        // A bell floating at jump height, as it is spawned in game.
+        const BELL: Collider = Collider::Aabb {
+            offset: Vec3::ZERO,
+            size: Vec3::splat(0.5),
+        };
+        let bell_transform = Transform::from_xyz(LANE_POSITIONS[1], 0.5 + JUMP_APEX_HEIGHT, -1.5);
+
+        // The train passes under it on the rails.
+        let player_transform = Transform::from_xyz(LANE_POSITIONS[1], 0.0, 0.0);
+        assert!(!PLAYER.intersects(&player_transform, &BELL, &bell_transform));
+
+        // The train collects it at the top of a jump.
+        let player_transform = Transform::from_xyz(LANE_POSITIONS[1], JUMP_APEX_HEIGHT, 0.0);
+        assert!(PLAYER.intersects(&player_transform, &BELL, &bell_transform));
+
+        // The train still reaches it well before the apex.
+        let player_transform = Transform::from_xyz(LANE_POSITIONS[1], JUMP_APEX_HEIGHT - 0.75, 0.0);
+        assert!(PLAYER.intersects(&player_transform, &BELL, &bell_transform));
+    }
+
+    // --- PROPERTY TESTS ---
+
+    fn vec3(range: f32) -> impl Strategy<Value = Vec3> {
+        (-range..range, -range..range, -range..range).prop_map(|(x, y, z)| Vec3::new(x, y, z))
+    }
+
+    fn aabb() -> impl Strategy<Value = Collider> {
+        (vec3(2.0), 0.1f32..4.0, 0.1f32..4.0, 0.1f32..4.0).prop_map(|(offset, x, y, z)| {
+            Collider::Aabb {
+                offset,
+                size: Vec3::new(x, y, z),
+            }
+        })
+    }
+
+    fn sphere() -> impl Strategy<Value = Collider> {
+        (vec3(2.0), 0.1f32..2.0).prop_map(|(offset, radius)| Collider::Sphere { offset, radius })
+    }
+
+    fn capsule() -> impl Strategy<Value = Collider> {
+        (vec3(2.0), 0.0f32..3.0, 0.1f32..2.0).prop_map(|(offset, half_length, radius)| {
+            Collider::Capsule {
+                offset,
+                half_length,
+                radius,
+            }
+        })
+    }
+
+    fn collider() -> impl Strategy<Value = Collider> {
+        prop_oneof![aabb(), sphere(), capsule()]
+    }
+
+    /// Returns the center of the collider and the radius of a sphere enclosing it.
+    fn bounds(collider: &Collider, transform: &Transform) -> (Vec3, f32) {
+        match collider {
+            Collider::Aabb { offset, size } => {
+                (transform.translation + *offset, (*size * 0.5).length())
+            }
+            Collider::Sphere { offset, radius } => (transform.translation + *offset, *radius),
+            Collider::Capsule {
+                offset,
+                half_length,
+                radius,
+            } => (transform.translation + *offset, half_length + radius),
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn prop_intersects_is_symmetric(
+            a in collider(),
+            b in collider(),
+            a_pos in vec3(10.0),
+            b_pos in vec3(10.0),
+        ) {
+            let a_transform = Transform::from_translation(a_pos);
+            let b_transform = Transform::from_translation(b_pos);
+            prop_assert_eq!(
+                a.intersects(&a_transform, &b, &b_transform),
+                b.intersects(&b_transform, &a, &a_transform)
+            );
+        }
+
+        #[test]
+        fn prop_aabb_contains_points_inside(
+            offset in vec3(2.0),
+            size in vec3(4.0),
+            pos in vec3(10.0),
+            fraction in vec3(0.5),
+        ) {
+            let aabb = Collider::Aabb { offset, size: size.abs() };
+            let point = Collider::Sphere { offset: Vec3::ZERO, radius: 0.0 };
+            let transform = Transform::from_translation(pos);
+            let point_transform =
+                Transform::from_translation(pos + offset + size.abs() * fraction);
+            prop_assert!(aabb.intersects(&transform, &point, &point_transform));
+        }
+
+        #[test]
+        fn prop_collider_contains_its_center(a in collider(), pos in vec3(10.0)) {
+            let transform = Transform::from_translation(pos);
+            let (center, _) = bounds(&a, &transform);
+            let point = Collider::Sphere { offset: Vec3::ZERO, radius: 0.0 };
+            prop_assert!(a.intersects(&transform, &point, &Transform::from_translation(center)));
+        }
+
+        #[test]
+        fn prop_distant_colliders_do_not_intersect(
+            a in collider(),
+            b in collider(),
+            a_pos in vec3(10.0),
+            b_pos in vec3(10.0),
+        ) {
+            let a_transform = Transform::from_translation(a_pos);
+            let b_transform = Transform::from_translation(b_pos);
+            let (a_center, a_radius) = bounds(&a, &a_transform);
+            let (b_center, b_radius) = bounds(&b, &b_transform);
+            prop_assume!(a_center.distance(b_center) > a_radius + b_radius + 1e-3);
+            prop_assert!(!a.intersects(&a_transform, &b, &b_transform));
+        }
+    }
+}
@@ -0,0 +1,273 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use rand::{
+    Rng,
+    distr::{Distribution, StandardUniform},
+};
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// Makes an obstacle sway between its lane and a neighbouring lane as it approaches.
+/// It keeps to its lane until it comes within [`WANDERING_STONE_DRIFT_DISTANCE`] of the player.
+#[derive(Debug, Clone, Copy, Component)]
+pub struct LateralDrift {
+    from: f32,
+    to: f32,
+    phase: f32,
+}
+
+impl LateralDrift {
+    pub fn new(from_lane: usize, to_lane: usize) -> Self {
+        Self {
+            from: LANE_POSITIONS[from_lane],
+            to: LANE_POSITIONS[to_lane],
+            phase: 0.0,
+        }
+    }
+
+    /// Advances the sway, given how far ahead of the player the obstacle is.
+    pub fn on_advanced(&mut self, elapsed: f32, distance: f32) {
+        if distance > WANDERING_STONE_DRIFT_DISTANCE {
+            return;
+        }
+        self.phase = (self.phase + WANDERING_STONE_ANGULAR_SPEED * elapsed) % TAU;
+    }
+
+    /// Returns the current x position. It eases back and forth, so it never leaves the two lanes.
+    pub fn x(&self) -> f32 {
+        let t = 0.5 * (1.0 - self.phase.cos());
+        self.from + (self.to - self.from) * t
+    }
+
+    /// Returns the lane nearest to the current x position.
+    pub fn nearest_lane(&self) -> usize {
+        let x = self.x();
+        (0..NUM_LANES)
+            .min_by(|&a, &b| {
+                let da = (LANE_POSITIONS[a] - x).abs();
+                let db = (LANE_POSITIONS[b] - x).abs();
+                da.total_cmp(&db)
+            })
+            .unwrap()
+    }
+
+    /// Returns the x range the obstacle sways over.
+    pub fn range(&self) -> (f32, f32) {
+        (self.from.min(self.to), self.from.max(self.to))
+    }
+}
+
+#[derive(Component)]
+pub struct Player;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Component, Deserialize, Serialize)]
+pub enum Object {
+    #[default]
+    Barricade,
+    Stone,
+    Fuel,
+    Bell,
+    Aoba,
+    Shield,
+    Clock,
+}
+
+impl Object {
+    /// Returns `true` for objects the player collects, as opposed to obstacles.
+    pub fn is_pickup(&self) -> bool {
+        matches!(
+            self,
+            Self::Fuel | Self::Bell | Self::Aoba | Self::Shield | Self::Clock
+        )
+    }
+}
+
+#[derive(Component)]
+pub struct Lane {
+    index: usize,
+}
+
+impl Lane {
+    pub fn new(index: usize) -> Self {
+        Self { index }
+    }
+
+    pub fn get(&self) -> usize {
+        self.index
+    }
+
+    pub fn inc(&mut self) {
+        self.index = (self.index + 1).min(MAX_LANE_INDEX);
+    }
+
+    pub fn dec(&mut self) {
+        self.index = self.index.saturating_sub(1);
+    }
+
+    /// Returns how much of this lane (0.0 to 1.0) is occupied by an entity at the given x position.
+    ///
+    /// The occupancy falls off linearly towards the neighbouring lane the entity is moving to,
+    /// so an entity halfway between two lanes occupies each of them by `0.5`.
+    pub fn occupancy(&self, x: f32) -> f32 {
+        let center = LANE_POSITIONS[self.index];
+        let neighbor = if x >= center {
+            Some(self.index + 1).filter(|&i| i <= MAX_LANE_INDEX)
+        } else {
+            self.index.checked_sub(1)
+        };
+
+        match neighbor {
+            Some(i) => {
+                let width = (LANE_POSITIONS[i] - center).abs();
+                (1.0 - (x - center).abs() / width).clamp(0.0, 1.0)
+            }
+            None => 1.0,
+        }
+    }
+}
+
+impl Default for Lane {
+    fn default() -> Self {
+        Self {
+            index: MAX_LANE_INDEX / 2,
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct ForwardMovement(f32);
+
+impl ForwardMovement {
+    pub fn new(velocity: f32) -> Self {
+        Self(velocity)
+    }
+
+    pub fn get(&self) -> f32 {
+        self.0
+    }
+
+    pub fn set(&mut self, velocity: f32) {
+        self.0 = velocity;
+    }
+}
+
+#[derive(Component)]
+pub struct VerticalMovement(f32);
+
+impl VerticalMovement {
+    pub fn new(velocity: f32) -> Self {
+        Self(velocity)
+    }
+
+    pub fn get(&self) -> f32 {
+        self.0
+    }
+
+    pub fn set(&mut self, velocity: f32) {
+        self.0 = velocity;
+    }
+
+    /// Returns the time, in seconds, until an entity at the given height lands on the ground under gravity.
+    pub fn time_to_land(&self, height: f32) -> f32 {
+        // Solves `height + velocity * t + GRAVITY * t^2 / 2 = 0` for the positive root.
+        let discriminant = (self.0 * self.0 - 2.0 * GRAVITY * height).max(0.0);
+        ((-self.0 - discriminant.sqrt()) / GRAVITY).max(0.0)
+    }
+}
+
+/// The air-dash of the player, which shifts it a lane at once while airborne.
+#[derive(Default, Component)]
+pub struct AirDash {
+    cooldown: f32,
+    /// Set when a dash starts, until the player is moved onto the new lane.
+    snap: bool,
+}
+
+impl AirDash {
+    pub fn is_ready(&self) -> bool {
+        self.cooldown <= 0.0
+    }
+
+    pub fn start(&mut self) {
+        self.cooldown = AIR_DASH_COOLDOWN;
+        self.snap = true;
+    }
+
+    /// Returns whether a dash started since the last call, so the player skips easing into its lane.
+    pub fn take_snap(&mut self) -> bool {
+        std::mem::take(&mut self.snap)
+    }
+
+    pub fn on_advanced(&mut self, elapsed: f32) {
+        self.cooldown = (self.cooldown - elapsed).max(0.0);
+    }
+
+    /// How far the dash has recharged, from 0.0 right after a dash to 1.0 once it is ready.
+    pub fn charge(&self) -> f32 {
+        1.0 - self.cooldown / AIR_DASH_COOLDOWN
+    }
+}
+
+#[derive(Component)]
+
+pub struct Acceleration(f32);
+
+impl Acceleration {
+    pub fn new(amount: f32) -> Self {
+        Self(amount)
+    }
+
+    pub fn get(&self) -> f32 {
+        self.0
+    }
+}
+
+/// The spawn pattern that placed an obstacle, as an index into `BARRICADE_POSITION_INDICES`
+/// or `STONE_POSITION_INDICES`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component)]
+pub struct SpawnPattern(pub usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Component)]
+pub enum Tok9Train {
+    Blue,
+    Orange,
+}
+
+impl Distribution<Tok9Train> for StandardUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Tok9Train {
+        let index = rng.random_range(0..=1);
+        match index {
+            0 => Tok9Train::Blue,
+            1 => Tok9Train::Orange,
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lateral_drift_waits_for_the_player_to_come_close() {
+        let mut drift = LateralDrift::new(0, 1);
+        drift.on_advanced(0.5, WANDERING_STONE_DRIFT_DISTANCE + 1.0);
+        assert_eq!(drift.x(), LANE_POSITIONS[0]);
+        assert_eq!(drift.nearest_lane(), 0);
+
+        drift.on_advanced(0.5, WANDERING_STONE_DRIFT_DISTANCE);
+        assert!(drift.x() != LANE_POSITIONS[0]);
+    }
+
+    #[test]
+    fn lateral_drift_stays_between_its_lanes() {
+        let mut drift = LateralDrift::new(2, 1);
+        let (min, max) = drift.range();
+        for _ in 0..100 {
+            drift.on_advanced(0.1, 0.0);
+            assert!((min..=max).contains(&drift.x()));
+        }
+    }
+}
@@ -0,0 +1,315 @@
+use std::{f32::consts::PI, ops::RangeInclusive};
+
+use bevy::{platform::collections::HashMap, prelude::*};
+use lazy_static::lazy_static;
+use rand::distr::weighted::WeightedIndex;
+
+use super::{collider::Collider, *};
+
+pub const NUM_LANES: usize = 3;
+pub const MAX_LANE_INDEX: usize = NUM_LANES - 1;
+pub const LANE_POSITIONS: [f32; NUM_LANES] = [-3.0, 0.25, 3.5];
+
+pub const SCORE_LIMITS: u32 = 999_999;
+pub const FUEL_LIMITS: f32 = 100.0;
+pub const INPUT_DELAY_TIME: f32 = 0.25;
+pub const POINT_PER_DIST: f32 = 1.0;
+
+pub const SPAWN_POSITION: f32 = 100.0;
+pub const DESPAWN_POSITION: f32 = -100.0;
+/// The spawn and despawn positions of the mobile tier, which keeps fewer objects on the track.
+pub const MOBILE_SPAWN_POSITION: f32 = 60.0;
+pub const MOBILE_DESPAWN_POSITION: f32 = -40.0;
+pub const GROUND_SPAWN_INTERVAL: f32 = 30.0;
+/// The distance between two splits of a pace curve, a little over two seconds at the lowest speed.
+pub const PACE_SPLIT_DISTANCE: f32 = 50.0;
+
+pub const MIN_PLAYER_SPEED: f32 = 20.0;
+pub const MAX_PLAYER_SPEED: f32 = 27.5;
+pub const LANE_SWITCH_SPEED: f32 = 5.0;
+/// The time, in seconds, after an air-dash before the player can dash again.
+pub const AIR_DASH_COOLDOWN: f32 = 1.5;
+/// The most the toy trains roll into a lane change, in radians.
+pub const MAX_BANK_ANGLE: f32 = 10f32.to_radians();
+pub const MIN_LANE_OVERLAP_RATIO: f32 = 0.5;
+pub const INVINCIBLE_SPEED: f32 = 2.0 * MAX_PLAYER_SPEED;
+pub const ACCELERATION: f32 = (MAX_PLAYER_SPEED - MIN_PLAYER_SPEED) / 30.0;
+pub const JUMP_STRENGTH: f32 = 12.5;
+pub const GRAVITY: f32 = -30.0;
+/// The highest the train rises above the rails during a jump.
+pub const JUMP_APEX_HEIGHT: f32 = JUMP_STRENGTH * JUMP_STRENGTH / (-2.0 * GRAVITY);
+pub const FUEL_USAGE: f32 = 100.0 / 20.0;
+
+/// The speed the world scrolls at in assist mode. The player's own controls keep their pace.
+pub const ASSIST_GAME_SPEED: f32 = 0.8;
+pub const ASSIST_FUEL_USAGE_RATIO: f32 = 0.5;
+/// How long, in seconds, assist mode keeps a lane switch pressed before the input delay has expired.
+pub const ASSIST_INPUT_BUFFER_TIME: f32 = 0.15;
+
+/// How much faster the world scrolls with the double speed mutator.
+pub const DOUBLE_SPEED_RATIO: f32 = 2.0;
+pub const DOUBLE_SPEED_MULTIPLIER: f32 = 1.5;
+pub const ONE_HIT_FUEL_MULTIPLIER: f32 = 2.0;
+pub const MIRROR_LANES_MULTIPLIER: f32 = 1.25;
+pub const NO_FUEL_PICKUPS_MULTIPLIER: f32 = 1.5;
+pub const HARDCORE_MULTIPLIER: f32 = 2.5;
+/// Hardcore runs place a fuel pickup in only one of this many fuel slots.
+pub const HARDCORE_FUEL_SLOT_INTERVAL: u32 = 3;
+
+pub const ATTACKED_DURATION: f32 = 3.0;
+pub const INVINCIBLE_DURATION: f32 = 8.0;
+
+pub const TOK9_TRAIN_DAMAGE: f32 = 50.0;
+pub const BARRICADE_DAMAGE: f32 = 20.0;
+pub const STONE_DAMAGE: f32 = 30.0;
+
+/// The distance the player must travel before stones may start to sway between lanes.
+pub const WANDERING_STONE_MIN_DISTANCE: f32 = 1500.0;
+/// The chance that a lone stone sways between lanes once they may appear.
+pub const WANDERING_STONE_CHANCE: f64 = 0.4;
+pub const WANDERING_STONE_ANGULAR_SPEED: f32 = PI * 0.8;
+/// How close to the player, along the track, a wandering stone comes before it starts to sway.
+pub const WANDERING_STONE_DRIFT_DISTANCE: f32 = 45.0;
+pub const FUEL_HEALING: f32 = 30.0;
+pub const BELL_POINT: u32 = 500;
+
+/// How far, in seconds, a beat bell may be collected from its beat and still count as on the beat.
+pub const RHYTHM_BEAT_WINDOW: f32 = 0.12;
+/// The multiplier gained by each bell collected on the beat after the first.
+pub const RHYTHM_MULTIPLIER_STEP: f32 = 0.25;
+pub const MAX_RHYTHM_MULTIPLIER: f32 = 3.0;
+
+/// The bells spent to throw a toy whistle.
+pub const WHISTLE_COST: u32 = 5;
+
+/// How long hits are still ignored after the shield absorbed one, so the player can clear the obstacle.
+pub const SHIELD_GRACE_DURATION: f32 = 0.75;
+
+/// How long the world scrolls slower after a clock is collected.
+pub const CLOCK_DURATION: f32 = 5.0;
+pub const CLOCK_TIME_SCALE: f32 = 0.5;
+/// The time over which the slow motion eases out before it ends.
+pub const CLOCK_BLEND_TIME: f32 = 0.5;
+/// The scale of time at the slowest of the coast that ends a run.
+pub const COAST_TIME_SCALE: f32 = 0.2;
+
+/// The number of cars that can be bought in addition to the default train.
+pub const MAX_EXTRA_TRAIN_CARS: usize = 2;
+/// The bells needed to buy each extra car, in the order they are bought.
+pub const TRAIN_CAR_COSTS: [u32; MAX_EXTRA_TRAIN_CARS] = [30, 60];
+/// The distance between the couplings of two passenger cars.
+pub const TRAIN_CAR_SPACING: f32 = 1.5;
+
+pub const NUM_OBJECTS: usize = 7;
+pub const OBJECT_SPAWN_INTERVAL: f32 = 25.0;
+pub const OBJECT_SPAWN_OFFSET: RangeInclusive<f32> = -5.0..=5.0;
+pub const OBJECT_LIST: [Object; NUM_OBJECTS] = [
+    Object::Barricade,
+    Object::Stone,
+    Object::Fuel,
+    Object::Bell,
+    Object::Aoba,
+    Object::Shield,
+    Object::Clock,
+];
+
+pub const NUM_TOK9_TRAINS: usize = 2;
+pub const TOK9_TRAIN_CYCLE: f32 = 20.0;
+pub const TOK9_TRAIN_INIT_CYCLE: f32 = 30.0;
+pub const TOK9_TRAIN_OFFSET: RangeInclusive<f32> = -15.0..=10.0;
+pub const TOK9_TRAIN_SPEED: f32 = (SPAWN_POSITION - DESPAWN_POSITION) / 1.25;
+
+/// How long, in seconds, patterns blocking every lane stay off the track after invincibility ends.
+pub const WALL_GRACE_DURATION: f32 = 1.5;
+/// How many times an obstacle pattern with an excluded tag is rolled again before it is kept anyway.
+pub const MAX_PATTERN_REROLLS: usize = 4;
+
+pub const NUM_BARRICADE_POSITIONS: usize = 7;
+pub const NUM_STONE_POSITIONS: usize = 7;
+pub const NUM_FUEL_POSITIONS: usize = 6;
+pub const NUM_BELL_POSITIONS: usize = 9;
+pub const NUM_AOBA_POSITIONS: usize = 6;
+pub const NUM_SHIELD_POSITIONS: usize = 3;
+pub const NUM_CLOCK_POSITIONS: usize = 3;
+pub const NUM_TOK9_TRAIN_POSITIONS: usize = 6;
+
+/// The number of bells laid along the path of a jump in a bell arc.
+pub const NUM_BELL_ARC_BELLS: usize = 5;
+/// How far along the track a bell arc stretches, about the distance covered by a jump at the lowest speed.
+pub const BELL_ARC_LENGTH: f32 = 16.0;
+
+lazy_static! {
+    pub static ref OBJECT_COLLIDER: HashMap<Object, Collider> = {
+        let map: HashMap<_, _> = [
+            (
+                Object::Barricade,
+                Collider::Aabb {
+                    offset: Vec3::new(0.0, 0.5, 0.0),
+                    size: Vec3::splat(1.0),
+                },
+            ),
+            (
+                Object::Stone,
+                Collider::Sphere {
+                    offset: Vec3::splat(0.0),
+                    radius: 1.0,
+                },
+            ),
+            (
+                Object::Fuel,
+                Collider::Aabb {
+                    offset: Vec3::new(0.0, 0.0, 0.0),
+                    size: Vec3::splat(0.5),
+                },
+            ),
+            (
+                Object::Bell,
+                Collider::Aabb {
+                    offset: Vec3::new(0.0, 0.0, 0.0),
+                    size: Vec3::splat(0.5),
+                },
+            ),
+            (
+                Object::Aoba,
+                Collider::Aabb {
+                    offset: Vec3::new(0.0, 0.5, 0.0),
+                    size: Vec3::new(0.5, 1.0, 0.5),
+                },
+            ),
+            (
+                Object::Shield,
+                Collider::Aabb {
+                    offset: Vec3::new(0.0, 0.0, 0.0),
+                    size: Vec3::splat(0.5),
+                },
+            ),
+            (
+                Object::Clock,
+                Collider::Aabb {
+                    offset: Vec3::new(0.0, 0.0, 0.0),
+                    size: Vec3::splat(0.5),
+                },
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        assert!(map.len() == NUM_OBJECTS);
+        map
+    };
+}
+
+lazy_static! {
+    pub static ref TOK9_TRAIN_COLLIDER: HashMap<Tok9Train, Collider> = {
+        let map: HashMap<_, _> = [
+            (
+                Tok9Train::Blue,
+                Collider::Aabb {
+                    offset: Vec3::new(0.0, 1.5, 16.725),
+                    size: Vec3::new(2.0, 3.0, 44.0),
+                },
+            ),
+            (
+                Tok9Train::Orange,
+                Collider::Aabb {
+                    offset: Vec3::new(0.0, 1.5, 20.5),
+                    size: Vec3::new(2.0, 3.0, 49.0),
+                },
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        assert!(map.len() == NUM_TOK9_TRAINS);
+        map
+    };
+}
+
+lazy_static! {
+    pub static ref SPAWN_WEIGHTS: WeightedIndex<u32> = {
+        const WEIGHTS: [u32; NUM_OBJECTS] = [400, 300, 200, 95, 5, 15, 10];
+        WeightedIndex::new(WEIGHTS).unwrap()
+    };
+    pub static ref BARRICADE_WEIGHTS: WeightedIndex<u32> = {
+        const WEIGHTS: [u32; NUM_BARRICADE_POSITIONS] = [3, 3, 2, 3, 2, 2, 1];
+        WeightedIndex::new(WEIGHTS).unwrap()
+    };
+    pub static ref STONE_WEIGHTS: WeightedIndex<u32> = {
+        const WEIGHTS: [u32; NUM_STONE_POSITIONS] = [3, 3, 2, 3, 2, 2, 1];
+        WeightedIndex::new(WEIGHTS).unwrap()
+    };
+    pub static ref TOK9_TRAIN_WEIGHTS: WeightedIndex<u32> = {
+        const WEIGHTS: [u32; NUM_TOK9_TRAIN_POSITIONS] = [5, 5, 1, 5, 1, 1];
+        WeightedIndex::new(WEIGHTS).unwrap()
+    };
+    pub static ref FUEL_WEIGHTS: WeightedIndex<u32> = {
+        const WEIGHTS: [u32; NUM_FUEL_POSITIONS] = [3, 3, 3, 1, 1, 1];
+        WeightedIndex::new(WEIGHTS).unwrap()
+    };
+    pub static ref BELL_WEIGHTS: WeightedIndex<u32> = {
+        const WEIGHTS: [u32; NUM_BELL_POSITIONS] = [3, 3, 3, 1, 1, 1, 1, 1, 1];
+        WeightedIndex::new(WEIGHTS).unwrap()
+    };
+    pub static ref AOBA_WEIGHTS: WeightedIndex<u32> = {
+        const WEIGHTS: [u32; NUM_AOBA_POSITIONS] = [3, 3, 3, 1, 1, 1];
+        WeightedIndex::new(WEIGHTS).unwrap()
+    };
+}
+
+lazy_static! {
+    #[rustfmt::skip]
+    pub static ref BARRICADE_POSITION_INDICES: [Vec<usize>; NUM_BARRICADE_POSITIONS] = [
+        vec![0], vec![1], vec![0, 1], vec![2], vec![0, 2], vec![1, 2], vec![0, 1, 2],
+    ];
+
+    #[rustfmt::skip]
+    pub static ref STONE_POSITION_INDICES: [Vec<usize>; NUM_STONE_POSITIONS] = [
+        vec![0], vec![1], vec![0, 1], vec![2], vec![0, 2], vec![1, 2], vec![0, 1, 2],
+    ];
+
+    #[rustfmt::skip]
+    pub static ref TOK9_TRAIN_POSITION_INDICES: [Vec<usize>; NUM_TOK9_TRAIN_POSITIONS] = [
+        vec![0], vec![1], vec![0, 1], vec![2], vec![0, 2], vec![1, 2]
+    ];
+
+    /// Single bells on the rails or at jump height, then arcs of bells that trace a jump in each lane.
+    pub static ref BELL_POSITIONS: [Vec<PickupPosition>; NUM_BELL_POSITIONS] = {
+        let arc = |lane: usize| -> Vec<PickupPosition> {
+            (0..NUM_BELL_ARC_BELLS)
+                .map(|i| {
+                    let t = i as f32 / (NUM_BELL_ARC_BELLS - 1) as f32;
+                    PickupPosition {
+                        lane,
+                        height: 4.0 * JUMP_APEX_HEIGHT * t * (1.0 - t),
+                        depth: t * BELL_ARC_LENGTH,
+                    }
+                })
+                .collect()
+        };
+
+        [
+            vec![PickupPosition::ground(0)],
+            vec![PickupPosition::ground(1)],
+            vec![PickupPosition::ground(2)],
+            vec![PickupPosition::aerial(0)],
+            vec![PickupPosition::aerial(1)],
+            vec![PickupPosition::aerial(2)],
+            arc(0),
+            arc(1),
+            arc(2),
+        ]
+    };
+}
+
+#[rustfmt::skip]
+pub const FUEL_POSITIONS: [PickupPosition; NUM_FUEL_POSITIONS] = [
+    PickupPosition::ground(0), PickupPosition::ground(1), PickupPosition::ground(2),
+    PickupPosition::aerial(0), PickupPosition::aerial(1), PickupPosition::aerial(2),
+];
+#[rustfmt::skip]
+pub const AOBA_POSITIONS: [PickupPosition; NUM_AOBA_POSITIONS] = [
+    PickupPosition::ground(0), PickupPosition::ground(1), PickupPosition::ground(2),
+    PickupPosition::aerial(0), PickupPosition::aerial(1), PickupPosition::aerial(2),
+];
+pub const SHIELD_POSITION_INDICES: [usize; NUM_SHIELD_POSITIONS] = [0, 1, 2];
+pub const CLOCK_POSITION_INDICES: [usize; NUM_CLOCK_POSITIONS] = [0, 1, 2];
@@ -0,0 +1,16 @@
+//! The gameplay rules of Shupogaki: collision, the components of the moving objects,
+//! the resources that track a run, such as the score and the fuel, the schedules that place
+//! the objects on the track, and the systems that advance them.
+//!
+//! Nothing in this crate renders, plays audio or loads assets, so it can be used
+//! by the client as well as by headless tools.
+
+pub mod collider;
+mod components;
+mod constants;
+mod path_check;
+mod resources;
+mod spawner;
+mod systems;
+
+pub use self::{components::*, constants::*, path_check::*, resources::*, spawner::*, systems::*};
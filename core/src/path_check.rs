@@ -0,0 +1,216 @@
+use super::{collider::Collider, *};
+
+/// The time, in seconds, the player is given on top of the moves a row of obstacles asks for.
+const REACTION_MARGIN: f32 = 0.1;
+
+/// How far a collider reaches up, down and along the track from the entity it is on.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Extent {
+    bottom: f32,
+    top: f32,
+    length: f32,
+}
+
+impl Extent {
+    fn of(collider: &Collider) -> Self {
+        match *collider {
+            Collider::Aabb { offset, size } => Self {
+                bottom: offset.y - size.y * 0.5,
+                top: offset.y + size.y * 0.5,
+                length: size.z,
+            },
+            Collider::Sphere { offset, radius } => Self {
+                bottom: offset.y - radius,
+                top: offset.y + radius,
+                length: 2.0 * radius,
+            },
+            Collider::Capsule {
+                offset,
+                half_length,
+                radius,
+            } => Self {
+                bottom: offset.y - radius,
+                top: offset.y + radius,
+                length: 2.0 * (half_length + radius),
+            },
+        }
+    }
+}
+
+/// Follows the lanes the player can be in as the rows of obstacles reach it,
+/// so that a row no lane change or jump could get past is never placed.
+///
+/// The player is taken to keep its current speed, to step one lane per input delay,
+/// and to need its train above an obstacle for the whole time it passes over it.
+/// The times are counted from the moment the last row reaches the front of the train.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PathCheck {
+    /// The earliest time the player can be back on the rails in each lane,
+    /// or `None` if it cannot be in the lane when the last row reaches it.
+    landing: [Option<f32>; NUM_LANES],
+    /// The time the train takes to pass the last row, during which it cannot change lanes.
+    passing: f32,
+    /// The distance the track has moved since the last row was placed.
+    since_row: f32,
+    speed: f32,
+    train: Extent,
+    /// The time between two lane steps.
+    lane_step: f32,
+    /// The time a lane step takes to leave the lane behind enough not to be hit in it.
+    lane_leave: f32,
+}
+
+impl PathCheck {
+    /// Creates a check for a player on the rails, with no row placed yet.
+    pub fn new(config: &GameplayConfig, train: &Collider) -> Self {
+        Self {
+            landing: [Some(f32::NEG_INFINITY); NUM_LANES],
+            passing: 0.0,
+            since_row: f32::INFINITY,
+            speed: MIN_PLAYER_SPEED,
+            train: Extent::of(train),
+            lane_step: config.input_delay,
+            lane_leave: config.min_lane_overlap.recip().ln() / LANE_SWITCH_SPEED,
+        }
+    }
+
+    /// Moves the track by the distance the player travelled at its current speed.
+    pub fn on_advanced(&mut self, speed: f32, elapsed: f32) {
+        self.speed = speed;
+        self.since_row += speed * elapsed;
+    }
+
+    /// Checks if the player can be in at least one lane when the last row reaches it.
+    pub fn is_passable(&self) -> bool {
+        self.landing.iter().any(Option::is_some)
+    }
+
+    /// Returns the check once a row of `obstacle`s placed in `lanes` has been added,
+    /// `ahead` units further along the track than where the last row was placed from.
+    pub fn after_row(&self, lanes: &[usize], obstacle: &Collider, ahead: f32) -> Self {
+        let obstacle = Extent::of(obstacle);
+        let gap = (self.since_row + ahead) / self.speed;
+        let jump = self.jump_over(&obstacle);
+
+        let mut landing = [None; NUM_LANES];
+        for (lane, landing) in landing.iter_mut().enumerate() {
+            let blocked = lanes.contains(&lane);
+            *landing = self
+                .landing
+                .iter()
+                .enumerate()
+                .filter_map(|(from, landed)| {
+                    let landed = (*landed)?;
+                    // Lanes can be changed in the air, but not while passing the last row.
+                    let moved = self.passing + self.lane_change(from.abs_diff(lane));
+                    if moved + REACTION_MARGIN > gap {
+                        return None;
+                    }
+                    match blocked {
+                        false => Some(landed - gap),
+                        true => {
+                            let (lead, airtime) = jump?;
+                            (landed + REACTION_MARGIN <= gap - lead).then_some(airtime - lead)
+                        }
+                    }
+                })
+                .reduce(f32::min);
+        }
+
+        Self {
+            landing,
+            passing: (self.train.length + obstacle.length) / self.speed,
+            since_row: -ahead,
+            ..*self
+        }
+    }
+
+    /// The time it takes to move the given number of lanes.
+    fn lane_change(&self, lanes: usize) -> f32 {
+        match lanes {
+            0 => 0.0,
+            _ => (lanes - 1) as f32 * self.lane_step + lanes as f32 * self.lane_leave,
+        }
+    }
+
+    /// Returns how long before an obstacle reaches the train it must jump to clear it,
+    /// and how long the jump lasts, or `None` if it cannot clear the obstacle at its speed.
+    /// The jump is timed to land as early as it can.
+    fn jump_over(&self, obstacle: &Extent) -> Option<(f32, f32)> {
+        let height = obstacle.top - self.train.bottom;
+        let discriminant = JUMP_STRENGTH * JUMP_STRENGTH + 2.0 * GRAVITY * height;
+        if discriminant <= 0.0 {
+            return None;
+        }
+
+        // The times after the takeoff between which the train is above the obstacle.
+        let rise = (JUMP_STRENGTH - discriminant.sqrt()) / -GRAVITY;
+        let fall = (JUMP_STRENGTH + discriminant.sqrt()) / -GRAVITY;
+        let passing = (self.train.length + obstacle.length) / self.speed;
+        let airtime = 2.0 * JUMP_STRENGTH / -GRAVITY;
+        (fall - rise >= passing).then_some((fall - passing, airtime))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use super::*;
+
+    const TRAIN: Collider = Collider::Aabb {
+        offset: Vec3::new(0.0, 0.5, -1.5),
+        size: Vec3::new(0.9, 1.0, 3.6),
+    };
+    const BARRICADE: Collider = Collider::Aabb {
+        offset: Vec3::new(0.0, 0.5, 0.0),
+        size: Vec3::splat(1.0),
+    };
+    /// An obstacle taller than the highest jump.
+    const TOWER: Collider = Collider::Aabb {
+        offset: Vec3::new(0.0, JUMP_APEX_HEIGHT, 0.0),
+        size: Vec3::new(1.0, 2.0 * JUMP_APEX_HEIGHT, 1.0),
+    };
+    const WALL: [usize; NUM_LANES] = [0, 1, 2];
+
+    fn check(speed: f32) -> PathCheck {
+        let mut check = PathCheck::new(&GameplayConfig::default(), &TRAIN);
+        check.on_advanced(speed, 0.0);
+        check
+    }
+
+    #[test]
+    fn jumps_over_a_wall_of_barricades() {
+        let check = check(MIN_PLAYER_SPEED).after_row(&WALL, &BARRICADE, 0.0);
+        assert!(check.is_passable());
+    }
+
+    #[test]
+    fn drives_past_obstacles_too_tall_to_jump_over() {
+        let check = check(MIN_PLAYER_SPEED);
+        assert!(!check.after_row(&WALL, &TOWER, 0.0).is_passable());
+        assert!(check.after_row(&[0, 2], &TOWER, 0.0).is_passable());
+    }
+
+    #[test]
+    fn needs_time_to_land_between_two_walls() {
+        let mut check = check(MAX_PLAYER_SPEED).after_row(&WALL, &BARRICADE, 0.0);
+        check.on_advanced(MAX_PLAYER_SPEED, 20.0 / MAX_PLAYER_SPEED);
+        assert!(!check.after_row(&WALL, &BARRICADE, 0.0).is_passable());
+
+        check.on_advanced(MAX_PLAYER_SPEED, 20.0 / MAX_PLAYER_SPEED);
+        assert!(check.after_row(&WALL, &BARRICADE, 0.0).is_passable());
+    }
+
+    #[test]
+    fn needs_time_to_cross_the_track() {
+        let mut check = check(MAX_PLAYER_SPEED).after_row(&[1, 2], &TOWER, 0.0);
+        check.on_advanced(MAX_PLAYER_SPEED, 0.5);
+        assert!(!check.after_row(&[0, 1], &TOWER, 0.0).is_passable());
+
+        // The placement of the row within the frame counts towards the gap.
+        check.on_advanced(MAX_PLAYER_SPEED, 0.5);
+        assert!(check.after_row(&[0, 1], &TOWER, 0.0).is_passable());
+        assert!(!check.after_row(&[0, 1], &TOWER, -15.0).is_passable());
+    }
+}
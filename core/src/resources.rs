@@ -0,0 +1,1009 @@
+use bevy::prelude::*;
+use rand::{SeedableRng, rngs::StdRng};
+use serde::{Deserialize, Serialize};
+
+use super::{collider::Collider, *};
+
+#[derive(Default, Resource)]
+pub struct HighScore(pub u32);
+
+/// The best score of the runs played in assist mode, kept apart from [`HighScore`].
+#[derive(Default, Resource)]
+pub struct AssistHighScore(pub u32);
+
+/// The best score of the runs played with the hardcore mutator, kept apart from [`HighScore`].
+#[derive(Default, Resource)]
+pub struct HardcoreHighScore(pub u32);
+
+/// The ranking a run's score is compared within.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScoreCategory {
+    Standard,
+    Assist,
+    Hardcore,
+    /// Runs played with mutators other than hardcore. Their scores are not kept,
+    /// as every set of mutators plays differently.
+    Mutated,
+}
+
+/// Whether the next run is played in assist mode, toggled on the title screen.
+#[derive(Default, Resource, Deref, DerefMut)]
+pub struct AssistMode(pub bool);
+
+/// The train upgrades bought with the bells collected over every run.
+#[derive(Debug, Default, Clone, Copy, Resource, Deserialize, Serialize)]
+pub struct TrainUpgrades {
+    /// The collected bells that have not been spent yet.
+    pub bells: u32,
+    /// The number of cars bought in addition to the default train.
+    pub extra_cars: usize,
+}
+
+impl TrainUpgrades {
+    /// The cost of the next car, or `None` once the train has every car.
+    pub fn next_car_cost(&self) -> Option<u32> {
+        TRAIN_CAR_COSTS.get(self.extra_cars).copied()
+    }
+
+    pub fn can_buy_car(&self) -> bool {
+        self.next_car_cost().is_some_and(|cost| self.bells >= cost)
+    }
+
+    /// Spends bells on the next car. Returns `true` if the car was bought.
+    pub fn buy_car(&mut self) -> bool {
+        match self.next_car_cost() {
+            Some(cost) if self.bells >= cost => {
+                self.bells -= cost;
+                self.extra_cars += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// The collider of the player's train with the cars bought for it.
+    /// Every extra car makes the train, and so the collider, longer at the back.
+    pub fn collider(&self) -> Collider {
+        let extra_length = TRAIN_CAR_SPACING * self.extra_cars as f32;
+        Collider::Aabb {
+            offset: Vec3::new(0.0, 0.5, -1.5 - extra_length * 0.5),
+            size: Vec3::new(0.9, 1.0, 3.6 + extra_length),
+        }
+    }
+}
+
+/// An optional rule change that makes a run harder in exchange for a higher score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Mutator {
+    /// The game time runs twice as fast.
+    DoubleSpeed,
+    /// Any hit empties the fuel tank.
+    OneHitFuel,
+    /// The world is shown mirrored left to right, and the lane switch inputs are swapped to match.
+    MirrorLanes,
+    /// No fuel pickups appear on the track.
+    NoFuelPickups,
+    /// Any hit ends the run, shields do not absorb hits, and fuel pickups are rarer.
+    Hardcore,
+}
+
+impl Mutator {
+    pub const ALL: [Mutator; 5] = [
+        Mutator::DoubleSpeed,
+        Mutator::OneHitFuel,
+        Mutator::MirrorLanes,
+        Mutator::NoFuelPickups,
+        Mutator::Hardcore,
+    ];
+
+    /// The translation key of the name of the mutator.
+    pub fn label(self) -> &'static str {
+        match self {
+            Mutator::DoubleSpeed => "double_speed",
+            Mutator::OneHitFuel => "one_hit_fuel",
+            Mutator::MirrorLanes => "mirror_lanes",
+            Mutator::NoFuelPickups => "no_fuel_pickups",
+            Mutator::Hardcore => "hardcore",
+        }
+    }
+
+    pub fn score_multiplier(self) -> f32 {
+        match self {
+            Mutator::DoubleSpeed => DOUBLE_SPEED_MULTIPLIER,
+            Mutator::OneHitFuel => ONE_HIT_FUEL_MULTIPLIER,
+            Mutator::MirrorLanes => MIRROR_LANES_MULTIPLIER,
+            Mutator::NoFuelPickups => NO_FUEL_PICKUPS_MULTIPLIER,
+            Mutator::Hardcore => HARDCORE_MULTIPLIER,
+        }
+    }
+
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// The set of mutators the next run is played with, toggled on the title screen.
+/// Every mutator is off by default.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Resource)]
+pub struct Mutators(u8);
+
+impl Mutators {
+    pub fn is_active(&self, mutator: Mutator) -> bool {
+        self.0 & mutator.bit() != 0
+    }
+
+    pub fn toggle(&mut self, mutator: Mutator) {
+        self.0 ^= mutator.bit();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Checks if any mutator but hardcore, which has a ranking of its own, is active.
+    pub fn is_modded(&self) -> bool {
+        self.0 & !Mutator::Hardcore.bit() != 0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Mutator> {
+        let mutators = *self;
+        Mutator::ALL
+            .into_iter()
+            .filter(move |&mutator| mutators.is_active(mutator))
+    }
+
+    /// The multipliers of every active mutator, combined.
+    pub fn score_multiplier(&self) -> f32 {
+        self.iter().map(Mutator::score_multiplier).product()
+    }
+
+    /// A short code of the set, attached to shared scores so they are compared only with runs of the same rules.
+    pub fn code(&self) -> String {
+        format!("M{:X}", self.0)
+    }
+
+    /// Returns the fuel a hit of `damage` takes, or the whole tank with the one-hit fuel or hardcore mutator.
+    pub fn damage(&self, damage: f32) -> f32 {
+        match self.is_active(Mutator::OneHitFuel) || self.is_active(Mutator::Hardcore) {
+            true => FUEL_LIMITS,
+            false => damage,
+        }
+    }
+}
+
+/// Tunable values that affect the gameplay rules.
+#[derive(Resource)]
+pub struct GameplayConfig {
+    /// The minimum lane occupancy the player must have in an obstacle's lane to be damaged by it.
+    pub min_lane_overlap: f32,
+    /// The speed the world scrolls at relative to the usual one.
+    pub game_speed: f32,
+    /// The fuel used per second.
+    pub fuel_usage: f32,
+    /// The time, in seconds, a held lane switch input waits before it repeats.
+    pub input_delay: f32,
+    /// How long, in seconds, a lane switch pressed before the input delay has expired is kept,
+    /// to be carried out once it does. Zero drops such presses.
+    pub input_buffer: f32,
+    /// Whether the rules are eased by assist mode. Scores of such runs are kept separately.
+    pub assist: bool,
+    /// The mutators the run is played with.
+    pub mutators: Mutators,
+    /// The most, in radians, the toy trains roll into a lane change. Zero keeps them upright.
+    pub max_bank_angle: f32,
+}
+
+impl GameplayConfig {
+    /// The rules of assist mode, overriding the default ones.
+    pub fn assist() -> Self {
+        Self {
+            game_speed: ASSIST_GAME_SPEED,
+            fuel_usage: FUEL_USAGE * ASSIST_FUEL_USAGE_RATIO,
+            input_buffer: ASSIST_INPUT_BUFFER_TIME,
+            assist: true,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the ranking the score of a run with these rules is kept in.
+    /// Assist mode eases the rules more than any mutator, so it takes precedence.
+    pub fn score_category(&self) -> ScoreCategory {
+        if self.assist {
+            ScoreCategory::Assist
+        } else if self.mutators.is_modded() {
+            ScoreCategory::Mutated
+        } else if self.mutators.is_active(Mutator::Hardcore) {
+            ScoreCategory::Hardcore
+        } else {
+            ScoreCategory::Standard
+        }
+    }
+
+    /// Applies the mutators to the rules.
+    pub fn with_mutators(mut self, mutators: Mutators) -> Self {
+        if mutators.is_active(Mutator::DoubleSpeed) {
+            self.game_speed *= DOUBLE_SPEED_RATIO;
+        }
+        self.mutators = mutators;
+        self
+    }
+}
+
+impl Default for GameplayConfig {
+    fn default() -> Self {
+        Self {
+            min_lane_overlap: MIN_LANE_OVERLAP_RATIO,
+            game_speed: 1.0,
+            fuel_usage: FUEL_USAGE,
+            input_delay: INPUT_DELAY_TIME,
+            input_buffer: 0.0,
+            assist: false,
+            mutators: Mutators::default(),
+            max_bank_angle: MAX_BANK_ANGLE,
+        }
+    }
+}
+
+/// Independent random number streams derived from a single seed.
+/// Sharing the seed of a run lets other players race the same obstacles.
+#[derive(Resource)]
+pub struct RngService {
+    seed: u64,
+    /// A seed requested by the player, used for every run instead of a random one.
+    fixed_seed: Option<u64>,
+    spawning: StdRng,
+    audio: StdRng,
+    cosmetic: StdRng,
+}
+
+impl RngService {
+    pub fn new(fixed_seed: Option<u64>) -> Self {
+        let seed = fixed_seed.unwrap_or_else(rand::random);
+        Self {
+            seed,
+            fixed_seed,
+            spawning: Self::stream(seed, 0),
+            audio: Self::stream(seed, 1),
+            cosmetic: Self::stream(seed, 2),
+        }
+    }
+
+    /// Reseeds every stream for a new run.
+    pub fn start_run(&mut self) {
+        *self = Self::new(self.fixed_seed);
+    }
+
+    /// The seed formatted the way it is shown to and entered by the player.
+    pub fn seed_text(&self) -> String {
+        format!("{:016X}", self.seed)
+    }
+
+    /// Parses a seed as shown to the player. A mutator code after the seed is ignored.
+    pub fn parse_seed(text: &str) -> Option<u64> {
+        let seed = text.trim().split('-').next().unwrap_or_default();
+        u64::from_str_radix(seed, 16).ok()
+    }
+
+    /// The stream that decides the obstacles and items of a run.
+    pub fn spawning(&mut self) -> &mut StdRng {
+        &mut self.spawning
+    }
+
+    /// The stream that picks voice lines and sound variations during a run.
+    pub fn audio(&mut self) -> &mut StdRng {
+        &mut self.audio
+    }
+
+    /// The stream for draws outside of a run, such as menu previews.
+    pub fn cosmetic(&mut self) -> &mut StdRng {
+        &mut self.cosmetic
+    }
+
+    fn stream(seed: u64, index: u64) -> StdRng {
+        StdRng::seed_from_u64(seed ^ index.wrapping_mul(0x9E37_79B9_7F4A_7C15))
+    }
+}
+
+#[derive(Default, Resource)]
+pub struct PlayTime {
+    play_time_ms: u128,
+}
+
+impl PlayTime {
+    pub fn tick(&mut self, time: &Time) {
+        self.play_time_ms = self.play_time_ms.saturating_add(time.delta().as_millis());
+    }
+
+    pub fn millis(&self) -> u128 {
+        self.play_time_ms
+    }
+}
+
+/// The play time, in milliseconds, at which a run passed each [`PACE_SPLIT_DISTANCE`] along the track.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PaceCurve {
+    pub splits: Vec<u32>,
+}
+
+impl PaceCurve {
+    /// Returns the play time, in seconds, at which the run reached the given distance,
+    /// or `None` if it never got that far.
+    pub fn time_at(&self, distance: f32) -> Option<f32> {
+        let position = distance.max(0.0) / PACE_SPLIT_DISTANCE;
+        let index = position.floor() as usize;
+        let start = match index {
+            0 => 0,
+            _ => *self.splits.get(index - 1)?,
+        };
+        let end = *self.splits.get(index)?;
+        let millis = start as f32 + (end - start) as f32 * position.fract();
+        Some(millis / 1000.0)
+    }
+}
+
+/// How far the current run has come, and when it passed each split.
+#[derive(Debug, Default, Resource)]
+pub struct RunPace {
+    /// The distance past the latest split. It is moved back by a split each time one is passed,
+    /// so that it stays small enough for the distance of every frame to count in full on long runs.
+    distance: f32,
+    curve: PaceCurve,
+}
+
+impl RunPace {
+    pub fn on_advanced(
+        &mut self,
+        forward_move: &ForwardMovement,
+        elapsed: f32,
+        play_time: &PlayTime,
+    ) {
+        self.distance += forward_move.get() * elapsed;
+        let millis = play_time.millis().min(u32::MAX as u128) as u32;
+        while self.distance >= PACE_SPLIT_DISTANCE {
+            self.distance -= PACE_SPLIT_DISTANCE;
+            self.curve.splits.push(millis);
+        }
+    }
+
+    pub fn distance(&self) -> f32 {
+        self.curve.splits.len() as f32 * PACE_SPLIT_DISTANCE + self.distance
+    }
+
+    pub fn curve(&self) -> &PaceCurve {
+        &self.curve
+    }
+
+    /// Returns how many seconds the run is behind the given curve at the same distance.
+    /// A negative value means the run is ahead. Returns `None` once the run has gone past the end of the curve.
+    pub fn delta(&self, best: &PaceCurve, play_time: &PlayTime) -> Option<f32> {
+        let best_time = best.time_at(self.distance())?;
+        Some(play_time.millis() as f32 / 1000.0 - best_time)
+    }
+}
+
+/// The pace of the run that set the high score.
+#[derive(Debug, Default, Clone, Resource, Deref, DerefMut, Deserialize, Serialize)]
+pub struct BestPace(pub PaceCurve);
+
+/// The totals of a finished run, kept to compare the next runs with.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RunRecord {
+    pub score: u32,
+    /// The distance travelled, in whole units.
+    pub distance: u32,
+    pub bells: u32,
+}
+
+impl RunRecord {
+    /// Returns how far each total of this run is above the one of the given run.
+    pub fn delta(&self, other: &RunRecord) -> RunDelta {
+        RunDelta {
+            score: i64::from(self.score) - i64::from(other.score),
+            distance: i64::from(self.distance) - i64::from(other.distance),
+            bells: i64::from(self.bells) - i64::from(other.bells),
+        }
+    }
+}
+
+/// The difference between the totals of two runs. Positive values mean the run did better.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RunDelta {
+    pub score: i64,
+    pub distance: i64,
+    pub bells: i64,
+}
+
+/// The last finished run and the best one, which the result screen compares a run with.
+#[derive(Debug, Default, Clone, Copy, Resource, Deserialize, Serialize)]
+pub struct RunHistory {
+    pub previous: Option<RunRecord>,
+    pub best: Option<RunRecord>,
+}
+
+impl RunHistory {
+    /// Records a finished run. Like the high score, runs played in assist mode or with mutators
+    /// other than hardcore never become the best run.
+    pub fn record(&mut self, run: RunRecord, unranked: bool) {
+        self.previous = Some(run);
+        if !unranked && self.best.is_none_or(|best| run.score > best.score) {
+            self.best = Some(run);
+        }
+    }
+}
+
+#[derive(Default, Resource)]
+pub struct Attacked {
+    count: u32,
+}
+
+impl Attacked {
+    pub fn add(&mut self) {
+        self.count = self.count.saturating_add(1);
+    }
+}
+
+/// The bells collected during the current run.
+#[derive(Default, Resource, Deref, DerefMut)]
+pub struct CollectedBells(pub u32);
+
+/// The streak of bells collected on the beat during a rhythm section, and the bonus it builds.
+#[derive(Debug, Default, Resource)]
+pub struct RhythmBonus {
+    combo: u32,
+}
+
+impl RhythmBonus {
+    /// Judges a beat bell collected `offset` seconds away from its beat.
+    /// Returns whether it was on the beat; a bell off the beat breaks the streak.
+    pub fn hit(&mut self, offset: f32) -> bool {
+        let on_beat = offset.abs() <= RHYTHM_BEAT_WINDOW;
+        self.combo = match on_beat {
+            true => self.combo.saturating_add(1),
+            false => 0,
+        };
+        on_beat
+    }
+
+    /// Breaks the streak when a beat bell passes the player.
+    pub fn miss(&mut self) {
+        self.combo = 0;
+    }
+
+    pub fn combo(&self) -> u32 {
+        self.combo
+    }
+
+    /// Returns the factor the points of the next beat bell are scaled by.
+    pub fn multiplier(&self) -> f32 {
+        let steps = self.combo.saturating_sub(1) as f32;
+        (1.0 + RHYTHM_MULTIPLIER_STEP * steps).min(MAX_RHYTHM_MULTIPLIER)
+    }
+
+    /// Returns the points a beat bell earns on top of [`BELL_POINT`].
+    pub fn bonus_point(&self) -> u32 {
+        ((self.multiplier() - 1.0) * BELL_POINT as f32).round() as u32
+    }
+}
+
+/// The pickups collected and missed during the current run.
+#[derive(Debug, Default, Resource)]
+pub struct RunStats {
+    pub bells_collected: u32,
+    pub bells_missed: u32,
+    pub fuel_collected: u32,
+    pub fuel_missed: u32,
+}
+
+impl RunStats {
+    pub fn collect(&mut self, obj: Object) {
+        match obj {
+            Object::Bell => self.bells_collected += 1,
+            Object::Fuel => self.fuel_collected += 1,
+            _ => { /* empty */ }
+        }
+    }
+
+    pub fn miss(&mut self, obj: Object) {
+        match obj {
+            Object::Bell => self.bells_missed += 1,
+            Object::Fuel => self.fuel_missed += 1,
+            _ => { /* empty */ }
+        }
+    }
+
+    /// The percentage of the bells that were collected, or `None` if no bell appeared.
+    pub fn bell_percentage(&self) -> Option<u32> {
+        percentage(self.bells_collected, self.bells_missed)
+    }
+
+    /// The percentage of the fuel that was collected, or `None` if no fuel appeared.
+    pub fn fuel_percentage(&self) -> Option<u32> {
+        percentage(self.fuel_collected, self.fuel_missed)
+    }
+}
+
+fn percentage(collected: u32, missed: u32) -> Option<u32> {
+    let total = collected + missed;
+    (total > 0).then(|| collected * 100 / total)
+}
+
+#[derive(Resource)]
+pub struct InputDelay {
+    duration: f32,
+    remaining: f32,
+    /// How long a lane switch pressed before the delay has expired is kept.
+    buffer: f32,
+    /// The side of the kept lane switch (`true` for the left), and how much longer it is kept.
+    queued: Option<(bool, f32)>,
+}
+
+impl InputDelay {
+    pub fn new(duration: f32) -> Self {
+        Self {
+            duration,
+            remaining: 0.0,
+            buffer: 0.0,
+            queued: None,
+        }
+    }
+
+    pub fn with_buffer(mut self, buffer: f32) -> Self {
+        self.buffer = buffer;
+        self
+    }
+
+    pub fn on_advanced(&mut self, elapsed: f32) {
+        self.remaining = (self.remaining - elapsed).max(0.0);
+        self.queued = self
+            .queued
+            .map(|(left, kept)| (left, kept - elapsed))
+            .filter(|&(_, kept)| kept > 0.0);
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.remaining <= 0.0
+    }
+
+    pub fn reset(&mut self) {
+        self.remaining = self.duration;
+        self.queued = None;
+    }
+
+    /// Keeps a lane switch toward the given side pressed before the delay has expired,
+    /// if the buffer allows it.
+    pub fn queue(&mut self, left: bool) {
+        if self.buffer > 0.0 {
+            self.queued = Some((left, self.buffer));
+        }
+    }
+
+    /// Takes the side of the kept lane switch, once the delay has expired.
+    pub fn take_queued(&mut self) -> Option<bool> {
+        match self.is_expired() {
+            true => self.queued.take().map(|(left, _)| left),
+            false => None,
+        }
+    }
+}
+
+impl Default for InputDelay {
+    fn default() -> Self {
+        Self::new(INPUT_DELAY_TIME)
+    }
+}
+
+#[derive(Resource)]
+pub struct CurrentScore {
+    point: u32,
+    distance: f32,
+    /// Scales every point gained, e.g. by the mutators of the run.
+    multiplier: f32,
+}
+
+impl CurrentScore {
+    pub fn with_multiplier(multiplier: f32) -> Self {
+        Self {
+            multiplier,
+            ..Default::default()
+        }
+    }
+
+    pub fn get(&self) -> u32 {
+        self.point
+    }
+
+    pub fn inc(&mut self, amount: u32) {
+        let amount = (amount as f32 * self.multiplier).round() as u32;
+        self.point = (self.point + amount).min(SCORE_LIMITS);
+    }
+
+    pub fn on_advanced(&mut self, forward_move: &ForwardMovement, elapsed: f32) {
+        self.distance += forward_move.get() * elapsed * self.multiplier;
+        let amount = (self.distance / POINT_PER_DIST).floor() as u32;
+        self.point = (self.point + amount).min(SCORE_LIMITS);
+        self.distance %= POINT_PER_DIST;
+    }
+}
+
+impl Default for CurrentScore {
+    fn default() -> Self {
+        Self {
+            point: 0,
+            distance: 0.0,
+            multiplier: 1.0,
+        }
+    }
+}
+
+#[derive(Resource)]
+pub struct TrainFuel {
+    remaining: f32,
+}
+
+impl TrainFuel {
+    pub fn get(&self) -> f32 {
+        self.remaining
+    }
+
+    pub fn set(&mut self, amount: f32) {
+        self.remaining = amount.min(FUEL_LIMITS);
+    }
+
+    /// Adds fuel and returns the amount actually added, which is less near the limit.
+    pub fn inc(&mut self, amount: f32) -> f32 {
+        let before = self.remaining;
+        self.remaining = (self.remaining + amount).min(FUEL_LIMITS);
+        self.remaining - before
+    }
+
+    /// Takes fuel away and returns the change, which is negative and smaller in size near empty.
+    pub fn dec(&mut self, amount: f32) -> f32 {
+        let before = self.remaining;
+        self.remaining = (self.remaining - amount).max(0.0);
+        self.remaining - before
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.remaining <= 0.0
+    }
+}
+
+impl Default for TrainFuel {
+    fn default() -> Self {
+        Self {
+            remaining: FUEL_LIMITS,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, Resource)]
+pub struct IsPlayerJumping {
+    prev: bool,
+    curr: bool,
+}
+
+impl IsPlayerJumping {
+    pub fn jump(&mut self) {
+        self.prev = self.curr;
+        self.curr = true;
+    }
+
+    pub fn reset(&mut self) {
+        self.prev = self.curr;
+        self.curr = false;
+    }
+
+    pub fn get(&self) -> bool {
+        self.curr
+    }
+
+    pub fn changed(&self) -> bool {
+        self.prev != self.curr
+    }
+}
+
+/// Scales how fast the world scrolls toward the player. The player's own controls are never scaled.
+#[derive(Debug, Resource)]
+pub struct TimeScale {
+    /// The speed of the world the run is played at, set by the gameplay rules.
+    game_speed: f32,
+    remaining: f32,
+    /// How far the coast that ends a run has slowed the world down (0.0 to 1.0).
+    coast: f32,
+}
+
+impl TimeScale {
+    /// Creates a time scale for a run whose world moves at the given speed.
+    pub fn new(game_speed: f32) -> Self {
+        Self {
+            game_speed,
+            ..Default::default()
+        }
+    }
+
+    /// Slows the world down for the given time, restarting any slow motion in progress.
+    pub fn slow_down(&mut self, duration: f32) {
+        self.remaining = duration;
+    }
+
+    pub fn on_advanced(&mut self, elapsed: f32) {
+        self.remaining = (self.remaining - elapsed).max(0.0);
+    }
+
+    /// Ends the slow motion and the coast. The world keeps the speed of the run.
+    pub fn clear(&mut self) {
+        *self = Self::new(self.game_speed);
+    }
+
+    /// Slows the world down toward the end of a run, from 0.0 (not at all) to 1.0 (the slowest of the coast).
+    pub fn set_coast(&mut self, strength: f32) {
+        self.coast = strength.clamp(0.0, 1.0);
+    }
+
+    /// Returns the factor to apply to the elapsed time of the world.
+    /// The slower of the slow motion and the coast applies on top of the speed of the run.
+    pub fn get(&self) -> f32 {
+        let clock = 1.0 - (1.0 - CLOCK_TIME_SCALE) * self.strength();
+        let coast = 1.0 - (1.0 - COAST_TIME_SCALE) * self.coast;
+        self.game_speed * clock.min(coast)
+    }
+
+    /// Returns how strong the slow motion is (0.0 to 1.0). It starts at full strength
+    /// and eases out over the blend time before it ends.
+    pub fn strength(&self) -> f32 {
+        (self.remaining / CLOCK_BLEND_TIME).min(1.0)
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.remaining > 0.0
+    }
+}
+
+impl Default for TimeScale {
+    fn default() -> Self {
+        Self {
+            game_speed: 1.0,
+            remaining: 0.0,
+            coast: 0.0,
+        }
+    }
+}
+
+/// The ground planes laid one interval apart along the track, from the despawn position to the spawn position.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GroundLayout {
+    pub spawn: f32,
+    pub despawn: f32,
+}
+
+impl GroundLayout {
+    pub fn num_grounds(&self) -> usize {
+        ((self.spawn - self.despawn) / GROUND_SPAWN_INTERVAL).floor() as usize + 1
+    }
+
+    /// Returns the depths the planes are laid at when a run is loaded.
+    pub fn depths(self) -> impl Iterator<Item = f32> {
+        (0..self.num_grounds()).map(move |i| self.despawn + GROUND_SPAWN_INTERVAL * i as f32)
+    }
+
+    /// Checks if a plane has scrolled past the despawn position and must be laid again ahead.
+    pub fn is_retired(&self, depth: f32) -> bool {
+        depth <= self.despawn
+    }
+
+    /// Returns the depth a retired plane is laid at, one interval ahead of the plane furthest down the track.
+    /// Laying it against its neighbour, rather than one span ahead of where it was,
+    /// keeps the rounding of every plane's own scrolling from adding up into a seam over a long session.
+    pub fn recycled_depth(&self, front: f32) -> f32 {
+        front + GROUND_SPAWN_INTERVAL
+    }
+}
+
+/// A one-hit protection collected from a shield pickup, layered on top of [`CurrentState`].
+#[derive(Debug, Default, Resource)]
+pub struct PlayerShield {
+    charged: bool,
+    grace: f32,
+}
+
+impl PlayerShield {
+    pub fn charge(&mut self) {
+        self.charged = true;
+    }
+
+    pub fn is_charged(&self) -> bool {
+        self.charged
+    }
+
+    /// Uses up the shield to absorb a hit. Returns `true` if the hit was absorbed,
+    /// which includes every hit shortly after the shield was used up.
+    pub fn absorb(&mut self) -> bool {
+        if self.grace > 0.0 {
+            true
+        } else if self.charged {
+            self.charged = false;
+            self.grace = SHIELD_GRACE_DURATION;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Checks if the shield was used up a moment ago, and still absorbs every hit.
+    pub fn is_absorbing(&self) -> bool {
+        self.grace > 0.0
+    }
+
+    pub fn on_advanced(&mut self, elapsed: f32) {
+        self.grace = (self.grace - elapsed).max(0.0);
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, Resource)]
+pub enum CurrentState {
+    #[cfg(not(feature = "no-debuging-player"))]
+    Debug,
+    #[default]
+    Idle,
+    Attacked {
+        remaining: f32,
+    },
+    Invincible {
+        remaining: f32,
+    },
+}
+
+impl CurrentState {
+    /// Checks if the player is in the `Debug` state (invincible).
+    #[cfg(not(feature = "no-debuging-player"))]
+    pub fn is_debug(&self) -> bool {
+        matches!(self, CurrentState::Debug)
+    }
+
+    /// Checks if the player is in any state that grants invincibility.
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn is_invincible(&self) -> bool {
+        match self {
+            #[cfg(not(feature = "no-debuging-player"))]
+            CurrentState::Debug => true,
+            CurrentState::Invincible { .. } => true,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mutators(active: &[Mutator]) -> Mutators {
+        let mut mutators = Mutators::default();
+        for mutator in active {
+            mutators.toggle(*mutator);
+        }
+        mutators
+    }
+
+    #[test]
+    fn score_multiplier_scales_every_point() {
+        let mutators = mutators(&[Mutator::OneHitFuel]);
+        assert_eq!(mutators.score_multiplier(), ONE_HIT_FUEL_MULTIPLIER);
+
+        let mut score = CurrentScore::with_multiplier(mutators.score_multiplier());
+        score.inc(BELL_POINT);
+        assert_eq!(
+            score.get(),
+            (BELL_POINT as f32 * ONE_HIT_FUEL_MULTIPLIER) as u32
+        );
+    }
+
+    #[test]
+    fn hardcore_runs_are_ranked_separately() {
+        let mutators = mutators(&[Mutator::Hardcore]);
+        assert_eq!(mutators.damage(BARRICADE_DAMAGE), FUEL_LIMITS);
+
+        let config = GameplayConfig::default().with_mutators(mutators);
+        assert_eq!(config.score_category(), ScoreCategory::Hardcore);
+        let config = GameplayConfig::assist().with_mutators(mutators);
+        assert_eq!(config.score_category(), ScoreCategory::Assist);
+        assert_eq!(
+            GameplayConfig::default().score_category(),
+            ScoreCategory::Standard
+        );
+    }
+
+    #[test]
+    fn mutated_runs_are_not_ranked() {
+        let config = GameplayConfig::default().with_mutators(mutators(&[Mutator::DoubleSpeed]));
+        assert_eq!(config.score_category(), ScoreCategory::Mutated);
+
+        let config = GameplayConfig::default()
+            .with_mutators(mutators(&[Mutator::DoubleSpeed, Mutator::Hardcore]));
+        assert_eq!(config.score_category(), ScoreCategory::Mutated);
+    }
+
+    #[test]
+    fn double_speed_scrolls_the_world_faster() {
+        let config = GameplayConfig::default().with_mutators(mutators(&[Mutator::DoubleSpeed]));
+        let mut time_scale = TimeScale::new(config.game_speed);
+        assert_eq!(time_scale.get(), DOUBLE_SPEED_RATIO);
+
+        time_scale.slow_down(1.0);
+        time_scale.clear();
+        assert_eq!(time_scale.get(), DOUBLE_SPEED_RATIO);
+    }
+
+    #[test]
+    fn rhythm_bonus_grows_with_the_streak_on_the_beat() {
+        let mut rhythm = RhythmBonus::default();
+        assert!(rhythm.hit(0.0));
+        assert_eq!(rhythm.bonus_point(), 0);
+
+        assert!(rhythm.hit(-RHYTHM_BEAT_WINDOW * 0.5));
+        assert_eq!(rhythm.multiplier(), 1.0 + RHYTHM_MULTIPLIER_STEP);
+
+        for _ in 0..100 {
+            rhythm.hit(0.0);
+        }
+        assert_eq!(rhythm.multiplier(), MAX_RHYTHM_MULTIPLIER);
+
+        assert!(!rhythm.hit(RHYTHM_BEAT_WINDOW * 2.0));
+        assert_eq!(rhythm.combo(), 0);
+
+        rhythm.hit(0.0);
+        rhythm.hit(0.0);
+        rhythm.miss();
+        assert_eq!(rhythm.multiplier(), 1.0);
+    }
+
+    #[test]
+    fn pace_distance_stays_exact_on_long_runs() {
+        const FRAME: f32 = 0.016_667;
+
+        let mut pace = RunPace::default();
+        let play_time = PlayTime::default();
+        let forward_move = ForwardMovement::new(MAX_PLAYER_SPEED);
+        // An hour of play at sixty frames per second.
+        let frames = 60 * 60 * 60;
+        for _ in 0..frames {
+            pace.on_advanced(&forward_move, FRAME, &play_time);
+        }
+
+        let traveled = f64::from(MAX_PLAYER_SPEED) * f64::from(FRAME) * frames as f64;
+        let error = (f64::from(pace.distance()) - traveled).abs() / traveled;
+        assert!(error < 1e-6, "Counted {} of {}", pace.distance(), traveled);
+        assert_eq!(
+            pace.curve().splits.len(),
+            (traveled / f64::from(PACE_SPLIT_DISTANCE)) as usize
+        );
+    }
+
+    #[test]
+    fn run_history_keeps_the_best_run_outside_assist_mode() {
+        let run = |score, distance, bells| RunRecord {
+            score,
+            distance,
+            bells,
+        };
+        let mut history = RunHistory::default();
+
+        history.record(run(500, 300, 4), false);
+        history.record(run(900, 450, 2), true);
+        assert_eq!(history.previous, Some(run(900, 450, 2)));
+        assert_eq!(history.best, Some(run(500, 300, 4)));
+
+        history.record(run(400, 250, 7), false);
+        let delta = run(400, 250, 7).delta(&history.best.unwrap());
+        assert_eq!(
+            delta,
+            RunDelta {
+                score: -100,
+                distance: -50,
+                bells: 3
+            }
+        );
+
+        history.record(run(600, 200, 0), false);
+        assert_eq!(history.best, Some(run(600, 200, 0)));
+    }
+}
@@ -0,0 +1,485 @@
+use bevy::{platform::collections::HashMap, prelude::*};
+use rand::{
+    Rng,
+    distr::{Distribution, weighted::WeightedIndex},
+    seq::IndexedRandom,
+};
+
+use super::{collider::Collider, *};
+
+/// A kind of obstacle pattern the spawner can be told to keep off the track for a while.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PatternTag {
+    /// Blocks every lane, so that it can only be jumped over.
+    Wall,
+}
+
+impl PatternTag {
+    /// Checks if a pattern placing obstacles in the given lanes has the tag.
+    pub fn matches(self, lanes: &[usize]) -> bool {
+        match self {
+            Self::Wall => (0..NUM_LANES).all(|lane| lanes.contains(&lane)),
+        }
+    }
+}
+
+/// Where a pickup is placed in a spawn pattern.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PickupPosition {
+    pub lane: usize,
+    /// How far above its usual spot the pickup floats.
+    pub height: f32,
+    /// How far behind the spawn point the pickup is placed along the track.
+    pub depth: f32,
+}
+
+impl PickupPosition {
+    pub const fn ground(lane: usize) -> Self {
+        Self {
+            lane,
+            height: 0.0,
+            depth: 0.0,
+        }
+    }
+
+    /// A pickup the train can only reach at the top of a jump.
+    pub const fn aerial(lane: usize) -> Self {
+        Self {
+            lane,
+            height: JUMP_APEX_HEIGHT,
+            depth: 0.0,
+        }
+    }
+}
+
+/// The patterns of an obstacle added by a content pack.
+pub struct ModdedPatterns {
+    id: String,
+    collider: Collider,
+    /// The lanes of each pattern.
+    lanes: Vec<Vec<usize>>,
+    weight: u32,
+    pattern_weights: WeightedIndex<u32>,
+}
+
+impl ModdedPatterns {
+    /// Returns `None` if the obstacle has no pattern with a weight, as it could never be placed.
+    pub fn new(
+        id: String,
+        collider: Collider,
+        weight: u32,
+        patterns: &[(Vec<usize>, u32)],
+    ) -> Option<Self> {
+        let pattern_weights =
+            WeightedIndex::new(patterns.iter().map(|&(_, weight)| weight)).ok()?;
+        Some(Self {
+            id,
+            collider,
+            lanes: patterns.iter().map(|(lanes, _)| lanes.clone()).collect(),
+            weight,
+            pattern_weights,
+        })
+    }
+}
+
+/// What a [`Placement`] puts on the track.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PlacedObject {
+    Builtin(Object),
+    /// An obstacle added by a content pack, as an index into the obstacles the schedule was created with.
+    Modded(usize),
+}
+
+/// An object the [`ObjectSchedule`] places on the track, in one lane.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Placement {
+    pub object: PlacedObject,
+    pub lane: usize,
+    pub translation: Vec3,
+    /// The spawn pattern of a barricade or a stone.
+    pub pattern: Option<usize>,
+    /// The lanes a wandering stone sways between.
+    pub drift: Option<(usize, usize)>,
+}
+
+impl Placement {
+    fn new(object: PlacedObject, lane: usize, height: f32, depth: f32) -> Self {
+        Self {
+            object,
+            lane,
+            translation: Vec3::new(LANE_POSITIONS[lane], height, depth),
+            pattern: None,
+            drift: None,
+        }
+    }
+}
+
+/// Decides which objects are placed on the track and where, as the player moves along it.
+/// It spawns nothing itself, so that the client can give the objects their models and sounds,
+/// and headless tools can play out a seeded run without them.
+pub struct ObjectSchedule {
+    distance: f32,
+    /// The distance travelled during the run, used to bring in harder obstacles.
+    /// It stops counting once they are all in, so that it never grows without bound on a long run.
+    traveled: f32,
+    next_obj: Object,
+    /// The number of fuel slots the schedule has reached, with or without a pickup placed in them.
+    fuel_slots: u32,
+    mutators: Mutators,
+    /// The obstacles added by content packs.
+    modded: Vec<ModdedPatterns>,
+    /// Picks between the built-in objects as a whole, at index 0, and each modded obstacle.
+    modded_weights: Option<WeightedIndex<u32>>,
+    next_modded: Option<usize>,
+    /// Where objects appear along the track.
+    spawn_position: f32,
+    /// The pattern tags kept off the track, with the seconds left until each is allowed again.
+    excluded: HashMap<PatternTag, f32>,
+    /// The lanes the player can get through the obstacles placed so far.
+    path: PathCheck,
+}
+
+impl ObjectSchedule {
+    pub fn new(
+        config: &GameplayConfig,
+        train: &Collider,
+        spawn_position: f32,
+        modded: Vec<ModdedPatterns>,
+    ) -> Self {
+        let modded_weights = match modded.is_empty() {
+            true => None,
+            false => WeightedIndex::new(
+                std::iter::once(SPAWN_WEIGHTS.total_weight())
+                    .chain(modded.iter().map(|modded| modded.weight)),
+            )
+            .ok(),
+        };
+
+        Self {
+            distance: 0.0,
+            traveled: 0.0,
+            next_obj: Object::default(),
+            fuel_slots: 0,
+            mutators: config.mutators,
+            modded,
+            modded_weights,
+            next_modded: None,
+            spawn_position,
+            excluded: HashMap::default(),
+            path: PathCheck::new(config, train),
+        }
+    }
+
+    /// Returns the object placed next and where along the track it will appear.
+    /// Its lanes are rolled only when it is placed.
+    pub fn next_spawn(&self) -> (Object, f32) {
+        (
+            self.next_obj,
+            self.spawn_position + OBJECT_SPAWN_INTERVAL - self.distance,
+        )
+    }
+
+    /// Keeps the patterns with the tag off the track for the given number of seconds from now,
+    /// unless they are already kept off for longer.
+    pub fn exclude(&mut self, tag: PatternTag, duration: f32) {
+        let remaining = self.excluded.entry(tag).or_default();
+        *remaining = remaining.max(duration);
+    }
+
+    /// Moves the track by the distance the player travelled, and returns the objects placed on it.
+    pub fn on_advanced(
+        &mut self,
+        forward_move: &ForwardMovement,
+        rng: &mut impl Rng,
+        elapsed: f32,
+    ) -> Vec<Placement> {
+        self.excluded.retain(|_, remaining| {
+            *remaining -= elapsed;
+            *remaining > 0.0
+        });
+
+        let mut placements = Vec::new();
+        self.path.on_advanced(forward_move.get(), elapsed);
+        self.distance += forward_move.get() * elapsed;
+        self.traveled =
+            (self.traveled + forward_move.get() * elapsed).min(WANDERING_STONE_MIN_DISTANCE);
+        while self.distance >= OBJECT_SPAWN_INTERVAL {
+            if let Some(index) = self.next_modded.take() {
+                self.place_modded(&mut placements, rng, index);
+                self.roll_next_object(rng);
+                continue;
+            }
+
+            let obj = self.next_obj;
+            let object = PlacedObject::Builtin(obj);
+            let collider = OBJECT_COLLIDER.get(&obj).cloned().unwrap();
+            let delta = OBJECT_SPAWN_INTERVAL - self.distance;
+            let depth = self.spawn_position + delta;
+
+            let place_fuel = obj == Object::Fuel && self.take_fuel_slot();
+            let row = match obj {
+                Object::Barricade => self.sample_pattern(
+                    &BARRICADE_WEIGHTS,
+                    |i| &BARRICADE_POSITION_INDICES[i],
+                    &collider,
+                    delta,
+                    rng,
+                ),
+                Object::Stone => self.sample_pattern(
+                    &STONE_WEIGHTS,
+                    |i| &STONE_POSITION_INDICES[i],
+                    &collider,
+                    delta,
+                    rng,
+                ),
+                _ => None,
+            };
+            match obj {
+                // No pattern lets the player get past the row, so the slot is left empty.
+                Object::Barricade | Object::Stone if row.is_none() => {
+                    info!("Skip {:?} entity", obj);
+                }
+                Object::Barricade => {
+                    let (index, checked) = row.unwrap();
+                    self.path = checked;
+                    for &lane_index in BARRICADE_POSITION_INDICES[index].iter() {
+                        placements.push(Placement {
+                            pattern: Some(index),
+                            ..Placement::new(object, lane_index, 0.0, depth)
+                        });
+                    }
+                }
+                Object::Stone => {
+                    let (index, mut checked) = row.unwrap();
+                    let indices = &STONE_POSITION_INDICES[index];
+                    // Only a lone stone may sway, so it never runs into another stone.
+                    let wandering = indices.len() == 1
+                        && self.traveled >= WANDERING_STONE_MIN_DISTANCE
+                        && rng.random_bool(WANDERING_STONE_CHANCE);
+                    let mut drift = None;
+                    if wandering {
+                        let lane_index = indices[0];
+                        let to_lane = match lane_index {
+                            0 => 1,
+                            MAX_LANE_INDEX => MAX_LANE_INDEX - 1,
+                            _ if rng.random_bool(0.5) => lane_index - 1,
+                            _ => lane_index + 1,
+                        };
+                        // The stone only sways if the player can get past it in both lanes.
+                        let swaying = self
+                            .path
+                            .after_row(&[lane_index, to_lane], &collider, delta);
+                        if swaying.is_passable() {
+                            drift = Some((lane_index, to_lane));
+                            checked = swaying;
+                        }
+                    }
+                    self.path = checked;
+                    for &lane_index in indices {
+                        placements.push(Placement {
+                            pattern: Some(index),
+                            drift,
+                            ..Placement::new(object, lane_index, 0.0, depth)
+                        });
+                    }
+                }
+                // The slot is left empty, so the rest of the run stays the same as with pickups.
+                Object::Fuel if !place_fuel => {}
+                Object::Fuel => {
+                    let position = FUEL_POSITIONS[FUEL_WEIGHTS.sample(rng)];
+                    placements.push(Placement::new(
+                        object,
+                        position.lane,
+                        0.5 + position.height,
+                        depth,
+                    ));
+                }
+                Object::Bell => {
+                    let index = BELL_WEIGHTS.sample(rng);
+                    for position in BELL_POSITIONS[index].iter() {
+                        placements.push(Placement::new(
+                            object,
+                            position.lane,
+                            0.5 + position.height,
+                            depth + position.depth,
+                        ));
+                    }
+                }
+                Object::Shield | Object::Clock => {
+                    let lane_indices: &[usize] = match obj {
+                        Object::Shield => &SHIELD_POSITION_INDICES,
+                        _ => &CLOCK_POSITION_INDICES,
+                    };
+                    let lane_index = lane_indices.choose(rng).copied().unwrap();
+                    placements.push(Placement::new(object, lane_index, 0.5, depth));
+                }
+                Object::Aoba => {
+                    let position = AOBA_POSITIONS[AOBA_WEIGHTS.sample(rng)];
+                    placements.push(Placement::new(
+                        object,
+                        position.lane,
+                        position.height,
+                        depth,
+                    ));
+                }
+            }
+
+            self.roll_next_object(rng);
+        }
+        debug_assert!(
+            self.path.is_passable(),
+            "the obstacles placed cannot be got past"
+        );
+        placements
+    }
+
+    /// Returns whether a fuel pickup is placed in the next fuel slot.
+    /// Hardcore runs keep only one in every few slots.
+    fn take_fuel_slot(&mut self) -> bool {
+        if self.mutators.is_active(Mutator::NoFuelPickups) {
+            return false;
+        }
+        self.fuel_slots += 1;
+        !self.mutators.is_active(Mutator::Hardcore)
+            || self.fuel_slots.is_multiple_of(HARDCORE_FUEL_SLOT_INTERVAL)
+    }
+
+    /// Rolls a pattern of `obstacle`s by its weight, rolling again while the player could not
+    /// get past it or it has an excluded tag, and returns it with the path check once it is placed.
+    /// A pattern with an excluded tag is kept if no other turns up, but one that cannot be got
+    /// past never is. Nothing more is drawn once a roll is kept, so seeded runs play out as before.
+    fn sample_pattern<'a>(
+        &self,
+        weights: &WeightedIndex<u32>,
+        lanes_of: impl Fn(usize) -> &'a [usize],
+        obstacle: &Collider,
+        ahead: f32,
+        rng: &mut impl Rng,
+    ) -> Option<(usize, PathCheck)> {
+        let mut fallback = None;
+        for _ in 0..=MAX_PATTERN_REROLLS {
+            let index = weights.sample(rng);
+            let checked = self.path.after_row(lanes_of(index), obstacle, ahead);
+            if !checked.is_passable() {
+                continue;
+            }
+            if !self.is_excluded(lanes_of(index)) {
+                return Some((index, checked));
+            }
+            fallback = Some((index, checked));
+        }
+        fallback
+    }
+
+    fn is_excluded(&self, lanes: &[usize]) -> bool {
+        self.excluded.keys().any(|tag| tag.matches(lanes))
+    }
+
+    fn roll_next_object(&mut self, rng: &mut impl Rng) {
+        let offset = rng.random_range(OBJECT_SPAWN_OFFSET);
+        // Without content packs no extra number is drawn, so seeded runs play out as before.
+        self.next_modded = self
+            .modded_weights
+            .as_ref()
+            .and_then(|weights| weights.sample(rng).checked_sub(1));
+        let index = SPAWN_WEIGHTS.sample(rng);
+        let next_obj = OBJECT_LIST[index];
+
+        self.distance -= OBJECT_SPAWN_INTERVAL + offset;
+        self.next_obj = next_obj;
+    }
+
+    /// Places a modded obstacle in the lanes of one of its patterns.
+    fn place_modded(&mut self, placements: &mut Vec<Placement>, rng: &mut impl Rng, index: usize) {
+        let delta = OBJECT_SPAWN_INTERVAL - self.distance;
+        let modded = &self.modded[index];
+        let Some((pattern, checked)) = self.sample_pattern(
+            &modded.pattern_weights,
+            |i| &modded.lanes[i],
+            &modded.collider,
+            delta,
+            rng,
+        ) else {
+            info!("Skip {} entity", modded.id);
+            return;
+        };
+        self.path = checked;
+
+        for &lane_index in modded.lanes[pattern].iter() {
+            placements.push(Placement::new(
+                PlacedObject::Modded(index),
+                lane_index,
+                0.0,
+                self.spawn_position + delta,
+            ));
+        }
+    }
+}
+
+impl Default for ObjectSchedule {
+    fn default() -> Self {
+        Self::new(
+            &GameplayConfig::default(),
+            &TrainUpgrades::default().collider(),
+            SPAWN_POSITION,
+            Vec::new(),
+        )
+    }
+}
+
+/// Decides when the Tok9 trains come down the track and which lanes they take.
+pub struct Tok9Schedule {
+    remaining_sec: f32,
+}
+
+impl Tok9Schedule {
+    /// Returns the lane and the kind of each train sent down the track, if any are sent this time.
+    pub fn on_advanced(&mut self, rng: &mut impl Rng, elapsed: f32) -> Vec<(usize, Tok9Train)> {
+        self.remaining_sec -= elapsed;
+        if self.remaining_sec > 0.0 {
+            return Vec::new();
+        }
+
+        let index = TOK9_TRAIN_WEIGHTS.sample(rng);
+        let trains = TOK9_TRAIN_POSITION_INDICES[index]
+            .iter()
+            .map(|&lane_index| (lane_index, rng.random::<Tok9Train>()))
+            .collect();
+
+        let offset = rng.random_range(TOK9_TRAIN_OFFSET);
+        self.remaining_sec = TOK9_TRAIN_CYCLE + offset;
+        trains
+    }
+}
+
+impl Default for Tok9Schedule {
+    fn default() -> Self {
+        Self {
+            remaining_sec: TOK9_TRAIN_INIT_CYCLE,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use super::*;
+
+    fn play(seed: u64, frames: usize) -> Vec<Placement> {
+        let mut schedule = ObjectSchedule::default();
+        let mut rng = StdRng::seed_from_u64(seed);
+        let forward_move = ForwardMovement::new(MAX_PLAYER_SPEED);
+        (0..frames)
+            .flat_map(|_| schedule.on_advanced(&forward_move, &mut rng, 1.0 / 60.0))
+            .collect()
+    }
+
+    #[test]
+    fn places_the_same_objects_for_the_same_seed() {
+        let placements = play(7, 3600);
+        assert!(!placements.is_empty());
+        assert_eq!(placements, play(7, 3600));
+        assert_ne!(placements, play(8, 3600));
+    }
+}
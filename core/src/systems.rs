@@ -0,0 +1,73 @@
+use bevy::prelude::*;
+
+use super::*;
+
+/// Counts down the damage and invincibility of the player.
+pub fn update_player_state(mut state: ResMut<CurrentState>, time: Res<Time>) {
+    match &mut *state {
+        CurrentState::Attacked { remaining } => {
+            *remaining -= time.delta_secs();
+            if *remaining <= 0.0 {
+                *state = CurrentState::Idle;
+            }
+        }
+        CurrentState::Invincible { remaining } => {
+            *remaining -= time.delta_secs();
+            if *remaining <= 0.0 {
+                *state = CurrentState::Attacked {
+                    remaining: ATTACKED_DURATION,
+                };
+            }
+        }
+        _ => { /* empty */ }
+    }
+}
+
+/// Counts down the damage of the player once the run has ended, leaving any invincibility
+/// as it was when the run ended.
+pub fn update_player_damage(mut state: ResMut<CurrentState>, time: Res<Time>) {
+    if let CurrentState::Attacked { remaining } = &mut *state {
+        *remaining -= time.delta_secs();
+        if *remaining <= 0.0 {
+            *state = CurrentState::Idle;
+        }
+    }
+}
+
+pub fn update_score(
+    mut score: ResMut<CurrentScore>,
+    player_query: Query<&ForwardMovement, With<Player>>,
+    time_scale: Res<TimeScale>,
+    time: Res<Time>,
+) {
+    let elapsed = time.delta_secs() * time_scale.get();
+    if let Ok(forward_move) = player_query.single() {
+        score.on_advanced(forward_move, elapsed);
+    }
+}
+
+/// Records when the run passes each split, to compare it with the pace of the best run.
+pub fn update_run_pace(
+    mut pace: ResMut<RunPace>,
+    player_query: Query<&ForwardMovement, With<Player>>,
+    play_time: Res<PlayTime>,
+    time_scale: Res<TimeScale>,
+    time: Res<Time>,
+) {
+    let elapsed = time.delta_secs() * time_scale.get();
+    if let Ok(forward_move) = player_query.single() {
+        pace.on_advanced(forward_move, elapsed, &play_time);
+    }
+}
+
+/// Burns the fuel of the train. No fuel is used while the player is invincible.
+pub fn consume_train_fuel(
+    mut fuel: ResMut<TrainFuel>,
+    state: Res<CurrentState>,
+    config: Res<GameplayConfig>,
+    time: Res<Time>,
+) {
+    if !state.is_invincible() {
+        fuel.dec(time.delta_secs() * config.fuel_usage);
+    }
+}
@@ -0,0 +1,163 @@
+//! Runs the fuel and score systems without a window, and checks the invariants
+//! the tuning of the gameplay constants must keep.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use proptest::prelude::*;
+use shupogaki_core::*;
+
+/// The time, in seconds, a full tank lasts in a run without damage.
+const DESIGNED_TANK_DURATION: f32 = 20.0;
+const FRAME: Duration = Duration::from_micros(16_667);
+
+fn new_run() -> App {
+    let mut app = App::new();
+    app.init_resource::<Time>()
+        .init_resource::<CurrentScore>()
+        .init_resource::<TrainFuel>()
+        .init_resource::<CurrentState>()
+        .init_resource::<TimeScale>()
+        .init_resource::<GameplayConfig>()
+        .add_systems(
+            Update,
+            (update_player_state, update_score, consume_train_fuel),
+        );
+    app.world_mut()
+        .spawn((Player, ForwardMovement::new(MIN_PLAYER_SPEED)));
+    app
+}
+
+fn step(app: &mut App) {
+    app.world_mut().resource_mut::<Time>().advance_by(FRAME);
+    app.update();
+}
+
+fn fuel(app: &App) -> f32 {
+    app.world().resource::<TrainFuel>().get()
+}
+
+fn score(app: &App) -> u32 {
+    app.world().resource::<CurrentScore>().get()
+}
+
+#[test]
+fn full_tank_lasts_designed_duration() {
+    let mut app = new_run();
+    let mut elapsed = 0.0;
+    while !app.world().resource::<TrainFuel>().is_empty() {
+        step(&mut app);
+        elapsed += FRAME.as_secs_f32();
+        assert!(
+            elapsed < 2.0 * DESIGNED_TANK_DURATION,
+            "The fuel never ran out"
+        );
+    }
+
+    assert!(
+        (elapsed - DESIGNED_TANK_DURATION).abs() <= 2.0 * FRAME.as_secs_f32(),
+        "A full tank lasted {} seconds",
+        elapsed
+    );
+}
+
+#[test]
+fn invincibility_does_not_consume_fuel() {
+    let mut app = new_run();
+    *app.world_mut().resource_mut::<CurrentState>() = CurrentState::Invincible {
+        remaining: INVINCIBLE_DURATION,
+    };
+
+    while app.world().resource::<CurrentState>().is_invincible() {
+        step(&mut app);
+        // The state changes during the update, so the frame it ends on still uses no fuel.
+        assert_eq!(fuel(&app), FUEL_LIMITS);
+    }
+
+    step(&mut app);
+    assert!(fuel(&app) < FUEL_LIMITS);
+}
+
+#[derive(Debug, Clone)]
+enum Action {
+    Play { frames: u32 },
+    Refuel,
+    Hit { damage: f32 },
+    BecomeInvincible,
+    CollectBell,
+    SlowDown,
+    ChangeSpeed { speed: f32 },
+}
+
+fn action() -> impl Strategy<Value = Action> {
+    prop_oneof![
+        (1u32..=60).prop_map(|frames| Action::Play { frames }),
+        Just(Action::Refuel),
+        prop::sample::select(vec![BARRICADE_DAMAGE, STONE_DAMAGE, TOK9_TRAIN_DAMAGE])
+            .prop_map(|damage| Action::Hit { damage }),
+        Just(Action::BecomeInvincible),
+        Just(Action::CollectBell),
+        Just(Action::SlowDown),
+        (MIN_PLAYER_SPEED..=INVINCIBLE_SPEED).prop_map(|speed| Action::ChangeSpeed { speed }),
+    ]
+}
+
+/// Applies the action to the run, and returns the score before and after each of its frames.
+fn apply(app: &mut App, action: &Action) -> Vec<u32> {
+    let mut scores = vec![score(app)];
+    let world = app.world_mut();
+    match *action {
+        Action::Play { frames } => {
+            for _ in 0..frames {
+                step(app);
+                scores.push(score(app));
+            }
+        }
+        Action::Refuel => {
+            world.resource_mut::<TrainFuel>().inc(FUEL_HEALING);
+        }
+        Action::Hit { damage } => {
+            world.resource_mut::<TrainFuel>().dec(damage);
+        }
+        Action::BecomeInvincible => {
+            *world.resource_mut::<CurrentState>() = CurrentState::Invincible {
+                remaining: INVINCIBLE_DURATION,
+            }
+        }
+        Action::CollectBell => world.resource_mut::<CurrentScore>().inc(BELL_POINT),
+        Action::SlowDown => world.resource_mut::<TimeScale>().slow_down(CLOCK_DURATION),
+        Action::ChangeSpeed { speed } => {
+            let mut query = world.query_filtered::<&mut ForwardMovement, With<Player>>();
+            query.single_mut(world).unwrap().set(speed);
+        }
+    }
+    scores.push(score(app));
+    scores
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn fuel_stays_within_limits(actions in prop::collection::vec(action(), 1..40)) {
+        let mut app = new_run();
+        for action in actions.iter() {
+            apply(&mut app, action);
+            let fuel = fuel(&app);
+            prop_assert!((0.0..=FUEL_LIMITS).contains(&fuel), "Fuel was {} after {:?}", fuel, action);
+        }
+    }
+
+    #[test]
+    fn score_never_decreases(actions in prop::collection::vec(action(), 1..40)) {
+        let mut app = new_run();
+        let mut previous = score(&app);
+        for action in actions.iter() {
+            for score in apply(&mut app, action) {
+                prop_assert!(score >= previous, "Score dropped from {} to {} on {:?}", previous, score, action);
+                prop_assert!(score <= SCORE_LIMITS);
+                previous = score;
+            }
+        }
+    }
+}
@@ -0,0 +1,40 @@
+//! Checks how lane switch presses are paced by the input delay.
+
+use shupogaki_core::*;
+
+#[test]
+fn drops_presses_before_the_delay_has_expired() {
+    let mut delay = InputDelay::new(INPUT_DELAY_TIME);
+    delay.reset();
+    delay.queue(true);
+    delay.on_advanced(INPUT_DELAY_TIME);
+    assert!(delay.is_expired());
+    assert_eq!(delay.take_queued(), None);
+}
+
+#[test]
+fn assist_mode_keeps_presses_shortly_before_the_delay_has_expired() {
+    let config = GameplayConfig::assist();
+    let mut delay = InputDelay::new(config.input_delay).with_buffer(config.input_buffer);
+    assert_eq!(config.input_delay, INPUT_DELAY_TIME);
+
+    delay.reset();
+    delay.on_advanced(INPUT_DELAY_TIME - ASSIST_INPUT_BUFFER_TIME * 0.5);
+    delay.queue(false);
+    assert_eq!(delay.take_queued(), None);
+
+    delay.on_advanced(ASSIST_INPUT_BUFFER_TIME * 0.5);
+    assert_eq!(delay.take_queued(), Some(false));
+    assert_eq!(delay.take_queued(), None);
+}
+
+#[test]
+fn assist_mode_forgets_presses_long_before_the_delay_has_expired() {
+    let config = GameplayConfig::assist();
+    let mut delay = InputDelay::new(config.input_delay).with_buffer(config.input_buffer);
+
+    delay.reset();
+    delay.queue(true);
+    delay.on_advanced(INPUT_DELAY_TIME);
+    assert_eq!(delay.take_queued(), None);
+}
@@ -0,0 +1,72 @@
+//! Runs the play time and pace systems without a window, and compares runs at the same distance.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use shupogaki_core::*;
+
+const FRAME: Duration = Duration::from_micros(16_667);
+
+/// Starts a run that keeps its play time and pace, at the given speed.
+fn new_paced_run(speed: f32) -> App {
+    let mut app = App::new();
+    app.init_resource::<Time>()
+        .init_resource::<TimeScale>()
+        .init_resource::<PlayTime>()
+        .init_resource::<RunPace>()
+        .add_systems(
+            Update,
+            (
+                |mut play_time: ResMut<PlayTime>, time: Res<Time>| play_time.tick(&time),
+                update_run_pace,
+            )
+                .chain(),
+        );
+    app.world_mut().spawn((Player, ForwardMovement::new(speed)));
+    app
+}
+
+fn step(app: &mut App) {
+    app.world_mut().resource_mut::<Time>().advance_by(FRAME);
+    app.update();
+}
+
+fn pace_delta(app: &App, best: &PaceCurve) -> Option<f32> {
+    let world = app.world();
+    world
+        .resource::<RunPace>()
+        .delta(best, world.resource::<PlayTime>())
+}
+
+#[test]
+fn pace_delta_compares_runs_at_the_same_distance() {
+    let mut best_run = new_paced_run(MIN_PLAYER_SPEED);
+    for _ in 0..600 {
+        step(&mut best_run);
+    }
+    let best = best_run.world().resource::<RunPace>().curve().clone();
+    assert!(!best.splits.is_empty());
+
+    // A run at the same speed keeps pace with the best run.
+    let mut same_run = new_paced_run(MIN_PLAYER_SPEED);
+    let mut faster_run = new_paced_run(MAX_PLAYER_SPEED);
+    for _ in 0..300 {
+        step(&mut same_run);
+        step(&mut faster_run);
+        let delta = pace_delta(&same_run, &best).unwrap();
+        assert!(
+            delta.abs() <= 2.0 * FRAME.as_secs_f32(),
+            "Off pace by {delta}"
+        );
+    }
+
+    // A faster run reaches the same distance sooner.
+    let delta = pace_delta(&faster_run, &best).unwrap();
+    assert!(delta < -1.0, "Only ahead by {delta}");
+
+    // There is nothing to compare with past the end of the best run.
+    for _ in 0..600 {
+        step(&mut faster_run);
+    }
+    assert_eq!(pace_delta(&faster_run, &best), None);
+}